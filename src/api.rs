@@ -0,0 +1,365 @@
+//! Library entry point for running depup without the CLI
+//!
+//! This module provides:
+//! - `RunOptions`, a plain options struct independent of clap
+//! - `Depup` and its builder for running on a project directory
+//! - Conversions between `RunOptions` and `CliArgs`
+
+use crate::cli::CliArgs;
+use crate::domain::Language;
+use crate::orchestrator::{Orchestrator, OrchestratorError, OrchestratorResult};
+use crate::registry::RegistryAdapter;
+use crate::update::PrereleaseChannel;
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Options for a programmatic run
+///
+/// The defaults match running `depup` with no flags, except that nothing is
+/// written unless `dry_run` is turned off explicitly.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    /// Report updates without writing manifests
+    pub dry_run: bool,
+    /// Languages to process (empty means all)
+    pub languages: Vec<Language>,
+    /// Packages to exclude from updates
+    pub exclude: Vec<String>,
+    /// If non-empty, only update these packages
+    pub only: Vec<String>,
+    /// Include pinned versions in updates
+    pub include_pinned: bool,
+    /// Update unconstrained and range constraints instead of skipping them
+    pub force_complex: bool,
+    /// Skip indirect (transitive) dependencies
+    pub only_direct: bool,
+    /// Offer the latest tagged release for Go pseudo-versions
+    pub upgrade_pseudo: bool,
+    /// Prerelease matching when a dependency is already on a prerelease
+    pub prerelease_channel: PrereleaseChannel,
+    /// Minimum age for versions to be considered
+    pub min_age: Option<Duration>,
+    /// Minimum age for dev dependencies, overriding `min_age` when set
+    pub min_age_dev: Option<Duration>,
+    /// Only consider versions released on or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Stop fetching after this long, skipping the remaining packages
+    pub deadline: Option<Duration>,
+    /// Fallback registry base URLs per language, tried in order
+    pub registry_mirrors: Vec<(Language, String)>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: true,
+            languages: Vec::new(),
+            exclude: Vec::new(),
+            only: Vec::new(),
+            include_pinned: false,
+            force_complex: false,
+            only_direct: false,
+            upgrade_pseudo: false,
+            prerelease_channel: PrereleaseChannel::Any,
+            min_age: None,
+            min_age_dev: None,
+            since: None,
+            deadline: None,
+            registry_mirrors: Vec::new(),
+        }
+    }
+}
+
+impl RunOptions {
+    /// Build the equivalent CLI arguments for a run on `path`
+    ///
+    /// Progress display is turned off, since there is no terminal to draw on.
+    pub fn to_cli_args(&self, path: &Path) -> CliArgs {
+        let mut args = CliArgs::parse_from(["depup", "--quiet"]);
+        args.path = path.to_path_buf();
+        args.dry_run = self.dry_run;
+        for language in &self.languages {
+            match language {
+                Language::Node => args.node = true,
+                Language::Python => args.python = true,
+                Language::Rust => args.rust_lang = true,
+                Language::Go => args.go = true,
+                Language::Ruby => args.ruby = true,
+                Language::Php => args.php = true,
+                Language::Java => args.java = true,
+                Language::Deno => args.deno = true,
+                Language::Nim => args.nim = true,
+                Language::Docker => args.docker = true,
+                Language::R => args.r = true,
+                Language::Crystal => args.crystal = true,
+                Language::PreCommit => args.pre_commit = true,
+            }
+        }
+        args.exclude = self.exclude.clone();
+        args.only = self.only.clone();
+        args.include_pinned = self.include_pinned;
+        args.force_complex = self.force_complex;
+        args.only_direct = self.only_direct;
+        args.upgrade_pseudo = self.upgrade_pseudo;
+        args.prerelease_channel = self.prerelease_channel;
+        args.age = self.min_age;
+        args.age_dev = self.min_age_dev;
+        args.since = self.since;
+        args.deadline = self.deadline.map(|d| d.as_secs().max(1));
+        args.registry_mirrors = self.registry_mirrors.clone();
+        args
+    }
+}
+
+impl From<&CliArgs> for RunOptions {
+    fn from(args: &CliArgs) -> Self {
+        Self {
+            dry_run: args.dry_run,
+            languages: Language::all()
+                .iter()
+                .copied()
+                .filter(|language| match language {
+                    Language::Node => args.node,
+                    Language::Python => args.python,
+                    Language::Rust => args.rust_lang,
+                    Language::Go => args.go,
+                    Language::Ruby => args.ruby,
+                    Language::Php => args.php,
+                    Language::Java => args.java,
+                    Language::Deno => args.deno,
+                    Language::Nim => args.nim,
+                    Language::Docker => args.docker,
+                    Language::R => args.r,
+                    Language::Crystal => args.crystal,
+                    Language::PreCommit => args.pre_commit,
+                })
+                .collect(),
+            exclude: args.exclude.clone(),
+            only: args.only.clone(),
+            include_pinned: args.include_pinned,
+            force_complex: args.force_complex,
+            only_direct: args.only_direct,
+            upgrade_pseudo: args.upgrade_pseudo,
+            prerelease_channel: args.prerelease_channel,
+            min_age: args.age,
+            min_age_dev: args.age_dev,
+            since: args.since,
+            deadline: args.deadline.map(Duration::from_secs),
+            registry_mirrors: args.registry_mirrors.clone(),
+        }
+    }
+}
+
+/// Programmatic depup runner
+///
+/// ```no_run
+/// # async fn example() -> Result<(), depup::orchestrator::OrchestratorError> {
+/// let result = depup::Depup::builder()
+///     .dry_run(true)
+///     .exclude(vec!["react".to_string()])
+///     .build()
+///     .run_on("path/to/project")
+///     .await?;
+/// println!("{} update(s)", result.summary.total_updates());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Depup {
+    options: RunOptions,
+    adapters: Vec<Arc<dyn RegistryAdapter>>,
+}
+
+impl Depup {
+    /// Start building a runner with default options
+    pub fn builder() -> DepupBuilder {
+        DepupBuilder::default()
+    }
+
+    /// Create a runner from options
+    pub fn new(options: RunOptions) -> Self {
+        Self {
+            options,
+            adapters: Vec::new(),
+        }
+    }
+
+    /// Options this runner was built with
+    pub fn options(&self) -> &RunOptions {
+        &self.options
+    }
+
+    /// Check the manifests under `path` and apply updates unless running dry
+    pub async fn run_on(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<OrchestratorResult, OrchestratorError> {
+        let mut orchestrator = Orchestrator::new(self.options.to_cli_args(path.as_ref()))?;
+        for adapter in &self.adapters {
+            orchestrator = orchestrator.with_adapter(adapter.clone());
+        }
+        Ok(orchestrator.run_with_progress(false).await)
+    }
+}
+
+/// Builder for `Depup`
+#[derive(Default)]
+pub struct DepupBuilder {
+    options: RunOptions,
+    adapters: Vec<Arc<dyn RegistryAdapter>>,
+}
+
+impl DepupBuilder {
+    /// Replace all options at once
+    pub fn options(mut self, options: RunOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Report updates without writing manifests (default: true)
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.options.dry_run = dry_run;
+        self
+    }
+
+    /// Only process these languages
+    pub fn languages(mut self, languages: Vec<Language>) -> Self {
+        self.options.languages = languages;
+        self
+    }
+
+    /// Exclude these packages from updates
+    pub fn exclude(mut self, packages: Vec<String>) -> Self {
+        self.options.exclude = packages;
+        self
+    }
+
+    /// Only update these packages
+    pub fn only(mut self, packages: Vec<String>) -> Self {
+        self.options.only = packages;
+        self
+    }
+
+    /// Include pinned versions in updates
+    pub fn include_pinned(mut self, include: bool) -> Self {
+        self.options.include_pinned = include;
+        self
+    }
+
+    /// Update unconstrained and range constraints instead of skipping them
+    pub fn force_complex(mut self, force: bool) -> Self {
+        self.options.force_complex = force;
+        self
+    }
+
+    /// Skip indirect (transitive) dependencies
+    pub fn only_direct(mut self, only_direct: bool) -> Self {
+        self.options.only_direct = only_direct;
+        self
+    }
+
+    /// Only consider versions at least this old
+    pub fn min_age(mut self, age: Duration) -> Self {
+        self.options.min_age = Some(age);
+        self
+    }
+
+    /// Fetch versions for the adapter's language from `adapter` instead of the registry
+    pub fn adapter(mut self, adapter: Arc<dyn RegistryAdapter>) -> Self {
+        self.adapters.push(adapter);
+        self
+    }
+
+    /// Finish building the runner
+    pub fn build(self) -> Depup {
+        Depup {
+            options: self.options,
+            adapters: self.adapters,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RegistryError;
+    use crate::update::VersionInfo;
+    use async_trait::async_trait;
+    use tempfile::TempDir;
+
+    /// Registry adapter answering every npm lookup with a fixed version
+    struct FixedNpmAdapter;
+
+    #[async_trait]
+    impl RegistryAdapter for FixedNpmAdapter {
+        fn language(&self) -> Language {
+            Language::Node
+        }
+
+        fn registry_name(&self) -> &'static str {
+            "fixed"
+        }
+
+        async fn fetch_versions(&self, _package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+            let released = Utc::now() - chrono::Duration::days(30);
+            Ok(vec![
+                VersionInfo::new("1.0.0", released),
+                VersionInfo::new("2.1.0", released),
+            ])
+        }
+    }
+
+    #[test]
+    fn test_run_options_cli_args_round_trip() {
+        let options = RunOptions {
+            dry_run: false,
+            languages: vec![Language::Node, Language::Go],
+            exclude: vec!["react".to_string()],
+            min_age: Some(Duration::from_secs(86400)),
+            deadline: Some(Duration::from_secs(30)),
+            ..RunOptions::default()
+        };
+
+        let args = options.to_cli_args(Path::new("/tmp/project"));
+        assert!(args.node && args.go && !args.python);
+        assert!(!args.dry_run);
+        assert!(args.quiet);
+        assert_eq!(args.path, Path::new("/tmp/project"));
+        assert_eq!(args.deadline, Some(30));
+
+        let back = RunOptions::from(&args);
+        assert_eq!(back.languages, vec![Language::Node, Language::Go]);
+        assert_eq!(back.exclude, vec!["react".to_string()]);
+        assert_eq!(back.min_age, Some(Duration::from_secs(86400)));
+        assert!(!back.dry_run);
+    }
+
+    #[tokio::test]
+    async fn test_run_on_with_mock_adapter() {
+        let dir = TempDir::new().unwrap();
+        let manifest = r#"{"dependencies": {"left-pad": "^1.0.0"}}"#;
+        std::fs::write(dir.path().join("package.json"), manifest).unwrap();
+
+        let depup = Depup::builder()
+            .languages(vec![Language::Node])
+            .adapter(Arc::new(FixedNpmAdapter))
+            .build();
+        assert!(depup.options().dry_run);
+
+        let result = depup.run_on(dir.path()).await.unwrap();
+        assert!(result.errors.is_empty());
+        assert_eq!(result.summary.total_updates(), 1);
+        let manifest_result = &result.summary.manifests[0];
+        assert_eq!(manifest_result.language, Language::Node);
+        assert!(manifest_result.updates().any(|u| matches!(
+            u,
+            crate::domain::UpdateResult::Update { new_version, .. } if new_version == "2.1.0"
+        )));
+
+        // Dry run leaves the manifest untouched
+        let content = std::fs::read_to_string(dir.path().join("package.json")).unwrap();
+        assert_eq!(content, manifest);
+    }
+}
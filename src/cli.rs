@@ -1,40 +1,108 @@
 //! CLI argument parsing module for depup
 
+use crate::domain::Language;
+use crate::output::Template;
+use crate::registry::expand_registry_url;
+use crate::update::{PrereleaseChannel, UpdateGroup};
+use chrono::{DateTime, NaiveDate, Utc};
 use clap::{ArgAction, Parser};
 use std::path::PathBuf;
 use std::time::Duration;
 
-/// Parse duration string in format: Nd (days), Nw (weeks), Nm (months)
-fn parse_duration(s: &str) -> Result<Duration, String> {
+/// Accepted --age units, longest suffix first so `mo` wins over `m`
+const DURATION_UNITS: &[(&str, u64)] = &[
+    ("mo", 30 * 24 * 60 * 60), // months (30 days)
+    ("s", 1),                  // seconds
+    ("h", 60 * 60),            // hours
+    ("d", 24 * 60 * 60),       // days
+    ("w", 7 * 24 * 60 * 60),   // weeks
+    ("m", 30 * 24 * 60 * 60),  // months (30 days), kept for compatibility
+    ("y", 365 * 24 * 60 * 60), // years (365 days)
+];
+
+/// Explanation of the duration format appended to every parse error
+const DURATION_HELP: &str = "expected a whole number followed by a unit: \
+s (seconds), h (hours), d (days), w (weeks), m or mo (months, 30 days), y (years, 365 days); \
+e.g., 12h, 3d, 2w, 1mo";
+
+/// Parse duration string in format: N<unit> (e.g., 12h, 3d, 2w, 1mo)
+pub(crate) fn parse_duration(s: &str) -> Result<Duration, String> {
     let s = s.trim();
     if s.is_empty() {
-        return Err("empty duration string".to_string());
+        return Err(format!("empty duration: {}", DURATION_HELP));
     }
 
-    let (num_str, unit) = if let Some(n) = s.strip_suffix('d') {
-        (n, 'd')
-    } else if let Some(n) = s.strip_suffix('w') {
-        (n, 'w')
-    } else if let Some(n) = s.strip_suffix('m') {
-        (n, 'm')
-    } else {
-        return Err(format!("invalid duration format: {}", s));
+    let Some((num_str, seconds_per_unit)) = DURATION_UNITS
+        .iter()
+        .find_map(|(unit, secs)| s.strip_suffix(unit).map(|n| (n, *secs)))
+    else {
+        return Err(format!("invalid duration '{}': {}", s, DURATION_HELP));
     };
 
     let num: u64 = num_str
         .parse()
-        .map_err(|_| format!("invalid number in duration: {}", num_str))?;
+        .map_err(|_| format!("invalid duration '{}': {}", s, DURATION_HELP))?;
 
-    let seconds = match unit {
-        'd' => num * 24 * 60 * 60,      // days
-        'w' => num * 7 * 24 * 60 * 60,  // weeks
-        'm' => num * 30 * 24 * 60 * 60, // months (30 days)
-        _ => unreachable!(),
-    };
+    let seconds = num
+        .checked_mul(seconds_per_unit)
+        .ok_or_else(|| format!("duration '{}' is too large", s))?;
 
     Ok(Duration::from_secs(seconds))
 }
 
+/// Parse date in format: YYYY-MM-DD (start of day, UTC)
+fn parse_since(s: &str) -> Result<DateTime<Utc>, String> {
+    let date = NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").map_err(|_| {
+        format!(
+            "invalid date '{}': expected YYYY-MM-DD, e.g., 2024-06-01",
+            s
+        )
+    })?;
+    Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+}
+
+/// Parse registry mirror in format: LANG=URL (e.g., node=https://registry.npmmirror.com)
+///
+/// `${ENV_VAR}` placeholders in the URL are expanded here, at startup.
+fn parse_registry_mirror(s: &str) -> Result<(Language, String), String> {
+    let (lang, url) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid registry mirror '{}': expected LANG=URL", s))?;
+
+    let language =
+        Language::from_name(lang).ok_or_else(|| format!("unknown language: {}", lang.trim()))?;
+
+    let url = expand_registry_url(url.trim())?;
+    let url = url.trim_end_matches('/');
+    if url.is_empty() {
+        return Err(format!("empty mirror URL for {}", lang.trim()));
+    }
+
+    Ok((language, url.to_string()))
+}
+
+/// Parse a per-host concurrency limit in format: HOST=N (e.g., crates.io=2)
+fn parse_host_concurrency(s: &str) -> Result<(String, usize), String> {
+    let (host, limit) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid host concurrency '{}': expected HOST=N", s))?;
+
+    let host = host.trim();
+    if host.is_empty() {
+        return Err(format!("empty host in '{}'", s));
+    }
+
+    let limit: usize = limit
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid concurrency limit: {}", limit.trim()))?;
+    if limit == 0 {
+        return Err(format!("concurrency limit for {} must be at least 1", host));
+    }
+
+    Ok((host.to_string(), limit))
+}
+
 /// Multi-language dependency updater
 #[derive(Parser, Debug, Clone)]
 #[command(
@@ -47,6 +115,18 @@ pub struct CliArgs {
     #[arg(short = 'V', long = "version")]
     pub print_version: bool,
 
+    /// List supported languages with their manifest files and registries (honors --json)
+    #[arg(long)]
+    pub list_languages: bool,
+
+    /// Report outdated runtime pins from .tool-versions (Node.js, Go) instead of updating
+    #[arg(long)]
+    pub runtimes: bool,
+
+    /// Report dependencies with no import found in source (Node.js, Rust) instead of updating
+    #[arg(long, conflicts_with = "runtimes")]
+    pub prune_report: bool,
+
     /// Target directory (default: current directory)
     #[arg(default_value = ".")]
     pub path: PathBuf,
@@ -55,19 +135,45 @@ pub struct CliArgs {
     #[arg(short = 'C', long = "cd", value_name = "DIR")]
     pub directory: Option<PathBuf>,
 
+    /// Process exactly the manifests listed in FILE, one path per line, instead of
+    /// detecting them (`-` reads the list from stdin, e.g. `git ls-files | depup --manifests-from -`)
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["runtimes", "prune_report"])]
+    pub manifests_from: Option<PathBuf>,
+
     // General options
     /// Dry run mode - show what would be updated without making changes
     #[arg(short = 'n', long)]
     pub dry_run: bool,
 
+    /// Like `git diff --exit-code`: implies --dry-run and exits 1 if any manifest would change
+    /// (0 if none, 2 on errors)
+    #[arg(long)]
+    pub diff_exit_code: bool,
+
+    /// Exit 3 without checking or writing anything if any manifest fails to parse
+    #[arg(long, conflicts_with = "max_parallel_manifests")]
+    pub strict_parse: bool,
+
     /// Enable verbose output
     #[arg(long)]
     pub verbose: bool,
 
+    /// Check that every parsed version spec re-renders to its original text and report mismatches
+    #[arg(long)]
+    pub validate: bool,
+
     /// Enable quiet mode - minimal output
     #[arg(short, long)]
     pub quiet: bool,
 
+    /// Hide per-package fetch failures and report them as one "could not be resolved" count
+    #[arg(long)]
+    pub quiet_errors: bool,
+
+    /// Leave already-latest packages out of the verbose skip listing (they are still counted)
+    #[arg(long)]
+    pub hide_latest: bool,
+
     // Language filters
     /// Update only Node.js (package.json) dependencies
     #[arg(long)]
@@ -97,6 +203,30 @@ pub struct CliArgs {
     #[arg(long)]
     pub java: bool,
 
+    /// Update only Deno (deno.json) dependencies
+    #[arg(long)]
+    pub deno: bool,
+
+    /// Update only Nim (*.nimble) dependencies
+    #[arg(long)]
+    pub nim: bool,
+
+    /// Update only Docker base images (Dockerfile); Dockerfiles are skipped without this flag
+    #[arg(long)]
+    pub docker: bool,
+
+    /// Update only R dependencies (renv.lock)
+    #[arg(long)]
+    pub r: bool,
+
+    /// Update only Crystal dependencies (shard.yml)
+    #[arg(long)]
+    pub crystal: bool,
+
+    /// Update only pre-commit hook revisions (.pre-commit-config.yaml)
+    #[arg(long)]
+    pub pre_commit: bool,
+
     // Package filters
     /// Exclude specific packages from update (can be specified multiple times)
     #[arg(long, action = ArgAction::Append)]
@@ -106,15 +236,149 @@ pub struct CliArgs {
     #[arg(long, action = ArgAction::Append)]
     pub only: Vec<String>,
 
+    /// Update only packages in a namespace, e.g. `@mycompany/*` or `github.com/myorg`
+    /// (can be specified multiple times)
+    #[arg(long = "scope", value_name = "PATTERN", action = ArgAction::Append)]
+    pub scopes: Vec<String>,
+
+    /// Process a single package only; other dependencies are dropped before any registry lookup
+    #[arg(long, value_name = "NAME")]
+    pub package: Option<String>,
+
     /// Include pinned versions in update
     #[arg(long)]
     pub include_pinned: bool,
 
+    /// Also update unconstrained dependencies and range constraints (e.g., `>= 0.18, < 2.0`),
+    /// which are skipped as complex by default
+    #[arg(long)]
+    pub force_complex: bool,
+
+    /// Add this operator to bare versions (e.g., `4.17.21` -> `^4.17.21`) in package.json
+    /// and Cargo.toml, whether or not they are updated
+    #[arg(long, value_name = "OP", value_parser = ["^", "~"], conflicts_with = "drift")]
+    pub normalize_prefix: Option<String>,
+
+    /// Skip indirect (transitive) dependencies, such as Go `// indirect` requirements
+    #[arg(long)]
+    pub only_direct: bool,
+
+    /// Only update dependencies with a known vulnerability (OSV), to the lowest fixed version
+    #[arg(long)]
+    pub only_vulnerable: bool,
+
+    /// Only report lock drift: dependencies whose manifest range already allows a newer
+    /// version than the lock file resolves (implies --dry-run)
+    #[arg(long, conflicts_with = "only_vulnerable")]
+    pub drift: bool,
+
+    /// Only check dependencies resolved in the lock file (Cargo.lock, package-lock.json),
+    /// ignoring manifest entries that are not installed
+    #[arg(long)]
+    pub check_only_installed: bool,
+
+    /// Offer the latest tagged release for Go pseudo-versions (v0.0.0-<date>-<commit>)
+    #[arg(long)]
+    pub upgrade_pseudo: bool,
+
+    /// Prerelease matching when already on a prerelease: strict keeps to the current
+    /// channel (e.g., canary) or stable, any allows every newer prerelease
+    #[arg(long, value_name = "MODE", default_value = "any", value_parser = PrereleaseChannel::parse)]
+    pub prerelease_channel: PrereleaseChannel,
+
+    /// Offer prerelease versions (e.g., Maven milestones) even for dependencies on a
+    /// stable release
+    #[arg(long)]
+    pub include_prerelease: bool,
+
+    /// When already on a prerelease, offer the newest stable release at or above its
+    /// base version (e.g., 1.0.0-rc.1 -> 1.0.0) instead of newer prereleases
+    #[arg(long)]
+    pub prefer_stable: bool,
+
+    /// Report packages matching NAME=GLOB,GLOB as one update group when all of them can update
+    /// (can be specified multiple times)
+    #[arg(long = "group", value_name = "NAME=GLOBS", value_parser = UpdateGroup::parse, action = ArgAction::Append)]
+    pub groups: Vec<UpdateGroup>,
+
+    /// Skip every update in a --group if any member can't be updated
+    #[arg(long)]
+    pub group_all_or_nothing: bool,
+
+    // Registry options
+    /// Fallback registry mirror as LANG=URL, tried in order when the primary fails;
+    /// `${ENV_VAR}` placeholders are expanded (can be specified multiple times)
+    #[arg(long = "registry-mirror", value_name = "LANG=URL", value_parser = parse_registry_mirror, action = ArgAction::Append)]
+    pub registry_mirrors: Vec<(Language, String)>,
+
+    /// Only contact these registry hosts; other requests fail before being sent
+    /// (can be specified multiple times)
+    #[arg(long = "allow-host", value_name = "HOST", action = ArgAction::Append)]
+    pub allowed_hosts: Vec<String>,
+
+    /// Maximum concurrent requests to each registry host without its own limit
+    /// (default: 10; 0 allows one per dependency, up to 256)
+    #[arg(long, value_name = "N")]
+    pub concurrency: Option<usize>,
+
+    /// Maximum concurrent requests to a registry host as HOST=N (e.g., crates.io=2)
+    /// (can be specified multiple times)
+    #[arg(long = "concurrency-per-host", value_name = "HOST=N", value_parser = parse_host_concurrency, action = ArgAction::Append)]
+    pub host_concurrency: Vec<(String, usize)>,
+
+    /// Reject plain-HTTP registry URLs
+    #[arg(long)]
+    pub strict_tls: bool,
+
+    /// npm dist-tag to follow (e.g., latest, next); newer versions than the tag are ignored,
+    /// and prereleases up to the tag are offered even to dependencies on a stable version
+    #[arg(long, value_name = "TAG")]
+    pub npm_tag: Option<String>,
+
+    /// Look for Go modules' later major versions (/v2, /v3, ...) and rewrite the module path
+    #[arg(long)]
+    pub go_major_upgrades: bool,
+
     // Age filter
-    /// Only update to versions released at least this long ago (e.g., 2w, 10d, 1m)
+    /// Only update to versions released at least this long ago (e.g., 12h, 10d, 2w, 1mo)
     #[arg(long, value_parser = parse_duration)]
     pub age: Option<Duration>,
 
+    /// Minimum age for dev dependencies, overriding --age for them (e.g., 2d)
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub age_dev: Option<Duration>,
+
+    /// Only consider versions released on or after this date (YYYY-MM-DD)
+    #[arg(long, value_name = "YYYY-MM-DD", value_parser = parse_since)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// Give up on registry lookups after this many seconds and report partial results
+    #[arg(long, value_name = "SECS")]
+    pub deadline: Option<u64>,
+
+    /// In verbose mode, list registry lookups that took at least this many milliseconds
+    #[arg(long, value_name = "MS", requires = "verbose")]
+    pub slow_threshold: Option<u64>,
+
+    /// Stream results per manifest with at most N manifests in flight instead of collecting
+    /// everything first (JSON output becomes JSON Lines; no final summary)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..), conflicts_with_all = ["diff", "sarif", "template", "preview_content", "write_summary", "summary_json_to_stderr", "audit"])]
+    pub max_parallel_manifests: Option<u64>,
+
+    /// Report updates in batches of N, in the order they are applied, to split
+    /// large update sets into reviewable chunks
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..), conflicts_with = "max_parallel_manifests")]
+    pub batch_size: Option<u64>,
+
+    /// List an update shared by several manifests (same package, current and new
+    /// version) once with a manifest count instead of repeating it per manifest
+    #[arg(long, conflicts_with = "max_parallel_manifests")]
+    pub dedupe_manifests: bool,
+
+    /// Leave out the summary block after the update listing (text output)
+    #[arg(long, conflicts_with = "quiet")]
+    pub no_summary: bool,
+
     // Output options
     /// Output results in JSON format
     #[arg(long)]
@@ -124,22 +388,92 @@ pub struct CliArgs {
     #[arg(long)]
     pub diff: bool,
 
+    /// Include the full would-be-written content of each manifest in JSON output
+    #[arg(long, requires = "json")]
+    pub preview_content: bool,
+
+    /// Output results as SARIF 2.1.0 for code scanning tools
+    #[arg(long)]
+    pub sarif: bool,
+
+    /// Label 0.x minor bumps (e.g., 0.2 -> 0.3) as major, matching Cargo's caret rules
+    #[arg(long)]
+    pub treat_zerover_minor_as_major: bool,
+
+    /// Print one line per update from a template; placeholders: {file}, {name}, {old},
+    /// {new}, {type}, {date}, {lang} (use {{ and }} for literal braces)
+    #[arg(long, value_name = "FORMAT", value_parser = Template::parse, conflicts_with_all = ["json", "diff", "sarif"])]
+    pub template: Option<Template>,
+
+    /// Fetch GitHub release notes for updated Go modules, Crystal shards and pre-commit
+    /// hooks hosted on github.com (uses GITHUB_TOKEN if set)
+    #[arg(long)]
+    pub notes: bool,
+
+    /// Also write the formatted results to this file (same format as stdout)
+    #[arg(long, value_name = "PATH")]
+    pub write_summary: Option<PathBuf>,
+
+    /// Write a JSON audit record to this file: every dependency examined, the latest
+    /// version seen, the version chosen or why it was skipped, with the run timestamp
+    #[arg(long, value_name = "PATH")]
+    pub audit: Option<PathBuf>,
+
+    /// Also write the results to stderr as one line of JSON, whatever the stdout format
+    #[arg(long)]
+    pub summary_json_to_stderr: bool,
+
     // Install option
     /// Run package manager install after update
     #[arg(long)]
     pub install: bool,
+
+    /// Restore the original manifests if the package manager install fails
+    #[arg(long, requires = "install")]
+    pub rollback_on_install_failure: bool,
+
+    /// Shell command to run in the target directory after updates are written
+    /// (can be specified multiple times; the run fails if any command fails)
+    #[arg(long, value_name = "CMD", action = ArgAction::Append)]
+    pub post_update: Vec<String>,
+
+    // Git options
+    /// Create a branch before updates are written and commit the changed manifests on it
+    #[arg(long, conflicts_with_all = ["dry_run", "drift", "diff_exit_code", "max_parallel_manifests"])]
+    pub git_commit: bool,
+
+    /// Branch to create with --git-commit (default: depup/update-YYYYMMDD)
+    #[arg(long, value_name = "NAME", requires = "git_commit")]
+    pub branch: Option<String>,
+
+    /// Allow --git-commit with uncommitted changes (only the changed manifests are committed)
+    #[arg(long, requires = "git_commit")]
+    pub allow_dirty: bool,
 }
 
 impl CliArgs {
     /// Check if any language filter is specified
     pub fn has_language_filter(&self) -> bool {
-        self.node || self.python || self.rust_lang || self.go || self.ruby || self.php || self.java
+        self.node
+            || self.python
+            || self.rust_lang
+            || self.go
+            || self.ruby
+            || self.php
+            || self.java
+            || self.deno
+            || self.nim
+            || self.docker
+            || self.r
+            || self.crystal
+            || self.pre_commit
     }
 
     /// Check if a specific language should be processed
     pub fn should_process_language(&self, lang: &str) -> bool {
         if !self.has_language_filter() {
-            return true; // No filter means process all
+            // No filter means process all but the opt-in languages
+            return Language::from_name(lang).is_none_or(|language| language.is_default());
         }
         match lang {
             "node" | "nodejs" | "javascript" => self.node,
@@ -149,10 +483,25 @@ impl CliArgs {
             "ruby" => self.ruby,
             "php" => self.php,
             "java" => self.java,
+            "deno" => self.deno,
+            "nim" | "nimble" => self.nim,
+            "docker" | "dockerfile" => self.docker,
+            "r" | "renv" => self.r,
+            "crystal" | "shards" => self.crystal,
+            "pre-commit" | "precommit" => self.pre_commit,
             _ => false,
         }
     }
 
+    /// Returns the configured mirror base URLs for a language, in order
+    pub fn mirrors_for(&self, language: Language) -> Vec<&str> {
+        self.registry_mirrors
+            .iter()
+            .filter(|(lang, _)| *lang == language)
+            .map(|(_, url)| url.as_str())
+            .collect()
+    }
+
     /// Check if a package should be processed based on filters
     pub fn should_process_package(&self, name: &str) -> bool {
         // If --only is specified, only process those packages
@@ -186,11 +535,60 @@ mod tests {
         assert!(!args.go);
         assert!(args.exclude.is_empty());
         assert!(args.only.is_empty());
+        assert!(args.scopes.is_empty());
         assert!(!args.include_pinned);
+        assert!(!args.force_complex);
+        assert!(args.normalize_prefix.is_none());
+        assert!(!args.only_direct);
+        assert!(!args.upgrade_pseudo);
+        assert_eq!(args.prerelease_channel, PrereleaseChannel::Any);
+        assert!(!args.prefer_stable);
         assert!(args.age.is_none());
+        assert!(args.age_dev.is_none());
+        assert!(args.deadline.is_none());
+        assert!(args.slow_threshold.is_none());
         assert!(!args.json);
         assert!(!args.diff);
+        assert!(!args.sarif);
+        assert!(!args.notes);
+        assert!(args.template.is_none());
+        assert!(!args.list_languages);
+        assert!(args.allowed_hosts.is_empty());
+        assert!(!args.strict_tls);
+        assert!(args.npm_tag.is_none());
+        assert!(!args.treat_zerover_minor_as_major);
+        assert!(args.post_update.is_empty());
+        assert!(!args.git_commit);
+        assert!(args.branch.is_none());
+        assert!(!args.allow_dirty);
+        assert!(!args.runtimes);
+        assert!(!args.prune_report);
+        assert!(!args.only_vulnerable);
+        assert!(!args.drift);
+        assert!(!args.check_only_installed);
+        assert!(!args.diff_exit_code);
+        assert!(!args.strict_parse);
+        assert!(args.max_parallel_manifests.is_none());
+        assert!(args.batch_size.is_none());
+        assert!(!args.dedupe_manifests);
+        assert!(!args.no_summary);
+        assert!(args.manifests_from.is_none());
+        assert!(!args.quiet_errors);
+        assert!(!args.hide_latest);
+        assert!(!args.go_major_upgrades);
+        assert!(args.concurrency.is_none());
+        assert!(args.host_concurrency.is_empty());
+        assert!(!args.validate);
+        assert!(args.groups.is_empty());
+        assert!(!args.group_all_or_nothing);
+        assert!(args.write_summary.is_none());
+        assert!(args.audit.is_none());
+        assert!(!args.summary_json_to_stderr);
+        assert!(!args.preview_content);
+        assert!(args.package.is_none());
+        assert!(args.since.is_none());
         assert!(!args.install);
+        assert!(!args.rollback_on_install_failure);
     }
 
     #[test]
@@ -226,6 +624,12 @@ mod tests {
         assert!(args.dry_run);
     }
 
+    #[test]
+    fn test_diff_exit_code_flag() {
+        let args = CliArgs::parse_from(["depup", "--diff-exit-code"]);
+        assert!(args.diff_exit_code);
+    }
+
     #[test]
     fn test_dry_run_long_flag() {
         let args = CliArgs::parse_from(["depup", "--dry-run"]);
@@ -265,6 +669,42 @@ mod tests {
         let args = CliArgs::parse_from(["depup", "--java"]);
         assert!(args.java);
         assert!(!args.node);
+
+        let args = CliArgs::parse_from(["depup", "--deno"]);
+        assert!(args.deno);
+        assert!(args.has_language_filter());
+        assert!(args.should_process_language("deno"));
+        assert!(!args.should_process_language("node"));
+
+        let args = CliArgs::parse_from(["depup", "--nim"]);
+        assert!(args.nim);
+        assert!(args.has_language_filter());
+        assert!(args.should_process_language("nimble"));
+        assert!(!args.should_process_language("deno"));
+
+        let args = CliArgs::parse_from(["depup", "--docker"]);
+        assert!(args.docker);
+        assert!(args.has_language_filter());
+        assert!(args.should_process_language("dockerfile"));
+        assert!(!args.should_process_language("nim"));
+
+        let args = CliArgs::parse_from(["depup", "--r"]);
+        assert!(args.r);
+        assert!(args.has_language_filter());
+        assert!(args.should_process_language("renv"));
+        assert!(!args.should_process_language("docker"));
+
+        let args = CliArgs::parse_from(["depup", "--crystal"]);
+        assert!(args.crystal);
+        assert!(args.has_language_filter());
+        assert!(args.should_process_language("shards"));
+        assert!(!args.should_process_language("r"));
+
+        let args = CliArgs::parse_from(["depup", "--pre-commit"]);
+        assert!(args.pre_commit);
+        assert!(args.has_language_filter());
+        assert!(args.should_process_language("precommit"));
+        assert!(!args.should_process_language("crystal"));
     }
 
     #[test]
@@ -296,6 +736,51 @@ mod tests {
         assert_eq!(args.only, vec!["foo", "bar"]);
     }
 
+    #[test]
+    fn test_scope_multiple() {
+        let args = CliArgs::parse_from([
+            "depup",
+            "--scope",
+            "@mycompany/*",
+            "--scope",
+            "github.com/myorg",
+        ]);
+        assert_eq!(args.scopes, vec!["@mycompany/*", "github.com/myorg"]);
+    }
+
+    #[test]
+    fn test_package() {
+        let args = CliArgs::parse_from(["depup", "--package", "lodash", "./proj"]);
+        assert_eq!(args.package.as_deref(), Some("lodash"));
+        assert_eq!(args.path, PathBuf::from("./proj"));
+    }
+
+    #[test]
+    fn test_upgrade_pseudo() {
+        let args = CliArgs::parse_from(["depup", "--upgrade-pseudo"]);
+        assert!(args.upgrade_pseudo);
+    }
+
+    #[test]
+    fn test_include_prerelease() {
+        assert!(!CliArgs::parse_from(["depup"]).include_prerelease);
+        let args = CliArgs::parse_from(["depup", "--include-prerelease"]);
+        assert!(args.include_prerelease);
+    }
+
+    #[test]
+    fn test_prerelease_channel() {
+        let args = CliArgs::parse_from(["depup", "--prerelease-channel", "strict"]);
+        assert_eq!(args.prerelease_channel, PrereleaseChannel::Strict);
+        assert!(CliArgs::try_parse_from(["depup", "--prerelease-channel", "beta"]).is_err());
+    }
+
+    #[test]
+    fn test_prefer_stable() {
+        let args = CliArgs::parse_from(["depup", "--prefer-stable"]);
+        assert!(args.prefer_stable);
+    }
+
     #[test]
     fn test_include_pinned() {
         let args = CliArgs::parse_from(["depup", "--include-pinned"]);
@@ -320,6 +805,61 @@ mod tests {
         assert_eq!(args.age, Some(Duration::from_secs(30 * 24 * 60 * 60)));
     }
 
+    #[test]
+    fn test_since() {
+        let args = CliArgs::parse_from(["depup", "--since", "2024-06-01"]);
+        assert_eq!(
+            args.since.unwrap().to_rfc3339(),
+            "2024-06-01T00:00:00+00:00"
+        );
+
+        assert!(CliArgs::try_parse_from(["depup", "--since", "06/01/2024"]).is_err());
+    }
+
+    #[test]
+    fn test_deadline() {
+        let args = CliArgs::parse_from(["depup", "--deadline", "120"]);
+        assert_eq!(args.deadline, Some(120));
+    }
+
+    #[test]
+    fn test_slow_threshold() {
+        let args = CliArgs::parse_from(["depup", "--verbose", "--slow-threshold", "500"]);
+        assert_eq!(args.slow_threshold, Some(500));
+
+        // The report is part of the verbose output
+        assert!(CliArgs::try_parse_from(["depup", "--slow-threshold", "500"]).is_err());
+    }
+
+    #[test]
+    fn test_registry_mirror() {
+        let args = CliArgs::parse_from([
+            "depup",
+            "--registry-mirror",
+            "node=https://registry.npmmirror.com/",
+            "--registry-mirror",
+            "node=https://mirror.example.com",
+            "--registry-mirror",
+            "go=https://goproxy.io",
+        ]);
+        assert_eq!(
+            args.mirrors_for(Language::Node),
+            vec![
+                "https://registry.npmmirror.com",
+                "https://mirror.example.com"
+            ]
+        );
+        assert_eq!(args.mirrors_for(Language::Go), vec!["https://goproxy.io"]);
+        assert!(args.mirrors_for(Language::Rust).is_empty());
+    }
+
+    #[test]
+    fn test_parse_registry_mirror_invalid() {
+        assert!(parse_registry_mirror("https://example.com").is_err());
+        assert!(parse_registry_mirror("cobol=https://example.com").is_err());
+        assert!(parse_registry_mirror("node=").is_err());
+    }
+
     #[test]
     fn test_json_output() {
         let args = CliArgs::parse_from(["depup", "--json"]);
@@ -332,12 +872,349 @@ mod tests {
         assert!(args.diff);
     }
 
+    #[test]
+    fn test_preview_content_requires_json() {
+        let args = CliArgs::parse_from(["depup", "--json", "--preview-content"]);
+        assert!(args.preview_content);
+
+        let result = CliArgs::try_parse_from(["depup", "--preview-content"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_sarif_output() {
+        let args = CliArgs::parse_from(["depup", "--sarif"]);
+        assert!(args.sarif);
+    }
+
+    #[test]
+    fn test_notes() {
+        let args = CliArgs::parse_from(["depup", "--notes"]);
+        assert!(args.notes);
+    }
+
+    #[test]
+    fn test_write_summary() {
+        let args = CliArgs::parse_from(["depup", "--json", "--write-summary", "depup.json"]);
+        assert_eq!(args.write_summary, Some(PathBuf::from("depup.json")));
+    }
+
+    #[test]
+    fn test_summary_json_to_stderr() {
+        let args = CliArgs::parse_from(["depup", "--summary-json-to-stderr"]);
+        assert!(args.summary_json_to_stderr);
+
+        // Streaming mode has no final result to summarize
+        assert!(CliArgs::try_parse_from([
+            "depup",
+            "--summary-json-to-stderr",
+            "--max-parallel-manifests",
+            "2"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_allow_host_and_strict_tls() {
+        let args = CliArgs::parse_from([
+            "depup",
+            "--allow-host",
+            "registry.npmjs.org",
+            "--allow-host",
+            "pypi.org",
+            "--strict-tls",
+        ]);
+        assert_eq!(args.allowed_hosts, vec!["registry.npmjs.org", "pypi.org"]);
+        assert!(args.strict_tls);
+    }
+
+    #[test]
+    fn test_list_languages() {
+        let args = CliArgs::parse_from(["depup", "--list-languages", "--json"]);
+        assert!(args.list_languages);
+        assert!(args.json);
+    }
+
+    #[test]
+    fn test_template() {
+        let args = CliArgs::parse_from(["depup", "--template", "{name} {old} -> {new}"]);
+        assert!(args.template.is_some());
+
+        // Unknown placeholders are rejected at startup
+        let result = CliArgs::try_parse_from(["depup", "--template", "{name} {version}"]);
+        assert!(result.is_err());
+
+        let result = CliArgs::try_parse_from(["depup", "--json", "--template", "{name}"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_npm_tag() {
+        let args = CliArgs::parse_from(["depup", "--npm-tag", "next"]);
+        assert_eq!(args.npm_tag.as_deref(), Some("next"));
+    }
+
+    #[test]
+    fn test_treat_zerover_minor_as_major() {
+        let args = CliArgs::parse_from(["depup", "--treat-zerover-minor-as-major"]);
+        assert!(args.treat_zerover_minor_as_major);
+    }
+
+    #[test]
+    fn test_post_update() {
+        let args = CliArgs::parse_from([
+            "depup",
+            "--post-update",
+            "cargo fmt",
+            "--post-update",
+            "cargo test",
+        ]);
+        assert_eq!(args.post_update, vec!["cargo fmt", "cargo test"]);
+    }
+
+    #[test]
+    fn test_git_commit() {
+        let args = CliArgs::parse_from([
+            "depup",
+            "--git-commit",
+            "--branch",
+            "deps/weekly",
+            "--allow-dirty",
+        ]);
+        assert!(args.git_commit);
+        assert_eq!(args.branch.as_deref(), Some("deps/weekly"));
+        assert!(args.allow_dirty);
+
+        assert!(CliArgs::try_parse_from(["depup", "--git-commit", "--dry-run"]).is_err());
+        assert!(CliArgs::try_parse_from(["depup", "--branch", "deps/weekly"]).is_err());
+        assert!(CliArgs::try_parse_from(["depup", "--allow-dirty"]).is_err());
+    }
+
+    #[test]
+    fn test_runtimes_flag() {
+        let args = CliArgs::parse_from(["depup", "--runtimes", "--json"]);
+        assert!(args.runtimes);
+        assert!(args.json);
+    }
+
+    #[test]
+    fn test_only_vulnerable() {
+        let args = CliArgs::parse_from(["depup", "--only-vulnerable"]);
+        assert!(args.only_vulnerable);
+    }
+
+    #[test]
+    fn test_group() {
+        let args = CliArgs::parse_from([
+            "depup",
+            "--group",
+            "babel=@babel/*,babel-*",
+            "--group",
+            "serde=serde*",
+            "--group-all-or-nothing",
+        ]);
+        assert_eq!(args.groups.len(), 2);
+        assert_eq!(args.groups[0].name, "babel");
+        assert_eq!(args.groups[0].patterns, vec!["@babel/*", "babel-*"]);
+        assert_eq!(args.groups[1].name, "serde");
+        assert!(args.group_all_or_nothing);
+
+        assert!(CliArgs::try_parse_from(["depup", "--group", "babel"]).is_err());
+    }
+
+    #[test]
+    fn test_age_dev() {
+        let args = CliArgs::parse_from(["depup", "--age", "2w", "--age-dev", "3d"]);
+        assert_eq!(args.age, Some(Duration::from_secs(14 * 24 * 60 * 60)));
+        assert_eq!(args.age_dev, Some(Duration::from_secs(3 * 24 * 60 * 60)));
+    }
+
+    #[test]
+    fn test_validate() {
+        let args = CliArgs::parse_from(["depup", "--validate"]);
+        assert!(args.validate);
+    }
+
+    #[test]
+    fn test_concurrency() {
+        let args = CliArgs::parse_from(["depup", "--concurrency", "32"]);
+        assert_eq!(args.concurrency, Some(32));
+
+        let args = CliArgs::parse_from(["depup", "--concurrency", "0"]);
+        assert_eq!(args.concurrency, Some(0));
+    }
+
+    #[test]
+    fn test_concurrency_per_host() {
+        let args = CliArgs::parse_from([
+            "depup",
+            "--concurrency-per-host",
+            "crates.io=2",
+            "--concurrency-per-host",
+            "registry.npmjs.org = 4",
+        ]);
+        assert_eq!(
+            args.host_concurrency,
+            vec![
+                ("crates.io".to_string(), 2),
+                ("registry.npmjs.org".to_string(), 4)
+            ]
+        );
+
+        assert!(parse_host_concurrency("crates.io").is_err());
+        assert!(parse_host_concurrency("crates.io=0").is_err());
+        assert!(parse_host_concurrency("=2").is_err());
+    }
+
+    #[test]
+    fn test_max_parallel_manifests() {
+        let args = CliArgs::parse_from(["depup", "--max-parallel-manifests", "4", "--json"]);
+        assert_eq!(args.max_parallel_manifests, Some(4));
+
+        assert!(CliArgs::try_parse_from(["depup", "--max-parallel-manifests", "0"]).is_err());
+        assert!(
+            CliArgs::try_parse_from(["depup", "--max-parallel-manifests", "4", "--sarif"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_quiet_errors_flag() {
+        let args = CliArgs::parse_from(["depup", "--quiet-errors"]);
+        assert!(args.quiet_errors);
+    }
+
+    #[test]
+    fn test_hide_latest_flag() {
+        let args = CliArgs::parse_from(["depup", "--hide-latest", "--verbose"]);
+        assert!(args.hide_latest);
+    }
+
+    #[test]
+    fn test_go_major_upgrades_flag() {
+        let args = CliArgs::parse_from(["depup", "--go-major-upgrades"]);
+        assert!(args.go_major_upgrades);
+    }
+
+    #[test]
+    fn test_prune_report_flag() {
+        let args = CliArgs::parse_from(["depup", "--prune-report", "--json"]);
+        assert!(args.prune_report);
+        assert!(args.json);
+        assert!(CliArgs::try_parse_from(["depup", "--prune-report", "--runtimes"]).is_err());
+    }
+
+    #[test]
+    fn test_strict_parse_flag() {
+        let args = CliArgs::parse_from(["depup", "--strict-parse"]);
+        assert!(args.strict_parse);
+        assert!(CliArgs::try_parse_from([
+            "depup",
+            "--strict-parse",
+            "--max-parallel-manifests",
+            "2"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_drift_flag() {
+        let args = CliArgs::parse_from(["depup", "--drift"]);
+        assert!(args.drift);
+        assert!(CliArgs::try_parse_from(["depup", "--drift", "--only-vulnerable"]).is_err());
+    }
+
+    #[test]
+    fn test_batch_size() {
+        let args = CliArgs::parse_from(["depup", "--batch-size", "50"]);
+        assert_eq!(args.batch_size, Some(50));
+        assert!(CliArgs::try_parse_from(["depup", "--batch-size", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_dedupe_manifests() {
+        let args = CliArgs::parse_from(["depup", "--dedupe-manifests"]);
+        assert!(args.dedupe_manifests);
+        assert!(CliArgs::try_parse_from([
+            "depup",
+            "--dedupe-manifests",
+            "--max-parallel-manifests",
+            "2"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_no_summary() {
+        let args = CliArgs::parse_from(["depup", "--no-summary"]);
+        assert!(args.no_summary);
+        assert!(CliArgs::try_parse_from(["depup", "--no-summary", "--quiet"]).is_err());
+    }
+
+    #[test]
+    fn test_manifests_from() {
+        let args = CliArgs::parse_from(["depup", "--manifests-from", "-"]);
+        assert_eq!(args.manifests_from, Some(PathBuf::from("-")));
+        assert!(CliArgs::try_parse_from(["depup", "--manifests-from", "-", "--runtimes"]).is_err());
+    }
+
+    #[test]
+    fn test_audit() {
+        let args = CliArgs::parse_from(["depup", "--audit", "audit.json"]);
+        assert_eq!(args.audit, Some(PathBuf::from("audit.json")));
+        assert!(CliArgs::try_parse_from([
+            "depup",
+            "--audit",
+            "audit.json",
+            "--max-parallel-manifests",
+            "2"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_check_only_installed() {
+        let args = CliArgs::parse_from(["depup", "--check-only-installed"]);
+        assert!(args.check_only_installed);
+    }
+
+    #[test]
+    fn test_force_complex() {
+        let args = CliArgs::parse_from(["depup", "--force-complex"]);
+        assert!(args.force_complex);
+    }
+
+    #[test]
+    fn test_normalize_prefix() {
+        let args = CliArgs::parse_from(["depup", "--normalize-prefix", "^"]);
+        assert_eq!(args.normalize_prefix.as_deref(), Some("^"));
+
+        let args = CliArgs::parse_from(["depup", "--normalize-prefix", "~"]);
+        assert_eq!(args.normalize_prefix.as_deref(), Some("~"));
+
+        assert!(CliArgs::try_parse_from(["depup", "--normalize-prefix", ">="]).is_err());
+    }
+
+    #[test]
+    fn test_only_direct() {
+        let args = CliArgs::parse_from(["depup", "--only-direct"]);
+        assert!(args.only_direct);
+    }
+
     #[test]
     fn test_install_flag() {
         let args = CliArgs::parse_from(["depup", "--install"]);
         assert!(args.install);
     }
 
+    #[test]
+    fn test_rollback_on_install_failure() {
+        let args = CliArgs::parse_from(["depup", "--install", "--rollback-on-install-failure"]);
+        assert!(args.rollback_on_install_failure);
+
+        // Only meaningful together with --install
+        assert!(CliArgs::try_parse_from(["depup", "--rollback-on-install-failure"]).is_err());
+    }
+
     #[test]
     fn test_has_language_filter() {
         let args = CliArgs::parse_from(["depup"]);
@@ -358,6 +1235,7 @@ mod tests {
         assert!(args.should_process_language("rust"));
         assert!(args.should_process_language("go"));
         assert!(args.should_process_language("java"));
+        assert!(!args.should_process_language("docker"));
 
         let args = CliArgs::parse_from(["depup", "--node", "--python"]);
         assert!(args.should_process_language("node"));
@@ -416,6 +1294,54 @@ mod tests {
         assert!(parse_duration("10x").is_err());
     }
 
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(parse_duration("90s").unwrap(), Duration::from_secs(90));
+        assert_eq!(
+            parse_duration("12h").unwrap(),
+            Duration::from_secs(12 * 3600)
+        );
+        assert_eq!(
+            parse_duration("3d").unwrap(),
+            Duration::from_secs(3 * 86400)
+        );
+        assert_eq!(
+            parse_duration("2w").unwrap(),
+            Duration::from_secs(14 * 86400)
+        );
+        assert_eq!(
+            parse_duration("90m").unwrap(),
+            Duration::from_secs(90 * 30 * 86400)
+        );
+        assert_eq!(
+            parse_duration("1mo").unwrap(),
+            Duration::from_secs(30 * 86400)
+        );
+        assert_eq!(
+            parse_duration("1y").unwrap(),
+            Duration::from_secs(365 * 86400)
+        );
+    }
+
+    #[test]
+    fn test_parse_duration_error_message() {
+        for input in ["2 weeks", "2x", "w", "-1d", ""] {
+            let err = parse_duration(input).unwrap_err();
+            assert!(err.contains("s (seconds)"), "{}: {}", input, err);
+            assert!(err.contains("e.g., 12h, 3d, 2w, 1mo"), "{}: {}", input, err);
+        }
+        assert!(parse_duration("2 weeks")
+            .unwrap_err()
+            .starts_with("invalid duration '2 weeks'"));
+
+        // clap surfaces the same message for --age
+        let err = CliArgs::try_parse_from(["depup", "--age", "2x"]).unwrap_err();
+        assert!(err.to_string().contains("w (weeks)"));
+
+        let err = parse_since("06/01/2024").unwrap_err();
+        assert!(err.contains("expected YYYY-MM-DD"));
+    }
+
     #[test]
     fn test_combined_flags() {
         let args = CliArgs::parse_from([
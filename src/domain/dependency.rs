@@ -1,6 +1,6 @@
 //! Dependency information structures
 
-use super::{Language, VersionSpec};
+use super::{Language, SkipReason, VersionSpec};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -13,11 +13,28 @@ pub struct Dependency {
     pub version_spec: VersionSpec,
     /// Whether this is a development dependency
     pub is_dev: bool,
+    /// Whether this dependency is only required transitively (e.g., Go `// indirect`)
+    #[serde(default)]
+    pub is_indirect: bool,
+    /// Whether installing may skip this dependency (e.g., npm `optionalDependencies`)
+    #[serde(default)]
+    pub is_optional: bool,
     /// The language/ecosystem this dependency belongs to
     pub language: Language,
     /// Optional variable name if version is defined via variable (e.g., Gradle def/val)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variable_name: Option<String>,
+    /// Reason to skip this dependency determined while parsing the manifest
+    /// (e.g., it is served from a local path or VCS repository)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<SkipReason>,
+    /// Version currently resolved in the lock file (e.g., Cargo.lock), if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_version: Option<String>,
+    /// Package looked up in the registry when `name` is an alias
+    /// (e.g., `"@std/path": "npm:@jsr/std__path@^1.0.0"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias_target: Option<String>,
 }
 
 impl Dependency {
@@ -32,8 +49,13 @@ impl Dependency {
             name: name.into(),
             version_spec,
             is_dev,
+            is_indirect: false,
+            is_optional: false,
             language,
             variable_name: None,
+            skip_reason: None,
+            resolved_version: None,
+            alias_target: None,
         }
     }
 
@@ -43,6 +65,48 @@ impl Dependency {
         self
     }
 
+    /// Marks this dependency as indirect (transitive) (builder pattern)
+    pub fn with_indirect(mut self) -> Self {
+        self.is_indirect = true;
+        self
+    }
+
+    /// Marks this dependency as optional (builder pattern)
+    pub fn with_optional(mut self) -> Self {
+        self.is_optional = true;
+        self
+    }
+
+    /// Marks this dependency to be skipped regardless of registry data (builder pattern)
+    pub fn with_skip_reason(mut self, reason: SkipReason) -> Self {
+        self.skip_reason = Some(reason);
+        self
+    }
+
+    /// Sets the version resolved in the lock file (builder pattern)
+    pub fn with_resolved_version(mut self, version: impl Into<String>) -> Self {
+        self.resolved_version = Some(version.into());
+        self
+    }
+
+    /// Sets the package the alias points at (builder pattern)
+    pub fn with_alias_target(mut self, target: impl Into<String>) -> Self {
+        self.alias_target = Some(target.into());
+        self
+    }
+
+    /// Package name to look up in the registry
+    pub fn lookup_name(&self) -> &str {
+        self.alias_target.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Returns true if the lock file resolves an older version than `version`
+    pub fn is_lock_behind(&self, version: &str) -> bool {
+        self.resolved_version.as_deref().is_some_and(|resolved| {
+            crate::update::compare_versions(resolved, version) == std::cmp::Ordering::Less
+        })
+    }
+
     /// Creates a new production dependency
     pub fn production(
         name: impl Into<String>,
@@ -195,4 +259,26 @@ mod tests {
         let parsed: Dependency = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed, dep);
     }
+
+    #[test]
+    fn test_dependency_with_skip_reason() {
+        let dep = Dependency::new("lodash", sample_version_spec(), false, Language::Node)
+            .with_skip_reason(SkipReason::NonRegistrySource);
+        assert_eq!(dep.skip_reason, Some(SkipReason::NonRegistrySource));
+
+        let json = serde_json::to_string(&dep).unwrap();
+        let parsed: Dependency = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, dep);
+    }
+
+    #[test]
+    fn test_dependency_with_resolved_version() {
+        let dep = Dependency::new("serde", sample_version_spec(), false, Language::Rust);
+        assert!(!dep.is_lock_behind("1.4.0"));
+
+        let dep = dep.with_resolved_version("1.2.5");
+        assert_eq!(dep.resolved_version.as_deref(), Some("1.2.5"));
+        assert!(dep.is_lock_behind("1.4.0"));
+        assert!(!dep.is_lock_behind("1.2.5"));
+    }
 }
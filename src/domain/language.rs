@@ -21,19 +21,48 @@ pub enum Language {
     Php,
     /// Java ecosystem (build.gradle, build.gradle.kts)
     Java,
+    /// Deno ecosystem (deno.json import map)
+    Deno,
+    /// Nim ecosystem (*.nimble)
+    Nim,
+    /// Docker base images (Dockerfile)
+    Docker,
+    /// R ecosystem (renv.lock)
+    R,
+    /// Crystal ecosystem (shard.yml)
+    Crystal,
+    /// pre-commit hook repositories (.pre-commit-config.yaml)
+    PreCommit,
 }
 
 impl Language {
     /// Returns the manifest filename for this language
-    pub fn manifest_filename(&self) -> &'static str {
+    ///
+    /// Nimble files are named after the package, so Nim has no fixed filename;
+    /// see [`Language::manifest_pattern`].
+    pub fn manifest_filename(&self) -> Option<&'static str> {
+        match self {
+            Language::Node => Some("package.json"),
+            Language::Python => Some("pyproject.toml"),
+            Language::Rust => Some("Cargo.toml"),
+            Language::Go => Some("go.mod"),
+            Language::Ruby => Some("Gemfile"),
+            Language::Php => Some("composer.json"),
+            Language::Java => Some("build.gradle"),
+            Language::Deno => Some("deno.json"),
+            Language::Nim => None,
+            Language::Docker => Some("Dockerfile"),
+            Language::R => Some("renv.lock"),
+            Language::Crystal => Some("shard.yml"),
+            Language::PreCommit => Some(".pre-commit-config.yaml"),
+        }
+    }
+
+    /// Returns the manifest filename, or a glob for manifests named after the package (`*.nimble`)
+    pub fn manifest_pattern(&self) -> &'static str {
         match self {
-            Language::Node => "package.json",
-            Language::Python => "pyproject.toml",
-            Language::Rust => "Cargo.toml",
-            Language::Go => "go.mod",
-            Language::Ruby => "Gemfile",
-            Language::Php => "composer.json",
-            Language::Java => "build.gradle",
+            Language::Nim => "*.nimble",
+            _ => self.manifest_filename().unwrap_or_default(),
         }
     }
 
@@ -47,6 +76,13 @@ impl Language {
             Language::Ruby => &["Gemfile.lock"],
             Language::Php => &["composer.lock"],
             Language::Java => &["gradle.lockfile"],
+            Language::Deno => &["deno.lock"],
+            Language::Nim => &["nimble.lock"],
+            Language::Docker => &[],
+            // renv.lock is both the manifest and the lock file
+            Language::R => &[],
+            Language::Crystal => &["shard.lock"],
+            Language::PreCommit => &[],
         }
     }
 
@@ -60,6 +96,12 @@ impl Language {
             Language::Ruby => "Ruby",
             Language::Php => "PHP",
             Language::Java => "Java",
+            Language::Deno => "Deno",
+            Language::Nim => "Nim",
+            Language::Docker => "Docker",
+            Language::R => "R",
+            Language::Crystal => "Crystal",
+            Language::PreCommit => "pre-commit",
         }
     }
 
@@ -73,20 +115,60 @@ impl Language {
             Language::Ruby,
             Language::Php,
             Language::Java,
+            Language::Deno,
+            Language::Nim,
+            Language::Docker,
+            Language::R,
+            Language::Crystal,
+            Language::PreCommit,
         ]
     }
 
+    /// Parses a language from its CLI name (e.g., `node`, `rust`, `golang`)
+    pub fn from_name(name: &str) -> Option<Language> {
+        match name.trim().to_lowercase().as_str() {
+            "node" | "nodejs" | "javascript" => Some(Language::Node),
+            "python" => Some(Language::Python),
+            "rust" => Some(Language::Rust),
+            "go" | "golang" => Some(Language::Go),
+            "ruby" => Some(Language::Ruby),
+            "php" => Some(Language::Php),
+            "java" => Some(Language::Java),
+            "deno" => Some(Language::Deno),
+            "nim" | "nimble" => Some(Language::Nim),
+            "docker" | "dockerfile" => Some(Language::Docker),
+            "r" | "renv" => Some(Language::R),
+            "crystal" | "shards" => Some(Language::Crystal),
+            "pre-commit" | "precommit" => Some(Language::PreCommit),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this language is processed when no language filter is given
+    ///
+    /// Docker base images are opt-in: bumping an image can change the whole
+    /// runtime, so Dockerfiles are only checked with `--docker`.
+    pub fn is_default(&self) -> bool {
+        !matches!(self, Language::Docker)
+    }
+
     /// Returns true if this language only supports pinned/exact versions
     ///
     /// Go doesn't have range specifiers in go.mod - all versions are
-    /// effectively pinned. For this language, `--include-pinned` should
-    /// be implicitly enabled.
+    /// effectively pinned. Docker image tags always name one exact image
+    /// (Docker is only checked with `--docker`, see [`Language::is_default`]),
+    /// renv.lock records the one installed version of each R package, and a
+    /// pre-commit `rev` names one exact tag.
+    /// For these languages, `--include-pinned` should be implicitly enabled.
     ///
     /// Note: Java/Gradle does support version ranges (Maven-style ranges,
     /// prefix versions like `1.+`, dynamic versions like `latest.release`),
     /// so it is NOT included here.
     pub fn always_pinned(&self) -> bool {
-        matches!(self, Language::Go)
+        matches!(
+            self,
+            Language::Go | Language::Docker | Language::R | Language::PreCommit
+        )
     }
 }
 
@@ -102,13 +184,24 @@ mod tests {
 
     #[test]
     fn test_manifest_filenames() {
-        assert_eq!(Language::Node.manifest_filename(), "package.json");
-        assert_eq!(Language::Python.manifest_filename(), "pyproject.toml");
-        assert_eq!(Language::Rust.manifest_filename(), "Cargo.toml");
-        assert_eq!(Language::Go.manifest_filename(), "go.mod");
-        assert_eq!(Language::Ruby.manifest_filename(), "Gemfile");
-        assert_eq!(Language::Php.manifest_filename(), "composer.json");
-        assert_eq!(Language::Java.manifest_filename(), "build.gradle");
+        assert_eq!(Language::Node.manifest_filename(), Some("package.json"));
+        assert_eq!(Language::Python.manifest_filename(), Some("pyproject.toml"));
+        assert_eq!(Language::Rust.manifest_filename(), Some("Cargo.toml"));
+        assert_eq!(Language::Go.manifest_filename(), Some("go.mod"));
+        assert_eq!(Language::Ruby.manifest_filename(), Some("Gemfile"));
+        assert_eq!(Language::Php.manifest_filename(), Some("composer.json"));
+        assert_eq!(Language::Java.manifest_filename(), Some("build.gradle"));
+        assert_eq!(Language::Deno.manifest_filename(), Some("deno.json"));
+        assert_eq!(Language::Nim.manifest_filename(), None);
+        assert_eq!(Language::Nim.manifest_pattern(), "*.nimble");
+        assert_eq!(Language::Node.manifest_pattern(), "package.json");
+        assert_eq!(Language::Docker.manifest_filename(), Some("Dockerfile"));
+        assert_eq!(Language::R.manifest_filename(), Some("renv.lock"));
+        assert_eq!(Language::Crystal.manifest_filename(), Some("shard.yml"));
+        assert_eq!(
+            Language::PreCommit.manifest_filename(),
+            Some(".pre-commit-config.yaml")
+        );
     }
 
     #[test]
@@ -126,6 +219,12 @@ mod tests {
         assert_eq!(Language::Ruby.lock_filenames(), &["Gemfile.lock"]);
         assert_eq!(Language::Php.lock_filenames(), &["composer.lock"]);
         assert_eq!(Language::Java.lock_filenames(), &["gradle.lockfile"]);
+        assert_eq!(Language::Deno.lock_filenames(), &["deno.lock"]);
+        assert_eq!(Language::Nim.lock_filenames(), &["nimble.lock"]);
+        assert!(Language::Docker.lock_filenames().is_empty());
+        assert!(Language::R.lock_filenames().is_empty());
+        assert_eq!(Language::Crystal.lock_filenames(), &["shard.lock"]);
+        assert!(Language::PreCommit.lock_filenames().is_empty());
     }
 
     #[test]
@@ -137,6 +236,12 @@ mod tests {
         assert_eq!(Language::Ruby.display_name(), "Ruby");
         assert_eq!(Language::Php.display_name(), "PHP");
         assert_eq!(Language::Java.display_name(), "Java");
+        assert_eq!(Language::Deno.display_name(), "Deno");
+        assert_eq!(Language::Nim.display_name(), "Nim");
+        assert_eq!(Language::Docker.display_name(), "Docker");
+        assert_eq!(Language::R.display_name(), "R");
+        assert_eq!(Language::Crystal.display_name(), "Crystal");
+        assert_eq!(Language::PreCommit.display_name(), "pre-commit");
     }
 
     #[test]
@@ -153,7 +258,7 @@ mod tests {
     #[test]
     fn test_all_languages() {
         let all = Language::all();
-        assert_eq!(all.len(), 7);
+        assert_eq!(all.len(), 13);
         assert!(all.contains(&Language::Node));
         assert!(all.contains(&Language::Python));
         assert!(all.contains(&Language::Rust));
@@ -161,6 +266,28 @@ mod tests {
         assert!(all.contains(&Language::Ruby));
         assert!(all.contains(&Language::Php));
         assert!(all.contains(&Language::Java));
+        assert!(all.contains(&Language::Deno));
+        assert!(all.contains(&Language::Nim));
+        assert!(all.contains(&Language::Docker));
+        assert!(all.contains(&Language::R));
+        assert!(all.contains(&Language::Crystal));
+        assert!(all.contains(&Language::PreCommit));
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Language::from_name("node"), Some(Language::Node));
+        assert_eq!(Language::from_name("nodejs"), Some(Language::Node));
+        assert_eq!(Language::from_name("Rust"), Some(Language::Rust));
+        assert_eq!(Language::from_name("golang"), Some(Language::Go));
+        assert_eq!(Language::from_name("java"), Some(Language::Java));
+        assert_eq!(Language::from_name("deno"), Some(Language::Deno));
+        assert_eq!(Language::from_name("nimble"), Some(Language::Nim));
+        assert_eq!(Language::from_name("Dockerfile"), Some(Language::Docker));
+        assert_eq!(Language::from_name("renv"), Some(Language::R));
+        assert_eq!(Language::from_name("shards"), Some(Language::Crystal));
+        assert_eq!(Language::from_name("pre-commit"), Some(Language::PreCommit));
+        assert_eq!(Language::from_name("cobol"), None);
     }
 
     #[test]
@@ -223,10 +350,25 @@ mod tests {
         assert_eq!(lang, Language::Java);
     }
 
+    #[test]
+    fn test_is_default() {
+        assert!(!Language::Docker.is_default());
+        for language in Language::all() {
+            if *language != Language::Docker {
+                assert!(language.is_default(), "{language}");
+            }
+        }
+    }
+
     #[test]
     fn test_always_pinned() {
         // Go only supports exact/pinned versions (no range syntax in go.mod)
         assert!(Language::Go.always_pinned());
+        // Docker tags name one exact image
+        assert!(Language::Docker.always_pinned());
+        // renv.lock records installed versions
+        assert!(Language::R.always_pinned());
+        assert!(Language::PreCommit.always_pinned());
 
         // Java/Gradle supports version ranges (Maven-style, prefix versions, dynamic versions)
         assert!(!Language::Java.always_pinned());
@@ -237,5 +379,6 @@ mod tests {
         assert!(!Language::Rust.always_pinned());
         assert!(!Language::Ruby.always_pinned());
         assert!(!Language::Php.always_pinned());
+        assert!(!Language::Crystal.always_pinned());
     }
 }
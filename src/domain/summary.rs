@@ -5,6 +5,7 @@
 use super::{Language, UpdateResult};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Update result for a single manifest file
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -17,6 +18,9 @@ pub struct ManifestUpdateResult {
     pub results: Vec<UpdateResult>,
     /// Whether the file was actually modified
     pub modified: bool,
+    /// Time spent fetching and judging this manifest's dependencies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<Duration>,
 }
 
 impl ManifestUpdateResult {
@@ -27,6 +31,7 @@ impl ManifestUpdateResult {
             language,
             results: Vec::new(),
             modified: false,
+            duration: None,
         }
     }
 
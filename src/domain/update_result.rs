@@ -25,6 +25,28 @@ pub enum SkipReason {
     ParseError(String),
     /// Language filter excluded this package
     LanguageFiltered,
+    /// Package is served from a local path or VCS repository, not a registry
+    NonRegistrySource,
+    /// No version was released on or after the --since date
+    NoReleaseSince(String),
+    /// Constraint combines alternatives (e.g., `^9.0 || ^10.0`) that cannot be rewritten safely
+    ComplexConstraint,
+    /// Go pseudo-version pointing at an untagged commit (see --upgrade-pseudo)
+    PseudoVersion,
+    /// No known vulnerability affects the current version (see --only-vulnerable)
+    NotVulnerable,
+    /// No advisory database covers the package's ecosystem (see --only-vulnerable)
+    NoAdvisoryDatabase,
+    /// Another member of the named update group can't be updated (see --group-all-or-nothing)
+    GroupBlocked(String),
+    /// Crate is overridden by a `[patch]` or `[replace]` section in Cargo.toml
+    Patched,
+    /// Lock file already resolves the newest version the range allows (see --drift)
+    NoDrift,
+    /// Dependency is only required transitively (see --only-direct)
+    Indirect,
+    /// Package is vendored into the published tarball (npm `bundledDependencies`)
+    Bundled,
 }
 
 impl fmt::Display for SkipReason {
@@ -38,6 +60,17 @@ impl fmt::Display for SkipReason {
             SkipReason::NoSuitableVersion => write!(f, "no suitable version"),
             SkipReason::ParseError(msg) => write!(f, "parse error: {}", msg),
             SkipReason::LanguageFiltered => write!(f, "language filtered"),
+            SkipReason::NonRegistrySource => write!(f, "path or VCS source"),
+            SkipReason::NoReleaseSince(date) => write!(f, "no release since {}", date),
+            SkipReason::ComplexConstraint => write!(f, "complex constraint"),
+            SkipReason::PseudoVersion => write!(f, "pseudo-version"),
+            SkipReason::NotVulnerable => write!(f, "no known vulnerability"),
+            SkipReason::NoAdvisoryDatabase => write!(f, "no advisory database"),
+            SkipReason::GroupBlocked(group) => write!(f, "group '{}' blocked", group),
+            SkipReason::Patched => write!(f, "overridden by [patch]/[replace]"),
+            SkipReason::NoDrift => write!(f, "lock up to date with range"),
+            SkipReason::Indirect => write!(f, "indirect dependency"),
+            SkipReason::Bundled => write!(f, "bundled dependency"),
         }
     }
 }
@@ -55,6 +88,15 @@ pub enum UpdateResult {
         /// When the new version was released
         #[serde(skip_serializing_if = "Option::is_none")]
         released_at: Option<DateTime<Utc>>,
+        /// Release notes for the versions between old and new (--notes)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        release_notes: Option<String>,
+        /// Update group whose members all update together (--group)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        group: Option<String>,
+        /// Mirror that served the versions because the primary registry failed (--registry-mirror)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        mirror: Option<String>,
     },
     /// Dependency update was skipped
     Skip {
@@ -72,6 +114,9 @@ impl UpdateResult {
             dependency,
             new_version: new_version.into(),
             released_at: None,
+            release_notes: None,
+            group: None,
+            mirror: None,
         }
     }
 
@@ -85,6 +130,9 @@ impl UpdateResult {
             dependency,
             new_version: new_version.into(),
             released_at: Some(released_at),
+            release_notes: None,
+            group: None,
+            mirror: None,
         }
     }
 
@@ -140,6 +188,51 @@ impl UpdateResult {
     pub fn package_name(&self) -> &str {
         &self.dependency().name
     }
+
+    /// Returns the attached release notes, if any
+    pub fn release_notes(&self) -> Option<&str> {
+        match self {
+            UpdateResult::Update { release_notes, .. } => release_notes.as_deref(),
+            UpdateResult::Skip { .. } => None,
+        }
+    }
+
+    /// Attaches release notes to an Update result (no-op for skips)
+    pub fn set_release_notes(&mut self, notes: impl Into<String>) {
+        if let UpdateResult::Update { release_notes, .. } = self {
+            *release_notes = Some(notes.into());
+        }
+    }
+
+    /// Returns the update group this result belongs to, if any
+    pub fn group(&self) -> Option<&str> {
+        match self {
+            UpdateResult::Update { group, .. } => group.as_deref(),
+            UpdateResult::Skip { .. } => None,
+        }
+    }
+
+    /// Assigns an Update result to an update group (no-op for skips)
+    pub fn set_group(&mut self, name: impl Into<String>) {
+        if let UpdateResult::Update { group, .. } = self {
+            *group = Some(name.into());
+        }
+    }
+
+    /// Returns the mirror that served this update's versions, if not the primary registry
+    pub fn mirror(&self) -> Option<&str> {
+        match self {
+            UpdateResult::Update { mirror, .. } => mirror.as_deref(),
+            UpdateResult::Skip { .. } => None,
+        }
+    }
+
+    /// Records the mirror that served an Update result's versions (no-op for skips)
+    pub fn set_mirror(&mut self, url: impl Into<String>) {
+        if let UpdateResult::Update { mirror, .. } = self {
+            *mirror = Some(url.into());
+        }
+    }
 }
 
 impl fmt::Display for UpdateResult {
@@ -149,6 +242,7 @@ impl fmt::Display for UpdateResult {
                 dependency,
                 new_version,
                 released_at,
+                ..
             } => {
                 write!(
                     f,
@@ -220,6 +314,40 @@ mod tests {
             format!("{}", SkipReason::LanguageFiltered),
             "language filtered"
         );
+        assert_eq!(
+            format!("{}", SkipReason::NonRegistrySource),
+            "path or VCS source"
+        );
+        assert_eq!(
+            format!("{}", SkipReason::NoReleaseSince("2024-06-01".to_string())),
+            "no release since 2024-06-01"
+        );
+        assert_eq!(
+            format!("{}", SkipReason::ComplexConstraint),
+            "complex constraint"
+        );
+        assert_eq!(format!("{}", SkipReason::PseudoVersion), "pseudo-version");
+        assert_eq!(
+            format!("{}", SkipReason::NotVulnerable),
+            "no known vulnerability"
+        );
+        assert_eq!(
+            format!("{}", SkipReason::NoAdvisoryDatabase),
+            "no advisory database"
+        );
+        assert_eq!(
+            format!("{}", SkipReason::GroupBlocked("babel".to_string())),
+            "group 'babel' blocked"
+        );
+        assert_eq!(
+            format!("{}", SkipReason::Patched),
+            "overridden by [patch]/[replace]"
+        );
+        assert_eq!(
+            format!("{}", SkipReason::NoDrift),
+            "lock up to date with range"
+        );
+        assert_eq!(format!("{}", SkipReason::Bundled), "bundled dependency");
     }
 
     #[test]
@@ -235,6 +363,7 @@ mod tests {
             dependency,
             new_version,
             released_at,
+            ..
         } = result
         {
             assert_eq!(dependency, dep);
@@ -258,6 +387,7 @@ mod tests {
             dependency,
             new_version,
             released_at,
+            ..
         } = result
         {
             assert_eq!(dependency, dep);
@@ -268,6 +398,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_update_result_release_notes() {
+        let mut result = UpdateResult::update(sample_dependency(), "2.0.0");
+        assert!(result.release_notes().is_none());
+
+        result.set_release_notes("Bug fixes");
+        assert_eq!(result.release_notes(), Some("Bug fixes"));
+
+        let mut skip = UpdateResult::skip(sample_dependency(), SkipReason::Excluded);
+        skip.set_release_notes("ignored");
+        assert!(skip.release_notes().is_none());
+    }
+
     #[test]
     fn test_update_result_skip() {
         let dep = sample_dependency();
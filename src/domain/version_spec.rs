@@ -104,6 +104,24 @@ impl VersionSpec {
 
         result
     }
+
+    /// Re-renders the spec with its own version, returning the result if it differs from `raw`
+    ///
+    /// Ranges and wildcards that keep their version for reference only (no prefix or
+    /// suffix to preserve) are never rewritten this way, so they always pass.
+    pub fn round_trip_mismatch(&self) -> Option<String> {
+        let reference_only = matches!(
+            self.kind,
+            VersionSpecKind::Range | VersionSpecKind::Wildcard | VersionSpecKind::Any
+        ) && self.prefix.is_none()
+            && self.suffix.is_none();
+        if reference_only {
+            return None;
+        }
+
+        let rendered = self.format_updated(&self.version);
+        (rendered != self.raw).then_some(rendered)
+    }
 }
 
 impl fmt::Display for VersionSpec {
@@ -238,4 +256,23 @@ mod tests {
         let parsed: VersionSpec = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed, spec);
     }
+
+    #[test]
+    fn test_round_trip_mismatch() {
+        let caret = VersionSpec::new(VersionSpecKind::Caret, "^1.2.3", "1.2.3").with_prefix("^");
+        assert_eq!(caret.round_trip_mismatch(), None);
+
+        let incompatible = VersionSpec::new(VersionSpecKind::Exact, "v3.0.0+incompatible", "3.0.0")
+            .with_prefix("v")
+            .with_suffix("+incompatible");
+        assert_eq!(incompatible.round_trip_mismatch(), None);
+
+        // Reference-only ranges are not checked
+        let range = VersionSpec::new(VersionSpecKind::Range, ">=1.0 <2.0", "1.0");
+        assert_eq!(range.round_trip_mismatch(), None);
+
+        // Prefix lost by the parser
+        let broken = VersionSpec::new(VersionSpecKind::Caret, "^1.2.3", "1.2.3");
+        assert_eq!(broken.round_trip_mismatch(), Some("1.2.3".to_string()));
+    }
 }
@@ -5,8 +5,10 @@
 //! - RegistryError: Issues with package registry communication
 //! - ConfigError: Issues with CLI configuration
 //! - IoError: File system operation failures
+//! - GitError: Failures committing updates with git (--git-commit)
 
 use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 
 use crate::domain::Language;
@@ -29,6 +31,10 @@ pub enum AppError {
     /// IO related errors
     #[error(transparent)]
     Io(#[from] IoError),
+
+    /// Git integration errors
+    #[error(transparent)]
+    Git(#[from] GitError),
 }
 
 /// Errors related to manifest file operations
@@ -46,6 +52,10 @@ pub enum ManifestError {
         source: std::io::Error,
     },
 
+    /// Manifest file is not valid UTF-8 (an encoding problem, not a syntax error)
+    #[error("manifest file {path} is not valid UTF-8 (invalid byte at offset {offset})")]
+    InvalidEncoding { path: PathBuf, offset: usize },
+
     /// Failed to write manifest file
     #[error("failed to write manifest file {path}: {source}")]
     WriteError {
@@ -96,7 +106,11 @@ pub enum RegistryError {
 
     /// Rate limit exceeded
     #[error("rate limit exceeded for {registry} registry")]
-    RateLimitExceeded { registry: String },
+    RateLimitExceeded {
+        registry: String,
+        /// Wait requested by the registry's `Retry-After` header, if any
+        retry_after: Option<Duration>,
+    },
 
     /// Invalid response from registry
     #[error("invalid response from {registry} for '{package}': {message}")]
@@ -121,6 +135,10 @@ pub enum RegistryError {
         registry: String,
         reason: String,
     },
+
+    /// Request blocked by the host allowlist or strict TLS policy
+    #[error("request to '{url}' blocked: {reason}")]
+    BlockedUrl { url: String, reason: String },
 }
 
 /// Errors related to configuration
@@ -163,6 +181,22 @@ pub enum IoError {
     },
 }
 
+/// Errors related to committing updates with git
+#[derive(Error, Debug)]
+pub enum GitError {
+    /// Uncommitted changes would be mixed into the update commit
+    #[error("working tree at {path} has uncommitted changes (use --allow-dirty to commit anyway)")]
+    DirtyWorkingTree { path: PathBuf },
+
+    /// The update branch already exists, so it can't be created for this run
+    #[error("branch {branch} already exists (use --branch to pick another name)")]
+    BranchExists { branch: String },
+
+    /// A git command failed or could not be run
+    #[error("`git {command}` failed: {message}")]
+    CommandFailed { command: String, message: String },
+}
+
 impl ManifestError {
     /// Creates a new NotFound error
     pub fn not_found(path: impl Into<PathBuf>) -> Self {
@@ -241,6 +275,7 @@ impl RegistryError {
     pub fn rate_limit_exceeded(registry: impl Into<String>) -> Self {
         RegistryError::RateLimitExceeded {
             registry: registry.into(),
+            retry_after: None,
         }
     }
 
@@ -252,6 +287,14 @@ impl RegistryError {
         }
     }
 
+    /// Creates a new BlockedUrl error
+    pub fn blocked_url(url: impl Into<String>, reason: impl Into<String>) -> Self {
+        RegistryError::BlockedUrl {
+            url: url.into(),
+            reason: reason.into(),
+        }
+    }
+
     /// Returns the registry name for this language
     pub fn registry_name(language: Language) -> &'static str {
         match language {
@@ -262,6 +305,12 @@ impl RegistryError {
             Language::Ruby => "RubyGems",
             Language::Php => "Packagist",
             Language::Java => "Maven Central",
+            Language::Deno => "JSR",
+            Language::Nim => "Nimble",
+            Language::Docker => "Docker Hub",
+            Language::R => "CRAN",
+            Language::Crystal => "GitHub",
+            Language::PreCommit => "GitHub/GitLab",
         }
     }
 }
@@ -358,6 +407,14 @@ mod tests {
         assert!(msg.contains("serde"));
     }
 
+    #[test]
+    fn test_registry_error_blocked_url() {
+        let err = RegistryError::blocked_url("http://example.com/pkg", "http is not allowed");
+        let msg = format!("{}", err);
+        assert!(msg.contains("http://example.com/pkg"));
+        assert!(msg.contains("blocked"));
+    }
+
     #[test]
     fn test_registry_name() {
         assert_eq!(RegistryError::registry_name(Language::Node), "npm");
@@ -399,6 +456,15 @@ mod tests {
         assert!(msg.contains("permission denied"));
     }
 
+    #[test]
+    fn test_git_error_dirty_working_tree() {
+        let err = GitError::DirtyWorkingTree {
+            path: PathBuf::from("."),
+        };
+        assert!(err.to_string().contains("uncommitted changes"));
+        assert!(err.to_string().contains("--allow-dirty"));
+    }
+
     #[test]
     fn test_app_error_from_manifest_error() {
         let manifest_err = ManifestError::not_found("/path");
@@ -0,0 +1,256 @@
+//! Git integration for committing updates (--git-commit)
+//!
+//! This module provides:
+//! - Dirty working tree and existing branch detection before anything is written
+//! - Default update branch names (`depup/update-YYYYMMDD`)
+//! - Commit messages summarizing the applied updates
+//! - Creating the branch before the manifests are written, then committing them
+//!
+//! Commands are run through the `git` executable, like package manager installs.
+
+use crate::domain::{UpdateResult, UpdateSummary};
+use crate::error::GitError;
+use chrono::NaiveDate;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Prefix of generated update branch names
+const BRANCH_PREFIX: &str = "depup/update-";
+
+/// Run a git command in `repo`, returning its trimmed standard output
+fn git(repo: &Path, args: &[&str]) -> Result<String, GitError> {
+    let failed = |message: String| GitError::CommandFailed {
+        command: args.join(" "),
+        message,
+    };
+
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()
+        .map_err(|e| failed(e.to_string()))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(failed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
+}
+
+/// Default branch name for updates made on `date`
+pub fn default_branch_name(date: NaiveDate) -> String {
+    format!("{}{}", BRANCH_PREFIX, date.format("%Y%m%d"))
+}
+
+/// Fail if the working tree has uncommitted changes
+pub fn ensure_clean(repo: &Path) -> Result<(), GitError> {
+    let status = git(repo, &["status", "--porcelain"])?;
+    if status.is_empty() {
+        Ok(())
+    } else {
+        Err(GitError::DirtyWorkingTree {
+            path: repo.to_path_buf(),
+        })
+    }
+}
+
+/// Fail if `branch` already exists, before anything is written
+pub fn ensure_branch_free(repo: &Path, branch: &str) -> Result<(), GitError> {
+    let reference = format!("refs/heads/{}", branch);
+    match git(repo, &["rev-parse", "--verify", "--quiet", &reference]) {
+        Ok(_) => Err(GitError::BranchExists {
+            branch: branch.to_string(),
+        }),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Create `branch` and check it out, so the manifests are written on it
+pub fn checkout_new_branch(repo: &Path, branch: &str) -> Result<(), GitError> {
+    git(repo, &["checkout", "--quiet", "-b", branch]).map(|_| ())
+}
+
+/// Return to the previously checked out branch and delete `branch`
+///
+/// Used when a run wrote nothing, so no empty update branch is left behind.
+pub fn discard_branch(repo: &Path, branch: &str) -> Result<(), GitError> {
+    git(repo, &["checkout", "--quiet", "-"])?;
+    git(repo, &["branch", "--quiet", "-D", branch]).map(|_| ())
+}
+
+/// Commit message summarizing the updates, with one Markdown list item per update
+pub fn commit_message(summary: &UpdateSummary) -> String {
+    let mut lines = Vec::new();
+    for manifest in &summary.manifests {
+        for result in manifest.updates() {
+            if let UpdateResult::Update {
+                dependency,
+                new_version,
+                ..
+            } = result
+            {
+                lines.push(format!(
+                    "- {} {} -> {} ({})",
+                    dependency.name,
+                    dependency.version_spec.version,
+                    new_version,
+                    manifest.path.display()
+                ));
+            }
+        }
+    }
+
+    let subject = match summary.total_updates() {
+        1 => "Update 1 dependency".to_string(),
+        n => format!("Update {} dependencies", n),
+    };
+    format!("{}\n\n{}\n", subject, lines.join("\n"))
+}
+
+/// Stage `files` and commit them on the current branch, returning the new commit's hash
+///
+/// Only the given files are staged, so other changes stay out of the commit.
+pub fn commit_updates(repo: &Path, files: &[PathBuf], message: &str) -> Result<String, GitError> {
+    // Absolute paths, so files resolve the same regardless of `repo`
+    let files: Vec<String> = files
+        .iter()
+        .map(|f| {
+            f.canonicalize()
+                .unwrap_or_else(|_| f.clone())
+                .display()
+                .to_string()
+        })
+        .collect();
+    let mut add = vec!["add", "--"];
+    add.extend(files.iter().map(String::as_str));
+    git(repo, &add)?;
+
+    git(repo, &["commit", "--quiet", "-m", message])?;
+    git(repo, &["rev-parse", "HEAD"])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Dependency, Language, ManifestUpdateResult, VersionSpec, VersionSpecKind};
+    use tempfile::TempDir;
+
+    fn init_repo() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for args in [
+            vec!["init", "--quiet"],
+            vec!["config", "user.name", "depup"],
+            vec!["config", "user.email", "depup@example.com"],
+        ] {
+            git(dir.path(), &args).unwrap();
+        }
+        std::fs::write(
+            dir.path().join("package.json"),
+            "{\"lodash\": \"^4.17.0\"}\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("README.md"), "demo\n").unwrap();
+        git(dir.path(), &["add", "."]).unwrap();
+        git(dir.path(), &["commit", "--quiet", "-m", "Initial commit"]).unwrap();
+        dir
+    }
+
+    fn sample_summary() -> UpdateSummary {
+        let spec = VersionSpec::new(VersionSpecKind::Caret, "^4.17.0", "4.17.0").with_prefix("^");
+        let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+        manifest.add_result(UpdateResult::update(
+            Dependency::new("lodash", spec, false, Language::Node),
+            "4.17.21",
+        ));
+        let mut summary = UpdateSummary::new(false);
+        summary.add_manifest(manifest);
+        summary
+    }
+
+    #[test]
+    fn test_default_branch_name() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+        assert_eq!(default_branch_name(date), "depup/update-20240309");
+    }
+
+    #[test]
+    fn test_commit_message() {
+        assert_eq!(
+            commit_message(&sample_summary()),
+            "Update 1 dependency\n\n- lodash 4.17.0 -> 4.17.21 (package.json)\n"
+        );
+    }
+
+    #[test]
+    fn test_ensure_clean() {
+        let repo = init_repo();
+        assert!(ensure_clean(repo.path()).is_ok());
+
+        std::fs::write(repo.path().join("README.md"), "changed\n").unwrap();
+        assert!(matches!(
+            ensure_clean(repo.path()),
+            Err(GitError::DirtyWorkingTree { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ensure_branch_free() {
+        let repo = init_repo();
+        assert!(ensure_branch_free(repo.path(), "depup/update-test").is_ok());
+
+        git(repo.path(), &["branch", "depup/update-test"]).unwrap();
+        assert!(matches!(
+            ensure_branch_free(repo.path(), "depup/update-test"),
+            Err(GitError::BranchExists { branch }) if branch == "depup/update-test"
+        ));
+    }
+
+    #[test]
+    fn test_discard_branch() {
+        let repo = init_repo();
+        let original = git(repo.path(), &["rev-parse", "--abbrev-ref", "HEAD"]).unwrap();
+        checkout_new_branch(repo.path(), "depup/update-test").unwrap();
+
+        discard_branch(repo.path(), "depup/update-test").unwrap();
+        assert_eq!(
+            git(repo.path(), &["rev-parse", "--abbrev-ref", "HEAD"]).unwrap(),
+            original
+        );
+        assert!(ensure_branch_free(repo.path(), "depup/update-test").is_ok());
+    }
+
+    #[test]
+    fn test_commit_updates_creates_branch_and_commit() {
+        let repo = init_repo();
+        // The branch is checked out before the manifest is written
+        checkout_new_branch(repo.path(), "depup/update-test").unwrap();
+        let manifest = repo.path().join("package.json");
+        std::fs::write(&manifest, "{\"lodash\": \"^4.17.21\"}\n").unwrap();
+        // Unrelated changes are left out of the commit
+        std::fs::write(repo.path().join("README.md"), "changed\n").unwrap();
+
+        let message = commit_message(&sample_summary());
+        let sha = commit_updates(repo.path(), &[manifest], &message).unwrap();
+
+        assert_eq!(
+            git(repo.path(), &["rev-parse", "--abbrev-ref", "HEAD"]).unwrap(),
+            "depup/update-test"
+        );
+        assert_eq!(git(repo.path(), &["rev-parse", "HEAD"]).unwrap(), sha);
+        assert_eq!(
+            git(repo.path(), &["show", "--name-only", "--format=", "HEAD"]).unwrap(),
+            "package.json"
+        );
+        assert_eq!(
+            git(repo.path(), &["log", "-1", "--format=%B"]).unwrap(),
+            message.trim()
+        );
+        assert_eq!(
+            git(repo.path(), &["status", "--porcelain"]).unwrap(),
+            "M README.md"
+        );
+    }
+}
@@ -6,10 +6,14 @@
 //! - Python (pyproject.toml)
 //! - Rust (Cargo.toml)
 //! - Go (go.mod)
+//!
+//! Use [`Depup`] to run it programmatically without going through the CLI.
 
+mod api;
 pub mod cli;
 pub mod domain;
 pub mod error;
+pub mod git;
 pub mod manifest;
 pub mod orchestrator;
 pub mod output;
@@ -18,3 +22,5 @@ pub mod parser;
 pub mod progress;
 pub mod registry;
 pub mod update;
+
+pub use api::{Depup, DepupBuilder, RunOptions};
@@ -6,18 +6,40 @@
 //! - Rust (Cargo.toml)
 //! - Go (go.mod)
 
+use anyhow::Context;
 use clap::Parser;
 use depup::cli::CliArgs;
-use depup::domain::Language;
-use depup::orchestrator::Orchestrator;
-use depup::output::{create_formatter, OutputConfig};
-use depup::package_manager::{run_installs, SystemPackageManager};
+use depup::domain::{Language, ManifestUpdateResult, UpdateResult};
+use depup::git;
+use depup::manifest::{
+    detect_manifests, find_unused_dependencies, manifests_from_list, read_tool_versions,
+    ManifestInfo, WriteResult, TOOL_VERSIONS_FILENAME,
+};
+use depup::orchestrator::{Orchestrator, OrchestratorError, OrchestratorResult};
+use depup::output::{
+    create_formatter, write_audit, write_language_list, write_prune_report, write_runtime_report,
+    JsonFormatter, OutputConfig, OutputFormatter, Verbosity,
+};
+use depup::package_manager::{
+    any_install_failed, restore_manifests, run_post_update_hooks, run_updates,
+    stale_composer_locks, SystemPackageManager, UpdatedPackage,
+};
+use depup::registry::{HttpClient, Netrc, RuntimeReleaseClient};
+use std::fs::File;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
+use std::sync::Arc;
 
 #[tokio::main]
 async fn main() -> ExitCode {
-    let args = CliArgs::parse();
+    let mut args = CliArgs::parse();
+
+    // --diff-exit-code only reports whether files would change, and --drift
+    // reports what an install would pick up
+    if args.diff_exit_code || args.drift {
+        args.dry_run = true;
+    }
 
     // Handle version flag
     if args.print_version {
@@ -25,6 +47,23 @@ async fn main() -> ExitCode {
         return ExitCode::SUCCESS;
     }
 
+    // Handle language listing
+    if args.list_languages {
+        let mut stdout = io::stdout().lock();
+        if let Err(e) = write_language_list(args.json, &mut stdout) {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    // --diff-exit-code reserves 1 for "files would change"
+    let error_code = if args.diff_exit_code {
+        ExitCode::from(2)
+    } else {
+        ExitCode::FAILURE
+    };
+
     // Change directory if --cd is specified
     if let Some(ref dir) = args.directory {
         if let Err(e) = std::env::set_current_dir(dir) {
@@ -33,18 +72,230 @@ async fn main() -> ExitCode {
                 dir.display(),
                 e
             );
-            return ExitCode::FAILURE;
+            return error_code;
         }
     }
 
     // Run the main logic and handle errors
-    match run(args).await {
+    let outcome = if args.runtimes {
+        run_runtimes(&args).await
+    } else if args.prune_report {
+        run_prune_report(&args)
+    } else {
+        run(args).await
+    };
+    match outcome {
         Ok(exit_code) => exit_code,
         Err(e) => {
             eprintln!("Error: {}", e);
-            ExitCode::FAILURE
+            error_code
+        }
+    }
+}
+
+/// Report runtime pins from .tool-versions (--runtimes)
+async fn run_runtimes(args: &CliArgs) -> anyhow::Result<ExitCode> {
+    let Some(entries) = read_tool_versions(&args.path) else {
+        eprintln!(
+            "Error: no {} found in '{}'",
+            TOOL_VERSIONS_FILENAME,
+            args.path.display()
+        );
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let client = HttpClient::new()?
+        .with_allowed_hosts(args.allowed_hosts.clone())
+        .with_strict_tls(args.strict_tls);
+    let client = match Netrc::load() {
+        Some(netrc) => client.with_netrc(netrc),
+        None => client,
+    };
+    let checks = RuntimeReleaseClient::new(client).check(&entries).await;
+
+    let mut stdout = io::stdout().lock();
+    write_runtime_report(&checks, args.json, &mut stdout)?;
+    stdout.flush()?;
+
+    if checks.iter().any(|c| c.error.is_some()) {
+        Ok(ExitCode::from(2))
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+/// Report declared dependencies with no import found in source (--prune-report)
+fn run_prune_report(args: &CliArgs) -> anyhow::Result<ExitCode> {
+    let mut unused = Vec::new();
+    for manifest in detect_manifests(&args.path) {
+        match find_unused_dependencies(&manifest) {
+            Ok(found) => unused.extend(found),
+            Err(e) => eprintln!("Warning: {}", e),
+        }
+    }
+
+    let mut stdout = io::stdout().lock();
+    write_prune_report(&unused, args.json, &mut stdout)?;
+    stdout.flush()?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// What the rest of a run needs to know once results have been printed
+struct RunOutcome {
+    errors: Vec<OrchestratorError>,
+    /// Updated packages per language, languages in first-seen order
+    updated_packages: Vec<(Language, Vec<UpdatedPackage>)>,
+    has_updates: bool,
+    any_written: bool,
+    /// Manifests rewritten on disk
+    written_files: Vec<PathBuf>,
+    /// Content of the rewritten manifests before the write (--rollback-on-install-failure)
+    originals: Vec<(PathBuf, String)>,
+}
+
+impl RunOutcome {
+    fn from_result(result: OrchestratorResult) -> Self {
+        let mut outcome = Self {
+            errors: result.errors,
+            updated_packages: Vec::new(),
+            has_updates: false,
+            any_written: result.write_results.iter().any(|r| r.file_modified),
+            written_files: result
+                .write_results
+                .iter()
+                .filter(|r| r.file_modified)
+                .map(|r| r.path.clone())
+                .collect(),
+            originals: Vec::new(),
+        };
+        outcome.record_originals(&result.write_results);
+        for manifest in &result.summary.manifests {
+            outcome.record_manifest(manifest);
+        }
+        outcome
+    }
+
+    fn record_originals<'a>(&mut self, write_results: impl IntoIterator<Item = &'a WriteResult>) {
+        for result in write_results {
+            if let Some(original) = &result.original {
+                self.originals.push((result.path.clone(), original.clone()));
+            }
         }
     }
+
+    fn record_manifest(&mut self, manifest: &ManifestUpdateResult) {
+        if manifest.has_updates() {
+            self.has_updates = true;
+            let index = match self
+                .updated_packages
+                .iter()
+                .position(|(language, _)| *language == manifest.language)
+            {
+                Some(index) => index,
+                None => {
+                    self.updated_packages.push((manifest.language, Vec::new()));
+                    self.updated_packages.len() - 1
+                }
+            };
+            let packages = &mut self.updated_packages[index].1;
+            for result in manifest.updates() {
+                if let UpdateResult::Update {
+                    dependency,
+                    new_version,
+                    ..
+                } = result
+                {
+                    packages.push(
+                        UpdatedPackage::new(
+                            &dependency.name,
+                            dependency.version_spec.format_updated(new_version),
+                        )
+                        .with_exact(dependency.version_spec.is_pinned()),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Tell how many bare versions got the --normalize-prefix operator in a manifest
+fn report_normalized(result: &WriteResult, dry_run: bool) {
+    if result.prefixes_normalized > 0 {
+        eprintln!(
+            "{} {} version prefix(es) in {}",
+            if dry_run {
+                "Would normalize"
+            } else {
+                "Normalized"
+            },
+            result.prefixes_normalized,
+            result.path.display()
+        );
+    }
+}
+
+/// Print each manifest as soon as it is processed (--max-parallel-manifests)
+async fn run_streaming(
+    orchestrator: Arc<Orchestrator>,
+    max_in_flight: usize,
+    formatter: &dyn OutputFormatter,
+    args: &CliArgs,
+) -> anyhow::Result<RunOutcome> {
+    let mut outcome = RunOutcome {
+        errors: Vec::new(),
+        updated_packages: Vec::new(),
+        has_updates: false,
+        any_written: false,
+        written_files: Vec::new(),
+        originals: Vec::new(),
+    };
+    let mut output_error = None;
+
+    let errors = orchestrator
+        .run_streaming(max_in_flight, |manifest, write_result| {
+            outcome.record_manifest(manifest);
+            if let Some(written) = write_result.filter(|r| r.file_modified) {
+                outcome.any_written = true;
+                outcome.written_files.push(written.path.clone());
+            }
+            outcome.record_originals(write_result);
+            if let Some(written) = write_result.filter(|_| !args.quiet) {
+                report_normalized(written, args.dry_run);
+            }
+
+            if output_error.is_none() {
+                let mut stdout = io::stdout().lock();
+                if let Err(e) = formatter
+                    .format_manifest(manifest, &mut stdout)
+                    .and_then(|()| stdout.flush())
+                {
+                    output_error = Some(e);
+                }
+            }
+        })
+        .await;
+
+    if let Some(e) = output_error {
+        return Err(e.into());
+    }
+    outcome.errors = errors;
+    Ok(outcome)
+}
+
+/// Manifests listed in `source` (`-` for stdin), warning about unsupported paths
+fn read_manifest_list(source: &Path) -> anyhow::Result<Vec<ManifestInfo>> {
+    let list = if source == Path::new("-") {
+        std::io::read_to_string(std::io::stdin())?
+    } else {
+        std::fs::read_to_string(source)
+            .with_context(|| format!("failed to read manifest list {}", source.display()))?
+    };
+    let (manifests, unsupported) = manifests_from_list(&list);
+    for path in unsupported {
+        eprintln!("Warning: {} is not a supported manifest", path.display());
+    }
+    Ok(manifests)
 }
 
 /// Main application logic
@@ -58,46 +309,180 @@ async fn run(args: CliArgs) -> anyhow::Result<ExitCode> {
         }
     }
 
+    // Create output formatter based on CLI options
+    let output_config = OutputConfig::from_cli(
+        args.json,
+        args.diff,
+        args.sarif,
+        args.verbose,
+        args.quiet,
+        args.dry_run,
+    )
+    .with_template(args.template.clone())
+    .with_zerover_minor_as_major(args.treat_zerover_minor_as_major)
+    .with_json_lines(args.max_parallel_manifests.is_some())
+    .with_quiet_errors(args.quiet_errors)
+    .with_lock_versions(args.drift)
+    .with_batch_size(args.batch_size.map(|n| n as usize))
+    .with_dedupe_manifests(args.dedupe_manifests)
+    .with_summary(!args.no_summary)
+    .with_hide_latest(args.hide_latest)
+    .with_scan_root(&args.path);
+    let formatter = create_formatter(output_config.clone());
+
+    // --git-commit must not sweep unrelated changes into the update commit, and
+    // the manifests are written on the update branch, so it is created up front
+    let branch = if args.git_commit {
+        if !args.allow_dirty {
+            git::ensure_clean(&args.path)?;
+        }
+        let branch = args
+            .branch
+            .clone()
+            .unwrap_or_else(|| git::default_branch_name(chrono::Local::now().date_naive()));
+        git::ensure_branch_free(&args.path, &branch)?;
+        git::checkout_new_branch(&args.path, &branch)?;
+        Some(branch)
+    } else {
+        None
+    };
+
     // Create and run the orchestrator
-    let orchestrator = Orchestrator::new(args.clone())?;
-    let result = orchestrator.run().await;
+    let mut orchestrator = Orchestrator::new(args.clone())?;
+    if let Some(source) = &args.manifests_from {
+        orchestrator = orchestrator.with_manifests(read_manifest_list(source)?);
+    }
+    let orchestrator = Arc::new(orchestrator);
+    let mut commit_message = None;
+    let outcome = match args.max_parallel_manifests {
+        Some(max_in_flight) => {
+            run_streaming(
+                Arc::clone(&orchestrator),
+                max_in_flight as usize,
+                formatter.as_ref(),
+                &args,
+            )
+            .await?
+        }
+        None => {
+            let result = orchestrator.run().await;
 
-    // Create output formatter based on CLI options
-    let output_config =
-        OutputConfig::from_cli(args.json, args.diff, args.verbose, args.quiet, args.dry_run);
-    let formatter = create_formatter(output_config);
+            // Output results
+            let mut stdout = io::stdout().lock();
+            formatter.format(&result, &mut stdout)?;
+            stdout.flush()?;
+            drop(stdout);
 
-    // Output results
-    let mut stdout = io::stdout().lock();
-    formatter.format(&result, &mut stdout)?;
-    stdout.flush()?;
+            if !args.quiet {
+                for write_result in &result.write_results {
+                    report_normalized(write_result, args.dry_run);
+                }
+            }
 
-    // Print errors in verbose mode
-    if args.verbose && !result.errors.is_empty() {
+            // Persist the same output to a file if requested (without terminal colors)
+            if let Some(ref path) = args.write_summary {
+                let mut file = io::BufWriter::new(File::create(path)?);
+                // The file gets the full listing, not lines fitted to the terminal
+                let file_formatter = create_formatter(output_config.clone().with_fit_width(false));
+                colored::control::set_override(false);
+                let written = file_formatter.format(&result, &mut file);
+                colored::control::unset_override();
+                written?;
+                file.flush()?;
+            }
+
+            // Compliance record of every decision, with the versions the registry returned
+            if let Some(ref path) = args.audit {
+                let mut file = io::BufWriter::new(File::create(path)?);
+                write_audit(
+                    &result,
+                    |dep| orchestrator.latest_seen(dep.language, dep.lookup_name()),
+                    &mut file,
+                )?;
+                file.flush()?;
+            }
+
+            // Machine-readable copy for wrapper scripts, independent of the stdout format
+            if args.summary_json_to_stderr {
+                let mut stderr = io::stderr().lock();
+                JsonFormatter::new(Verbosity::Normal)
+                    .with_json_lines(true)
+                    .format(&result, &mut stderr)?;
+                stderr.flush()?;
+            }
+
+            if args.git_commit {
+                commit_message = Some(git::commit_message(&result.summary));
+            }
+            RunOutcome::from_result(result)
+        }
+    };
+
+    // --strict-parse: malformed manifests are a hard failure with their own exit code
+    if args.strict_parse && outcome.errors.iter().any(OrchestratorError::is_parse_error) {
+        for error in outcome.errors.iter().filter(|e| e.is_parse_error()) {
+            eprintln!("Error: {}", error);
+        }
+        return Ok(ExitCode::from(3));
+    }
+
+    // Print errors in verbose mode (registry failures stay aggregated with --quiet-errors)
+    let (warnings, listed_errors): (Vec<_>, Vec<_>) = outcome
+        .errors
+        .iter()
+        .filter(|e| !(args.quiet_errors && matches!(e, OrchestratorError::RegistryError { .. })))
+        .partition(|e| e.is_warning());
+    if args.verbose && !listed_errors.is_empty() {
         eprintln!();
         eprintln!("Errors encountered:");
-        for error in &result.errors {
+        for error in listed_errors {
             eprintln!("  - {}", error);
         }
     }
+    if args.verbose && !warnings.is_empty() {
+        eprintln!();
+        eprintln!("Warnings:");
+        for warning in warnings {
+            eprintln!("  - {}", warning);
+        }
+    }
 
-    // Determine which languages had updates
-    let mut updated_languages: Vec<Language> = Vec::new();
-    for manifest in &result.summary.manifests {
-        if manifest.has_updates() && !updated_languages.contains(&manifest.language) {
-            updated_languages.push(manifest.language);
+    // List slow registry lookups in verbose mode (--slow-threshold)
+    let slow_lookups = orchestrator.slow_lookups();
+    if args.verbose && !slow_lookups.is_empty() {
+        eprintln!();
+        eprintln!("Slow lookups:");
+        for lookup in &slow_lookups {
+            eprintln!(
+                "  - {} ({}): {} ms",
+                lookup.package,
+                lookup.language.display_name(),
+                lookup.duration.as_millis()
+            );
+        }
+    }
+
+    let updated_packages = &outcome.updated_packages;
+
+    // Without --install, composer.lock no longer matches the rewritten composer.json
+    if !args.install && !args.quiet {
+        for lock in stale_composer_locks(&outcome.written_files) {
+            eprintln!(
+                "Note: {} is now out of date; run `composer update` for the updated packages (or use --install)",
+                lock.display()
+            );
         }
     }
 
     // Run package manager install if requested and not dry-run
-    if args.install && !args.dry_run && !updated_languages.is_empty() {
+    if args.install && !args.dry_run && !updated_packages.is_empty() {
         if args.verbose {
             eprintln!();
             eprintln!("Running package manager install...");
         }
 
         let pm_runner = SystemPackageManager::new();
-        let install_results = run_installs(&pm_runner, &updated_languages, &args.path);
+        let install_results = run_updates(&pm_runner, updated_packages, &args.path);
 
         for install_result in &install_results {
             if install_result.command.is_empty() {
@@ -125,22 +510,73 @@ async fn run(args: CliArgs) -> anyhow::Result<ExitCode> {
             }
         }
 
-        // Check if any install failed
-        let any_install_failed = install_results
-            .iter()
-            .any(|r| !r.command.is_empty() && !r.success);
-        if any_install_failed {
+        if any_install_failed(&install_results) {
+            // --rollback-on-install-failure: put the manifests back as they were
+            if args.rollback_on_install_failure {
+                for (path, error) in restore_manifests(&outcome.originals) {
+                    eprintln!("  failed to restore {}: {}", path.display(), error);
+                }
+                if !args.quiet {
+                    eprintln!(
+                        "Restored {} manifest(s) after the failed install",
+                        outcome.originals.len()
+                    );
+                }
+            }
             return Ok(ExitCode::FAILURE);
         }
     }
 
+    // Run post-update hooks once manifests were actually rewritten
+    if !args.post_update.is_empty() && !args.dry_run && outcome.any_written {
+        for hook in run_post_update_hooks(&args.post_update, &args.path) {
+            if hook.success {
+                if args.verbose {
+                    eprintln!("  post-update hook completed: {}", hook.command);
+                    if !hook.stdout.is_empty() {
+                        eprint!("{}", hook.stdout);
+                    }
+                }
+            } else {
+                eprintln!("  post-update hook failed: {}", hook.command);
+                if !hook.stdout.is_empty() {
+                    eprint!("{}", hook.stdout);
+                }
+                if !hook.stderr.is_empty() {
+                    eprint!("{}", hook.stderr);
+                }
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    }
+
+    // Commit the rewritten manifests on the update branch, or drop it if nothing changed
+    if let Some(branch) = branch {
+        match commit_message.filter(|_| !outcome.written_files.is_empty()) {
+            Some(message) => {
+                let commit = git::commit_updates(&args.path, &outcome.written_files, &message)?;
+                if args.verbose {
+                    eprintln!(
+                        "  committed {} on branch {}",
+                        &commit[..commit.len().min(12)],
+                        branch
+                    );
+                }
+            }
+            None => git::discard_branch(&args.path, &branch)?,
+        }
+    }
+
     // Return appropriate exit code
-    let has_errors = !result.errors.is_empty();
-    let has_updates = result.summary.total_updates() > 0;
+    let has_errors = outcome.errors.iter().any(|e| !e.is_warning());
+    let has_updates = outcome.has_updates;
 
     if has_errors {
         // Partial success - some errors occurred
         Ok(ExitCode::from(2))
+    } else if args.diff_exit_code && has_updates {
+        // Files would change (like `git diff --exit-code`)
+        Ok(ExitCode::from(1))
     } else if has_updates || args.dry_run {
         // Success - updates were made (or would be in dry-run)
         Ok(ExitCode::SUCCESS)
@@ -0,0 +1,193 @@
+//! Cargo.lock reader for resolved Rust crate versions
+//!
+//! Handles:
+//! - `[[package]]` entries (name + version)
+//! - Locating the lock file next to the manifest or at the workspace root
+//! - Crates locked at several semver-incompatible versions
+
+use crate::domain::Dependency;
+use std::collections::HashMap;
+use std::path::Path;
+use toml::Value;
+
+/// Cargo lock file name
+pub const CARGO_LOCK_FILENAME: &str = "Cargo.lock";
+
+/// Resolved crate versions read from a Cargo.lock file
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CargoLock {
+    /// Locked versions per crate name
+    packages: HashMap<String, Vec<String>>,
+}
+
+impl CargoLock {
+    /// Parse Cargo.lock content, returning `None` if it isn't valid TOML
+    pub fn parse(content: &str) -> Option<Self> {
+        let toml: Value = toml::from_str(content).ok()?;
+        let mut packages: HashMap<String, Vec<String>> = HashMap::new();
+
+        for package in toml
+            .get("package")
+            .and_then(|p| p.as_array())
+            .into_iter()
+            .flatten()
+        {
+            let name = package.get("name").and_then(|n| n.as_str());
+            let version = package.get("version").and_then(|v| v.as_str());
+            if let (Some(name), Some(version)) = (name, version) {
+                packages
+                    .entry(name.to_string())
+                    .or_default()
+                    .push(version.to_string());
+            }
+        }
+
+        Some(Self { packages })
+    }
+
+    /// Find and parse the Cargo.lock that applies to a Cargo.toml
+    ///
+    /// Workspace members share the lock file at the workspace root, so the
+    /// manifest's directory and its ancestors are searched in order.
+    pub fn find(manifest_path: &Path) -> Option<Self> {
+        manifest_path
+            .parent()?
+            .ancestors()
+            .map(|dir| dir.join(CARGO_LOCK_FILENAME))
+            .find(|path| path.is_file())
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| Self::parse(&content))
+    }
+
+    /// Locked version of a crate compatible with the given manifest version
+    ///
+    /// When a crate is locked at several versions, the newest one in the same
+    /// semver-compatible series as `version` is returned.
+    pub fn resolved_version(&self, name: &str, version: &str) -> Option<&str> {
+        let wanted = compatibility_key(version);
+        self.packages
+            .get(name)?
+            .iter()
+            .filter(|locked| compatibility_key(locked) == wanted)
+            .max_by(|a, b| crate::update::compare_versions(a, b))
+            .map(String::as_str)
+    }
+
+    /// Attach resolved versions to the dependencies found in the lock file
+    pub fn attach_resolved_versions(&self, dependencies: Vec<Dependency>) -> Vec<Dependency> {
+        dependencies
+            .into_iter()
+            .map(
+                |dep| match self.resolved_version(&dep.name, dep.version()) {
+                    Some(resolved) => dep.with_resolved_version(resolved),
+                    None => dep,
+                },
+            )
+            .collect()
+    }
+}
+
+/// Leading version components that Cargo treats as compatible
+/// (`1.2.3` -> `1`, `0.3.1` -> `0.3`, `0.0.4` -> `0.0.4`)
+fn compatibility_key(version: &str) -> Vec<&str> {
+    let mut key = Vec::new();
+    for part in version.split(['.', '-', '+']).take(3) {
+        key.push(part);
+        if part != "0" {
+            break;
+        }
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Language, VersionSpec, VersionSpecKind};
+    use tempfile::TempDir;
+
+    const SAMPLE_LOCK: &str = r#"
+# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "myapp"
+version = "0.1.0"
+dependencies = [
+ "rand 0.7.3",
+ "rand 0.8.5",
+ "serde",
+]
+
+[[package]]
+name = "rand"
+version = "0.7.3"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "rand"
+version = "0.8.5"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "serde"
+version = "1.0.190"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#;
+
+    fn rust_dependency(name: &str, version: &str) -> Dependency {
+        let spec = VersionSpec::new(VersionSpecKind::Caret, version, version);
+        Dependency::new(name, spec, false, Language::Rust)
+    }
+
+    #[test]
+    fn test_parse_cargo_lock() {
+        let lock = CargoLock::parse(SAMPLE_LOCK).unwrap();
+        assert_eq!(lock.resolved_version("serde", "1.0"), Some("1.0.190"));
+        assert_eq!(lock.resolved_version("myapp", "0.1.0"), Some("0.1.0"));
+        assert_eq!(lock.resolved_version("tokio", "1"), None);
+    }
+
+    #[test]
+    fn test_resolved_version_picks_compatible_series() {
+        let lock = CargoLock::parse(SAMPLE_LOCK).unwrap();
+        assert_eq!(lock.resolved_version("rand", "0.7"), Some("0.7.3"));
+        assert_eq!(lock.resolved_version("rand", "0.8.0"), Some("0.8.5"));
+        assert_eq!(lock.resolved_version("rand", "0.9"), None);
+    }
+
+    #[test]
+    fn test_attach_resolved_versions() {
+        let lock = CargoLock::parse(SAMPLE_LOCK).unwrap();
+        let deps = lock.attach_resolved_versions(vec![
+            rust_dependency("serde", "1.0.100"),
+            rust_dependency("rand", "0.8"),
+            rust_dependency("tokio", "1.0"),
+        ]);
+
+        assert_eq!(deps[0].resolved_version.as_deref(), Some("1.0.190"));
+        assert_eq!(deps[1].resolved_version.as_deref(), Some("0.8.5"));
+        assert_eq!(deps[2].resolved_version, None);
+    }
+
+    #[test]
+    fn test_parse_invalid_lock() {
+        assert!(CargoLock::parse("not = [valid").is_none());
+        assert_eq!(
+            CargoLock::parse("version = 3").unwrap(),
+            CargoLock::default()
+        );
+    }
+
+    #[test]
+    fn test_find_lock_in_workspace_root() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join(CARGO_LOCK_FILENAME), SAMPLE_LOCK).unwrap();
+        let member = temp_dir.path().join("crates/member");
+        std::fs::create_dir_all(&member).unwrap();
+
+        let lock = CargoLock::find(&member.join("Cargo.toml")).unwrap();
+        assert_eq!(lock.resolved_version("serde", "1"), Some("1.0.190"));
+    }
+}
@@ -4,11 +4,13 @@
 //! - dependencies
 //! - dev-dependencies
 //! - build-dependencies
+//! - Target-specific tables: [target.'cfg(...)'.dependencies] (and dev-/build-)
 //! - workspace.dependencies (for Cargo workspace root)
 //! - Inline table format: { version = "1.0" }
 //! - Workspace dependencies
+//! - [patch.*] and [replace] overrides (marked as skipped)
 
-use crate::domain::{Dependency, Language};
+use crate::domain::{Dependency, Language, SkipReason};
 use crate::error::ManifestError;
 use crate::manifest::ManifestParser;
 use crate::parser::{get_parser, VersionParser};
@@ -58,6 +60,12 @@ impl ManifestParser for CargoTomlParser {
                 {
                     parse_cargo_dependencies(deps, parser.as_ref(), true, &mut dependencies);
                 }
+                if let Some(deps) = target_config
+                    .get("build-dependencies")
+                    .and_then(|d| d.as_table())
+                {
+                    parse_cargo_dependencies(deps, parser.as_ref(), true, &mut dependencies);
+                }
             }
         }
 
@@ -68,6 +76,15 @@ impl ManifestParser for CargoTomlParser {
             }
         }
 
+        // Crates overridden by [patch.*] or [replace] point at an intentional fork
+        let patched = patched_crates(&toml);
+        for dep in dependencies
+            .iter_mut()
+            .filter(|dep| dep.skip_reason.is_none() && patched.contains(&dep.name.as_str()))
+        {
+            dep.skip_reason = Some(SkipReason::Patched);
+        }
+
         Ok(dependencies)
     }
 
@@ -85,60 +102,59 @@ impl ManifestParser for CargoTomlParser {
         let mut result = content.to_string();
         let mut updated = false;
 
-        // Pattern for simple version: package = "1.0.0" or package = "^1.0.0"
-        let simple_pattern = format!(r#"(?m)^(\s*{})\s*=\s*"([^"]+)""#, regex::escape(package));
-        if let Ok(re) = Regex::new(&simple_pattern) {
-            if let Some(caps) = re.captures(&result) {
-                let old_version = caps.get(2).map(|m| m.as_str()).unwrap_or("");
-                // Check if this is a simple version string (not a path or git dependency)
-                if !old_version.contains('/') && !old_version.starts_with('{') {
-                    if let Some(spec) = parser.parse(old_version) {
-                        let new_ver = spec.format_updated(new_version);
-                        let replacement = format!(r#"{} = "{}""#, &caps[1], new_ver);
-                        result = re.replace(&result, replacement.as_str()).to_string();
-                        updated = true;
+        // Every occurrence is rewritten, so a crate declared in several tables
+        // (e.g., [dependencies] and [dev-dependencies]) moves to the same version
+        let mut replace_versions = |result: &mut String, pattern: &str| {
+            let Ok(re) = Regex::new(pattern) else {
+                return;
+            };
+            *result = re
+                .replace_all(result, |caps: &regex::Captures| {
+                    let old_version = &caps[2];
+                    // Path or git dependencies keep their value
+                    if old_version.contains('/') || old_version.starts_with('{') {
+                        return caps[0].to_string();
                     }
-                }
-            }
-        }
+                    match parser.parse(old_version) {
+                        Some(spec) => {
+                            updated = true;
+                            format!(r#"{}"{}""#, &caps[1], spec.format_updated(new_version))
+                        }
+                        None => caps[0].to_string(),
+                    }
+                })
+                .to_string();
+        };
 
-        // Pattern for inline table: package = { version = "1.0.0", ... }
-        // Match only the version value part to preserve the rest of the line
-        let table_pattern = format!(
-            r#"(?m)({})\s*=\s*\{{\s*version\s*=\s*"([^"]+)""#,
-            regex::escape(package)
+        // Pattern for simple version: package = "1.0.0" or package = "^1.0.0"
+        replace_versions(
+            &mut result,
+            &format!(r#"(?m)^(\s*{}\s*=\s*)"([^"]+)""#, regex::escape(package)),
+        );
+
+        // Pattern for inline table: package = { ..., version = "1.0.0", ... }
+        // Capture everything up to the version value so that only the value is
+        // replaced and every other key (before or after `version`) is preserved
+        replace_versions(
+            &mut result,
+            &format!(
+                r#"(?m)^(\s*{}\s*=\s*\{{(?:[^}}\n]*?,)?\s*version\s*=\s*)"([^"]+)""#,
+                regex::escape(package)
+            ),
         );
-        if let Ok(re) = Regex::new(&table_pattern) {
-            if let Some(caps) = re.captures(&result) {
-                let old_version = caps.get(2).map(|m| m.as_str()).unwrap_or("");
-                if let Some(spec) = parser.parse(old_version) {
-                    let new_ver = spec.format_updated(new_version);
-                    let replacement = format!(r#"{} = {{ version = "{}""#, &caps[1], new_ver);
-                    result = re.replace(&result, replacement.as_str()).to_string();
-                    updated = true;
-                }
-            }
-        }
 
         // Pattern for multi-line table format:
         // [dependencies.package]
         // version = "1.0.0"
-        // Also handles [workspace.dependencies.package]
-        let multiline_pattern = format!(
-            r#"(?m)(\[(?:dependencies|dev-dependencies|build-dependencies|workspace\.dependencies)\.{}[^\]]*\][^\[]*version\s*=\s*)"([^"]+)""#,
-            regex::escape(package)
+        // Also handles [workspace.dependencies.package] and target-scoped
+        // tables such as [target.'cfg(windows)'.build-dependencies.package]
+        replace_versions(
+            &mut result,
+            &format!(
+                r#"(?m)(\[(?:target\.(?:'[^']*'|"[^"]*"|[^.\]\s]+)\.)?(?:dependencies|dev-dependencies|build-dependencies|workspace\.dependencies)\.{}[^\]]*\][^\[]*version\s*=\s*)"([^"]+)""#,
+                regex::escape(package)
+            ),
         );
-        if let Ok(re) = Regex::new(&multiline_pattern) {
-            if let Some(caps) = re.captures(&result) {
-                let old_version = caps.get(2).map(|m| m.as_str()).unwrap_or("");
-                if let Some(spec) = parser.parse(old_version) {
-                    let new_ver = spec.format_updated(new_version);
-                    let replacement = format!(r#"{}"{}""#, &caps[1], new_ver);
-                    result = re.replace(&result, replacement.as_str()).to_string();
-                    updated = true;
-                }
-            }
-        }
 
         if updated {
             Ok(result)
@@ -152,6 +168,27 @@ impl ManifestParser for CargoTomlParser {
     }
 }
 
+/// Names of crates overridden in `[patch.<registry>]` or `[replace]`
+///
+/// `[replace]` keys are package IDs (`name:version`), so only the name is kept.
+fn patched_crates(toml: &Value) -> Vec<&str> {
+    let patch = toml
+        .get("patch")
+        .and_then(|p| p.as_table())
+        .into_iter()
+        .flat_map(|sources| sources.values())
+        .filter_map(|source| source.as_table())
+        .flat_map(|crates| crates.keys().map(String::as_str));
+    let replace = toml
+        .get("replace")
+        .and_then(|r| r.as_table())
+        .into_iter()
+        .flat_map(|crates| crates.keys())
+        .map(|id| id.split(':').next().unwrap_or(id));
+
+    patch.chain(replace).collect()
+}
+
 fn parse_cargo_dependencies(
     deps: &toml::map::Map<String, Value>,
     parser: &dyn VersionParser,
@@ -208,6 +245,37 @@ tokio = "^1.28.0"
         assert_eq!(tokio.version_spec.kind, VersionSpecKind::Caret);
     }
 
+    #[test]
+    fn test_parse_patched_crates_are_skipped() {
+        let content = r#"
+[dependencies]
+serde = "1.0"
+tokio = "1.28"
+log = "0.4"
+
+[patch.crates-io]
+serde = { git = "https://github.com/example/serde", branch = "fix" }
+
+[replace]
+"log:0.4.20" = { path = "vendor/log" }
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 3);
+
+        let serde = deps.iter().find(|d| d.name == "serde").unwrap();
+        assert_eq!(serde.skip_reason, Some(SkipReason::Patched));
+        let log = deps.iter().find(|d| d.name == "log").unwrap();
+        assert_eq!(log.skip_reason, Some(SkipReason::Patched));
+        let tokio = deps.iter().find(|d| d.name == "tokio").unwrap();
+        assert!(tokio.skip_reason.is_none());
+
+        // Only the unpatched crate reaches the registry
+        let judge = crate::update::UpdateJudge::new(crate::update::UpdateFilter::new());
+        assert_eq!(judge.should_skip(serde), Some(SkipReason::Patched));
+        assert_eq!(judge.should_skip(tokio), None);
+    }
+
     #[test]
     fn test_parse_pinned_version() {
         let content = r#"
@@ -371,6 +439,57 @@ libc = "0.2"
         assert!(!libc.is_dev);
     }
 
+    #[test]
+    fn test_parse_target_specific_build_dependencies() {
+        let content = r#"
+[target.'cfg(windows)'.build-dependencies]
+winres = "0.1"
+
+[target.'cfg(unix)'.dev-dependencies]
+nix = "0.27"
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 2);
+
+        let winres = deps.iter().find(|d| d.name == "winres").unwrap();
+        assert!(winres.is_dev);
+        assert_eq!(winres.version_spec.version, "0.1");
+    }
+
+    #[test]
+    fn test_update_target_specific_build_dependency() {
+        let content = r#"[target.'cfg(windows)'.build-dependencies]
+winres = "0.1"
+
+[target.'cfg(windows)'.dependencies.winapi]
+version = "0.3.8"
+features = ["winuser"]
+
+[target."cfg(unix)".build-dependencies.cc]
+version = "1.0.0"
+"#;
+
+        let result = CargoTomlParser
+            .update_version(content, "winres", "0.1.12")
+            .unwrap();
+        assert!(result.contains("winres = \"0.1.12\""));
+
+        let result = CargoTomlParser
+            .update_version(&result, "winapi", "0.3.9")
+            .unwrap();
+        assert!(result.contains(
+            "[target.'cfg(windows)'.dependencies.winapi]\nversion = \"0.3.9\"\nfeatures = [\"winuser\"]"
+        ));
+
+        let result = CargoTomlParser
+            .update_version(&result, "cc", "1.0.83")
+            .unwrap();
+        assert!(
+            result.contains("[target.\"cfg(unix)\".build-dependencies.cc]\nversion = \"1.0.83\"")
+        );
+    }
+
     #[test]
     fn test_update_simple_version() {
         let content = r#"
@@ -384,6 +503,30 @@ serde = "1.0.0"
         assert!(result.contains("\"1.1.0\""));
     }
 
+    #[test]
+    fn test_update_every_dependency_table() {
+        let content = r#"
+[dependencies]
+serde = { version = "1.0.190", features = ["derive"] }
+
+[dev-dependencies]
+serde = "1.0.180"
+serde_json = "1.0.100"
+
+[build-dependencies.serde]
+version = "=1.0.190"
+"#;
+
+        let result = CargoTomlParser
+            .update_version(content, "serde", "1.0.200")
+            .unwrap();
+        assert!(result.contains(r#"serde = { version = "1.0.200", features = ["derive"] }"#));
+        assert!(
+            result.contains("[dev-dependencies]\nserde = \"1.0.200\"\nserde_json = \"1.0.100\"")
+        );
+        assert!(result.contains("[build-dependencies.serde]\nversion = \"=1.0.200\""));
+    }
+
     #[test]
     fn test_update_caret_version() {
         let content = r#"
@@ -411,6 +554,59 @@ serde = { version = "1.0.0", features = ["derive"] }
         assert!(result.contains("features"));
     }
 
+    #[test]
+    fn test_update_inline_table_preserves_default_features() {
+        let content = r#"
+[dependencies]
+foo = { version = "1", default-features = false }
+"#;
+
+        let result = CargoTomlParser.update_version(content, "foo", "2").unwrap();
+        assert!(result.contains(r#"foo = { version = "2", default-features = false }"#));
+    }
+
+    #[test]
+    fn test_update_inline_table_preserves_optional() {
+        let content = r#"
+[dependencies]
+foo = { version = "1", default-features = false, optional = true }
+"#;
+
+        let result = CargoTomlParser.update_version(content, "foo", "2").unwrap();
+        assert!(result
+            .contains(r#"foo = { version = "2", default-features = false, optional = true }"#));
+    }
+
+    #[test]
+    fn test_update_inline_table_version_after_features() {
+        let content = r#"
+[dependencies]
+serde = { features = ["derive", "rc"], version = "1.0.0", optional = true }
+"#;
+
+        let result = CargoTomlParser
+            .update_version(content, "serde", "1.1.0")
+            .unwrap();
+        assert!(result.contains(
+            r#"serde = { features = ["derive", "rc"], version = "1.1.0", optional = true }"#
+        ));
+    }
+
+    #[test]
+    fn test_update_inline_table_ignores_name_suffix_match() {
+        let content = r#"
+[dependencies]
+serde_json = { version = "1.0.0" }
+json = { version = "0.12.0" }
+"#;
+
+        let result = CargoTomlParser
+            .update_version(content, "json", "0.12.4")
+            .unwrap();
+        assert!(result.contains(r#"serde_json = { version = "1.0.0" }"#));
+        assert!(result.contains(r#"json = { version = "0.12.4" }"#));
+    }
+
     #[test]
     fn test_update_version_not_found() {
         let content = r#"
@@ -807,4 +1003,22 @@ criterion = "0.5"
         let criterion = deps.iter().find(|d| d.name == "criterion").unwrap();
         assert!(criterion.is_dev);
     }
+
+    #[test]
+    fn test_commented_out_dependency_ignored() {
+        let content = r#"[dependencies]
+# serde = "0.9"
+serde = "1.0"
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].version_spec.raw, "1.0");
+
+        let result = CargoTomlParser
+            .update_version(content, "serde", "1.0.200")
+            .unwrap();
+        assert!(result.contains("# serde = \"0.9\"\n"));
+        assert!(result.contains("\nserde = \"1.0.200\"\n"));
+    }
 }
@@ -4,15 +4,22 @@
 //! - require (production dependencies)
 //! - require-dev (development dependencies)
 //! - PHP platform package filtering (php, ext-*)
+//! - Path/VCS packages (`dev-*`/`@dev` constraints, inline repositories) marked as skipped;
+//!   VCS repositories match the `owner/repo` of their URL, and `path` repositories
+//!   are resolved on disk by [`mark_path_repository_packages`]
+//! - `||` alternatives (e.g., `^9.0 || ^10.0`) marked as skipped and never rewritten
 //! - Version constraint preservation during updates
+//! - `minimum-stability`/`prefer-stable` limiting which versions are offered
 
-use crate::domain::{Dependency, Language};
+use crate::domain::{Dependency, Language, SkipReason, VersionSpec, VersionSpecKind};
 use crate::error::ManifestError;
+use crate::manifest::detector::expand_path_glob;
 use crate::manifest::ManifestParser;
 use crate::parser::get_parser;
+use crate::update::{Stability, StabilityPolicy};
 use regex::Regex;
 use serde_json::{Map, Value};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Parser for composer.json files
 pub struct ComposerJsonParser;
@@ -27,15 +34,28 @@ impl ManifestParser for ComposerJsonParser {
 
         let mut dependencies = Vec::new();
         let parser = get_parser(Language::Php);
+        let repositories = non_registry_repositories(&json);
 
         // Parse require (production dependencies)
         if let Some(deps) = json.get("require").and_then(|v| v.as_object()) {
-            parse_dependency_object(deps, parser.as_ref(), false, &mut dependencies);
+            parse_dependency_object(
+                deps,
+                parser.as_ref(),
+                false,
+                &repositories,
+                &mut dependencies,
+            );
         }
 
         // Parse require-dev (development dependencies)
         if let Some(deps) = json.get("require-dev").and_then(|v| v.as_object()) {
-            parse_dependency_object(deps, parser.as_ref(), true, &mut dependencies);
+            parse_dependency_object(
+                deps,
+                parser.as_ref(),
+                true,
+                &repositories,
+                &mut dependencies,
+            );
         }
 
         Ok(dependencies)
@@ -45,6 +65,23 @@ impl ManifestParser for ComposerJsonParser {
         Language::Php
     }
 
+    /// Read `minimum-stability` and `prefer-stable`
+    ///
+    /// Returns `None` when `minimum-stability` isn't declared, keeping the default
+    /// prerelease handling.
+    fn stability_policy(&self, content: &str) -> Option<StabilityPolicy> {
+        let json: Value = serde_json::from_str(content).ok()?;
+        let minimum = Stability::parse(json.get("minimum-stability")?.as_str()?)?;
+        let prefer_stable = json
+            .get("prefer-stable")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        Some(StabilityPolicy {
+            minimum,
+            prefer_stable,
+        })
+    }
+
     fn update_version(
         &self,
         content: &str,
@@ -65,11 +102,22 @@ impl ManifestParser for ComposerJsonParser {
             message: format!("invalid regex pattern: {}", e),
         })?;
 
+        // Only the first occurrence holding a version is rewritten; earlier keys with the
+        // same name (e.g., a script called "eslint") and every other byte stay untouched
         let mut updated = false;
-        let result = re.replace(content, |caps: &regex::Captures| {
+        let result = re.replace_all(content, |caps: &regex::Captures| {
+            if updated {
+                return caps[0].to_string();
+            }
+
             let prefix = &caps[1];
             let old_version = &caps[2];
 
+            // Rewriting one alternative would silently drop the others
+            if is_alternative_constraint(old_version) {
+                return caps[0].to_string();
+            }
+
             if let Some(spec) = parser.parse(old_version) {
                 updated = true;
                 let new_ver = spec.format_updated(new_version);
@@ -103,10 +151,106 @@ fn is_platform_package(name: &str) -> bool {
         || name == "composer-runtime-api"
 }
 
+/// Collect the full names (`vendor/name`) of packages served by repositories
+/// declared in composer.json that are not registries
+///
+/// Inline `package` repositories declare the name; VCS repositories are matched
+/// by the `owner/repo` of their URL. `path` repositories need the file system, see
+/// [`mark_path_repository_packages`].
+fn non_registry_repositories(json: &Value) -> Vec<String> {
+    repository_entries(json)
+        .into_iter()
+        .filter_map(
+            |entry| match entry.get("type").and_then(|t| t.as_str()).unwrap_or("") {
+                "package" => entry
+                    .get("package")
+                    .and_then(|p| p.get("name"))
+                    .and_then(|n| n.as_str())
+                    .map(str::to_lowercase),
+                "vcs" | "git" | "github" | "gitlab" | "bitbucket" => entry
+                    .get("url")
+                    .and_then(|u| u.as_str())
+                    .and_then(repository_full_name),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+/// Repository entries, declared as an array or an object keyed by name
+fn repository_entries(json: &Value) -> Vec<&Value> {
+    match json.get("repositories") {
+        Some(Value::Array(items)) => items.iter().collect(),
+        Some(Value::Object(map)) => map.values().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// `owner/repo` of a VCS URL (`https://github.com/owner/repo.git`, `git@host:owner/repo`)
+fn repository_full_name(url: &str) -> Option<String> {
+    let path = url.trim_end_matches('/').trim_end_matches(".git");
+    let mut segments = path.rsplit(['/', ':']).filter(|s| !s.is_empty());
+    let repo = segments.next()?;
+    let owner = segments.next()?;
+    Some(format!("{}/{}", owner, repo).to_lowercase())
+}
+
+/// Mark dependencies served by composer.json `path` repositories as non-registry
+///
+/// Repository URLs are resolved against `dir`, the manifest's directory, with
+/// wildcards (`packages/*`) expanded; each matched directory's composer.json
+/// `name` identifies the package it serves.
+pub fn mark_path_repository_packages(
+    dependencies: Vec<Dependency>,
+    content: &str,
+    dir: &Path,
+) -> Vec<Dependency> {
+    let Ok(json) = serde_json::from_str::<Value>(content) else {
+        return dependencies;
+    };
+    let names: Vec<String> = repository_entries(&json)
+        .into_iter()
+        .filter(|entry| entry.get("type").and_then(|t| t.as_str()) == Some("path"))
+        .filter_map(|entry| entry.get("url").and_then(|u| u.as_str()))
+        .flat_map(|url| expand_path_glob(dir, url))
+        .filter_map(|package_dir| {
+            let content = std::fs::read_to_string(package_dir.join("composer.json")).ok()?;
+            let json: Value = serde_json::from_str(&content).ok()?;
+            json.get("name")?.as_str().map(str::to_lowercase)
+        })
+        .collect();
+    if names.is_empty() {
+        return dependencies;
+    }
+
+    dependencies
+        .into_iter()
+        .map(|dep| {
+            if dep.skip_reason.is_none() && names.contains(&dep.name.to_lowercase()) {
+                dep.with_skip_reason(SkipReason::NonRegistrySource)
+            } else {
+                dep
+            }
+        })
+        .collect()
+}
+
+/// Check if a constraint refers to a development branch (`dev-main`, `1.x-dev`, `@dev`)
+fn is_dev_constraint(version: &str) -> bool {
+    let version = version.trim();
+    version.starts_with("dev-") || version.ends_with("-dev") || version.contains("@dev")
+}
+
+/// Check if a constraint lists `||` alternatives (`^9.0 || ^10.0`)
+fn is_alternative_constraint(version: &str) -> bool {
+    version.contains("||")
+}
+
 fn parse_dependency_object(
     deps: &Map<String, Value>,
     parser: &dyn crate::parser::VersionParser,
     is_dev: bool,
+    repositories: &[String],
     output: &mut Vec<Dependency>,
 ) {
     for (name, version_value) in deps {
@@ -116,12 +260,27 @@ fn parse_dependency_object(
         }
 
         if let Some(version_str) = version_value.as_str() {
-            if let Some(spec) = parser.parse(version_str) {
-                let dep = if is_dev {
+            let from_repository = repositories.contains(&name.to_lowercase());
+
+            // Development branches and path/VCS packages cannot be updated from Packagist,
+            // but are still reported so the skip is visible
+            let spec = if is_dev_constraint(version_str) {
+                Some(VersionSpec::new(VersionSpecKind::Any, version_str, ""))
+            } else {
+                parser.parse(version_str)
+            };
+
+            if let Some(spec) = spec {
+                let mut dep = if is_dev {
                     Dependency::development(name.clone(), spec, Language::Php)
                 } else {
                     Dependency::production(name.clone(), spec, Language::Php)
                 };
+                if from_repository || is_dev_constraint(version_str) {
+                    dep = dep.with_skip_reason(SkipReason::NonRegistrySource);
+                } else if is_alternative_constraint(version_str) {
+                    dep = dep.with_skip_reason(SkipReason::ComplexConstraint);
+                }
                 output.push(dep);
             }
         }
@@ -414,6 +573,27 @@ mod tests {
         let deps = parse(content).unwrap();
         assert_eq!(deps.len(), 1);
         assert_eq!(deps[0].version_spec.kind, VersionSpecKind::Range);
+        assert_eq!(deps[0].skip_reason, Some(SkipReason::ComplexConstraint));
+    }
+
+    #[test]
+    fn test_update_or_constraint_left_intact() {
+        let content = r#"{
+    "require": {
+        "laravel/framework": "^9.0 || ^10.0",
+        "monolog/monolog": "^2.0"
+    }
+}"#;
+
+        let result = ComposerJsonParser.update_version(content, "laravel/framework", "11.0.0");
+        assert!(result.is_err());
+
+        // Other packages still update normally
+        let result = ComposerJsonParser
+            .update_version(content, "monolog/monolog", "3.5.0")
+            .unwrap();
+        assert!(result.contains(r#""laravel/framework": "^9.0 || ^10.0""#));
+        assert!(result.contains(r#""monolog/monolog": "^3.5.0""#));
     }
 
     #[test]
@@ -428,4 +608,184 @@ mod tests {
         let pkg = deps.first().unwrap();
         assert_eq!(pkg.version_spec.kind, VersionSpecKind::GreaterOrEqual);
     }
+
+    #[test]
+    fn test_dev_branch_constraint_skipped() {
+        let content = r#"{
+            "require": {
+                "acme/internal": "dev-main",
+                "monolog/monolog": "^3.0"
+            }
+        }"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 2);
+
+        let internal = deps.iter().find(|d| d.name == "acme/internal").unwrap();
+        assert_eq!(internal.skip_reason, Some(SkipReason::NonRegistrySource));
+        assert_eq!(internal.version_spec.raw, "dev-main");
+
+        let monolog = deps.iter().find(|d| d.name == "monolog/monolog").unwrap();
+        assert!(monolog.skip_reason.is_none());
+        assert_eq!(monolog.version_spec.kind, VersionSpecKind::Caret);
+    }
+
+    #[test]
+    fn test_dev_branch_skipped_and_caret_updated() {
+        use crate::update::{UpdateFilter, UpdateJudge, VersionInfo};
+
+        let content = r#"{
+            "require": {
+                "acme/internal": "dev-main",
+                "monolog/monolog": "^3.0"
+            }
+        }"#;
+
+        let deps = parse(content).unwrap();
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        let versions = vec![VersionInfo::now("3.5.0")];
+
+        let internal = deps.iter().find(|d| d.name == "acme/internal").unwrap();
+        let result = judge.judge(internal, &versions);
+        assert!(matches!(
+            result,
+            crate::domain::UpdateResult::Skip {
+                reason: SkipReason::NonRegistrySource,
+                ..
+            }
+        ));
+
+        let monolog = deps.iter().find(|d| d.name == "monolog/monolog").unwrap();
+        assert!(judge.judge(monolog, &versions).is_update());
+    }
+
+    #[test]
+    fn test_at_dev_constraint_skipped() {
+        let content = r#"{
+            "require-dev": {
+                "acme/tools": "1.0.x-dev",
+                "acme/other": "*@dev"
+            }
+        }"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 2);
+        assert!(deps
+            .iter()
+            .all(|d| d.is_dev && d.skip_reason == Some(SkipReason::NonRegistrySource)));
+    }
+
+    #[test]
+    fn test_stability_policy() {
+        let parser = ComposerJsonParser;
+        assert_eq!(
+            parser.stability_policy(r#"{"minimum-stability": "beta", "prefer-stable": true}"#),
+            Some(StabilityPolicy {
+                minimum: Stability::Beta,
+                prefer_stable: true,
+            })
+        );
+        assert_eq!(
+            parser.stability_policy(r#"{"minimum-stability": "stable"}"#),
+            Some(StabilityPolicy {
+                minimum: Stability::Stable,
+                prefer_stable: false,
+            })
+        );
+        assert_eq!(parser.stability_policy(r#"{"require": {}}"#), None);
+    }
+
+    #[test]
+    fn test_repository_packages_skipped() {
+        let content = r#"{
+            "repositories": [
+                { "type": "vcs", "url": "https://github.com/acme/forked-lib.git" },
+                { "type": "git", "url": "git@gitlab.com:Acme/Other-Lib.git" },
+                { "type": "package", "package": { "name": "acme/zip-lib", "version": "1.0.0" } }
+            ],
+            "require": {
+                "acme/forked-lib": "^2.0",
+                "acme/other-lib": "^1.0",
+                "acme/zip-lib": "1.0.0",
+                "monolog/monolog": "^3.0"
+            }
+        }"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 4);
+
+        for name in ["acme/forked-lib", "acme/other-lib", "acme/zip-lib"] {
+            let dep = deps.iter().find(|d| d.name == name).unwrap();
+            assert_eq!(
+                dep.skip_reason,
+                Some(SkipReason::NonRegistrySource),
+                "{}",
+                name
+            );
+        }
+
+        let monolog = deps.iter().find(|d| d.name == "monolog/monolog").unwrap();
+        assert!(monolog.skip_reason.is_none());
+    }
+
+    #[test]
+    fn test_vcs_repository_matches_full_name() {
+        let content = r#"{
+            "repositories": [
+                { "type": "vcs", "url": "https://github.com/myfork/console.git" }
+            ],
+            "require": {
+                "acme/console": "^1.0",
+                "myfork/console": "^2.0"
+            }
+        }"#;
+
+        let deps = parse(content).unwrap();
+        // Only the repository's own owner/repo is served by it, not every "console"
+        let acme = deps.iter().find(|d| d.name == "acme/console").unwrap();
+        assert!(acme.skip_reason.is_none());
+        let fork = deps.iter().find(|d| d.name == "myfork/console").unwrap();
+        assert_eq!(fork.skip_reason, Some(SkipReason::NonRegistrySource));
+    }
+
+    #[test]
+    fn test_mark_path_repository_packages() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for (path, name) in [
+            ("packages/console", "acme/console"),
+            ("packages/http", "acme/http-kernel"),
+            ("lib/mailer", "acme/mailer"),
+        ] {
+            std::fs::create_dir_all(dir.path().join(path)).unwrap();
+            std::fs::write(
+                dir.path().join(path).join("composer.json"),
+                format!(r#"{{"name": "{}"}}"#, name),
+            )
+            .unwrap();
+        }
+        let content = r#"{
+            "repositories": [
+                { "type": "path", "url": "packages/*" },
+                { "type": "path", "url": "./lib/mailer" }
+            ],
+            "require": {
+                "acme/console": "^1.0",
+                "acme/http-kernel": "^1.0",
+                "acme/mailer": "^1.0",
+                "other/http": "^1.0"
+            }
+        }"#;
+
+        let deps = mark_path_repository_packages(parse(content).unwrap(), content, dir.path());
+        let skipped: Vec<&str> = deps
+            .iter()
+            .filter(|d| d.skip_reason == Some(SkipReason::NonRegistrySource))
+            .map(|d| d.name.as_str())
+            .collect();
+        // Matched by the composer.json name, not the directory name
+        assert_eq!(
+            skipped,
+            vec!["acme/console", "acme/http-kernel", "acme/mailer"]
+        );
+    }
 }
@@ -0,0 +1,244 @@
+//! deno.json parser for Deno projects
+//!
+//! Handles:
+//! - `imports` map entries with `jsr:@scope/pkg@<range>` specifiers
+//! - `imports` map entries with `npm:pkg@<range>` specifiers
+//! - Trailing subpaths and the `jsr:/@scope/pkg@<range>/` prefix form
+//! - Version segment rewriting that leaves the rest of the specifier untouched
+//!
+//! Dependencies are named by scheme and package (e.g., `jsr:@std/assert`,
+//! `npm:chalk`) so the registry adapter can route them. URL and path imports
+//! are ignored.
+
+use crate::domain::{Dependency, Language};
+use crate::error::ManifestError;
+use crate::manifest::ManifestParser;
+use crate::parser::get_parser;
+use regex::Regex;
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// Specifier schemes resolved through a registry
+const REGISTRY_SCHEMES: [&str; 2] = ["jsr", "npm"];
+
+/// Parser for deno.json files
+pub struct DenoJsonParser;
+
+impl ManifestParser for DenoJsonParser {
+    fn parse(&self, content: &str) -> Result<Vec<Dependency>, ManifestError> {
+        let json: Value =
+            serde_json::from_str(content).map_err(|e| ManifestError::JsonParseError {
+                path: PathBuf::from("deno.json"),
+                message: e.to_string(),
+            })?;
+
+        let mut dependencies: Vec<Dependency> = Vec::new();
+        let parser = get_parser(Language::Deno);
+
+        if let Some(imports) = json.get("imports").and_then(|v| v.as_object()) {
+            for specifier in imports.values().filter_map(|v| v.as_str()) {
+                let Some((name, version)) = parse_specifier(specifier) else {
+                    continue;
+                };
+                // The same package may be mapped under several aliases
+                if dependencies.iter().any(|d| d.name == name) {
+                    continue;
+                }
+                if let Some(spec) = parser.parse(version) {
+                    dependencies.push(Dependency::production(name, spec, Language::Deno));
+                }
+            }
+        }
+
+        Ok(dependencies)
+    }
+
+    fn language(&self) -> Language {
+        Language::Deno
+    }
+
+    fn update_version(
+        &self,
+        content: &str,
+        package: &str,
+        new_version: &str,
+    ) -> Result<String, ManifestError> {
+        let parser = get_parser(Language::Deno);
+
+        let invalid = |message: String| ManifestError::InvalidVersionSpec {
+            path: PathBuf::from("deno.json"),
+            spec: package.to_string(),
+            message,
+        };
+
+        let (scheme, name) = package
+            .split_once(':')
+            .ok_or_else(|| invalid("expected a jsr: or npm: package".to_string()))?;
+
+        // Pattern matches: "jsr:@scope/pkg@<version>" (optionally "jsr:/@scope/pkg@<version>/")
+        let pattern = format!(
+            r#"("{}:/?{}@)([^"/]+)"#,
+            regex::escape(scheme),
+            regex::escape(name)
+        );
+        let re =
+            Regex::new(&pattern).map_err(|e| invalid(format!("invalid regex pattern: {}", e)))?;
+
+        // Only the first specifier holding a version is rewritten
+        let mut updated = false;
+        let result = re.replace_all(content, |caps: &regex::Captures| {
+            if updated {
+                return caps[0].to_string();
+            }
+
+            match parser.parse(&caps[2]) {
+                Some(spec) => {
+                    updated = true;
+                    format!("{}{}", &caps[1], spec.format_updated(new_version))
+                }
+                None => caps[0].to_string(),
+            }
+        });
+
+        if !updated {
+            return Err(invalid(
+                "package not found or version could not be updated".to_string(),
+            ));
+        }
+
+        Ok(result.to_string())
+    }
+}
+
+/// Split a `jsr:`/`npm:` specifier into the scheme-qualified package name and its version
+///
+/// e.g., `jsr:@std/path@^1.0.8/posix` -> (`jsr:@std/path`, `^1.0.8`)
+fn parse_specifier(specifier: &str) -> Option<(String, &str)> {
+    let (scheme, rest) = specifier.split_once(':')?;
+    if !REGISTRY_SCHEMES.contains(&scheme) {
+        return None;
+    }
+    let rest = rest.strip_prefix('/').unwrap_or(rest);
+
+    // Scoped names start with '@', so the version separator is the next '@'
+    let offset = usize::from(rest.starts_with('@'));
+    let at = rest[offset..].find('@')? + offset;
+    let name = &rest[..at];
+    let version = rest[at + 1..].split('/').next()?;
+    if name.is_empty() || version.is_empty() {
+        return None;
+    }
+
+    Some((format!("{}:{}", scheme, name), version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DENO_JSON: &str = r#"{
+  "tasks": {
+    "dev": "deno run --watch main.ts"
+  },
+  "imports": {
+    "@std/assert": "jsr:@std/assert@^1.0.8",
+    "@std/path/": "jsr:/@std/path@^1.0.2/",
+    "chalk": "npm:chalk@5.3.0",
+    "assert": "jsr:@std/assert@^1.0.8",
+    "oak": "https://deno.land/x/oak@v12.6.1/mod.ts",
+    "utils/": "./utils/",
+    "hono": "jsr:@hono/hono"
+  }
+}
+"#;
+
+    #[test]
+    fn test_parse_specifier() {
+        assert_eq!(
+            parse_specifier("jsr:@std/assert@^1.0.8"),
+            Some(("jsr:@std/assert".to_string(), "^1.0.8"))
+        );
+        assert_eq!(
+            parse_specifier("jsr:/@std/path@^1.0.2/posix"),
+            Some(("jsr:@std/path".to_string(), "^1.0.2"))
+        );
+        assert_eq!(
+            parse_specifier("npm:chalk@5.3.0"),
+            Some(("npm:chalk".to_string(), "5.3.0"))
+        );
+        assert_eq!(
+            parse_specifier("npm:@types/node@^20.0.0"),
+            Some(("npm:@types/node".to_string(), "^20.0.0"))
+        );
+        assert_eq!(parse_specifier("jsr:@hono/hono"), None);
+        assert_eq!(
+            parse_specifier("https://deno.land/x/oak@v12.6.1/mod.ts"),
+            None
+        );
+        assert_eq!(parse_specifier("./utils/"), None);
+    }
+
+    #[test]
+    fn test_parse_deno_json() {
+        let deps = DenoJsonParser.parse(DENO_JSON).unwrap();
+        let names: Vec<&str> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["jsr:@std/assert", "jsr:@std/path", "npm:chalk"]);
+
+        assert_eq!(deps[0].version(), "1.0.8");
+        assert_eq!(deps[0].language, Language::Deno);
+        assert!(!deps[0].is_dev);
+        assert!(deps[2].is_pinned());
+    }
+
+    #[test]
+    fn test_parse_without_imports() {
+        let deps = DenoJsonParser.parse(r#"{"tasks": {}}"#).unwrap();
+        assert!(deps.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_json() {
+        assert!(DenoJsonParser.parse("{").is_err());
+    }
+
+    #[test]
+    fn test_update_jsr_specifier() {
+        let result = DenoJsonParser
+            .update_version(DENO_JSON, "jsr:@std/assert", "1.0.13")
+            .unwrap();
+        assert!(result.contains(r#""@std/assert": "jsr:@std/assert@^1.0.13""#));
+        // Only the first alias is rewritten, everything else is byte-identical
+        assert!(result.contains(r#""assert": "jsr:@std/assert@^1.0.8""#));
+        assert_eq!(
+            result.replace("@^1.0.13", "@^1.0.8"),
+            DENO_JSON,
+            "only the version segment should change"
+        );
+    }
+
+    #[test]
+    fn test_update_jsr_prefix_form_keeps_subpath() {
+        let result = DenoJsonParser
+            .update_version(DENO_JSON, "jsr:@std/path", "1.1.0")
+            .unwrap();
+        assert!(result.contains(r#""@std/path/": "jsr:/@std/path@^1.1.0/""#));
+    }
+
+    #[test]
+    fn test_update_npm_specifier() {
+        let result = DenoJsonParser
+            .update_version(DENO_JSON, "npm:chalk", "5.4.1")
+            .unwrap();
+        assert!(result.contains(r#""chalk": "npm:chalk@5.4.1""#));
+    }
+
+    #[test]
+    fn test_update_missing_package() {
+        assert!(DenoJsonParser
+            .update_version(DENO_JSON, "npm:lodash", "4.17.21")
+            .is_err());
+        assert!(DenoJsonParser
+            .update_version(DENO_JSON, "lodash", "4.17.21")
+            .is_err());
+    }
+}
@@ -0,0 +1,106 @@
+//! depup settings embedded in package.json
+//!
+//! Reads the `"depup"` key of the package.json in the target directory:
+//! - `exclude`: packages never updated in any manifest, added to any `--exclude` flags
+//! - `age`: minimum release age (e.g., `"7d"`), used when `--age` is not given
+//!
+//! Unknown keys and non-string `exclude` entries are ignored; an `age` that isn't
+//! a valid duration is an error.
+
+use crate::cli::parse_duration;
+use std::path::Path;
+use std::time::Duration;
+
+/// Key holding depup settings in package.json
+pub const DEPUP_CONFIG_KEY: &str = "depup";
+
+/// depup settings from package.json
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DepupConfig {
+    /// Packages excluded from updates
+    pub exclude: Vec<String>,
+    /// Minimum release age for versions to be considered
+    pub age: Option<Duration>,
+}
+
+impl DepupConfig {
+    /// Parse the `"depup"` settings from package.json content
+    ///
+    /// Content that isn't JSON, or has no `"depup"` key, yields the defaults.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(content) else {
+            return Ok(Self::default());
+        };
+        let Some(config) = json.get(DEPUP_CONFIG_KEY) else {
+            return Ok(Self::default());
+        };
+
+        let exclude = config
+            .get("exclude")
+            .and_then(|v| v.as_array())
+            .map(|names| {
+                names
+                    .iter()
+                    .filter_map(|name| name.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let age = match config.get("age") {
+            None => None,
+            Some(serde_json::Value::String(age)) => {
+                Some(parse_duration(age).map_err(|e| format!("\"age\": {}", e))?)
+            }
+            Some(other) => return Err(format!("\"age\" must be a string, got {}", other)),
+        };
+
+        Ok(DepupConfig { exclude, age })
+    }
+
+    /// Read the settings from the package.json in a directory, if present
+    pub fn from_dir(dir: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(dir.join("package.json")) {
+            Ok(content) => Self::parse(&content),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_depup_config() {
+        let config = DepupConfig::parse(
+            r#"{
+  "name": "app",
+  "dependencies": {"react": "^18.0.0"},
+  "depup": {"exclude": ["react", 1, "vue"], "age": "7d", "unknown": true}
+}"#,
+        )
+        .unwrap();
+        assert_eq!(config.exclude, vec!["react", "vue"]);
+        assert_eq!(config.age, Some(Duration::from_secs(7 * 24 * 60 * 60)));
+    }
+
+    #[test]
+    fn test_parse_without_depup_config() {
+        assert_eq!(
+            DepupConfig::parse(r#"{"name": "app"}"#),
+            Ok(DepupConfig::default())
+        );
+        assert_eq!(DepupConfig::parse("not json"), Ok(DepupConfig::default()));
+    }
+
+    #[test]
+    fn test_parse_invalid_age() {
+        let err = DepupConfig::parse(r#"{"depup": {"age": "soon"}}"#).unwrap_err();
+        assert!(
+            err.starts_with("\"age\": invalid duration 'soon'"),
+            "{}",
+            err
+        );
+        let err = DepupConfig::parse(r#"{"depup": {"age": 7}}"#).unwrap_err();
+        assert_eq!(err, "\"age\" must be a string, got 7");
+    }
+}
@@ -3,9 +3,20 @@
 //! Features:
 //! - Detects package.json, pyproject.toml, Cargo.toml, go.mod
 //! - Supports pnpm-workspace.yaml for monorepo detection
+//! - Supports Cargo workspaces (`[workspace] members`, with globs)
 //! - Supports Tauri projects (src-tauri/Cargo.toml)
+//! - Detects the Gradle version catalog (gradle/libs.versions.toml)
+//! - Detects Pipfile for Pipenv projects
+//! - Detects pnpm catalogs in pnpm-workspace.yaml
+//! - Detects `*.nimble` package files for Nim projects
+//! - Reads an explicit list of manifest paths (--manifests-from)
 
 use crate::domain::Language;
+use crate::manifest::{
+    find_nimble_file, language_for_path, parse_catalog_entries, PIPFILE_FILENAME,
+    PNPM_WORKSPACE_FILENAME, VERSION_CATALOG_FILENAME,
+};
+use crate::update::glob_match;
 use std::path::{Path, PathBuf};
 
 /// Information about a detected manifest file
@@ -63,18 +74,30 @@ pub struct ManifestFile {
 /// 2. Checks for pnpm-workspace.yaml to detect monorepo
 /// 3. Checks for src-tauri/Cargo.toml for Tauri projects
 /// 4. Checks for build.gradle.kts (Kotlin DSL) for Gradle projects
+/// 5. Checks for gradle/libs.versions.toml (Gradle version catalog)
+/// 6. Checks for Pipfile (Pipenv) for Python projects
+/// 7. Checks Cargo.toml `[workspace] members` for Cargo workspace crates
+/// 8. Checks pnpm-workspace.yaml for `catalog:`/`catalogs:` entries
+/// 9. Checks for a `*.nimble` package file for Nim projects
 pub fn detect_manifests(dir: &Path) -> Vec<ManifestInfo> {
     let mut manifests = Vec::new();
 
     // Check if this is a pnpm workspace
     let is_pnpm_workspace = dir.join("pnpm-workspace.yaml").exists();
 
+    // Check if this is a Cargo workspace
+    let cargo_workspace_members = detect_cargo_workspace_members(dir);
+
     // Detect each manifest type
     for language in Language::all() {
-        let manifest_name = language.manifest_filename();
-        let manifest_path = dir.join(manifest_name);
-
-        if manifest_path.exists() {
+        // Nim has no fixed manifest filename; its files are found below
+        let manifest_path = language
+            .manifest_filename()
+            .map(|name| dir.join(name))
+            .filter(|path| path.exists());
+        let manifest_exists = manifest_path.is_some();
+
+        if let Some(manifest_path) = manifest_path {
             let mut info = ManifestInfo::new(&manifest_path, *language);
 
             // Mark as workspace root if pnpm-workspace.yaml exists and this is package.json
@@ -82,16 +105,42 @@ pub fn detect_manifests(dir: &Path) -> Vec<ManifestInfo> {
                 info = info.with_workspace_root(true);
             }
 
+            // Mark as workspace root if Cargo.toml declares [workspace]
+            if *language == Language::Rust && cargo_workspace_members.is_some() {
+                info = info.with_workspace_root(true);
+            }
+
             manifests.push(info);
         }
 
+        // Nimble files are named after the package (e.g., `myapp.nimble`)
+        if *language == Language::Nim {
+            if let Some(nimble_path) = find_nimble_file(dir) {
+                manifests.push(ManifestInfo::new(&nimble_path, Language::Nim));
+            }
+        }
+
+        // Check for Pipfile (Pipenv) for Python projects
+        if *language == Language::Python {
+            let pipfile_path = dir.join(PIPFILE_FILENAME);
+            if pipfile_path.exists() {
+                manifests.push(ManifestInfo::new(&pipfile_path, Language::Python));
+            }
+        }
+
         // Check for Kotlin DSL variant for Java (build.gradle.kts)
         if *language == Language::Java {
             let kts_path = dir.join("build.gradle.kts");
-            if kts_path.exists() && !manifest_path.exists() {
+            if kts_path.exists() && !manifest_exists {
                 // Only add .kts if no build.gradle exists (prefer Groovy over Kotlin DSL)
                 manifests.push(ManifestInfo::new(&kts_path, Language::Java));
             }
+
+            // Gradle version catalog (gradle/libs.versions.toml)
+            let catalog_path = dir.join("gradle").join(VERSION_CATALOG_FILENAME);
+            if catalog_path.exists() {
+                manifests.push(ManifestInfo::new(&catalog_path, Language::Java));
+            }
         }
     }
 
@@ -128,9 +177,89 @@ pub fn detect_manifests(dir: &Path) -> Vec<ManifestInfo> {
         }
     }
 
+    // pnpm catalogs hold the versions package.json `catalog:` references point at
+    if is_pnpm_workspace {
+        let workspace_path = dir.join(PNPM_WORKSPACE_FILENAME);
+        let has_catalog = std::fs::read_to_string(&workspace_path)
+            .is_ok_and(|content| !parse_catalog_entries(&content).is_empty());
+        if has_catalog {
+            manifests.push(ManifestInfo::new(&workspace_path, Language::Node));
+        }
+    }
+
+    // Check for Cargo workspace member crates
+    for member_path in cargo_workspace_members.unwrap_or_default() {
+        let cargo_toml_path = member_path.join("Cargo.toml");
+        // Skip the root itself and members already found (e.g., src-tauri)
+        if cargo_toml_path.exists() && !manifests.iter().any(|m| m.path == cargo_toml_path) {
+            manifests.push(ManifestInfo::new(&cargo_toml_path, Language::Rust));
+        }
+    }
+
     manifests
 }
 
+/// Parse the root Cargo.toml `[workspace]` table and return member crate directories
+///
+/// Returns `None` if the directory has no Cargo.toml or it doesn't declare a workspace.
+/// Member patterns may use `*` and `?` in any path segment; `exclude` entries are dropped.
+fn detect_cargo_workspace_members(dir: &Path) -> Option<Vec<PathBuf>> {
+    let content = std::fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    let toml: toml::Value = toml::from_str(&content).ok()?;
+    let workspace = toml.get("workspace")?.as_table()?;
+
+    let patterns = |key: &str| -> Vec<&str> {
+        workspace
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|a| a.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default()
+    };
+    let excluded: Vec<PathBuf> = patterns("exclude")
+        .into_iter()
+        .map(|p| dir.join(p))
+        .collect();
+
+    let mut members = Vec::new();
+    for pattern in patterns("members") {
+        for path in expand_path_glob(dir, pattern) {
+            if !excluded.contains(&path) && !members.contains(&path) {
+                members.push(path);
+            }
+        }
+    }
+    members.sort();
+
+    Some(members)
+}
+
+/// Expand a relative path pattern whose segments may contain `*`/`?` into existing directories
+pub(crate) fn expand_path_glob(dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut paths = vec![dir.to_path_buf()];
+
+    for segment in pattern.split('/').filter(|s| !s.is_empty() && *s != ".") {
+        if !segment.contains(['*', '?']) {
+            paths = paths
+                .into_iter()
+                .map(|p| p.join(segment))
+                .filter(|p| p.is_dir())
+                .collect();
+            continue;
+        }
+
+        paths = paths
+            .iter()
+            .filter_map(|p| std::fs::read_dir(p).ok())
+            .flat_map(|entries| entries.flatten())
+            .filter(|entry| entry.path().is_dir())
+            .filter(|entry| glob_match(segment, &entry.file_name().to_string_lossy()))
+            .map(|entry| entry.path())
+            .collect();
+    }
+
+    paths
+}
+
 /// Parse pnpm-workspace.yaml and return package directories
 fn detect_pnpm_workspace_packages(dir: &Path) -> Result<Vec<PathBuf>, std::io::Error> {
     let workspace_file = dir.join("pnpm-workspace.yaml");
@@ -208,6 +337,23 @@ pub fn is_pnpm_workspace(dir: &Path) -> bool {
     dir.join("pnpm-workspace.yaml").exists()
 }
 
+/// Manifests named in a newline-separated list of paths (e.g., from `git ls-files`)
+///
+/// Each language is inferred from the file name; blank lines are ignored.
+/// Returns the manifests and the paths that are not a supported manifest.
+pub fn manifests_from_list(list: &str) -> (Vec<ManifestInfo>, Vec<PathBuf>) {
+    let mut manifests = Vec::new();
+    let mut unsupported = Vec::new();
+    for line in list.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let path = PathBuf::from(line);
+        match language_for_path(&path) {
+            Some(language) => manifests.push(ManifestInfo::new(path, language)),
+            None => unsupported.push(path),
+        }
+    }
+    (manifests, unsupported)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,6 +411,35 @@ mod tests {
         assert!(root.is_workspace_root);
     }
 
+    #[test]
+    fn test_detect_pnpm_catalog() {
+        let dir = create_temp_dir();
+        fs::write(dir.path().join("package.json"), "{}").unwrap();
+        fs::write(
+            dir.path().join("pnpm-workspace.yaml"),
+            "packages:\n  - 'packages/*'\n",
+        )
+        .unwrap();
+
+        // No catalog entries: the workspace file isn't a manifest
+        let manifests = detect_manifests(dir.path());
+        assert!(!manifests
+            .iter()
+            .any(|m| m.path == dir.path().join("pnpm-workspace.yaml")));
+
+        fs::write(
+            dir.path().join("pnpm-workspace.yaml"),
+            "packages:\n  - 'packages/*'\ncatalog:\n  react: ^18.2.0\n",
+        )
+        .unwrap();
+        let manifests = detect_manifests(dir.path());
+        let catalog = manifests
+            .iter()
+            .find(|m| m.path == dir.path().join("pnpm-workspace.yaml"))
+            .unwrap();
+        assert_eq!(catalog.language, Language::Node);
+    }
+
     #[test]
     fn test_detect_tauri_project() {
         let dir = create_temp_dir();
@@ -401,6 +576,76 @@ mod tests {
         assert!(root.is_workspace_root);
     }
 
+    #[test]
+    fn test_cargo_workspace_members_detection() {
+        let dir = create_temp_dir();
+
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            r#"[workspace]
+members = ["crates/*", "tools/cli"]
+exclude = ["crates/experimental"]
+"#,
+        )
+        .unwrap();
+        for member in ["crates/core", "crates/experimental", "tools/cli"] {
+            let member_dir = dir.path().join(member);
+            fs::create_dir_all(&member_dir).unwrap();
+            fs::write(member_dir.join("Cargo.toml"), "[package]\nname = \"x\"\n").unwrap();
+        }
+        // Directory matched by the glob without a manifest is ignored
+        fs::create_dir_all(dir.path().join("crates").join("docs")).unwrap();
+
+        let manifests = detect_manifests(dir.path());
+        let rust_paths: Vec<_> = manifests
+            .iter()
+            .filter(|m| m.language == Language::Rust)
+            .map(|m| m.path.clone())
+            .collect();
+
+        assert_eq!(
+            rust_paths,
+            vec![
+                dir.path().join("Cargo.toml"),
+                dir.path().join("crates").join("core").join("Cargo.toml"),
+                dir.path().join("tools").join("cli").join("Cargo.toml"),
+            ]
+        );
+        assert!(manifests[0].is_workspace_root);
+    }
+
+    #[test]
+    fn test_cargo_workspace_member_not_duplicated() {
+        let dir = create_temp_dir();
+
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\".\", \"src-tauri\"]\n",
+        )
+        .unwrap();
+        fs::create_dir(dir.path().join("src-tauri")).unwrap();
+        fs::write(dir.path().join("src-tauri").join("Cargo.toml"), "").unwrap();
+
+        let manifests = detect_manifests(dir.path());
+        let rust: Vec<_> = manifests
+            .iter()
+            .filter(|m| m.language == Language::Rust)
+            .collect();
+
+        assert_eq!(rust.len(), 2);
+        assert!(rust[1].is_tauri_rust);
+    }
+
+    #[test]
+    fn test_plain_cargo_manifest_is_not_workspace_root() {
+        let dir = create_temp_dir();
+        fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"x\"\n").unwrap();
+
+        let manifests = detect_manifests(dir.path());
+        assert_eq!(manifests.len(), 1);
+        assert!(!manifests[0].is_workspace_root);
+    }
+
     #[test]
     fn test_detect_build_gradle() {
         let dir = create_temp_dir();
@@ -439,4 +684,84 @@ mod tests {
         assert_eq!(java_manifests.len(), 1);
         assert!(java_manifests[0].path.ends_with("build.gradle"));
     }
+
+    #[test]
+    fn test_detect_gradle_version_catalog() {
+        let dir = create_temp_dir();
+        fs::write(dir.path().join("build.gradle.kts"), "").unwrap();
+        fs::create_dir(dir.path().join("gradle")).unwrap();
+        fs::write(dir.path().join("gradle").join("libs.versions.toml"), "").unwrap();
+
+        let manifests = detect_manifests(dir.path());
+        assert_eq!(manifests.len(), 2);
+        assert!(
+            manifests
+                .iter()
+                .any(|m| m.path.ends_with("gradle/libs.versions.toml")
+                    && m.language == Language::Java)
+        );
+    }
+
+    #[test]
+    fn test_detect_pipfile() {
+        let dir = create_temp_dir();
+        fs::write(dir.path().join("Pipfile"), "[packages]\n").unwrap();
+
+        let manifests = detect_manifests(dir.path());
+        assert_eq!(manifests.len(), 1);
+        assert_eq!(manifests[0].language, Language::Python);
+        assert!(manifests[0].path.ends_with("Pipfile"));
+    }
+
+    #[test]
+    fn test_detect_nimble_file() {
+        let dir = create_temp_dir();
+        fs::write(
+            dir.path().join("myapp.nimble"),
+            "requires \"jester >= 0.5.0\"\n",
+        )
+        .unwrap();
+
+        let manifests = detect_manifests(dir.path());
+        assert_eq!(manifests.len(), 1);
+        assert_eq!(manifests[0].language, Language::Nim);
+        assert!(manifests[0].path.ends_with("myapp.nimble"));
+    }
+
+    #[test]
+    fn test_detect_multiple_python_manifests() {
+        let dir = create_temp_dir();
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[project]\nname = \"app\"\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("Pipfile"), "[packages]\n").unwrap();
+
+        let manifests = detect_manifests(dir.path());
+        assert_eq!(manifests.len(), 2);
+        assert!(manifests.iter().all(|m| m.language == Language::Python));
+        assert!(manifests.iter().any(|m| m.path.ends_with("pyproject.toml")));
+        assert!(manifests.iter().any(|m| m.path.ends_with("Pipfile")));
+    }
+
+    #[test]
+    fn test_manifests_from_list() {
+        let list = "apps/web/package.json\n\n  crates/core/Cargo.toml  \nREADME.md\ngradle/libs.versions.toml\n";
+        let (manifests, unsupported) = manifests_from_list(list);
+
+        let found: Vec<(&Path, Language)> = manifests
+            .iter()
+            .map(|m| (m.path.as_path(), m.language))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Path::new("apps/web/package.json"), Language::Node),
+                (Path::new("crates/core/Cargo.toml"), Language::Rust),
+                (Path::new("gradle/libs.versions.toml"), Language::Java),
+            ]
+        );
+        assert_eq!(unsupported, vec![PathBuf::from("README.md")]);
+    }
 }
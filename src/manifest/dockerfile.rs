@@ -0,0 +1,215 @@
+//! Dockerfile parser for base image tags
+//!
+//! Handles:
+//! - `FROM image:tag` lines, case-insensitive, with `--platform=...` flags and `AS name`
+//! - Registry hosts and namespaces (`ghcr.io/owner/image:1.2.3`, `library/node:20`)
+//! - Variant tags (`node:20.10.0-alpine`): the variant is kept on update
+//!
+//! Images without a tag, pinned by digest (`@sha256:...`), with a non-version tag
+//! (`latest`) or built from `ARG` variables are not reported. An image used in
+//! several stages is reported once; every stage with the same tag is updated.
+
+use crate::domain::{Dependency, Language};
+use crate::error::ManifestError;
+use crate::manifest::ManifestParser;
+use crate::parser::get_parser;
+use regex::Regex;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+/// Parser for Dockerfiles
+pub struct DockerfileParser;
+
+/// `FROM` instruction: leading text up to the image, then the image reference
+static FROM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(\s*FROM\s+(?:--\S+\s+)*)(\S+)").unwrap());
+
+/// Image reference split into (name, tag)
+///
+/// The tag follows the last `:` after the last `/`, so registry ports
+/// (`localhost:5000/app`) are part of the name.
+fn split_image(reference: &str) -> Option<(&str, &str)> {
+    if reference.contains('@') || reference.contains('$') {
+        return None;
+    }
+    let name_start = reference.rfind('/').map_or(0, |i| i + 1);
+    let colon = reference[name_start..].rfind(':')? + name_start;
+    Some((&reference[..colon], &reference[colon + 1..]))
+}
+
+/// Base images with a tag, as (line index, image name, tag)
+fn base_images(content: &str) -> Vec<(usize, &str, &str)> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let caps = FROM_RE.captures(line)?;
+            let (name, tag) = split_image(caps.get(2)?.as_str())?;
+            Some((index, name, tag))
+        })
+        .collect()
+}
+
+impl ManifestParser for DockerfileParser {
+    fn parse(&self, content: &str) -> Result<Vec<Dependency>, ManifestError> {
+        let parser = get_parser(Language::Docker);
+        let mut dependencies: Vec<Dependency> = Vec::new();
+
+        for (_, name, tag) in base_images(content) {
+            if dependencies.iter().any(|dep| dep.name == name) {
+                continue;
+            }
+            if let Some(spec) = parser.parse(tag) {
+                dependencies.push(Dependency::production(name, spec, Language::Docker));
+            }
+        }
+
+        Ok(dependencies)
+    }
+
+    fn language(&self) -> Language {
+        Language::Docker
+    }
+
+    fn update_version(
+        &self,
+        content: &str,
+        package: &str,
+        new_version: &str,
+    ) -> Result<String, ManifestError> {
+        let parser = get_parser(Language::Docker);
+        let images = base_images(content);
+
+        // The first stage using the image decides the tag being updated
+        let current = images
+            .iter()
+            .find(|(_, name, tag)| *name == package && parser.parse(tag).is_some())
+            .map(|(_, _, tag)| *tag)
+            .ok_or_else(|| ManifestError::InvalidVersionSpec {
+                path: PathBuf::from("Dockerfile"),
+                spec: package.to_string(),
+                message: "base image not found or has no version tag".to_string(),
+            })?;
+        let new_tag = parser
+            .parse(current)
+            .map(|spec| spec.format_updated(new_version))
+            .unwrap_or_else(|| new_version.to_string());
+
+        let targets: Vec<usize> = images
+            .iter()
+            .filter(|(_, name, tag)| *name == package && *tag == current)
+            .map(|(index, _, _)| *index)
+            .collect();
+        let old_reference = format!("{}:{}", package, current);
+        let new_reference = format!("{}:{}", package, new_tag);
+
+        let lines: Vec<String> = content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                if targets.contains(&index) {
+                    line.replacen(&old_reference, &new_reference, 1)
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect();
+
+        let mut result = lines.join("\n");
+        if content.ends_with('\n') {
+            result.push('\n');
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::VersionSpecKind;
+
+    const DOCKERFILE: &str = "\
+FROM --platform=$BUILDPLATFORM node:20.10.0-alpine AS build
+WORKDIR /app
+RUN npm ci
+
+FROM golang:1.21 as tools
+FROM ghcr.io/owner/app:v1.2.3
+FROM localhost:5000/base:2.0.0
+FROM python:latest
+FROM ubuntu@sha256:0123456789abcdef
+FROM build AS test
+from node:20.10.0-alpine
+COPY --from=build /app /app
+";
+
+    #[test]
+    fn test_parse_base_images() {
+        let deps = DockerfileParser.parse(DOCKERFILE).unwrap();
+        let names: Vec<&str> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["node", "golang", "ghcr.io/owner/app", "localhost:5000/base"]
+        );
+
+        assert_eq!(deps[0].version_spec.version, "20.10.0");
+        assert_eq!(deps[0].version_spec.suffix.as_deref(), Some("-alpine"));
+        assert_eq!(deps[0].version_spec.kind, VersionSpecKind::Exact);
+        assert_eq!(deps[0].language, Language::Docker);
+        assert_eq!(deps[1].version_spec.version, "1.21");
+        assert_eq!(deps[2].version_spec.version, "1.2.3");
+    }
+
+    #[test]
+    fn test_update_preserves_variant_suffix() {
+        let result = DockerfileParser
+            .update_version(DOCKERFILE, "node", "20.11.1")
+            .unwrap();
+
+        assert!(result.starts_with("FROM --platform=$BUILDPLATFORM node:20.11.1-alpine AS build\n"));
+        // Later stages with the same tag move together
+        assert!(result.contains("\nfrom node:20.11.1-alpine\n"));
+        assert!(!result.contains("20.10.0"));
+        assert!(result.ends_with("COPY --from=build /app /app\n"));
+    }
+
+    #[test]
+    fn test_update_registry_image() {
+        let result = DockerfileParser
+            .update_version(DOCKERFILE, "localhost:5000/base", "2.1.0")
+            .unwrap();
+        assert!(result.contains("FROM localhost:5000/base:2.1.0\n"));
+
+        let result = DockerfileParser
+            .update_version(DOCKERFILE, "ghcr.io/owner/app", "1.3.0")
+            .unwrap();
+        assert!(result.contains("FROM ghcr.io/owner/app:v1.3.0\n"));
+    }
+
+    #[test]
+    fn test_update_missing_image() {
+        assert!(DockerfileParser
+            .update_version(DOCKERFILE, "python", "3.12")
+            .is_err());
+        assert!(DockerfileParser
+            .update_version(DOCKERFILE, "redis", "7.2")
+            .is_err());
+    }
+
+    #[test]
+    fn test_commented_out_from_ignored() {
+        let content = "# FROM node:18.0.0-alpine\nFROM node:20.10.0-alpine\n";
+
+        let deps = DockerfileParser.parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].version_spec.version, "20.10.0");
+
+        let result = DockerfileParser
+            .update_version(content, "node", "20.11.1")
+            .unwrap();
+        assert_eq!(
+            result,
+            "# FROM node:18.0.0-alpine\nFROM node:20.11.1-alpine\n"
+        );
+    }
+}
@@ -138,8 +138,10 @@ impl ManifestParser for GemfileParser {
         let escaped_name = regex::escape(package);
 
         // First, try to update existing version
-        // gem 'package' or gem "package" followed by version
-        let version_pattern = format!(r#"(gem\s+['"]{escaped_name}['"]\s*,\s*['"])([^'"]+)(['"])"#);
+        // gem 'package' or gem "package" followed by version, at the start of a line
+        // so commented-out declarations are never rewritten
+        let version_pattern =
+            format!(r#"(?m)^(\s*gem\s+['"]{escaped_name}['"]\s*,\s*['"])([^'"]+)(['"])"#);
 
         let version_re =
             Regex::new(&version_pattern).map_err(|e| ManifestError::InvalidVersionSpec {
@@ -170,7 +172,7 @@ impl ManifestParser for GemfileParser {
         // If no existing version, try to add version to unversioned gem
         // Pattern for gem without version: gem 'package' (not followed by comma and version)
         let no_version_pattern = format!(
-            r#"(gem\s+)(['"])({escaped_name})(['"])(\s*(?:,\s*(?:require|group|git|path|branch|ref|tag|source|platforms?)\s*:|#|$))"#
+            r#"(?m)^(\s*gem\s+)(['"])({escaped_name})(['"])(\s*(?:,\s*(?:require|group|git|path|branch|ref|tag|source|platforms?)\s*:|#|$))"#
         );
 
         let no_version_re =
@@ -583,4 +585,25 @@ gem 'nokogiri'
             .unwrap();
         assert!(result2.contains("gem 'rmagick', '5.3.0'"));
     }
+
+    #[test]
+    fn test_commented_out_gem_ignored() {
+        let content = r#"source 'https://rubygems.org'
+
+# gem 'rails', '~> 6.0'
+  # gem 'rails'
+gem 'rails', '~> 7.0'
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].version_spec.raw, "~> 7.0");
+
+        let result = GemfileParser
+            .update_version(content, "rails", "7.1.0")
+            .unwrap();
+        assert!(result.contains("# gem 'rails', '~> 6.0'\n"));
+        assert!(result.contains("  # gem 'rails'\n"));
+        assert!(result.contains("gem 'rails', '~> 7.1.0'\n"));
+    }
 }
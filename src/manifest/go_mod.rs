@@ -4,11 +4,13 @@
 //! - require statements (single and block)
 //! - // pinned comments for version pinning
 //! - replace directives (skipped from updates)
+//! - exclude directives (versions never suggested)
+//! - retract directives (ignored)
 
 use crate::domain::{Dependency, Language};
 use crate::error::ManifestError;
 use crate::manifest::ManifestParser;
-use crate::parser::{get_parser, VersionParser};
+use crate::parser::{get_parser, module_major, module_path_for_major, VersionParser};
 use regex::Regex;
 use std::path::PathBuf;
 use std::sync::LazyLock;
@@ -26,6 +28,10 @@ static BLOCK_ENTRY_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"^\s*(\S+)\s+(v[\d]+\.[\d]+\.[\d]+[^\s]*)\s*(//.*)?\s*$").unwrap()
 });
 
+// Regex for exclude block entry: module/path v1.2.3
+static EXCLUDE_ENTRY_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(\S+)\s+(v[\d]+\.[\d]+\.[\d]+[^\s]*)").unwrap());
+
 // Regex for pinned comment
 static PINNED_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"//\s*pinned").unwrap());
 
@@ -36,6 +42,7 @@ impl ManifestParser for GoModParser {
 
         let mut in_require_block = false;
         let mut in_replace_block = false;
+        let mut in_ignored_block = false;
 
         for line in content.lines() {
             let trimmed = line.trim();
@@ -56,9 +63,16 @@ impl ManifestParser for GoModParser {
                 continue;
             }
 
+            // exclude/retract blocks never declare dependencies
+            if trimmed.starts_with("exclude (") || trimmed.starts_with("retract (") {
+                in_ignored_block = true;
+                continue;
+            }
+
             if trimmed == ")" {
                 in_require_block = false;
                 in_replace_block = false;
+                in_ignored_block = false;
                 continue;
             }
 
@@ -67,6 +81,14 @@ impl ManifestParser for GoModParser {
                 continue;
             }
 
+            // Skip exclude and retract directives
+            if in_ignored_block
+                || trimmed.starts_with("exclude ")
+                || trimmed.starts_with("retract ")
+            {
+                continue;
+            }
+
             // Check for pinned comment
             let is_pinned = PINNED_RE.is_match(line);
 
@@ -95,6 +117,38 @@ impl ManifestParser for GoModParser {
         Language::Go
     }
 
+    fn excluded_versions(&self, content: &str) -> Vec<(String, String)> {
+        let mut excluded = Vec::new();
+        let mut in_exclude_block = false;
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+
+            let entry = if in_exclude_block {
+                if trimmed == ")" {
+                    in_exclude_block = false;
+                    continue;
+                }
+                trimmed
+            } else if let Some(rest) = trimmed.strip_prefix("exclude") {
+                let rest = rest.trim_start();
+                if rest.starts_with('(') {
+                    in_exclude_block = true;
+                    continue;
+                }
+                rest
+            } else {
+                continue;
+            };
+
+            if let Some(caps) = EXCLUDE_ENTRY_RE.captures(entry) {
+                excluded.push((caps[1].to_string(), caps[2].to_string()));
+            }
+        }
+
+        excluded
+    }
+
     fn update_version(
         &self,
         content: &str,
@@ -111,6 +165,9 @@ impl ManifestParser for GoModParser {
             format!("v{}", new_version)
         };
 
+        // Crossing a major boundary moves the module to a new /vN import path
+        let target = target_module_path(package, &new_ver);
+
         for line in content.lines() {
             let trimmed = line.trim();
 
@@ -122,9 +179,9 @@ impl ManifestParser for GoModParser {
                     if module == package {
                         let comment = caps.get(3).map(|m| m.as_str()).unwrap_or("");
                         let new_line = if comment.is_empty() {
-                            format!("require {} {}", package, new_ver)
+                            format!("require {} {}", target, new_ver)
                         } else {
-                            format!("require {} {} {}", package, new_ver, comment)
+                            format!("require {} {} {}", target, new_ver, comment)
                         };
                         updated = true;
                         Some(new_line)
@@ -140,9 +197,9 @@ impl ManifestParser for GoModParser {
                         let leading_ws = line.len() - line.trim_start().len();
                         let indent = &line[..leading_ws];
                         let new_line = if comment.is_empty() {
-                            format!("{}{} {}", indent, package, new_ver)
+                            format!("{}{} {}", indent, target, new_ver)
                         } else {
-                            format!("{}{} {} {}", indent, package, new_ver, comment)
+                            format!("{}{} {} {}", indent, target, new_ver, comment)
                         };
                         updated = true;
                         Some(new_line)
@@ -189,18 +246,18 @@ fn parse_go_dependency(
     let module = caps.get(1)?.as_str();
     let version = caps.get(2)?.as_str();
 
-    // Skip indirect dependencies (usually have // indirect comment)
+    // Indirect dependencies have a // indirect comment
     let comment = caps.get(3).map(|m| m.as_str()).unwrap_or("");
     let is_indirect = comment.contains("indirect");
 
     let spec = parser.parse(version)?;
 
     // Mark as pinned if has // pinned comment
+    let dep = Dependency::production(module, spec, Language::Go);
     let dep = if is_indirect {
-        // Indirect dependencies are treated as dev dependencies
-        Dependency::development(module, spec, Language::Go)
+        dep.with_indirect()
     } else {
-        Dependency::production(module, spec, Language::Go)
+        dep
     };
 
     // If pinned, we need to mark it somehow
@@ -214,6 +271,24 @@ fn parse_go_dependency(
     Some(dep.clone())
 }
 
+/// Module path to write for `new_ver`, following a major version change
+///
+/// `+incompatible` versions keep the original path, as do `gopkg.in` modules.
+fn target_module_path(package: &str, new_ver: &str) -> String {
+    if new_ver.ends_with("+incompatible") {
+        return package.to_string();
+    }
+    let new_major = new_ver
+        .trim_start_matches('v')
+        .split('.')
+        .next()
+        .and_then(|m| m.parse::<u64>().ok());
+    match (module_major(package), new_major) {
+        (Some(current), Some(new)) if current != new.max(1) => module_path_for_major(package, new),
+        _ => package.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,9 +365,44 @@ require (
             .find(|d| d.name == "github.com/gin-gonic/gin")
             .unwrap();
         assert!(!gin.is_dev);
+        assert!(!gin.is_indirect);
 
         let text = deps.iter().find(|d| d.name == "golang.org/x/text").unwrap();
-        assert!(text.is_dev); // indirect marked as dev
+        assert!(text.is_indirect);
+        assert!(!text.is_dev);
+    }
+
+    #[test]
+    fn test_indirect_dependencies_skipped_with_only_direct() {
+        use crate::domain::SkipReason;
+        use crate::update::{UpdateFilter, UpdateJudge};
+
+        let content = r#"
+module example.com/myproject
+
+go 1.21
+
+require github.com/google/uuid v1.4.0 // indirect
+
+require (
+	github.com/gin-gonic/gin v1.9.1
+	golang.org/x/text v0.14.0 // indirect
+	golang.org/x/net v0.19.0 // indirect
+)
+"#;
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 4);
+        assert_eq!(deps.iter().filter(|d| d.is_indirect).count(), 3);
+
+        let judge = UpdateJudge::new(UpdateFilter::new().with_only_direct(true));
+        for dep in &deps {
+            let expected = dep.is_indirect.then_some(SkipReason::Indirect);
+            assert_eq!(judge.should_skip(dep), expected, "{}", dep.name);
+        }
+
+        // Without --only-direct, indirect dependencies are still updated
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        assert!(deps.iter().all(|dep| judge.should_skip(dep).is_none()));
     }
 
     #[test]
@@ -390,6 +500,77 @@ go 1.21
         assert!(deps.is_empty());
     }
 
+    #[test]
+    fn test_parse_ignores_retract_block() {
+        let content = r#"
+module example.com/myproject
+
+require github.com/gin-gonic/gin v1.9.1
+
+retract (
+	v1.0.0 // published accidentally
+	[v1.1.0, v1.2.0] // broken builds
+)
+
+retract v0.9.0
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].name, "github.com/gin-gonic/gin");
+    }
+
+    #[test]
+    fn test_parse_ignores_exclude_directives() {
+        let content = r#"
+module example.com/myproject
+
+require github.com/gin-gonic/gin v1.9.1
+
+exclude github.com/gin-gonic/gin v1.9.2
+
+exclude (
+	github.com/stretchr/testify v1.8.5
+)
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].version_spec.version, "1.9.1");
+    }
+
+    #[test]
+    fn test_excluded_versions() {
+        let content = r#"
+module example.com/myproject
+
+require github.com/gin-gonic/gin v1.9.1
+
+exclude github.com/gin-gonic/gin v1.9.2 // broken
+
+exclude (
+	github.com/stretchr/testify v1.8.5
+	github.com/stretchr/testify v1.8.6
+)
+"#;
+
+        let excluded = GoModParser.excluded_versions(content);
+        assert_eq!(
+            excluded,
+            vec![
+                ("github.com/gin-gonic/gin".to_string(), "v1.9.2".to_string()),
+                (
+                    "github.com/stretchr/testify".to_string(),
+                    "v1.8.5".to_string()
+                ),
+                (
+                    "github.com/stretchr/testify".to_string(),
+                    "v1.8.6".to_string()
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_update_single_require() {
         let content = r#"module example.com/myproject
@@ -456,6 +637,34 @@ require github.com/gin-gonic/gin v1.9.1
         assert!(result.contains("v1.10.0"));
     }
 
+    #[test]
+    fn test_update_major_version_path() {
+        let parser = GoModParser;
+        let content = r#"module example.com/myapp
+
+require (
+	github.com/foo/bar/v2 v2.3.0
+	github.com/baz/qux v1.0.0
+)
+"#;
+        let result = parser
+            .update_version(content, "github.com/foo/bar/v2", "v3.0.0")
+            .unwrap();
+        assert!(result.contains("\tgithub.com/foo/bar/v3 v3.0.0\n"));
+        assert!(!result.contains("bar/v2"));
+
+        let result = parser
+            .update_version(content, "github.com/baz/qux", "v2.1.0")
+            .unwrap();
+        assert!(result.contains("github.com/baz/qux/v2 v2.1.0"));
+
+        let single = "module example.com/myapp\n\nrequire github.com/foo/bar/v2 v2.3.0\n";
+        let result = parser
+            .update_version(single, "github.com/foo/bar/v2", "v2.4.0")
+            .unwrap();
+        assert!(result.contains("require github.com/foo/bar/v2 v2.4.0"));
+    }
+
     #[test]
     fn test_update_not_found() {
         let content = r#"module example.com/myproject
@@ -469,8 +678,55 @@ require github.com/gin-gonic/gin v1.9.1
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_pseudo_versions() {
+        let content = r#"module example.com/app
+
+require (
+	golang.org/x/exp v0.0.0-20231010123456-abcdef123456
+	github.com/foo/bar v1.2.4-0.20231010123456-abcdef123456
+)
+"#;
+
+        let deps = GoModParser.parse(content).unwrap();
+        assert_eq!(deps.len(), 2);
+        assert_eq!(
+            deps[0].version_spec.raw,
+            "v0.0.0-20231010123456-abcdef123456"
+        );
+        assert_eq!(
+            deps[1].version_spec.raw,
+            "v1.2.4-0.20231010123456-abcdef123456"
+        );
+    }
+
     #[test]
     fn test_language() {
         assert_eq!(GoModParser.language(), Language::Go);
     }
+
+    #[test]
+    fn test_commented_out_require_ignored() {
+        let content = r#"module example.com/app
+
+// require github.com/gin-gonic/gin v1.8.0
+require github.com/gin-gonic/gin v1.9.1
+
+require (
+	//github.com/stretchr/testify v1.7.0
+	github.com/stretchr/testify v1.8.4
+)
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].version_spec.raw, "v1.9.1");
+        assert_eq!(deps[1].version_spec.raw, "v1.8.4");
+
+        let result = GoModParser
+            .update_version(content, "github.com/gin-gonic/gin", "v1.10.0")
+            .unwrap();
+        assert!(result.contains("// require github.com/gin-gonic/gin v1.8.0\n"));
+        assert!(result.contains("\nrequire github.com/gin-gonic/gin v1.10.0\n"));
+    }
 }
@@ -7,8 +7,10 @@
 //! - Map notation dependencies: group: 'x', name: 'y', version: 'z'
 //! - String notation dependencies: 'group:name:version'
 //! - Variable references in versions
+//! - Plugins block: `id 'x' version 'y'` / `id("x") version "y"` (resolved via the Gradle Plugin Portal)
+//! - Maven-style ranges (`[1.0,2.0)`): the lower bound is updated, ranges without one are skipped
 
-use crate::domain::{Dependency, Language, VersionSpec, VersionSpecKind};
+use crate::domain::{Dependency, Language, SkipReason, VersionSpec, VersionSpecKind};
 use crate::error::ManifestError;
 use crate::manifest::ManifestParser;
 use crate::parser::get_parser;
@@ -20,6 +22,16 @@ use std::sync::LazyLock;
 /// Parser for build.gradle and build.gradle.kts files
 pub struct GradleParser;
 
+/// Mark Maven-style ranges without a lower bound (e.g., `(,2.0]`) as skipped,
+/// since there is no version in them that can be moved forward
+pub(crate) fn skip_unbounded_range(dep: Dependency) -> Dependency {
+    if dep.version_spec.kind == VersionSpecKind::Range && dep.version_spec.prefix.is_none() {
+        dep.with_skip_reason(SkipReason::ComplexConstraint)
+    } else {
+        dep
+    }
+}
+
 /// Information about a variable definition
 #[derive(Debug, Clone)]
 struct VariableDefinition {
@@ -73,6 +85,14 @@ static DEP_STRING_VAR: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r#"^\s*(\w+)\s*[\(\s]*"([^:"]+):([^:"]+):\$\{?(\w+)\}?""#).unwrap()
 });
 
+// Plugin declaration: id 'org.springframework.boot' version '3.0.0'
+// Kotlin DSL: id("org.springframework.boot") version "3.0.0"
+// Core plugins without a version (id 'java') are not matched
+static PLUGIN_DECL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\s*id\s*\(?\s*['"]([^'"]+)['"]\s*\)?\s+version\s*\(?\s*['"]([^'"]+)['"]"#)
+        .unwrap()
+});
+
 // Development configurations
 const DEV_CONFIGURATIONS: [&str; 6] = [
     "testImplementation",
@@ -322,6 +342,21 @@ impl GradleParser {
         Some((dep, None))
     }
 
+    /// Parse a plugin declaration from the plugins block
+    ///
+    /// Plugins are named by their id, which has no `:` unlike `group:artifact`.
+    fn parse_plugin(
+        &self,
+        line: &str,
+        parser: &dyn crate::parser::VersionParser,
+    ) -> Option<Dependency> {
+        let caps = PLUGIN_DECL.captures(line)?;
+        let id = caps.get(1)?.as_str();
+        let spec = parser.parse(caps.get(2)?.as_str())?;
+
+        Some(Dependency::production(id, spec, Language::Java))
+    }
+
     /// Resolve a version value, handling variable references
     fn resolve_version(
         &self,
@@ -382,7 +417,13 @@ impl ManifestParser for GradleParser {
                 continue;
             }
 
-            // Try map notation first
+            // Try plugin declarations
+            if let Some(dep) = self.parse_plugin(line, parser.as_ref()) {
+                dependencies.push(skip_unbounded_range(dep));
+                continue;
+            }
+
+            // Try map notation
             if let Some((dep, var_name)) =
                 self.parse_map_notation(line, &variables, parser.as_ref())
             {
@@ -391,7 +432,7 @@ impl ManifestParser for GradleParser {
                 } else {
                     dep
                 };
-                dependencies.push(dep);
+                dependencies.push(skip_unbounded_range(dep));
                 continue;
             }
 
@@ -404,7 +445,7 @@ impl ManifestParser for GradleParser {
                 } else {
                     dep
                 };
-                dependencies.push(dep);
+                dependencies.push(skip_unbounded_range(dep));
             }
         }
 
@@ -426,38 +467,64 @@ impl ManifestParser for GradleParser {
 
         // Find which variable (if any) is used for this package
         let mut variable_for_package: Option<String> = None;
+        let mut current_spec: Option<VersionSpec> = None;
 
         for line in content.lines() {
+            if line.trim_start().starts_with("//") {
+                continue;
+            }
+
+            // Check plugin declarations
+            if let Some(dep) = self.parse_plugin(line, parser.as_ref()) {
+                if dep.name == package {
+                    current_spec = Some(dep.version_spec);
+                    break;
+                }
+            }
+
             // Check map notation
-            if let Some((_dep, var_name)) =
+            if let Some((dep, var_name)) =
                 self.parse_map_notation(line, &variables, parser.as_ref())
             {
-                if _dep.name == package {
+                if dep.name == package {
                     variable_for_package = var_name;
+                    current_spec = Some(dep.version_spec);
                     break;
                 }
             }
 
             // Check string notation
-            if let Some((_dep, var_name)) =
+            if let Some((dep, var_name)) =
                 self.parse_string_notation(line, &variables, parser.as_ref())
             {
-                if _dep.name == package {
+                if dep.name == package {
                     variable_for_package = var_name;
+                    current_spec = Some(dep.version_spec);
                     break;
                 }
             }
         }
 
+        // Maven-style ranges keep their brackets and upper bound: [1.0,2.0) -> [1.5,2.0)
+        let new_version = match current_spec {
+            Some(spec) if spec.kind == VersionSpecKind::Range => spec.format_updated(new_version),
+            _ => new_version.to_string(),
+        };
+
         // If using variable, update the variable definition
         if let Some(var_name) = variable_for_package {
             if let Some(var_def) = variables.get(&var_name) {
-                return self.update_variable_definition(content, var_def, new_version);
+                return self.update_variable_definition(content, var_def, &new_version);
             }
         }
 
+        // Plugin ids have no `:`
+        if !package.contains(':') {
+            return self.update_plugin_version(content, package, &new_version);
+        }
+
         // Otherwise, update the direct version in the dependency line
-        self.update_direct_version(content, package, new_version)
+        self.update_direct_version(content, package, &new_version)
     }
 }
 
@@ -539,6 +606,41 @@ impl GradleParser {
         Ok(result.join("\n"))
     }
 
+    /// Update the version of a plugin declaration in the plugins block
+    fn update_plugin_version(
+        &self,
+        content: &str,
+        plugin_id: &str,
+        new_version: &str,
+    ) -> Result<String, ManifestError> {
+        let pattern = format!(
+            r#"(?m)^(\s*id\s*\(?\s*['"]{}['"]\s*\)?\s+version\s*\(?\s*)(['"])[^'"]+['"]"#,
+            regex::escape(plugin_id)
+        );
+        let re = Regex::new(&pattern).map_err(|e| ManifestError::InvalidVersionSpec {
+            path: PathBuf::from("build.gradle"),
+            spec: plugin_id.to_string(),
+            message: format!("invalid regex pattern: {}", e),
+        })?;
+
+        let mut updated = false;
+        let result = re.replace(content, |caps: &regex::Captures| {
+            let quote = &caps[2];
+            updated = true;
+            format!("{}{}{}{}", &caps[1], quote, new_version, quote)
+        });
+
+        if updated {
+            return Ok(result.to_string());
+        }
+
+        Err(ManifestError::InvalidVersionSpec {
+            path: PathBuf::from("build.gradle"),
+            spec: plugin_id.to_string(),
+            message: "plugin not found or version could not be updated".to_string(),
+        })
+    }
+
     /// Update a direct version in a dependency line
     fn update_direct_version(
         &self,
@@ -559,9 +661,10 @@ impl GradleParser {
         let escaped_artifact = regex::escape(artifact);
 
         // Try to update map notation: group: 'x', name: 'y', version: 'z'
-        // Use non-backreference pattern (accepts either quote type)
+        // Use non-backreference pattern (accepts either quote type); like the parser,
+        // only declarations starting a line match, so `//` comments are left alone
         let map_pattern = format!(
-            r#"(group:\s*['"]{}['"]\s*,\s*name:\s*['"]{}['"]\s*,\s*version:\s*)(['"])([^'"]+)['"]"#,
+            r#"(?m)^([ \t]*\w+[ \t]*[\( \t]+group:\s*['"]{}['"]\s*,\s*name:\s*['"]{}['"]\s*,\s*version:\s*)(['"])([^'"]+)['"]"#,
             escaped_group, escaped_artifact
         );
         let map_re = Regex::new(&map_pattern).map_err(|e| ManifestError::InvalidVersionSpec {
@@ -584,7 +687,10 @@ impl GradleParser {
 
         // Try to update string notation: 'group:artifact:version'
         // Use non-backreference pattern (accepts either quote type)
-        let string_pattern = format!(r#"(['"]){}:{}:[^'"]+['"]"#, escaped_group, escaped_artifact);
+        let string_pattern = format!(
+            r#"(?m)^([ \t]*\w+[ \t]*[\( \t]*)(['"]){}:{}:[^'"]+['"]"#,
+            escaped_group, escaped_artifact
+        );
         let string_re =
             Regex::new(&string_pattern).map_err(|e| ManifestError::InvalidVersionSpec {
                 path: PathBuf::from("build.gradle"),
@@ -593,9 +699,12 @@ impl GradleParser {
             })?;
 
         let result = string_re.replace(content, |caps: &regex::Captures| {
-            let quote = &caps[1];
+            let quote = &caps[2];
             updated = true;
-            format!("{}{}:{}:{}{}", quote, group, artifact, new_version, quote)
+            format!(
+                "{}{}{}:{}:{}{}",
+                &caps[1], quote, group, artifact, new_version, quote
+            )
         });
 
         if updated {
@@ -799,6 +908,19 @@ dependencies {
         assert!(result.contains("'org.apache.wicket:wicket-core:10.0.0'"));
     }
 
+    #[test]
+    fn test_update_version_keeps_release_suffix() {
+        let content = r#"
+dependencies {
+    implementation 'org.springframework:spring-core:5.2.9.RELEASE'
+}
+"#;
+        let result = GradleParser
+            .update_version(content, "org.springframework:spring-core", "5.2.25.RELEASE")
+            .unwrap();
+        assert!(result.contains("'org.springframework:spring-core:5.2.25.RELEASE'"));
+    }
+
     #[test]
     fn test_update_version_map_notation() {
         let content = r#"
@@ -944,7 +1066,15 @@ dependencies {
 }
 "#;
         let deps = parse(content).unwrap();
-        assert_eq!(deps.len(), 5);
+        assert_eq!(deps.len(), 6);
+
+        // Core plugins without a version are not dependencies
+        let boot_plugin = deps
+            .iter()
+            .find(|d| d.name == "org.springframework.boot")
+            .unwrap();
+        assert_eq!(boot_plugin.version_spec.version, "3.0.0");
+        assert!(!deps.iter().any(|d| d.name == "java"));
 
         // Check specific dependencies
         let spring_boot = deps
@@ -964,4 +1094,121 @@ dependencies {
         let test_deps: Vec<_> = deps.iter().filter(|d| d.is_dev).collect();
         assert_eq!(test_deps.len(), 2);
     }
+
+    #[test]
+    fn test_parse_maven_range() {
+        let content = r#"
+dependencies {
+    implementation 'com.google.guava:guava:[30.0,32.0)'
+    implementation 'org.slf4j:slf4j-api:(,2.0.0]'
+}
+"#;
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 2);
+
+        let guava = deps
+            .iter()
+            .find(|d| d.name == "com.google.guava:guava")
+            .unwrap();
+        assert_eq!(guava.version_spec.kind, VersionSpecKind::Range);
+        assert_eq!(guava.version_spec.version, "30.0");
+        assert!(guava.skip_reason.is_none());
+
+        // No lower bound to move forward
+        let slf4j = deps
+            .iter()
+            .find(|d| d.name == "org.slf4j:slf4j-api")
+            .unwrap();
+        assert_eq!(slf4j.skip_reason, Some(SkipReason::ComplexConstraint));
+    }
+
+    #[test]
+    fn test_update_maven_range_lower_bound() {
+        let content = r#"
+dependencies {
+    implementation 'com.google.guava:guava:[30.0,32.0)'
+}
+"#;
+        let result = GradleParser
+            .update_version(content, "com.google.guava:guava", "31.1-jre")
+            .unwrap();
+        assert!(result.contains("'com.google.guava:guava:[31.1-jre,32.0)'"));
+    }
+
+    #[test]
+    fn test_parse_plugins_block() {
+        let content = r#"
+plugins {
+    id 'java'
+    id 'org.springframework.boot' version '3.0.0'
+    id("io.spring.dependency-management") version "1.1.0" apply false
+    kotlin("jvm") version "1.9.0"
+}
+"#;
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].name, "org.springframework.boot");
+        assert_eq!(deps[0].version_spec.version, "3.0.0");
+        assert_eq!(deps[0].version_spec.kind, VersionSpecKind::Exact);
+        assert!(!deps[0].is_dev);
+        assert_eq!(deps[1].name, "io.spring.dependency-management");
+        assert_eq!(deps[1].version_spec.version, "1.1.0");
+    }
+
+    #[test]
+    fn test_update_plugin_version() {
+        let content = r#"plugins {
+    id 'org.springframework.boot' version '3.0.0'
+    id("io.spring.dependency-management") version "1.1.0" apply false
+}
+
+dependencies {
+    implementation 'org.springframework.boot:spring-boot-starter-web:3.0.0'
+}"#;
+        let result = GradleParser
+            .update_version(content, "org.springframework.boot", "3.2.1")
+            .unwrap();
+        assert!(result.contains("id 'org.springframework.boot' version '3.2.1'"));
+        // The dependency sharing the version is left alone
+        assert!(result.contains("'org.springframework.boot:spring-boot-starter-web:3.0.0'"));
+
+        let result = GradleParser
+            .update_version(content, "io.spring.dependency-management", "1.1.4")
+            .unwrap();
+        assert!(
+            result.contains(r#"id("io.spring.dependency-management") version "1.1.4" apply false"#)
+        );
+
+        assert!(GradleParser
+            .update_version(content, "org.example.missing", "1.0.0")
+            .is_err());
+    }
+
+    #[test]
+    fn test_commented_out_dependency_ignored() {
+        let content = r#"dependencies {
+    // implementation 'com.google.guava:guava:30.0-jre'
+    implementation 'com.google.guava:guava:31.1-jre'
+    // implementation group: 'org.slf4j', name: 'slf4j-api', version: '1.7.0'
+    implementation group: 'org.slf4j', name: 'slf4j-api', version: '2.0.7'
+}
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].version_spec.raw, "31.1-jre");
+        assert_eq!(deps[1].version_spec.raw, "2.0.7");
+
+        let result = GradleParser
+            .update_version(content, "com.google.guava:guava", "32.0.0-jre")
+            .unwrap();
+        assert!(result.contains("// implementation 'com.google.guava:guava:30.0-jre'\n"));
+        assert!(result.contains("    implementation 'com.google.guava:guava:32.0.0-jre'\n"));
+
+        let result = GradleParser
+            .update_version(content, "org.slf4j:slf4j-api", "2.0.9")
+            .unwrap();
+        assert!(result.contains("name: 'slf4j-api', version: '1.7.0'"));
+        assert!(result.contains("name: 'slf4j-api', version: '2.0.9'"));
+    }
 }
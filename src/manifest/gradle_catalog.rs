@@ -0,0 +1,380 @@
+//! Gradle version catalog parser (gradle/libs.versions.toml)
+//!
+//! Handles:
+//! - [versions] table of shared version strings
+//! - [libraries] entries using `module = "group:name"` or `group`/`name` keys
+//! - String notation: `alias = "group:name:version"`
+//! - `version.ref` indirection into [versions]
+//! - Inline `version = "x.y.z"`
+
+use crate::domain::{Dependency, Language, VersionSpec, VersionSpecKind};
+use crate::error::ManifestError;
+use crate::manifest::gradle::skip_unbounded_range;
+use crate::manifest::ManifestParser;
+use crate::parser::get_parser;
+use regex::Regex;
+use std::path::PathBuf;
+use toml::Value;
+
+/// File name of the Gradle version catalog
+pub const VERSION_CATALOG_FILENAME: &str = "libs.versions.toml";
+
+/// Parser for Gradle version catalogs
+pub struct GradleVersionCatalogParser;
+
+/// Version declaration of a catalog library
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CatalogVersion {
+    /// Inline version string
+    Inline(String),
+    /// Reference to a [versions] key
+    Ref(String),
+    /// No plain version (e.g., rich `strictly`/`require` versions or none)
+    Unsupported,
+}
+
+/// A library entry from the [libraries] table
+#[derive(Debug, Clone)]
+struct CatalogLibrary {
+    /// Maven coordinates "group:name"
+    module: String,
+    /// Version declaration
+    version: CatalogVersion,
+}
+
+/// Extract a library entry from its TOML value
+fn parse_library(value: &Value) -> Option<CatalogLibrary> {
+    match value {
+        // String notation: "group:name:version"
+        Value::String(s) => {
+            let mut parts = s.splitn(3, ':');
+            let group = parts.next()?;
+            let name = parts.next()?;
+            let version = match parts.next() {
+                Some(v) if !v.is_empty() => CatalogVersion::Inline(v.to_string()),
+                _ => CatalogVersion::Unsupported,
+            };
+            Some(CatalogLibrary {
+                module: format!("{}:{}", group, name),
+                version,
+            })
+        }
+        Value::Table(t) => {
+            let module = match t.get("module").and_then(|m| m.as_str()) {
+                Some(module) => module.to_string(),
+                None => {
+                    let group = t.get("group").and_then(|g| g.as_str())?;
+                    let name = t.get("name").and_then(|n| n.as_str())?;
+                    format!("{}:{}", group, name)
+                }
+            };
+
+            // `version.ref = "x"` is a dotted key, so it parses as version = { ref = "x" }
+            let version = match t.get("version") {
+                Some(Value::String(v)) => CatalogVersion::Inline(v.clone()),
+                Some(Value::Table(v)) => match v.get("ref").and_then(|r| r.as_str()) {
+                    Some(r) => CatalogVersion::Ref(r.to_string()),
+                    None => CatalogVersion::Unsupported,
+                },
+                _ => CatalogVersion::Unsupported,
+            };
+
+            Some(CatalogLibrary { module, version })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the line range (start, end) of a `[section]` in the content
+fn section_lines(lines: &[&str], section: &str) -> Option<(usize, usize)> {
+    let header = format!("[{}]", section);
+    let start = lines.iter().position(|l| l.trim() == header)? + 1;
+    let end = lines[start..]
+        .iter()
+        .position(|l| l.trim_start().starts_with('['))
+        .map(|offset| start + offset)
+        .unwrap_or(lines.len());
+    Some((start, end))
+}
+
+/// Replace the first capture group 2 in the given line range, keeping group 1
+fn replace_in_section(
+    content: &str,
+    section: &str,
+    line_matches: impl Fn(&str) -> bool,
+    re: &Regex,
+    new_value: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let (start, end) = section_lines(&lines, section)?;
+
+    let index = (start..end).find(|&i| {
+        !lines[i].trim_start().starts_with('#') && line_matches(lines[i]) && re.is_match(lines[i])
+    })?;
+    let caps = re.captures(lines[index])?;
+    let replacement = new_value(&caps[2])?;
+    let updated_line = re
+        .replace(
+            lines[index],
+            regex::NoExpand(&format!("{}{}\"", &caps[1], replacement)),
+        )
+        .to_string();
+
+    let mut result: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    result[index] = updated_line;
+    let mut joined = result.join("\n");
+    if content.ends_with('\n') {
+        joined.push('\n');
+    }
+    Some(joined)
+}
+
+impl GradleVersionCatalogParser {
+    /// Parse the catalog into its TOML value
+    fn parse_toml(content: &str) -> Result<Value, ManifestError> {
+        toml::from_str(content).map_err(|e: toml::de::Error| ManifestError::TomlParseError {
+            path: PathBuf::from(VERSION_CATALOG_FILENAME),
+            message: e.to_string(),
+        })
+    }
+
+    /// Find the library declaring the given "group:name" module
+    fn find_library(toml: &Value, package: &str) -> Option<CatalogLibrary> {
+        toml.get("libraries")?
+            .as_table()?
+            .values()
+            .filter_map(parse_library)
+            .find(|lib| lib.module == package)
+    }
+}
+
+impl ManifestParser for GradleVersionCatalogParser {
+    fn parse(&self, content: &str) -> Result<Vec<Dependency>, ManifestError> {
+        let toml = Self::parse_toml(content)?;
+        let parser = get_parser(Language::Java);
+        let versions = toml.get("versions").and_then(|v| v.as_table());
+
+        let Some(libraries) = toml.get("libraries").and_then(|l| l.as_table()) else {
+            return Ok(Vec::new());
+        };
+
+        let mut dependencies = Vec::new();
+        for library in libraries.values().filter_map(parse_library) {
+            let (version, variable) = match &library.version {
+                CatalogVersion::Inline(v) => (v.clone(), None),
+                CatalogVersion::Ref(r) => {
+                    match versions.and_then(|v| v.get(r)).and_then(|v| v.as_str()) {
+                        Some(v) => (v.to_string(), Some(r.clone())),
+                        None => continue,
+                    }
+                }
+                CatalogVersion::Unsupported => continue,
+            };
+
+            let spec = parser
+                .parse(&version)
+                .unwrap_or_else(|| VersionSpec::new(VersionSpecKind::Any, version.clone(), ""));
+            let mut dep = Dependency::production(library.module, spec, Language::Java);
+            if let Some(variable) = variable {
+                dep = dep.with_variable(variable);
+            }
+            dependencies.push(skip_unbounded_range(dep));
+        }
+
+        Ok(dependencies)
+    }
+
+    fn language(&self) -> Language {
+        Language::Java
+    }
+
+    fn update_version(
+        &self,
+        content: &str,
+        package: &str,
+        new_version: &str,
+    ) -> Result<String, ManifestError> {
+        let not_found = || ManifestError::InvalidVersionSpec {
+            path: PathBuf::from(VERSION_CATALOG_FILENAME),
+            spec: package.to_string(),
+            message: "package not found or version could not be updated".to_string(),
+        };
+
+        let toml = Self::parse_toml(content)?;
+        let library = Self::find_library(&toml, package).ok_or_else(not_found)?;
+        let parser = get_parser(Language::Java);
+        let format_new = |old: &str| {
+            parser
+                .parse(old)
+                .map(|spec| spec.format_updated(new_version))
+        };
+
+        let updated = match &library.version {
+            // Update the shared [versions] entry
+            CatalogVersion::Ref(key) => {
+                let re = Regex::new(&format!(r#"^(\s*{}\s*=\s*")([^"]+)""#, regex::escape(key)))
+                    .map_err(|_| not_found())?;
+                replace_in_section(content, "versions", |_| true, &re, format_new)
+            }
+            CatalogVersion::Inline(_) => {
+                let (group, name) = package.split_once(':').ok_or_else(not_found)?;
+                let module = format!("\"{}\"", package);
+                let group_re = Regex::new(&format!(r#"\bgroup\s*=\s*"{}""#, regex::escape(group)))
+                    .map_err(|_| not_found())?;
+                let name_re = Regex::new(&format!(r#"\bname\s*=\s*"{}""#, regex::escape(name)))
+                    .map_err(|_| not_found())?;
+                let declares = |line: &str| {
+                    line.contains(&module) || (group_re.is_match(line) && name_re.is_match(line))
+                };
+
+                // Table form: version = "x.y.z"
+                let version_re = Regex::new(r#"(\bversion\s*=\s*")([^"]+)""#).unwrap();
+                replace_in_section(content, "libraries", declares, &version_re, format_new).or_else(
+                    || {
+                        // String notation: "group:name:x.y.z"
+                        let string_re =
+                            Regex::new(&format!(r#"("{}:)([^"]+)""#, regex::escape(package)))
+                                .ok()?;
+                        replace_in_section(content, "libraries", |_| true, &string_re, format_new)
+                    },
+                )
+            }
+            CatalogVersion::Unsupported => None,
+        };
+
+        updated.ok_or_else(not_found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CATALOG: &str = r#"[versions]
+groovy = "3.0.5"
+checkstyle = "8.37"
+
+[libraries]
+groovy-core = { module = "org.codehaus.groovy:groovy", version.ref = "groovy" }
+groovy-json = { group = "org.codehaus.groovy", name = "groovy-json", version.ref = "groovy" }
+commons-lang3 = { module = "org.apache.commons:commons-lang3", version = "3.12.0" }
+guava = "com.google.guava:guava:32.1.2-jre"
+bom = { module = "org.springframework:spring-bom", version = { strictly = "6.0.0" } }
+
+[plugins]
+versions = { id = "com.github.ben-manes.versions", version = "0.45.0" }
+"#;
+
+    #[test]
+    fn test_parse_catalog() {
+        let deps = GradleVersionCatalogParser.parse(CATALOG).unwrap();
+        assert_eq!(deps.len(), 4);
+
+        let groovy = deps
+            .iter()
+            .find(|d| d.name == "org.codehaus.groovy:groovy")
+            .unwrap();
+        assert_eq!(groovy.version_spec.version, "3.0.5");
+        assert_eq!(groovy.variable_name.as_deref(), Some("groovy"));
+        assert_eq!(groovy.language, Language::Java);
+
+        let json = deps
+            .iter()
+            .find(|d| d.name == "org.codehaus.groovy:groovy-json")
+            .unwrap();
+        assert_eq!(json.version_spec.version, "3.0.5");
+
+        let lang3 = deps
+            .iter()
+            .find(|d| d.name == "org.apache.commons:commons-lang3")
+            .unwrap();
+        assert_eq!(lang3.version_spec.version, "3.12.0");
+        assert!(lang3.variable_name.is_none());
+
+        let guava = deps
+            .iter()
+            .find(|d| d.name == "com.google.guava:guava")
+            .unwrap();
+        assert_eq!(guava.version_spec.version, "32.1.2-jre");
+    }
+
+    #[test]
+    fn test_update_version_ref() {
+        let result = GradleVersionCatalogParser
+            .update_version(CATALOG, "org.codehaus.groovy:groovy", "3.0.19")
+            .unwrap();
+
+        assert!(result.contains("groovy = \"3.0.19\""));
+        // The library line itself is untouched
+        assert!(result.contains(
+            r#"groovy-core = { module = "org.codehaus.groovy:groovy", version.ref = "groovy" }"#
+        ));
+        assert!(result.contains("checkstyle = \"8.37\""));
+        assert!(result.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_update_inline_version() {
+        let result = GradleVersionCatalogParser
+            .update_version(CATALOG, "org.apache.commons:commons-lang3", "3.14.0")
+            .unwrap();
+
+        assert!(result.contains(
+            r#"commons-lang3 = { module = "org.apache.commons:commons-lang3", version = "3.14.0" }"#
+        ));
+        // Plugin versions in other sections are not touched
+        assert!(result.contains(r#"version = "0.45.0""#));
+    }
+
+    #[test]
+    fn test_update_group_name_inline_version() {
+        let content = r#"[libraries]
+junit = { group = "junit", name = "junit", version = "4.12" }
+"#;
+
+        let result = GradleVersionCatalogParser
+            .update_version(content, "junit:junit", "4.13.2")
+            .unwrap();
+        assert!(result.contains(r#"name = "junit", version = "4.13.2" }"#));
+    }
+
+    #[test]
+    fn test_update_string_notation() {
+        let result = GradleVersionCatalogParser
+            .update_version(CATALOG, "com.google.guava:guava", "33.0.0-jre")
+            .unwrap();
+        assert!(result.contains(r#"guava = "com.google.guava:guava:33.0.0-jre""#));
+    }
+
+    #[test]
+    fn test_update_not_found() {
+        let result =
+            GradleVersionCatalogParser.update_version(CATALOG, "com.example:missing", "1.0");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_language() {
+        assert_eq!(GradleVersionCatalogParser.language(), Language::Java);
+    }
+
+    #[test]
+    fn test_commented_out_library_ignored() {
+        let content = r#"[libraries]
+# guava = { group = "com.google.guava", name = "guava", version = "30.0-jre" }
+guava = { group = "com.google.guava", name = "guava", version = "31.1-jre" }
+"#;
+
+        let deps = GradleVersionCatalogParser.parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].version_spec.raw, "31.1-jre");
+
+        let result = GradleVersionCatalogParser
+            .update_version(content, "com.google.guava:guava", "32.0.0-jre")
+            .unwrap();
+        assert!(result.contains(
+            r#"# guava = { group = "com.google.guava", name = "guava", version = "30.0-jre" }"#
+        ));
+        assert!(result.contains(r#"name = "guava", version = "32.0.0-jre" }"#));
+    }
+}
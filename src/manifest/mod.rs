@@ -6,30 +6,65 @@
 //! - Support monorepo structures (pnpm-workspace.yaml)
 //! - Support Tauri projects (src-tauri/Cargo.toml)
 
+mod cargo_lock;
 mod cargo_toml;
 mod composer_json;
+mod deno_json;
+mod depup_config;
 mod detector;
+mod dockerfile;
 mod gemfile;
 mod go_mod;
 mod gradle;
+mod gradle_catalog;
+mod nimble;
 mod package_json;
+mod package_lock;
+mod pipfile;
+mod pnpm_catalog;
 mod pnpm_settings;
+mod pre_commit_config;
 mod pyproject_toml;
+mod python_version;
+mod renv_lock;
+mod shard_yml;
+mod tool_versions;
+mod usage;
 mod writer;
 
+pub use cargo_lock::{CargoLock, CARGO_LOCK_FILENAME};
 pub use cargo_toml::CargoTomlParser;
-pub use composer_json::ComposerJsonParser;
-pub use detector::{detect_manifests, ManifestFile, ManifestInfo};
+pub use composer_json::{mark_path_repository_packages, ComposerJsonParser};
+pub use deno_json::DenoJsonParser;
+pub use depup_config::{DepupConfig, DEPUP_CONFIG_KEY};
+pub use detector::{detect_manifests, manifests_from_list, ManifestFile, ManifestInfo};
+pub use dockerfile::DockerfileParser;
 pub use gemfile::GemfileParser;
 pub use go_mod::GoModParser;
 pub use gradle::GradleParser;
+pub use gradle_catalog::{GradleVersionCatalogParser, VERSION_CATALOG_FILENAME};
+pub use nimble::{find_nimble_file, NimbleParser, NIMBLE_EXTENSION};
 pub use package_json::PackageJsonParser;
+pub use package_lock::{PackageLock, PACKAGE_LOCK_FILENAME};
+pub use pipfile::{PipfileParser, PIPFILE_FILENAME};
+pub use pnpm_catalog::{
+    parse_catalog_entries, CatalogEntry, PnpmCatalogParser, PNPM_WORKSPACE_FILENAME,
+};
 pub use pnpm_settings::{has_pnpm_workspace, PnpmSettings};
+pub use pre_commit_config::PreCommitConfigParser;
 pub use pyproject_toml::PyprojectTomlParser;
-pub use writer::{read_manifest, write_manifest, ManifestWriter, WriteResult};
+pub use python_version::{parse_python_version, read_python_version, PYTHON_VERSION_FILENAME};
+pub use renv_lock::RenvLockParser;
+pub use shard_yml::ShardYmlParser;
+pub use tool_versions::{
+    parse_tool_versions, read_tool_versions, Runtime, ToolVersion, TOOL_VERSIONS_FILENAME,
+};
+pub use usage::{find_unused_dependencies, UnusedDependency};
+pub use writer::{read_manifest, read_manifest_lossy, write_manifest, ManifestWriter, WriteResult};
 
 use crate::domain::{Dependency, Language};
 use crate::error::ManifestError;
+use crate::update::StabilityPolicy;
 use std::path::Path;
 
 /// Trait for parsing manifest files
@@ -47,6 +82,35 @@ pub trait ManifestParser {
         package: &str,
         new_version: &str,
     ) -> Result<String, ManifestError>;
+
+    /// Find packages that are declared more than once in the manifest
+    ///
+    /// Parsers that cannot contain duplicates keep the default empty result.
+    fn find_duplicates(&self, _content: &str) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Find `(package, version)` pairs the manifest forbids (e.g., go.mod `exclude`)
+    ///
+    /// Parsers without such a directive keep the default empty result.
+    fn excluded_versions(&self, _content: &str) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// Stability settings that limit which versions may be offered (e.g., composer.json
+    /// `minimum-stability`)
+    ///
+    /// Parsers without such settings keep the default `None`.
+    fn stability_policy(&self, _content: &str) -> Option<StabilityPolicy> {
+        None
+    }
+
+    /// Python versions the project supports (pyproject.toml `requires-python`)
+    ///
+    /// Parsers for other manifests keep the default `None`.
+    fn requires_python(&self, _content: &str) -> Option<String> {
+        None
+    }
 }
 
 /// Get a manifest parser for the specified language
@@ -59,29 +123,56 @@ pub fn get_parser(language: Language) -> Box<dyn ManifestParser> {
         Language::Ruby => Box::new(GemfileParser),
         Language::Php => Box::new(ComposerJsonParser),
         Language::Java => Box::new(GradleParser),
+        Language::Deno => Box::new(DenoJsonParser),
+        Language::Nim => Box::new(NimbleParser),
+        Language::Docker => Box::new(DockerfileParser),
+        Language::R => Box::new(RenvLockParser),
+        Language::Crystal => Box::new(ShardYmlParser),
+        Language::PreCommit => Box::new(PreCommitConfigParser),
     }
 }
 
+/// Get the manifest parser for a specific manifest file
+///
+/// Some languages have more than one manifest format (e.g., the Gradle
+/// version catalog alongside build.gradle, or Pipfile alongside
+/// pyproject.toml, or pnpm catalogs alongside package.json), selected by file name.
+pub fn get_parser_for_path(path: &Path, language: Language) -> Box<dyn ManifestParser> {
+    match path.file_name().and_then(|n| n.to_str()) {
+        Some(VERSION_CATALOG_FILENAME) => Box::new(GradleVersionCatalogParser),
+        Some(PIPFILE_FILENAME) => Box::new(PipfileParser),
+        Some(PNPM_WORKSPACE_FILENAME) => Box::new(PnpmCatalogParser),
+        _ => get_parser(language),
+    }
+}
+
+/// Determine the language of a manifest from its file name
+fn language_for_path(path: &Path) -> Option<Language> {
+    let name = path.file_name()?.to_str()?;
+    match name {
+        VERSION_CATALOG_FILENAME => return Some(Language::Java),
+        PIPFILE_FILENAME => return Some(Language::Python),
+        PNPM_WORKSPACE_FILENAME => return Some(Language::Node),
+        _ => {}
+    }
+    if path.extension().is_some_and(|ext| ext == NIMBLE_EXTENSION) {
+        return Some(Language::Nim);
+    }
+    Language::all()
+        .iter()
+        .copied()
+        .find(|lang| lang.manifest_filename() == Some(name))
+}
+
 /// Parse dependencies from a manifest file path
 pub fn parse_manifest(path: &Path) -> Result<Vec<Dependency>, ManifestError> {
-    let content = std::fs::read_to_string(path).map_err(|e| ManifestError::ReadError {
+    let content = read_manifest(path)?;
+
+    let language = language_for_path(path).ok_or_else(|| ManifestError::UnsupportedFormat {
         path: path.to_path_buf(),
-        source: e,
     })?;
 
-    let language = Language::all()
-        .iter()
-        .find(|lang| {
-            path.file_name()
-                .and_then(|n| n.to_str())
-                .map(|n| n == lang.manifest_filename())
-                .unwrap_or(false)
-        })
-        .ok_or_else(|| ManifestError::UnsupportedFormat {
-            path: path.to_path_buf(),
-        })?;
-
-    let parser = get_parser(*language);
+    let parser = get_parser_for_path(path, language);
     parser.parse(&content)
 }
 
@@ -119,6 +210,22 @@ mod tests {
         assert_eq!(parser.language(), Language::Ruby);
     }
 
+    #[test]
+    fn test_get_parser_for_path() {
+        let parser = get_parser_for_path(Path::new("gradle/libs.versions.toml"), Language::Java);
+        assert_eq!(parser.language(), Language::Java);
+        assert!(parser
+            .parse("[libraries]\nguava = \"com.google.guava:guava:32.1.2-jre\"\n")
+            .is_ok());
+
+        let parser = get_parser_for_path(Path::new("Pipfile"), Language::Python);
+        assert_eq!(parser.language(), Language::Python);
+        assert!(parser.parse("[packages]\nrequests = \">=2.28\"\n").is_ok());
+
+        let parser = get_parser_for_path(Path::new("package.json"), Language::Node);
+        assert_eq!(parser.language(), Language::Node);
+    }
+
     #[test]
     fn test_get_parser_php() {
         let parser = get_parser(Language::Php);
@@ -130,4 +237,10 @@ mod tests {
         let parser = get_parser(Language::Java);
         assert_eq!(parser.language(), Language::Java);
     }
+
+    #[test]
+    fn test_get_parser_deno() {
+        let parser = get_parser(Language::Deno);
+        assert_eq!(parser.language(), Language::Deno);
+    }
 }
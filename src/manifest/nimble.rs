@@ -0,0 +1,320 @@
+//! Nimble package file parser for Nim projects (`*.nimble`)
+//!
+//! Handles:
+//! - `requires "pkg >= 1.2.3"` declarations, with or without parentheses
+//! - Several requirements per statement (`requires "a >= 1.0", "b ^= 2.0"`)
+//!   and comma-separated requirements inside one string
+//! - Statements continued over several lines after a trailing comma
+//! - URL package names (`requires "https://github.com/owner/repo >= 1.0"`)
+//!
+//! The `nim` compiler requirement, unconstrained requirements and `#head`/
+//! commit pins are not reported: they have no version to bump.
+
+use crate::domain::{Dependency, Language};
+use crate::error::ManifestError;
+use crate::manifest::ManifestParser;
+use crate::parser::get_parser;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// File extension of Nimble package files
+pub const NIMBLE_EXTENSION: &str = "nimble";
+
+/// Parser for `*.nimble` files
+pub struct NimbleParser;
+
+/// Start of a `requires` statement
+static REQUIRES_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*requires\s*[(\s]").unwrap());
+
+/// Double-quoted string literal
+static STRING_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#""([^"]*)""#).unwrap());
+
+/// Single requirement: `name`, then an optional constraint
+static REQUIREMENT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\s*)([^\s<>=~^#&]+)(\s*)(.*?)(\s*)$").unwrap());
+
+/// Find the Nimble package file in a directory, if any
+///
+/// When several exist, the first by file name is used.
+pub fn find_nimble_file(dir: &Path) -> Option<PathBuf> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file() && path.extension().is_some_and(|ext| ext == NIMBLE_EXTENSION)
+        })
+        .collect();
+    files.sort();
+    files.into_iter().next()
+}
+
+/// Length of a line's code, before any `#` comment outside a string literal
+fn code_len(line: &str) -> usize {
+    let mut in_string = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return index,
+            _ => {}
+        }
+    }
+    line.len()
+}
+
+/// Indices of the lines that belong to `requires` statements
+///
+/// Comment lines inside a multi-line statement are not part of it.
+fn requires_lines(content: &str) -> Vec<usize> {
+    let mut indices = Vec::new();
+    let mut continued = false;
+
+    for (index, line) in content.lines().enumerate() {
+        let code = &line[..code_len(line)];
+        if continued && code.trim().is_empty() {
+            continue;
+        }
+        if !continued && !REQUIRES_RE.is_match(code) {
+            continue;
+        }
+        indices.push(index);
+
+        // A trailing comma after the last string continues the statement
+        let rest = code.rfind('"').map_or(code, |i| &code[i + 1..]);
+        continued = rest.trim().ends_with(',');
+    }
+
+    indices
+}
+
+/// Whether a requirement names the Nim compiler rather than a package
+fn is_compiler(name: &str) -> bool {
+    name.eq_ignore_ascii_case("nim")
+}
+
+impl ManifestParser for NimbleParser {
+    fn parse(&self, content: &str) -> Result<Vec<Dependency>, ManifestError> {
+        let parser = get_parser(Language::Nim);
+        let lines: Vec<&str> = content.lines().collect();
+        let mut dependencies = Vec::new();
+
+        for index in requires_lines(content) {
+            let line = lines[index];
+            for literal in STRING_RE.captures_iter(&line[..code_len(line)]) {
+                for requirement in literal[1].split(',') {
+                    let Some(caps) = REQUIREMENT_RE.captures(requirement) else {
+                        continue;
+                    };
+                    let name = &caps[2];
+                    if is_compiler(name) {
+                        continue;
+                    }
+                    if let Some(spec) = parser.parse(&caps[4]) {
+                        dependencies.push(Dependency::production(name, spec, Language::Nim));
+                    }
+                }
+            }
+        }
+
+        Ok(dependencies)
+    }
+
+    fn language(&self) -> Language {
+        Language::Nim
+    }
+
+    fn update_version(
+        &self,
+        content: &str,
+        package: &str,
+        new_version: &str,
+    ) -> Result<String, ManifestError> {
+        let parser = get_parser(Language::Nim);
+        let targets = requires_lines(content);
+        let mut updated = false;
+
+        let mut result: Vec<String> = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            if updated || !targets.contains(&index) {
+                result.push(line.to_string());
+                continue;
+            }
+
+            let (code, comment) = line.split_at(code_len(line));
+            let rewritten = STRING_RE.replace_all(code, |literal: &regex::Captures| {
+                let parts: Vec<String> = literal[1]
+                    .split(',')
+                    .map(|requirement| {
+                        let Some(caps) = REQUIREMENT_RE.captures(requirement) else {
+                            return requirement.to_string();
+                        };
+                        if updated || &caps[2] != package {
+                            return requirement.to_string();
+                        }
+                        let Some(spec) = parser.parse(&caps[4]) else {
+                            return requirement.to_string();
+                        };
+                        updated = true;
+                        format!(
+                            "{}{}{}{}{}",
+                            &caps[1],
+                            &caps[2],
+                            &caps[3],
+                            spec.format_updated(new_version),
+                            &caps[5]
+                        )
+                    })
+                    .collect();
+                format!("\"{}\"", parts.join(","))
+            });
+            result.push(format!("{}{}", rewritten, comment));
+        }
+
+        if !updated {
+            return Err(ManifestError::InvalidVersionSpec {
+                path: PathBuf::from(Language::Nim.manifest_pattern()),
+                spec: package.to_string(),
+                message: "requirement not found or has no version constraint".to_string(),
+            });
+        }
+
+        let mut output = result.join("\n");
+        if content.ends_with('\n') {
+            output.push('\n');
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::VersionSpecKind;
+    use tempfile::TempDir;
+
+    const NIMBLE: &str = r#"# Package
+
+version       = "0.1.0"
+author        = "Example"
+description   = "Example app"
+license       = "MIT"
+srcDir        = "src"
+
+# Dependencies
+
+requires "nim >= 2.0.0"
+requires "jester >= 0.5.0", "karax#head"
+requires("regex ^= 0.20.0")
+requires "chronos ~= 3.2.0, https://github.com/status-im/nim-stew >= 0.1.0",
+         "unittest2"  # unconstrained
+requires "checksums == 0.1.0"
+"#;
+
+    #[test]
+    fn test_parse_requires() {
+        let deps = NimbleParser.parse(NIMBLE).unwrap();
+        let summary: Vec<_> = deps
+            .iter()
+            .map(|d| (d.name.as_str(), d.version_spec.kind))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("jester", VersionSpecKind::GreaterOrEqual),
+                ("regex", VersionSpecKind::Caret),
+                ("chronos", VersionSpecKind::Tilde),
+                (
+                    "https://github.com/status-im/nim-stew",
+                    VersionSpecKind::GreaterOrEqual
+                ),
+                ("checksums", VersionSpecKind::Exact),
+            ]
+        );
+        assert!(deps
+            .iter()
+            .all(|d| d.language == Language::Nim && !d.is_dev));
+        assert_eq!(deps[0].version_spec.version, "0.5.0");
+    }
+
+    #[test]
+    fn test_parse_without_constraints() {
+        let content = "requires \"jester\"\nrequires \"karax#head\", \"nim\"\n";
+        assert!(NimbleParser.parse(content).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_requires_continuation() {
+        let content = "requires \"a >= 1.0\",\n  \"b >= 2.0\"\nversion = \"1.0.0\"\n";
+        assert_eq!(requires_lines(content), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_update_version_preserves_operator() {
+        let updated = NimbleParser
+            .update_version(NIMBLE, "jester", "0.6.0")
+            .unwrap();
+        assert!(updated.contains("requires \"jester >= 0.6.0\", \"karax#head\"\n"));
+
+        let updated = NimbleParser
+            .update_version(NIMBLE, "regex", "0.25.0")
+            .unwrap();
+        assert!(updated.contains("requires(\"regex ^= 0.25.0\")\n"));
+
+        let updated = NimbleParser
+            .update_version(NIMBLE, "https://github.com/status-im/nim-stew", "0.2.0")
+            .unwrap();
+        assert!(updated.contains(
+            "requires \"chronos ~= 3.2.0, https://github.com/status-im/nim-stew >= 0.2.0\",\n"
+        ));
+        // Everything else is left as written
+        assert!(updated.starts_with("# Package\n\nversion       = \"0.1.0\"\n"));
+        assert!(updated.contains("         \"unittest2\"  # unconstrained\n"));
+    }
+
+    #[test]
+    fn test_update_version_not_found() {
+        assert!(NimbleParser
+            .update_version(NIMBLE, "unittest2", "1.0.0")
+            .is_err());
+        assert!(NimbleParser
+            .update_version(NIMBLE, "missing", "1.0.0")
+            .is_err());
+    }
+
+    #[test]
+    fn test_find_nimble_file() {
+        let dir = TempDir::new().unwrap();
+        assert!(find_nimble_file(dir.path()).is_none());
+
+        std::fs::write(dir.path().join("demo.nimble"), NIMBLE).unwrap();
+        std::fs::write(dir.path().join("README.md"), "").unwrap();
+        assert_eq!(
+            find_nimble_file(dir.path()),
+            Some(dir.path().join("demo.nimble"))
+        );
+    }
+
+    #[test]
+    fn test_commented_out_requirement_ignored() {
+        let content = r#"# requires "jester >= 0.4.0"
+requires "jester >= 0.5.0", # "regex >= 0.1.0"
+  # "chronos >= 3.0.0",
+  "karax >= 1.2.0"
+"#;
+
+        let deps = NimbleParser.parse(content).unwrap();
+        let names: Vec<&str> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["jester", "karax"]);
+
+        let result = NimbleParser
+            .update_version(content, "jester", "0.6.0")
+            .unwrap();
+        assert!(result.starts_with("# requires \"jester >= 0.4.0\"\n"));
+        assert!(result.contains("requires \"jester >= 0.6.0\", # \"regex >= 0.1.0\"\n"));
+        assert!(NimbleParser
+            .update_version(content, "chronos", "3.1.0")
+            .is_err());
+    }
+}
@@ -4,12 +4,19 @@
 //! - dependencies
 //! - devDependencies
 //! - peerDependencies
-//! - optionalDependencies
-
-use crate::domain::{Dependency, Language};
+//! - optionalDependencies (flagged optional)
+//! - bundledDependencies / bundleDependencies (skipped: vendored into the tarball)
+//! - Duplicate declarations across sections (merged, preferring production)
+//! - pnpm `catalog:` references (left to the pnpm-workspace.yaml catalog)
+//! - `npm:` aliases (`npm:@jsr/std__path@^1.0.0`), looked up under the aliased name
+//! - `jsr:` specifiers (`jsr:@std/path@^1.0.0`, `jsr:^1.0.0`), looked up in JSR's
+//!   npm compatibility registry as `@jsr/std__path`
+
+use crate::domain::{Dependency, Language, SkipReason};
 use crate::error::ManifestError;
 use crate::manifest::ManifestParser;
 use crate::parser::{get_parser, VersionParser};
+use crate::registry::JSR_SCOPE;
 use regex::Regex;
 use serde_json::{Map, Value};
 use std::path::PathBuf;
@@ -45,12 +52,51 @@ impl ManifestParser for PackageJsonParser {
 
         // Parse optionalDependencies
         if let Some(deps) = json.get("optionalDependencies").and_then(|v| v.as_object()) {
-            parse_dependency_object(deps, parser.as_ref(), false, &mut dependencies);
+            let mut optional = Vec::new();
+            parse_dependency_object(deps, parser.as_ref(), false, &mut optional);
+            dependencies.extend(optional.into_iter().map(Dependency::with_optional));
+        }
+
+        // Bundled packages ship inside the published tarball, pinned to what was vendored
+        let bundled = bundled_dependencies(&json);
+        let mut dependencies = merge_duplicates(dependencies);
+        for dep in dependencies
+            .iter_mut()
+            .filter(|dep| dep.skip_reason.is_none() && bundled.contains(&dep.name.as_str()))
+        {
+            dep.skip_reason = Some(SkipReason::Bundled);
         }
 
         Ok(dependencies)
     }
 
+    fn find_duplicates(&self, content: &str) -> Vec<String> {
+        let json: Value = match serde_json::from_str(content) {
+            Ok(v) => v,
+            Err(_) => return Vec::new(),
+        };
+
+        // peerDependencies are intentionally excluded: declaring a peer dependency
+        // again in devDependencies is the standard pattern for libraries
+        let mut seen: Vec<&str> = Vec::new();
+        let mut duplicates = Vec::new();
+        for section in ["dependencies", "devDependencies", "optionalDependencies"] {
+            if let Some(deps) = json.get(section).and_then(|v| v.as_object()) {
+                for name in deps.keys() {
+                    if seen.contains(&name.as_str()) {
+                        if !duplicates.contains(name) {
+                            duplicates.push(name.clone());
+                        }
+                    } else {
+                        seen.push(name);
+                    }
+                }
+            }
+        }
+
+        duplicates
+    }
+
     fn language(&self) -> Language {
         Language::Node
     }
@@ -75,15 +121,26 @@ impl ManifestParser for PackageJsonParser {
             message: format!("invalid regex pattern: {}", e),
         })?;
 
+        // Only the first occurrence holding a version is rewritten; earlier keys with the
+        // same name (e.g., a script called "eslint") and every other byte stay untouched
         let mut updated = false;
-        let result = re.replace(content, |caps: &regex::Captures| {
+        let result = re.replace_all(content, |caps: &regex::Captures| {
+            if updated {
+                return caps[0].to_string();
+            }
+
             let prefix = &caps[1]; // "package": or "package" :
-            let old_version = &caps[2];
+
+            // Aliases keep everything before the version range
+            let (alias, old_version) = match split_alias(&caps[2]) {
+                Some((head, _, range)) => (head, range),
+                None => ("", &caps[2]),
+            };
 
             if let Some(spec) = parser.parse(old_version) {
                 updated = true;
                 let new_ver = spec.format_updated(new_version);
-                format!(r#"{}"{}""#, prefix, new_ver)
+                format!(r#"{}"{}{}""#, prefix, alias, new_ver)
             } else {
                 // If we can't parse the version, keep the original
                 caps[0].to_string()
@@ -110,18 +167,106 @@ fn parse_dependency_object(
 ) {
     for (name, version_value) in deps {
         if let Some(version_str) = version_value.as_str() {
+            let (target, version_str) = match split_alias(version_str) {
+                Some((head, package, range)) => (alias_target(head, name, package), range),
+                None => (None, version_str),
+            };
             if let Some(spec) = parser.parse(version_str) {
-                let dep = if is_dev {
+                let mut dep = if is_dev {
                     Dependency::development(name.clone(), spec, Language::Node)
                 } else {
                     Dependency::production(name.clone(), spec, Language::Node)
                 };
+                if let Some(target) = target.filter(|target| target != name) {
+                    dep = dep.with_alias_target(target);
+                }
                 output.push(dep);
             }
         }
     }
 }
 
+/// Split an `npm:`/`jsr:` specifier into (text before the range, package, range)
+///
+/// e.g., `npm:@jsr/std__path@^1.0.0` -> (`npm:@jsr/std__path@`, `@jsr/std__path`, `^1.0.0`).
+/// The package is empty for the `jsr:^1.0.0` shorthand, which names the key's package.
+fn split_alias(specifier: &str) -> Option<(&str, &str, &str)> {
+    let rest = specifier
+        .strip_prefix("npm:")
+        .or_else(|| specifier.strip_prefix("jsr:"))?;
+    let scheme_len = specifier.len() - rest.len();
+
+    // Scoped names start with '@', so the version separator is the next '@'
+    let offset = usize::from(rest.starts_with('@'));
+    match rest[offset..].find('@') {
+        Some(at) => {
+            let at = at + offset;
+            Some((
+                &specifier[..scheme_len + at + 1],
+                &rest[..at],
+                &rest[at + 1..],
+            ))
+        }
+        None if specifier.starts_with("jsr:") && offset == 0 => {
+            Some((&specifier[..scheme_len], "", rest))
+        }
+        None => None,
+    }
+}
+
+/// Registry package for an alias: the npm target, or the npm-compatible name of a JSR package
+fn alias_target(head: &str, name: &str, package: &str) -> Option<String> {
+    let package = if package.is_empty() { name } else { package };
+    if !head.starts_with("jsr:") {
+        return Some(package.to_string());
+    }
+    // `@std/path` is published to npm.jsr.io as `@jsr/std__path`
+    let (scope, pkg) = package.strip_prefix('@')?.split_once('/')?;
+    Some(format!("@{}/{}__{}", JSR_SCOPE, scope, pkg))
+}
+
+/// Names listed in `bundledDependencies` (or its `bundleDependencies` alias)
+///
+/// `true` bundles every package in `dependencies`.
+fn bundled_dependencies(json: &Value) -> Vec<&str> {
+    let Some(bundled) = json
+        .get("bundledDependencies")
+        .or_else(|| json.get("bundleDependencies"))
+    else {
+        return Vec::new();
+    };
+    match bundled {
+        Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+        Value::Bool(true) => json
+            .get("dependencies")
+            .and_then(|v| v.as_object())
+            .map(|deps| deps.keys().map(String::as_str).collect())
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+/// Merge dependencies declared in more than one section into a single entry
+///
+/// The production declaration wins over a development one so that a package
+/// listed in both `dependencies` and `devDependencies` is only updated once.
+fn merge_duplicates(dependencies: Vec<Dependency>) -> Vec<Dependency> {
+    let mut merged: Vec<Dependency> = Vec::with_capacity(dependencies.len());
+
+    for dep in dependencies {
+        match merged.iter_mut().find(|d| d.name == dep.name) {
+            Some(existing) => {
+                if existing.is_dev && !dep.is_dev {
+                    *existing = dep;
+                }
+            }
+            None => merged.push(dep),
+        }
+    }
+
+    merged
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,6 +416,80 @@ mod tests {
         assert_eq!(PackageJsonParser.language(), Language::Node);
     }
 
+    const ALIASES: &str = r#"{
+  "dependencies": {
+    "@std/path": "npm:@jsr/std__path@^1.0.8",
+    "@std/assert": "jsr:@std/assert@1.0.13",
+    "@std/fmt": "jsr:^1.0.3",
+    "lodash4": "npm:lodash@~4.17.20",
+    "lodash": "npm:lodash@^4.17.21"
+  }
+}"#;
+
+    #[test]
+    fn test_parse_aliases() {
+        let deps = parse(ALIASES).unwrap();
+        let lookup = |name: &str| {
+            let dep = deps.iter().find(|d| d.name == name).unwrap();
+            (dep.lookup_name().to_string(), dep.version_spec.raw.clone())
+        };
+
+        assert_eq!(
+            lookup("@std/path"),
+            ("@jsr/std__path".to_string(), "^1.0.8".to_string())
+        );
+        assert_eq!(
+            lookup("@std/assert"),
+            ("@jsr/std__assert".to_string(), "1.0.13".to_string())
+        );
+        assert_eq!(
+            lookup("@std/fmt"),
+            ("@jsr/std__fmt".to_string(), "^1.0.3".to_string())
+        );
+        assert_eq!(
+            lookup("lodash4"),
+            ("lodash".to_string(), "~4.17.20".to_string())
+        );
+        // An alias to the same name needs no separate lookup name
+        let lodash = deps.iter().find(|d| d.name == "lodash").unwrap();
+        assert!(lodash.alias_target.is_none());
+    }
+
+    #[test]
+    fn test_split_alias() {
+        assert_eq!(
+            split_alias("npm:@jsr/std__path@^1.0.8"),
+            Some(("npm:@jsr/std__path@", "@jsr/std__path", "^1.0.8"))
+        );
+        assert_eq!(split_alias("jsr:^1.0.3"), Some(("jsr:", "", "^1.0.3")));
+        assert_eq!(split_alias("npm:@types/node"), None);
+        assert_eq!(split_alias("^1.0.0"), None);
+    }
+
+    #[test]
+    fn test_update_aliases() {
+        let result = PackageJsonParser
+            .update_version(ALIASES, "@std/path", "1.1.0")
+            .unwrap();
+        assert!(result.contains(r#""@std/path": "npm:@jsr/std__path@^1.1.0""#));
+
+        let result = PackageJsonParser
+            .update_version(&result, "@std/assert", "1.0.14")
+            .unwrap();
+        assert!(result.contains(r#""@std/assert": "jsr:@std/assert@1.0.14""#));
+
+        let result = PackageJsonParser
+            .update_version(&result, "@std/fmt", "1.0.4")
+            .unwrap();
+        assert!(result.contains(r#""@std/fmt": "jsr:^1.0.4""#));
+
+        let result = PackageJsonParser
+            .update_version(&result, "lodash4", "4.17.21")
+            .unwrap();
+        assert!(result.contains(r#""lodash4": "npm:lodash@~4.17.21""#));
+        assert!(result.contains(r#""lodash": "npm:lodash@^4.17.21""#));
+    }
+
     #[test]
     fn test_parse_with_prerelease() {
         let content = r#"{
@@ -284,6 +503,21 @@ mod tests {
         assert_eq!(deps[0].version_spec.version, "14.0.0-canary.1");
     }
 
+    #[test]
+    fn test_parse_skips_catalog_references() {
+        let content = r#"{
+            "dependencies": {
+                "react": "catalog:",
+                "react-dom": "catalog:legacy",
+                "lodash": "^4.17.21"
+            }
+        }"#;
+
+        let deps = parse(content).unwrap();
+        let names: Vec<_> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["lodash"]);
+    }
+
     #[test]
     fn test_parse_wildcard() {
         let content = r#"{
@@ -358,4 +592,147 @@ mod tests {
         // Should preserve the original spacing around the colon
         assert!(result.contains("\"lodash\" : \"^4.18.0\""));
     }
+
+    #[test]
+    fn test_update_version_unusual_formatting_byte_identical() {
+        let content = "{\n\t\"name\" :\"app\",\n\t\"dependencies\":{\n\t\t\"lodash\"   :   \"^4.17.20\" ,\n\t\t\"react\":\"~18.2.0\"\n\t},\n\n  \"devDependencies\": { \"jest\" : \"29.0.0\" }   \n}\n";
+        let result = PackageJsonParser
+            .update_version(content, "lodash", "4.17.21")
+            .unwrap();
+
+        let changed: Vec<(&str, &str)> = content
+            .lines()
+            .zip(result.lines())
+            .filter(|(before, after)| before != after)
+            .collect();
+        assert_eq!(
+            changed,
+            vec![(
+                "\t\t\"lodash\"   :   \"^4.17.20\" ,",
+                "\t\t\"lodash\"   :   \"^4.17.21\" ,"
+            )]
+        );
+        assert_eq!(result.len(), content.len());
+        assert!(result.ends_with("}   \n}\n"));
+    }
+
+    #[test]
+    fn test_update_version_skips_script_with_same_name() {
+        let content = r#"{
+  "scripts": {
+    "eslint": "eslint ."
+  },
+  "devDependencies": {
+    "eslint": "^8.50.0"
+  }
+}"#;
+        let result = PackageJsonParser
+            .update_version(content, "eslint", "8.57.0")
+            .unwrap();
+        assert!(result.contains(r#""eslint": "eslint .""#));
+        assert!(result.contains(r#""eslint": "^8.57.0""#));
+    }
+
+    #[test]
+    fn test_parse_duplicate_prefers_production() {
+        let content = r#"{
+            "dependencies": {
+                "react": "^18.2.0"
+            },
+            "devDependencies": {
+                "react": "^18.0.0",
+                "typescript": "^5.0.0"
+            }
+        }"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 2);
+
+        let react: Vec<_> = deps.iter().filter(|d| d.name == "react").collect();
+        assert_eq!(react.len(), 1);
+        assert!(!react[0].is_dev);
+        assert_eq!(react[0].version_spec.version, "18.2.0");
+    }
+
+    #[test]
+    fn test_find_duplicates() {
+        let content = r#"{
+            "dependencies": {
+                "react": "^18.2.0"
+            },
+            "devDependencies": {
+                "react": "^18.0.0",
+                "typescript": "^5.0.0"
+            },
+            "peerDependencies": {
+                "typescript": "^5.0.0"
+            }
+        }"#;
+
+        assert_eq!(PackageJsonParser.find_duplicates(content), vec!["react"]);
+    }
+
+    #[test]
+    fn test_find_duplicates_none() {
+        let content = r#"{
+            "dependencies": {
+                "react": "^18.2.0"
+            }
+        }"#;
+
+        assert!(PackageJsonParser.find_duplicates(content).is_empty());
+        assert!(PackageJsonParser.find_duplicates("not json").is_empty());
+    }
+
+    #[test]
+    fn test_bundled_skipped_and_optional_processed() {
+        let content = r#"{
+            "dependencies": {
+                "vendored-lib": "^1.0.0",
+                "express": "^4.18.0"
+            },
+            "optionalDependencies": {
+                "fsevents": "^2.3.0"
+            },
+            "bundledDependencies": ["vendored-lib"]
+        }"#;
+
+        let deps = parse(content).unwrap();
+        let judge = crate::update::UpdateJudge::new(crate::update::UpdateFilter::new());
+
+        let vendored = deps.iter().find(|d| d.name == "vendored-lib").unwrap();
+        assert_eq!(judge.should_skip(vendored), Some(SkipReason::Bundled));
+
+        let fsevents = deps.iter().find(|d| d.name == "fsevents").unwrap();
+        assert!(fsevents.is_optional);
+        assert_eq!(fsevents.version_spec.version, "2.3.0");
+        assert_eq!(judge.should_skip(fsevents), None);
+
+        let express = deps.iter().find(|d| d.name == "express").unwrap();
+        assert!(!express.is_optional);
+        assert_eq!(judge.should_skip(express), None);
+    }
+
+    #[test]
+    fn test_bundle_dependencies_alias_and_true() {
+        let content = r#"{
+            "dependencies": {"a": "^1.0.0", "b": "^1.0.0"},
+            "bundleDependencies": ["b"]
+        }"#;
+        let deps = parse(content).unwrap();
+        assert_eq!(deps[0].skip_reason, None);
+        assert_eq!(deps[1].skip_reason, Some(SkipReason::Bundled));
+
+        // `true` bundles every production dependency
+        let content = r#"{
+            "dependencies": {"a": "^1.0.0"},
+            "devDependencies": {"jest": "^29.0.0"},
+            "bundledDependencies": true
+        }"#;
+        let deps = parse(content).unwrap();
+        let a = deps.iter().find(|d| d.name == "a").unwrap();
+        assert_eq!(a.skip_reason, Some(SkipReason::Bundled));
+        let jest = deps.iter().find(|d| d.name == "jest").unwrap();
+        assert_eq!(jest.skip_reason, None);
+    }
 }
@@ -0,0 +1,154 @@
+//! package-lock.json reader for resolved npm package versions
+//!
+//! Handles:
+//! - lockfileVersion 2/3 `packages` entries (`node_modules/<name>`)
+//! - lockfileVersion 1 `dependencies` entries
+//! - Locating the lock file next to the manifest or at the workspace root
+
+use crate::domain::Dependency;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// npm lock file name
+pub const PACKAGE_LOCK_FILENAME: &str = "package-lock.json";
+
+/// Resolved package versions read from a package-lock.json file
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PackageLock {
+    /// Locked version per top-level package name
+    packages: HashMap<String, String>,
+}
+
+impl PackageLock {
+    /// Parse package-lock.json content, returning `None` if it isn't valid JSON
+    ///
+    /// Only top-level installs are read: nested copies under another
+    /// package's `node_modules` are not what the manifest resolves to.
+    pub fn parse(content: &str) -> Option<Self> {
+        let json: Value = serde_json::from_str(content).ok()?;
+        let mut packages = HashMap::new();
+
+        if let Some(entries) = json.get("packages").and_then(|p| p.as_object()) {
+            for (path, entry) in entries {
+                let Some(name) = path.strip_prefix("node_modules/") else {
+                    continue;
+                };
+                if name.contains("/node_modules/") {
+                    continue;
+                }
+                if let Some(version) = entry.get("version").and_then(|v| v.as_str()) {
+                    packages.insert(name.to_string(), version.to_string());
+                }
+            }
+        } else if let Some(entries) = json.get("dependencies").and_then(|d| d.as_object()) {
+            for (name, entry) in entries {
+                if let Some(version) = entry.get("version").and_then(|v| v.as_str()) {
+                    packages.insert(name.to_string(), version.to_string());
+                }
+            }
+        }
+
+        Some(Self { packages })
+    }
+
+    /// Find and parse the package-lock.json that applies to a package.json
+    ///
+    /// npm workspaces share the lock file at the workspace root, so the
+    /// manifest's directory and its ancestors are searched in order.
+    pub fn find(manifest_path: &Path) -> Option<Self> {
+        manifest_path
+            .parent()?
+            .ancestors()
+            .map(|dir| dir.join(PACKAGE_LOCK_FILENAME))
+            .find(|path| path.is_file())
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| Self::parse(&content))
+    }
+
+    /// Locked version of a package
+    pub fn resolved_version(&self, name: &str) -> Option<&str> {
+        self.packages.get(name).map(String::as_str)
+    }
+
+    /// Attach resolved versions to the dependencies found in the lock file
+    pub fn attach_resolved_versions(&self, dependencies: Vec<Dependency>) -> Vec<Dependency> {
+        dependencies
+            .into_iter()
+            .map(|dep| match self.resolved_version(&dep.name) {
+                Some(resolved) => dep.with_resolved_version(resolved),
+                None => dep,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Language, VersionSpec, VersionSpecKind};
+    use tempfile::TempDir;
+
+    const SAMPLE_LOCK: &str = r#"{
+  "name": "myapp",
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "myapp", "dependencies": { "lodash": "^4.17.0" } },
+    "node_modules/lodash": { "version": "4.17.5" },
+    "node_modules/@types/node": { "version": "20.11.0" },
+    "node_modules/foo/node_modules/lodash": { "version": "3.10.1" }
+  }
+}"#;
+
+    fn node_dependency(name: &str, version: &str) -> Dependency {
+        let spec = VersionSpec::new(VersionSpecKind::Caret, format!("^{}", version), version)
+            .with_prefix("^");
+        Dependency::new(name, spec, false, Language::Node)
+    }
+
+    #[test]
+    fn test_parse_package_lock() {
+        let lock = PackageLock::parse(SAMPLE_LOCK).unwrap();
+        assert_eq!(lock.resolved_version("lodash"), Some("4.17.5"));
+        assert_eq!(lock.resolved_version("@types/node"), Some("20.11.0"));
+        assert_eq!(lock.resolved_version("react"), None);
+    }
+
+    #[test]
+    fn test_parse_lockfile_v1() {
+        let lock = PackageLock::parse(
+            r#"{"lockfileVersion": 1, "dependencies": {"lodash": {"version": "4.17.5"}}}"#,
+        )
+        .unwrap();
+        assert_eq!(lock.resolved_version("lodash"), Some("4.17.5"));
+    }
+
+    #[test]
+    fn test_attach_resolved_versions() {
+        let lock = PackageLock::parse(SAMPLE_LOCK).unwrap();
+        let deps = lock.attach_resolved_versions(vec![
+            node_dependency("lodash", "4.17.0"),
+            node_dependency("react", "18.2.0"),
+        ]);
+
+        assert_eq!(deps[0].resolved_version.as_deref(), Some("4.17.5"));
+        assert_eq!(deps[1].resolved_version, None);
+    }
+
+    #[test]
+    fn test_parse_invalid_lock() {
+        assert!(PackageLock::parse("{not json").is_none());
+        assert_eq!(PackageLock::parse("{}").unwrap(), PackageLock::default());
+    }
+
+    #[test]
+    fn test_find_lock_in_workspace_root() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join(PACKAGE_LOCK_FILENAME), SAMPLE_LOCK).unwrap();
+        let member = temp_dir.path().join("packages/web");
+        std::fs::create_dir_all(&member).unwrap();
+
+        let lock = PackageLock::find(&member.join("package.json")).unwrap();
+        assert_eq!(lock.resolved_version("lodash"), Some("4.17.5"));
+    }
+}
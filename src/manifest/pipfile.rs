@@ -0,0 +1,382 @@
+//! Pipfile parser for Pipenv projects
+//!
+//! Handles:
+//! - [packages]
+//! - [dev-packages]
+//! - String form: requests = ">=2.28"
+//! - Inline table form: requests = { version = ">=2.28", extras = ["socks"] }
+//! - Wildcard (`*`), git, path and file entries (skipped)
+
+use crate::domain::{Dependency, Language, VersionSpecKind};
+use crate::error::ManifestError;
+use crate::manifest::ManifestParser;
+use crate::parser::{get_parser, VersionParser};
+use regex::Regex;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+use toml::Value;
+
+/// File name of the Pipenv manifest
+pub const PIPFILE_FILENAME: &str = "Pipfile";
+
+/// Tables holding dependencies
+const PACKAGE_TABLES: [&str; 2] = ["packages", "dev-packages"];
+
+// [packages], [[source]]
+static TABLE_HEADER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*\[\[?([^\[\]]+)\]\]?\s*(?:#.*)?$").unwrap());
+
+/// Parser for Pipfile files
+pub struct PipfileParser;
+
+impl ManifestParser for PipfileParser {
+    fn parse(&self, content: &str) -> Result<Vec<Dependency>, ManifestError> {
+        let toml: Value = toml::from_str(content).map_err(|e: toml::de::Error| {
+            ManifestError::TomlParseError {
+                path: PathBuf::from(PIPFILE_FILENAME),
+                message: e.to_string(),
+            }
+        })?;
+
+        let mut dependencies = Vec::new();
+        let parser = get_parser(Language::Python);
+
+        if let Some(packages) = toml.get("packages").and_then(|p| p.as_table()) {
+            parse_pipfile_packages(packages, parser.as_ref(), false, &mut dependencies);
+        }
+
+        if let Some(packages) = toml.get("dev-packages").and_then(|p| p.as_table()) {
+            parse_pipfile_packages(packages, parser.as_ref(), true, &mut dependencies);
+        }
+
+        Ok(dependencies)
+    }
+
+    fn language(&self) -> Language {
+        Language::Python
+    }
+
+    fn update_version(
+        &self,
+        content: &str,
+        package: &str,
+        new_version: &str,
+    ) -> Result<String, ManifestError> {
+        let parser = get_parser(Language::Python);
+        let mut result = String::with_capacity(content.len());
+        let mut updated = false;
+
+        // Package keys may be quoted: "zope.interface" = "*"
+        let key = format!(r#""?{}"?"#, regex::escape(package));
+
+        // Pattern for string form: requests = ">=2.28"
+        let simple_pattern = format!(r#"^(\s*{}\s*=\s*)"([^"]+)""#, key);
+
+        // Pattern for inline table: requests = { ..., version = ">=2.28", ... }
+        let table_pattern = format!(
+            r#"^(\s*{}\s*=\s*\{{(?:[^}}\n]*?,)?\s*version\s*=\s*)"([^"]+)""#,
+            key
+        );
+        let patterns: Vec<Regex> = [simple_pattern, table_pattern]
+            .iter()
+            .filter_map(|pattern| Regex::new(pattern).ok())
+            .collect();
+
+        // Only entries of [packages] and [dev-packages] are dependencies; a package
+        // listed in both is updated in both
+        let mut in_packages = false;
+        for line in content.split_inclusive('\n') {
+            if let Some(caps) = TABLE_HEADER_RE.captures(line) {
+                in_packages = PACKAGE_TABLES.contains(&caps[1].trim());
+                result.push_str(line);
+                continue;
+            }
+            if !in_packages {
+                result.push_str(line);
+                continue;
+            }
+
+            let mut line = line.to_string();
+            for re in &patterns {
+                let Some(caps) = re.captures(&line) else {
+                    continue;
+                };
+                let old_version = caps.get(2).map(|m| m.as_str()).unwrap_or("");
+                if let Some(spec) = parser.parse(old_version) {
+                    // Range constraints (>=X,<Y) are compound, so keep them as written
+                    let new_ver = if spec.kind == VersionSpecKind::Range {
+                        old_version.to_string()
+                    } else {
+                        spec.format_updated(new_version)
+                    };
+                    if new_ver != old_version {
+                        let replacement = format!(r#"{}"{}""#, &caps[1], new_ver);
+                        line = re.replace(&line, regex::NoExpand(&replacement)).to_string();
+                        updated = true;
+                    }
+                }
+            }
+            result.push_str(&line);
+        }
+
+        if updated {
+            Ok(result)
+        } else {
+            Err(ManifestError::InvalidVersionSpec {
+                path: PathBuf::from(PIPFILE_FILENAME),
+                spec: package.to_string(),
+                message: "package not found or version could not be updated".to_string(),
+            })
+        }
+    }
+}
+
+fn parse_pipfile_packages(
+    packages: &toml::map::Map<String, Value>,
+    parser: &dyn VersionParser,
+    is_dev: bool,
+    output: &mut Vec<Dependency>,
+) {
+    for (name, value) in packages {
+        let version_str = match value {
+            // String form: requests = ">=2.28"
+            Value::String(s) => Some(s.as_str()),
+            // Inline table form; git/path/file entries carry no version
+            Value::Table(t) => t.get("version").and_then(|v| v.as_str()),
+            _ => None,
+        };
+
+        // "*" accepts any version, so there is nothing to update
+        let Some(version_str) = version_str.filter(|v| v.trim() != "*") else {
+            continue;
+        };
+
+        if let Some(spec) = parser.parse(version_str) {
+            let dep = if is_dev {
+                Dependency::development(name.clone(), spec, Language::Python)
+            } else {
+                Dependency::production(name.clone(), spec, Language::Python)
+            };
+            output.push(dep);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Result<Vec<Dependency>, ManifestError> {
+        PipfileParser.parse(content)
+    }
+
+    #[test]
+    fn test_parse_string_form() {
+        let content = r#"
+[packages]
+requests = ">=2.28"
+flask = "==2.3.0"
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 2);
+
+        let requests = deps.iter().find(|d| d.name == "requests").unwrap();
+        assert_eq!(requests.version_spec.version, "2.28");
+        assert_eq!(requests.version_spec.kind, VersionSpecKind::GreaterOrEqual);
+        assert!(!requests.is_dev);
+        assert_eq!(requests.language, Language::Python);
+
+        let flask = deps.iter().find(|d| d.name == "flask").unwrap();
+        assert_eq!(flask.version_spec.kind, VersionSpecKind::Exact);
+    }
+
+    #[test]
+    fn test_parse_inline_table_form() {
+        let content = r#"
+[packages]
+requests = { version = ">=2.28", extras = ["socks"] }
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].name, "requests");
+        assert_eq!(deps[0].version_spec.version, "2.28");
+    }
+
+    #[test]
+    fn test_parse_dev_packages() {
+        let content = r#"
+[packages]
+requests = ">=2.28"
+
+[dev-packages]
+pytest = "~=7.4"
+"#;
+
+        let deps = parse(content).unwrap();
+        let pytest = deps.iter().find(|d| d.name == "pytest").unwrap();
+        assert!(pytest.is_dev);
+        assert_eq!(pytest.version_spec.version, "7.4");
+    }
+
+    #[test]
+    fn test_parse_skips_wildcard_git_and_path() {
+        let content = r#"
+[packages]
+requests = "*"
+django = { version = "*" }
+mylib = { git = "https://github.com/me/mylib.git", ref = "main" }
+local = { path = "./local", editable = true }
+wheel = { file = "https://example.com/pkg.whl" }
+flask = ">=2.0"
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].name, "flask");
+    }
+
+    #[test]
+    fn test_update_string_form_preserves_operator() {
+        let content = r#"
+[packages]
+requests = ">=2.28"
+"#;
+
+        let result = PipfileParser
+            .update_version(content, "requests", "2.31.0")
+            .unwrap();
+        assert!(result.contains(r#"requests = ">=2.31.0""#));
+    }
+
+    #[test]
+    fn test_update_inline_table_preserves_keys() {
+        let content = r#"
+[packages]
+requests = { extras = ["socks"], version = "~=2.28", markers = "python_version >= '3.8'" }
+"#;
+
+        let result = PipfileParser
+            .update_version(content, "requests", "2.31.0")
+            .unwrap();
+        assert!(result.contains(
+            r#"requests = { extras = ["socks"], version = "~=2.31.0", markers = "python_version >= '3.8'" }"#
+        ));
+    }
+
+    #[test]
+    fn test_update_quoted_key() {
+        let content = r#"
+[packages]
+"zope.interface" = "==6.0"
+"#;
+
+        let result = PipfileParser
+            .update_version(content, "zope.interface", "6.1")
+            .unwrap();
+        assert!(result.contains(r#""zope.interface" = "==6.1""#));
+    }
+
+    #[test]
+    fn test_update_package_in_both_tables() {
+        let content = r#"[[source]]
+name = "pypi"
+url = "https://pypi.org/simple"
+
+[packages]
+pytest = ">=7.0"
+
+[dev-packages]
+pytest = { version = ">=7.0", extras = ["testing"] }
+"#;
+
+        let result = PipfileParser
+            .update_version(content, "pytest", "8.1.0")
+            .unwrap();
+        assert!(result.contains("[packages]\npytest = \">=8.1.0\"\n"));
+        assert!(result.contains(r#"pytest = { version = ">=8.1.0", extras = ["testing"] }"#));
+    }
+
+    #[test]
+    fn test_update_ignores_other_tables() {
+        let content = r#"[[source]]
+name = "pypi"
+url = "https://pypi.org/simple"
+
+[requires]
+python_version = "3.11"
+
+[packages]
+requests = ">=2.28"
+"#;
+
+        let result = PipfileParser
+            .update_version(content, "python_version", "3.12")
+            .unwrap_err();
+        assert!(matches!(result, ManifestError::InvalidVersionSpec { .. }));
+        assert!(PipfileParser
+            .update_version(content, "name", "1.0")
+            .is_err());
+    }
+
+    #[test]
+    fn test_update_range_kept_is_not_an_update() {
+        let content = r#"
+[packages]
+requests = ">=2.28,<3"
+
+[dev-packages]
+requests = ">=2.28"
+"#;
+
+        // Only the entry whose text changes counts as updated
+        let result = PipfileParser
+            .update_version(content, "requests", "2.31.0")
+            .unwrap();
+        assert!(result.contains("[packages]\nrequests = \">=2.28,<3\"\n"));
+        assert!(result.contains("[dev-packages]\nrequests = \">=2.31.0\"\n"));
+
+        // A range alone leaves the text unchanged, so nothing was updated
+        let content = "[packages]\nrequests = \">=2.28,<3\"\n";
+        assert!(matches!(
+            PipfileParser.update_version(content, "requests", "2.31.0"),
+            Err(ManifestError::InvalidVersionSpec { .. })
+        ));
+    }
+
+    #[test]
+    fn test_update_not_found() {
+        let content = r#"
+[packages]
+requests = ">=2.28"
+"#;
+
+        assert!(PipfileParser
+            .update_version(content, "flask", "3.0.0")
+            .is_err());
+    }
+
+    #[test]
+    fn test_language() {
+        assert_eq!(PipfileParser.language(), Language::Python);
+    }
+
+    #[test]
+    fn test_commented_out_package_ignored() {
+        let content = r#"[packages]
+# requests = ">=2.0"
+requests = ">=2.28"
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].version_spec.raw, ">=2.28");
+
+        let result = PipfileParser
+            .update_version(content, "requests", "2.31.0")
+            .unwrap();
+        assert!(result.contains("# requests = \">=2.0\"\n"));
+        assert!(result.contains("\nrequests = \">=2.31.0\"\n"));
+    }
+}
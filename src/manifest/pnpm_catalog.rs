@@ -0,0 +1,314 @@
+//! pnpm catalog parser (pnpm-workspace.yaml)
+//!
+//! Handles:
+//! - `catalog:` default catalog of shared versions
+//! - `catalogs:` named catalogs
+//! - Quoted keys and values (e.g., `'@types/node': "^20.0.0"`)
+//! - Trailing `#` comments (preserved on update)
+//!
+//! package.json entries such as `"react": "catalog:"` take their version from
+//! these catalogs, so updates are written here rather than to package.json.
+//! Entries of a named catalog are reported as `<catalog>:<package>` (e.g.,
+//! `legacy:react`), so each catalog's version is checked and updated on its own.
+
+use crate::domain::{Dependency, Language};
+use crate::error::ManifestError;
+use crate::manifest::ManifestParser;
+use crate::parser::get_parser;
+use regex::Regex;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+/// File name of the pnpm workspace configuration
+pub const PNPM_WORKSPACE_FILENAME: &str = "pnpm-workspace.yaml";
+
+/// Name pnpm gives the `catalog:` default catalog
+pub const DEFAULT_CATALOG: &str = "default";
+
+/// Parser for pnpm-workspace.yaml catalogs
+pub struct PnpmCatalogParser;
+
+/// Indented `key: value` entry, keeping the pieces needed to rewrite the value
+static ENTRY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"^(\s+)('[^']+'|"[^"]+"|[^\s:#'"][^:#]*?)(\s*:\s*)('[^']*'|"[^"]*"|[^\s#'"]+)(\s*(?:#.*)?)$"#,
+    )
+    .unwrap()
+});
+
+/// Indented `name:` line opening a named catalog
+static CATALOG_NAME_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\s+('[^']+'|"[^"]+"|[^\s:#'"][^:#]*?)\s*:\s*(?:#.*)?$"#).unwrap()
+});
+
+/// A `package: version` entry from one of the catalogs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogEntry {
+    /// Catalog name (`default` for the `catalog:` table)
+    pub catalog: String,
+    /// Package name
+    pub name: String,
+    /// Version specifier
+    pub version: String,
+    /// Zero-based line index in the file
+    line: usize,
+}
+
+impl CatalogEntry {
+    /// Dependency name reported for this entry: the package for the default
+    /// catalog, `<catalog>:<package>` for a named one
+    pub fn dependency_name(&self) -> String {
+        if self.catalog == DEFAULT_CATALOG {
+            self.name.clone()
+        } else {
+            format!("{}:{}", self.catalog, self.name)
+        }
+    }
+}
+
+/// Remove matching single or double quotes around a YAML scalar
+fn unquote(s: &str) -> &str {
+    for quote in ['\'', '"'] {
+        if let Some(inner) = s.strip_prefix(quote).and_then(|s| s.strip_suffix(quote)) {
+            return inner;
+        }
+    }
+    s
+}
+
+/// Collect every catalog entry in pnpm-workspace.yaml, in file order
+pub fn parse_catalog_entries(content: &str) -> Vec<CatalogEntry> {
+    /// Top-level section the current line belongs to
+    enum Section {
+        Other,
+        Default,
+        Named(Option<String>),
+    }
+
+    let mut entries = Vec::new();
+    let mut section = Section::Other;
+    let mut named_indent = 0;
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        // Unindented lines start a new top-level section
+        if !line.starts_with([' ', '\t']) {
+            section = match trimmed.split('#').next().unwrap_or("").trim() {
+                "catalog:" => Section::Default,
+                "catalogs:" => Section::Named(None),
+                _ => Section::Other,
+            };
+            continue;
+        }
+
+        let indent = line.len() - line.trim_start().len();
+        match &mut section {
+            Section::Other => {}
+            Section::Default => {
+                if let Some(caps) = ENTRY_RE.captures(line) {
+                    entries.push(CatalogEntry {
+                        catalog: DEFAULT_CATALOG.to_string(),
+                        name: unquote(&caps[2]).to_string(),
+                        version: unquote(&caps[4]).to_string(),
+                        line: index,
+                    });
+                }
+            }
+            Section::Named(current) => {
+                if let Some(caps) = CATALOG_NAME_RE.captures(line) {
+                    *current = Some(unquote(&caps[1]).to_string());
+                    named_indent = indent;
+                } else if let (Some(catalog), Some(caps)) = (current, ENTRY_RE.captures(line)) {
+                    if indent > named_indent {
+                        entries.push(CatalogEntry {
+                            catalog: catalog.clone(),
+                            name: unquote(&caps[2]).to_string(),
+                            version: unquote(&caps[4]).to_string(),
+                            line: index,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    entries
+}
+
+impl ManifestParser for PnpmCatalogParser {
+    /// Parse catalog entries as dependencies
+    ///
+    /// Named catalog entries are looked up under their package name.
+    fn parse(&self, content: &str) -> Result<Vec<Dependency>, ManifestError> {
+        let parser = get_parser(Language::Node);
+        let mut dependencies: Vec<Dependency> = Vec::new();
+
+        for entry in parse_catalog_entries(content) {
+            let name = entry.dependency_name();
+            if dependencies.iter().any(|d| d.name == name) {
+                continue;
+            }
+            if let Some(spec) = parser.parse(&entry.version) {
+                let mut dep = Dependency::new(name, spec, false, Language::Node);
+                if entry.catalog != DEFAULT_CATALOG {
+                    dep = dep.with_alias_target(entry.name);
+                }
+                dependencies.push(dep);
+            }
+        }
+
+        Ok(dependencies)
+    }
+
+    fn language(&self) -> Language {
+        Language::Node
+    }
+
+    fn update_version(
+        &self,
+        content: &str,
+        package: &str,
+        new_version: &str,
+    ) -> Result<String, ManifestError> {
+        let parser = get_parser(Language::Node);
+        let not_found = || ManifestError::InvalidVersionSpec {
+            path: PathBuf::from(PNPM_WORKSPACE_FILENAME),
+            spec: package.to_string(),
+            message: "package not found in catalog".to_string(),
+        };
+
+        let entry = parse_catalog_entries(content)
+            .into_iter()
+            .find(|e| e.dependency_name() == package && parser.parse(&e.version).is_some())
+            .ok_or_else(not_found)?;
+        let spec = parser.parse(&entry.version).ok_or_else(not_found)?;
+
+        let mut result: Vec<String> = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            if index != entry.line {
+                result.push(line.to_string());
+                continue;
+            }
+            let caps = ENTRY_RE.captures(line).ok_or_else(not_found)?;
+            // Keep the original quoting around the value
+            let raw_value = &caps[4];
+            let quote = match raw_value.chars().next() {
+                Some(q @ ('\'' | '"')) => q.to_string(),
+                _ => String::new(),
+            };
+            result.push(format!(
+                "{}{}{}{}{}{}{}",
+                &caps[1],
+                &caps[2],
+                &caps[3],
+                quote,
+                spec.format_updated(new_version),
+                quote,
+                &caps[5]
+            ));
+        }
+
+        let mut output = result.join("\n");
+        if content.ends_with('\n') {
+            output.push('\n');
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSPACE: &str = r#"packages:
+  - 'packages/*'
+
+catalog:
+  react: ^18.2.0
+  '@types/node': "~20.1.0" # keep in sync with CI
+
+catalogs:
+  legacy:
+    react: ^17.0.2
+    lodash: 4.17.20
+"#;
+
+    #[test]
+    fn test_parse_catalog_entries() {
+        let entries = parse_catalog_entries(WORKSPACE);
+        let summary: Vec<_> = entries
+            .iter()
+            .map(|e| (e.catalog.as_str(), e.name.as_str(), e.version.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("default", "react", "^18.2.0"),
+                ("default", "@types/node", "~20.1.0"),
+                ("legacy", "react", "^17.0.2"),
+                ("legacy", "lodash", "4.17.20"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_catalog_dependencies() {
+        let deps = PnpmCatalogParser.parse(WORKSPACE).unwrap();
+        let names: Vec<_> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["react", "@types/node", "legacy:react", "legacy:lodash"]
+        );
+        assert_eq!(deps[0].version_spec.version, "18.2.0");
+        assert_eq!(deps[0].lookup_name(), "react");
+        assert_eq!(deps[2].version_spec.version, "17.0.2");
+        assert_eq!(deps[2].lookup_name(), "react");
+    }
+
+    #[test]
+    fn test_update_catalog_version() {
+        let updated = PnpmCatalogParser
+            .update_version(WORKSPACE, "@types/node", "22.0.0")
+            .unwrap();
+        assert!(updated.contains("  '@types/node': \"~22.0.0\" # keep in sync with CI\n"));
+
+        let updated = PnpmCatalogParser
+            .update_version(WORKSPACE, "react", "19.0.0")
+            .unwrap();
+        assert!(updated.contains("  react: ^19.0.0\n"));
+        // Only the default catalog's entry changes
+        assert!(updated.contains("    react: ^17.0.2\n"));
+        assert!(updated.starts_with("packages:\n  - 'packages/*'\n"));
+
+        let updated = PnpmCatalogParser
+            .update_version(WORKSPACE, "legacy:react", "17.0.3")
+            .unwrap();
+        assert!(updated.contains("  react: ^18.2.0\n"));
+        assert!(updated.contains("    react: ^17.0.3\n"));
+    }
+
+    #[test]
+    fn test_update_catalog_not_found() {
+        assert!(PnpmCatalogParser
+            .update_version(WORKSPACE, "vue", "3.0.0")
+            .is_err());
+    }
+
+    #[test]
+    fn test_commented_out_entry_ignored() {
+        let content = "catalog:\n  # react: ^17.0.0\n  react: ^18.2.0\n";
+
+        let deps = PnpmCatalogParser.parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].version_spec.raw, "^18.2.0");
+
+        let result = PnpmCatalogParser
+            .update_version(content, "react", "18.3.1")
+            .unwrap();
+        assert_eq!(result, "catalog:\n  # react: ^17.0.0\n  react: ^18.3.1\n");
+    }
+}
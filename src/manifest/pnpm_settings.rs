@@ -5,6 +5,7 @@
 //! - pnpm-workspace.yaml (minimumReleaseAge: 14400) - value in minutes
 //! - package.json (pnpm.settings.minimumReleaseAge)
 
+use crate::manifest::pnpm_catalog::PNPM_WORKSPACE_FILENAME;
 use std::path::Path;
 use std::time::Duration;
 
@@ -23,26 +24,14 @@ impl PnpmSettings {
     /// 2. pnpm-workspace.yaml (minimumReleaseAge in minutes)
     /// 3. package.json (pnpm.settings.minimumReleaseAge)
     pub fn from_dir(dir: &Path) -> Self {
-        let mut settings = PnpmSettings::default();
+        // .npmrc first (highest priority), then pnpm-workspace.yaml, then package.json
+        let minimum_release_age = read_npmrc_minimum_release_age(dir)
+            .or_else(|| read_workspace_yaml_minimum_release_age(dir))
+            .or_else(|| read_package_json_minimum_release_age(dir));
 
-        // Try reading from .npmrc first (highest priority)
-        if let Some(age) = read_npmrc_minimum_release_age(dir) {
-            settings.minimum_release_age = Some(age);
-            return settings;
+        PnpmSettings {
+            minimum_release_age,
         }
-
-        // Try reading from pnpm-workspace.yaml
-        if let Some(age) = read_workspace_yaml_minimum_release_age(dir) {
-            settings.minimum_release_age = Some(age);
-            return settings;
-        }
-
-        // Try reading from package.json
-        if let Some(age) = read_package_json_minimum_release_age(dir) {
-            settings.minimum_release_age = Some(age);
-        }
-
-        settings
     }
 }
 
@@ -103,7 +92,7 @@ fn read_npmrc_minimum_release_age(dir: &Path) -> Option<Duration> {
 ///
 /// The value in pnpm-workspace.yaml is in minutes (e.g., 14400 = 10 days)
 fn read_workspace_yaml_minimum_release_age(dir: &Path) -> Option<Duration> {
-    let workspace_path = dir.join("pnpm-workspace.yaml");
+    let workspace_path = dir.join(PNPM_WORKSPACE_FILENAME);
     let content = std::fs::read_to_string(workspace_path).ok()?;
 
     // Simple YAML parsing for minimumReleaseAge
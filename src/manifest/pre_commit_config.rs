@@ -0,0 +1,272 @@
+//! .pre-commit-config.yaml parser for hook repository revisions
+//!
+//! Handles:
+//! - `repos:` list entries with `repo:` and `rev:` keys, in either order
+//! - Lists indented under `repos:` or flush with it
+//! - GitHub and GitLab repositories, updated from their release tags
+//! - Quoted and unquoted revisions and trailing comments, kept on update
+//!
+//! `local` and `meta` repos, revisions pinned to a commit SHA and flow-style
+//! entries (`- {repo: ..., rev: ...}`) are not reported. Repositories on other
+//! hosts are marked as skipped.
+
+use crate::domain::{Dependency, Language, SkipReason};
+use crate::error::ManifestError;
+use crate::manifest::ManifestParser;
+use crate::parser::get_parser;
+use std::path::PathBuf;
+
+/// Parser for .pre-commit-config.yaml files
+pub struct PreCommitConfigParser;
+
+/// Hosts whose tags can be looked up
+const SUPPORTED_HOSTS: [&str; 2] = ["github.com/", "gitlab.com/"];
+
+/// Hook repository declared in the `repos:` list
+#[derive(Debug, Default)]
+struct RepoEntry<'a> {
+    /// Column of the entry's keys (just after `- `)
+    column: usize,
+    repo: Option<&'a str>,
+    /// Line index and unquoted value of the `rev:` key
+    rev: Option<(usize, &'a str)>,
+}
+
+/// Value without surrounding whitespace and quotes
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches('"').trim_matches('\'')
+}
+
+/// Entries of the top-level `repos:` list, in file order
+fn repo_entries(content: &str) -> Vec<RepoEntry<'_>> {
+    let mut entries: Vec<RepoEntry> = Vec::new();
+    let mut in_repos = false;
+
+    for (index, line) in content.lines().enumerate() {
+        let code = line.split(" #").next().unwrap_or("").trim_end();
+        if code.trim().is_empty() || code.trim_start().starts_with('#') {
+            continue;
+        }
+        let indent = code.len() - code.trim_start().len();
+
+        if indent == 0 && !code.starts_with('-') {
+            in_repos = code.split(':').next() == Some("repos");
+            continue;
+        }
+        if !in_repos {
+            continue;
+        }
+
+        // `- key: value` starts a list item; its keys line up after the dash
+        let (column, item) = match code.trim_start().strip_prefix("- ") {
+            Some(item) => (
+                indent + 2 + (item.len() - item.trim_start().len()),
+                item.trim_start(),
+            ),
+            None => (indent, code.trim_start()),
+        };
+        let Some((key, value)) = item.split_once(':') else {
+            continue;
+        };
+        let (key, value) = (unquote(key), unquote(value));
+
+        let starts_entry =
+            column != indent && entries.last().is_none_or(|entry| indent < entry.column);
+        if starts_entry {
+            entries.push(RepoEntry {
+                column,
+                ..RepoEntry::default()
+            });
+        }
+        let Some(entry) = entries.last_mut().filter(|entry| entry.column == column) else {
+            continue;
+        };
+        match key {
+            "repo" => entry.repo = Some(value),
+            "rev" => entry.rev = Some((index, value)),
+            _ => {}
+        }
+    }
+
+    entries
+}
+
+impl ManifestParser for PreCommitConfigParser {
+    fn parse(&self, content: &str) -> Result<Vec<Dependency>, ManifestError> {
+        let parser = get_parser(Language::PreCommit);
+        let mut dependencies = Vec::new();
+
+        for entry in repo_entries(content) {
+            let (Some(repo), Some((_, rev))) = (entry.repo, entry.rev) else {
+                continue;
+            };
+            let Some(spec) = parser.parse(rev) else {
+                continue;
+            };
+            let mut dep = Dependency::production(repo, spec, Language::PreCommit);
+            if !SUPPORTED_HOSTS.iter().any(|host| repo.contains(host)) {
+                dep = dep.with_skip_reason(SkipReason::NonRegistrySource);
+            }
+            dependencies.push(dep);
+        }
+
+        Ok(dependencies)
+    }
+
+    fn language(&self) -> Language {
+        Language::PreCommit
+    }
+
+    fn update_version(
+        &self,
+        content: &str,
+        package: &str,
+        new_version: &str,
+    ) -> Result<String, ManifestError> {
+        let parser = get_parser(Language::PreCommit);
+        let (target, old_rev) = repo_entries(content)
+            .into_iter()
+            .find(|entry| entry.repo == Some(package))
+            .and_then(|entry| entry.rev)
+            .ok_or_else(|| ManifestError::InvalidVersionSpec {
+                path: PathBuf::from(".pre-commit-config.yaml"),
+                spec: package.to_string(),
+                message: "repo not found or has no rev".to_string(),
+            })?;
+        let new_rev = parser
+            .parse(old_rev)
+            .map(|spec| spec.format_updated(new_version))
+            .unwrap_or_else(|| new_version.to_string());
+
+        let lines: Vec<String> = content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                if index != target {
+                    return line.to_string();
+                }
+                // Only the value after `rev:` is replaced, keeping quotes and comments
+                let value_start = line.find("rev:").map_or(0, |i| i + "rev:".len());
+                format!(
+                    "{}{}",
+                    &line[..value_start],
+                    line[value_start..].replacen(old_rev, &new_rev, 1)
+                )
+            })
+            .collect();
+
+        let mut result = lines.join("\n");
+        if content.ends_with('\n') {
+            result.push('\n');
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::VersionSpecKind;
+
+    const CONFIG: &str = r#"default_stages: [pre-commit]
+repos:
+  - repo: https://github.com/pre-commit/pre-commit-hooks
+    rev: v4.5.0
+    hooks:
+      - id: trailing-whitespace
+      - id: end-of-file-fixer
+        args: [--fix=lf]
+  - repo: https://github.com/psf/black
+    rev: "23.12.1"  # formatter
+    hooks:
+      - id: black
+  - rev: v1.8.0
+    repo: https://gitlab.com/pycqa/flake8
+    hooks:
+      - id: flake8
+  - repo: https://github.com/astral-sh/ruff-pre-commit
+    rev: 3298ddab3c13dd77d6ce1fc0baf97691430d84b0
+    hooks:
+      - id: ruff
+  - repo: https://bitbucket.org/team/hooks
+    rev: v1.0.0
+    hooks:
+      - id: lint
+  - repo: local
+    hooks:
+      - id: check
+        name: check
+        entry: ./check.sh
+        language: script
+"#;
+
+    #[test]
+    fn test_parse_repos() {
+        let deps = PreCommitConfigParser.parse(CONFIG).unwrap();
+        let names: Vec<&str> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "https://github.com/pre-commit/pre-commit-hooks",
+                "https://github.com/psf/black",
+                "https://gitlab.com/pycqa/flake8",
+                "https://bitbucket.org/team/hooks",
+            ]
+        );
+
+        assert_eq!(deps[0].version_spec.kind, VersionSpecKind::Exact);
+        assert_eq!(deps[0].version_spec.version, "4.5.0");
+        assert_eq!(deps[0].language, Language::PreCommit);
+        assert_eq!(deps[1].version_spec.version, "23.12.1");
+        assert_eq!(deps[2].version_spec.version, "1.8.0");
+        assert!(deps[2].skip_reason.is_none());
+        assert_eq!(deps[3].skip_reason, Some(SkipReason::NonRegistrySource));
+    }
+
+    #[test]
+    fn test_parse_unindented_list() {
+        let content = "repos:\n- repo: https://github.com/psf/black\n  rev: 24.1.0\n  hooks:\n  - id: black\n";
+        let deps = PreCommitConfigParser.parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].version_spec.version, "24.1.0");
+    }
+
+    #[test]
+    fn test_update_rev() {
+        let result = PreCommitConfigParser
+            .update_version(
+                CONFIG,
+                "https://github.com/pre-commit/pre-commit-hooks",
+                "4.6.0",
+            )
+            .unwrap();
+        assert!(result.contains(
+            "  - repo: https://github.com/pre-commit/pre-commit-hooks\n    rev: v4.6.0\n    hooks:\n"
+        ));
+        // Everything else is left as it was
+        assert_eq!(result.replace("v4.6.0", "v4.5.0"), CONFIG);
+    }
+
+    #[test]
+    fn test_update_keeps_quotes_and_comment() {
+        let result = PreCommitConfigParser
+            .update_version(CONFIG, "https://github.com/psf/black", "24.1.0")
+            .unwrap();
+        assert!(result.contains("    rev: \"24.1.0\"  # formatter\n"));
+
+        let result = PreCommitConfigParser
+            .update_version(CONFIG, "https://gitlab.com/pycqa/flake8", "7.0.0")
+            .unwrap();
+        assert!(result.contains("  - rev: v7.0.0\n    repo: https://gitlab.com/pycqa/flake8\n"));
+    }
+
+    #[test]
+    fn test_update_missing_repo() {
+        assert!(PreCommitConfigParser
+            .update_version(CONFIG, "local", "1.0.0")
+            .is_err());
+        assert!(PreCommitConfigParser
+            .update_version(CONFIG, "https://github.com/pycqa/isort", "5.13.2")
+            .is_err());
+    }
+}
@@ -6,7 +6,9 @@
 //! - dependency-groups (PEP 735)
 //! - tool.poetry.dependencies (Poetry)
 //! - tool.poetry.dev-dependencies (Poetry)
+//! - tool.poetry.group.<name>.dependencies (Poetry 1.2+; groups other than `main` are dev)
 //! - tool.rye.dev-dependencies (Rye)
+//! - project.requires-python, limiting updates to versions supporting it
 
 use crate::domain::{Dependency, Language, VersionSpecKind};
 use crate::error::ManifestError;
@@ -21,9 +23,11 @@ use toml::Value;
 pub struct PyprojectTomlParser;
 
 // Regex to parse PEP 508 dependency specifiers
-// Matches: package-name>=1.0,<2.0 or package-name==1.0 or package-name^1.0, etc.
+// Matches: package-name>=1.0,<2.0 or package-name[extra]==1.0 or package-name^1.0, etc.
+// The name (group 1) is what the registry knows; extras (group 2) only select
+// optional features and are kept as written on update.
 static PEP508_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^([a-zA-Z0-9][-a-zA-Z0-9._]*)\s*(.*)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^([a-zA-Z0-9][-a-zA-Z0-9._]*)\s*(\[[^\]]*\])?\s*(.*)$").unwrap());
 
 impl ManifestParser for PyprojectTomlParser {
     fn parse(&self, content: &str) -> Result<Vec<Dependency>, ManifestError> {
@@ -131,7 +135,7 @@ impl ManifestParser for PyprojectTomlParser {
             .and_then(|g| g.as_table())
         {
             for (group_name, group) in groups {
-                let is_dev = group_name == "dev" || group_name == "test";
+                let is_dev = group_name != "main";
                 if let Some(deps) = group.get("dependencies").and_then(|d| d.as_table()) {
                     for (name, value) in deps {
                         if let Some(parsed) =
@@ -167,6 +171,12 @@ impl ManifestParser for PyprojectTomlParser {
         Language::Python
     }
 
+    fn requires_python(&self, content: &str) -> Option<String> {
+        let toml: Value = toml::from_str(content).ok()?;
+        let requires = toml.get("project")?.get("requires-python")?.as_str()?;
+        Some(requires.to_string())
+    }
+
     fn update_version(
         &self,
         content: &str,
@@ -205,39 +215,45 @@ impl ManifestParser for PyprojectTomlParser {
                 let old_version = caps.get(2).map(|m| m.as_str()).unwrap_or("");
                 if let Some(spec) = parser.parse(old_version) {
                     let new_ver = spec.format_updated(new_version);
-                    let replacement = format!(r#"{}"{}"#, &caps[1], new_ver);
+                    let replacement = format!(r#"{}"{}""#, &caps[1], new_ver);
                     result = re.replace(&result, replacement.as_str()).to_string();
                     updated = true;
                 }
             }
         }
 
-        // Pattern for PEP 508 in array: "package>=1.0,<2.0"
-        let pep508_pattern = format!(r#""({}(?:\s*[<>=!~^]+[^"]+)?)""#, regex::escape(package));
+        // Pattern for PEP 508 in array: "package[extra]>=1.0,<2.0 ; marker"
+        // Covers project.dependencies, every optional-dependencies group and
+        // dependency-groups; only the version part is rewritten so extras,
+        // markers and the surrounding array formatting stay untouched.
+        let pep508_pattern = format!(
+            r#""({}(?:\s*\[[^\]"]*\])?\s*)([<>=!~^][^";]*?)(\s*;[^"]*)?""#,
+            regex::escape(package)
+        );
         if let Ok(re) = Regex::new(&pep508_pattern) {
-            let result_clone = result.clone();
-            for caps in re.captures_iter(&result_clone) {
-                let full_dep = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-                if let Some(pep_caps) = PEP508_RE.captures(full_dep) {
-                    let pkg_name = pep_caps.get(1).map(|m| m.as_str()).unwrap_or("");
-                    let version_part = pep_caps.get(2).map(|m| m.as_str()).unwrap_or("").trim();
-
-                    if pkg_name == package && !version_part.is_empty() {
-                        if let Some(spec) = parser.parse(version_part) {
-                            // Range型（>=X,<Y）は複合制約のため元の指定子をそのまま保持
-                            let new_ver = if spec.kind == VersionSpecKind::Range {
-                                version_part.to_string()
-                            } else {
-                                spec.format_updated(new_version)
-                            };
-                            let new_dep = format!("{}{}", package, new_ver);
-                            result = result
-                                .replace(&format!(r#""{full_dep}""#), &format!(r#""{new_dep}""#));
-                            updated = true;
-                        }
+            result = re
+                .replace_all(&result, |caps: &regex::Captures| {
+                    // Commented-out requirements stay as written
+                    let offset = caps.get(0).map_or(0, |m| m.start());
+                    let line_start = result[..offset].rfind('\n').map_or(0, |i| i + 1);
+                    if result[line_start..offset].trim_start().starts_with('#') {
+                        return caps[0].to_string();
                     }
-                }
-            }
+                    let version_part = &caps[2];
+                    let marker = caps.get(3).map(|m| m.as_str()).unwrap_or("");
+                    let Some(spec) = parser.parse(version_part) else {
+                        return caps[0].to_string();
+                    };
+                    updated = true;
+                    // Range型（>=X,<Y）は複合制約のため元の指定子をそのまま保持
+                    let new_ver = if spec.kind == VersionSpecKind::Range {
+                        version_part.to_string()
+                    } else {
+                        spec.format_updated(new_version)
+                    };
+                    format!(r#""{}{}{}""#, &caps[1], new_ver, marker)
+                })
+                .to_string();
         }
 
         if updated {
@@ -259,14 +275,7 @@ fn parse_pep508_dependency(
 ) -> Option<Dependency> {
     let caps = PEP508_RE.captures(dep_str)?;
     let name = caps.get(1)?.as_str();
-    let mut version_part = caps.get(2).map(|m| m.as_str()).unwrap_or("").trim();
-
-    // Handle extras like package[extra]>=1.0 - strip [extra] from version_part
-    if version_part.starts_with('[') {
-        if let Some(idx) = version_part.find(']') {
-            version_part = version_part[idx + 1..].trim();
-        }
-    }
+    let version_part = caps.get(3).map(|m| m.as_str()).unwrap_or("").trim();
 
     // Remove any environment markers (after ;)
     let version_part = version_part
@@ -351,6 +360,40 @@ dev = [
         assert_eq!(deps[0].name, "pytest");
     }
 
+    #[test]
+    fn test_update_optional_dependency_only() {
+        let content = r#"
+[project]
+dependencies = [
+    "requests>=2.28.0",
+]
+
+[project.optional-dependencies]
+dev = [
+    "pytest[testing] >=7.0.0 ; python_version >= '3.8'",
+    "pytest-cov>=4.0.0",
+]
+
+[dependency-groups]
+lint = ["ruff>=0.1.0"]
+"#;
+
+        let result = PyprojectTomlParser
+            .update_version(content, "pytest", "8.1.0")
+            .unwrap();
+
+        assert!(result.contains(r#""pytest[testing] >=8.1.0 ; python_version >= '3.8'","#));
+        assert!(result.contains(r#""requests>=2.28.0","#));
+        assert!(result.contains(r#""pytest-cov>=4.0.0","#));
+        assert!(result.contains(r#"lint = ["ruff>=0.1.0"]"#));
+
+        let result = PyprojectTomlParser
+            .update_version(content, "ruff", "0.4.0")
+            .unwrap();
+        assert!(result.contains(r#"lint = ["ruff>=0.4.0"]"#));
+        assert!(result.contains(r#""pytest[testing] >=7.0.0 ; python_version >= '3.8'","#));
+    }
+
     #[test]
     fn test_parse_poetry_dependencies() {
         let content = r#"
@@ -392,16 +435,67 @@ pytest = "^7.0.0"
 
 [tool.poetry.group.docs.dependencies]
 sphinx = "^6.0.0"
+
+[tool.poetry.group.main.dependencies]
+requests = "^2.28.0"
 "#;
 
         let deps = parse(content).unwrap();
-        assert_eq!(deps.len(), 2);
+        assert_eq!(deps.len(), 3);
 
         let pytest = deps.iter().find(|d| d.name == "pytest").unwrap();
         assert!(pytest.is_dev);
 
         let sphinx = deps.iter().find(|d| d.name == "sphinx").unwrap();
-        assert!(!sphinx.is_dev); // docs group is not dev
+        assert!(sphinx.is_dev); // every group but main is dev
+
+        let requests = deps.iter().find(|d| d.name == "requests").unwrap();
+        assert!(!requests.is_dev);
+    }
+
+    #[test]
+    fn test_parse_poetry_test_group() {
+        let content = r#"
+[tool.poetry.dependencies]
+python = "^3.10"
+requests = "^2.28.0"
+
+[tool.poetry.group.test.dependencies]
+pytest = "^7.0.0"
+pytest-cov = { version = "^4.0.0", optional = true }
+"#;
+
+        let deps = parse(content).unwrap();
+        let pytest = deps.iter().find(|d| d.name == "pytest").unwrap();
+        assert!(pytest.is_dev);
+        assert_eq!(pytest.version_spec.version, "7.0.0");
+
+        let pytest_cov = deps.iter().find(|d| d.name == "pytest-cov").unwrap();
+        assert!(pytest_cov.is_dev);
+        assert_eq!(pytest_cov.version_spec.version, "4.0.0");
+    }
+
+    #[test]
+    fn test_update_poetry_group_dependency() {
+        let content = r#"[tool.poetry.dependencies]
+requests = "^2.28.0"
+
+[tool.poetry.group.test.dependencies]
+pytest = "^7.0.0"
+pytest-cov = { version = "^4.0.0", optional = true }
+"#;
+
+        let result = PyprojectTomlParser
+            .update_version(content, "pytest", "8.1.0")
+            .unwrap();
+        assert!(result.contains("pytest = \"^8.1.0\"\n"));
+        assert!(result.contains("requests = \"^2.28.0\""));
+
+        let result = PyprojectTomlParser
+            .update_version(&result, "pytest-cov", "5.0.0")
+            .unwrap();
+        assert!(result.contains("pytest-cov = { version = \"^5.0.0\", optional = true }"));
+        assert!(result.contains("pytest = \"^8.1.0\"\n"));
     }
 
     #[test]
@@ -485,6 +579,34 @@ dependencies = [
         assert_eq!(deps[0].name, "httpx");
     }
 
+    #[test]
+    fn test_extras_stripped_for_lookup_and_kept_on_update() {
+        let content = r#"
+[project]
+dependencies = [
+    "uvicorn[standard]>=0.20",
+    "fastapi [all, testing] >=0.100",
+]
+"#;
+
+        // The registry is queried by the bare name
+        let deps = parse(content).unwrap();
+        assert_eq!(deps[0].name, "uvicorn");
+        assert_eq!(deps[0].version_spec.version, "0.20");
+        assert_eq!(deps[1].name, "fastapi");
+        assert_eq!(deps[1].version_spec.version, "0.100");
+
+        let result = PyprojectTomlParser
+            .update_version(content, "uvicorn", "0.25")
+            .unwrap();
+        assert!(result.contains(r#""uvicorn[standard]>=0.25","#));
+
+        let result = PyprojectTomlParser
+            .update_version(content, "fastapi", "0.110")
+            .unwrap();
+        assert!(result.contains(r#""fastapi [all, testing] >=0.110","#));
+    }
+
     #[test]
     fn test_parse_with_environment_markers() {
         let content = r#"
@@ -561,6 +683,28 @@ dev-dependencies = [
         assert_eq!(PyprojectTomlParser.language(), Language::Python);
     }
 
+    #[test]
+    fn test_requires_python() {
+        let content = r#"
+[project]
+name = "myapp"
+requires-python = ">=3.8"
+dependencies = ["requests>=2.28.0"]
+"#;
+        assert_eq!(
+            PyprojectTomlParser.requires_python(content).as_deref(),
+            Some(">=3.8")
+        );
+        assert_eq!(
+            PyprojectTomlParser.requires_python(
+                "[project]
+name = \"x\"
+"
+            ),
+            None
+        );
+    }
+
     #[test]
     fn test_parse_pep508_range_version() {
         let content = r#"
@@ -645,4 +789,25 @@ dependencies = [
             result
         );
     }
+
+    #[test]
+    fn test_commented_out_requirement_ignored() {
+        let content = r#"
+[project]
+dependencies = [
+    # "requests>=2.0.0",
+    "requests>=2.28.0",
+]
+"#;
+
+        let deps = parse(content).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].version_spec.raw, ">=2.28.0");
+
+        let result = PyprojectTomlParser
+            .update_version(content, "requests", "2.31.0")
+            .unwrap();
+        assert!(result.contains(r#"# "requests>=2.0.0","#));
+        assert!(result.contains(r#"    "requests>=2.31.0","#));
+    }
 }
@@ -0,0 +1,68 @@
+//! .python-version (pyenv) reader for the selected Python interpreter
+//!
+//! Handles:
+//! - A version per line; the first usable one is the selected interpreter
+//! - Comments (`#`) and blank lines
+//! - Build suffixes (`3.13.0t`, `3.12-dev`), which are dropped
+//!
+//! Non-version selections such as `system` or a virtualenv name are ignored.
+
+use std::path::Path;
+
+/// pyenv version file name
+pub const PYTHON_VERSION_FILENAME: &str = ".python-version";
+
+/// Python version selected by `.python-version` content (e.g., `3.9` or `3.12.1`)
+pub fn parse_python_version(content: &str) -> Option<String> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .find_map(|line| {
+            let token = line.split_whitespace().next()?;
+            let end = token
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(token.len());
+            let version = token[..end].trim_end_matches('.');
+            version
+                .starts_with(|c: char| c.is_ascii_digit())
+                .then(|| version.to_string())
+        })
+}
+
+/// Read the `.python-version` file in a directory, if present
+pub fn read_python_version(dir: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(dir.join(PYTHON_VERSION_FILENAME)).ok()?;
+    parse_python_version(&content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_python_version() {
+        assert_eq!(parse_python_version("3.9\n").as_deref(), Some("3.9"));
+        assert_eq!(
+            parse_python_version("# pinned for CI\n\n3.12.1\n3.11.7\n").as_deref(),
+            Some("3.12.1")
+        );
+        assert_eq!(parse_python_version("3.13.0t").as_deref(), Some("3.13.0"));
+        assert_eq!(parse_python_version("3.12-dev").as_deref(), Some("3.12"));
+    }
+
+    #[test]
+    fn test_parse_python_version_without_version() {
+        assert_eq!(parse_python_version("system\n"), None);
+        assert_eq!(parse_python_version("my-venv\n"), None);
+        assert_eq!(parse_python_version(""), None);
+    }
+
+    #[test]
+    fn test_read_python_version() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(read_python_version(dir.path()), None);
+
+        std::fs::write(dir.path().join(PYTHON_VERSION_FILENAME), "3.11\n").unwrap();
+        assert_eq!(read_python_version(dir.path()).as_deref(), Some("3.11"));
+    }
+}
@@ -0,0 +1,208 @@
+//! renv.lock parser for R projects
+//!
+//! Handles:
+//! - `Packages` entries (`name` -> `{"Package", "Version", "Source", ...}`)
+//! - CRAN packages (`"Source": "Repository"`), updated via CRAN
+//! - GitHub, Bioconductor and local packages, marked as skipped
+//! - Version rewrites that leave every other field and all formatting untouched
+//!
+//! The `R` interpreter entry is not a package and is ignored. Updated entries keep
+//! their old `Hash`; `renv::restore()` / `renv::snapshot()` refresh it.
+
+use crate::domain::{Dependency, Language, SkipReason};
+use crate::error::ManifestError;
+use crate::manifest::ManifestParser;
+use crate::parser::get_parser;
+use regex::Regex;
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// Parser for renv.lock files
+pub struct RenvLockParser;
+
+/// `Source` value of packages installed from a CRAN-like repository
+const REPOSITORY_SOURCE: &str = "Repository";
+
+impl ManifestParser for RenvLockParser {
+    fn parse(&self, content: &str) -> Result<Vec<Dependency>, ManifestError> {
+        let json: Value =
+            serde_json::from_str(content).map_err(|e| ManifestError::JsonParseError {
+                path: PathBuf::from("renv.lock"),
+                message: e.to_string(),
+            })?;
+
+        let parser = get_parser(Language::R);
+        let mut dependencies = Vec::new();
+
+        let Some(packages) = json.get("Packages").and_then(|v| v.as_object()) else {
+            return Ok(dependencies);
+        };
+
+        for (name, entry) in packages {
+            let Some(version) = entry.get("Version").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let Some(spec) = parser.parse(version) else {
+                continue;
+            };
+
+            let mut dep = Dependency::production(name, spec, Language::R);
+            let source = entry.get("Source").and_then(|v| v.as_str());
+            if source.is_some_and(|source| source != REPOSITORY_SOURCE) {
+                dep = dep.with_skip_reason(SkipReason::NonRegistrySource);
+            }
+            dependencies.push(dep);
+        }
+
+        Ok(dependencies)
+    }
+
+    fn language(&self) -> Language {
+        Language::R
+    }
+
+    fn update_version(
+        &self,
+        content: &str,
+        package: &str,
+        new_version: &str,
+    ) -> Result<String, ManifestError> {
+        let not_found = || ManifestError::InvalidVersionSpec {
+            path: PathBuf::from("renv.lock"),
+            spec: package.to_string(),
+            message: "package not found or version could not be updated".to_string(),
+        };
+
+        // Only the `Packages` section is searched, so the `R` entry is never touched
+        let packages_start = content.find("\"Packages\"").ok_or_else(not_found)?;
+        let pattern = format!(
+            r#"("{}"\s*:\s*\{{[^{{}}]*?"Version"\s*:\s*)"([^"]*)""#,
+            regex::escape(package)
+        );
+        let re = Regex::new(&pattern).map_err(|e| ManifestError::InvalidVersionSpec {
+            path: PathBuf::from("renv.lock"),
+            spec: package.to_string(),
+            message: format!("invalid regex pattern: {}", e),
+        })?;
+
+        let caps = re
+            .captures(&content[packages_start..])
+            .ok_or_else(not_found)?;
+        let version = caps.get(2).ok_or_else(not_found)?;
+        let start = packages_start + version.start();
+        let end = packages_start + version.end();
+
+        Ok(format!(
+            "{}{}{}",
+            &content[..start],
+            new_version,
+            &content[end..]
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RENV_LOCK: &str = r#"{
+  "R": {
+    "Version": "4.3.1",
+    "Repositories": [
+      {
+        "Name": "CRAN",
+        "URL": "https://cloud.r-project.org"
+      }
+    ]
+  },
+  "Packages": {
+    "R6": {
+      "Package": "R6",
+      "Version": "2.5.1",
+      "Source": "Repository",
+      "Repository": "CRAN",
+      "Hash": "470851b6d5d0ac559e9d01bb352b4021"
+    },
+    "dplyr": {
+      "Package": "dplyr",
+      "Version": "1.1.2",
+      "Source": "Repository",
+      "Repository": "CRAN",
+      "Requirements": [
+        "R6",
+        "cli"
+      ],
+      "Hash": "dea6970ff715ca541c387de363ff405e"
+    },
+    "zoo": {
+      "Package": "zoo",
+      "Version": "1.8-12",
+      "Source": "Repository",
+      "Repository": "CRAN"
+    },
+    "mypkg": {
+      "Package": "mypkg",
+      "Version": "0.1.0",
+      "Source": "GitHub",
+      "RemoteUsername": "owner",
+      "RemoteRepo": "mypkg"
+    }
+  }
+}
+"#;
+
+    #[test]
+    fn test_parse_renv_lock() {
+        let deps = RenvLockParser.parse(RENV_LOCK).unwrap();
+        let names: Vec<&str> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["R6", "dplyr", "mypkg", "zoo"]);
+
+        let dplyr = deps.iter().find(|d| d.name == "dplyr").unwrap();
+        assert_eq!(dplyr.version_spec.version, "1.1.2");
+        assert_eq!(dplyr.language, Language::R);
+        assert!(dplyr.skip_reason.is_none());
+
+        let zoo = deps.iter().find(|d| d.name == "zoo").unwrap();
+        assert_eq!(zoo.version_spec.version, "1.8-12");
+
+        let mypkg = deps.iter().find(|d| d.name == "mypkg").unwrap();
+        assert_eq!(mypkg.skip_reason, Some(SkipReason::NonRegistrySource));
+    }
+
+    #[test]
+    fn test_parse_renv_lock_without_packages() {
+        let deps = RenvLockParser
+            .parse(r#"{"R": {"Version": "4.3.1"}}"#)
+            .unwrap();
+        assert!(deps.is_empty());
+        assert!(RenvLockParser.parse("not json").is_err());
+    }
+
+    #[test]
+    fn test_update_version() {
+        let result = RenvLockParser
+            .update_version(RENV_LOCK, "dplyr", "1.1.4")
+            .unwrap();
+        assert!(result.contains("\"Package\": \"dplyr\",\n      \"Version\": \"1.1.4\","));
+        assert!(!result.contains("1.1.2"));
+        // The interpreter and other packages are left alone
+        assert!(result.contains("\"Version\": \"4.3.1\""));
+        assert!(result.contains("\"Version\": \"2.5.1\""));
+        assert_eq!(result.len(), RENV_LOCK.len());
+
+        let result = RenvLockParser
+            .update_version(RENV_LOCK, "zoo", "1.8-14")
+            .unwrap();
+        assert!(result.contains("\"Version\": \"1.8-14\""));
+    }
+
+    #[test]
+    fn test_update_missing_package() {
+        assert!(RenvLockParser
+            .update_version(RENV_LOCK, "ggplot2", "3.5.0")
+            .is_err());
+        assert!(RenvLockParser
+            .update_version(RENV_LOCK, "R", "4.4.0")
+            .is_err());
+    }
+}
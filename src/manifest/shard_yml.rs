@@ -0,0 +1,239 @@
+//! shard.yml parser for Crystal projects
+//!
+//! Handles:
+//! - `dependencies` and `development_dependencies` sections
+//! - GitHub shards (`github: owner/repo`) with a `version:` constraint, resolved
+//!   from the repository's tags
+//! - Shards from other sources (`gitlab:`, `git:`, `path:`, ...), marked as skipped
+//! - Quoted and unquoted constraints and trailing comments, kept on update
+//!
+//! Shards without a `version:` (tracking a branch or the latest tag) are not reported.
+
+use crate::domain::{Dependency, Language, SkipReason};
+use crate::error::ManifestError;
+use crate::manifest::ManifestParser;
+use crate::parser::get_parser;
+use std::path::PathBuf;
+
+/// Parser for shard.yml files
+pub struct ShardYmlParser;
+
+/// Shard declared in a dependencies section
+#[derive(Debug, Default)]
+struct ShardEntry<'a> {
+    name: &'a str,
+    /// Declared in `development_dependencies`
+    dev: bool,
+    /// `owner/repo` of a GitHub shard
+    github: Option<&'a str>,
+    /// Line index and unquoted value of the `version:` key
+    version: Option<(usize, &'a str)>,
+}
+
+/// Value without surrounding whitespace and quotes
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches('"').trim_matches('\'')
+}
+
+/// Shards of both dependency sections, in file order
+fn shard_entries(content: &str) -> Vec<ShardEntry<'_>> {
+    let mut entries = Vec::new();
+    let mut section: Option<bool> = None;
+    let mut name_indent: Option<usize> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let code = line.split(" #").next().unwrap_or("").trim_end();
+        if code.trim().is_empty() || code.trim_start().starts_with('#') {
+            continue;
+        }
+        let indent = code.len() - code.trim_start().len();
+        let Some((key, value)) = code.trim_start().split_once(':') else {
+            continue;
+        };
+        let (key, value) = (unquote(key), unquote(value));
+
+        if indent == 0 {
+            section = match key {
+                "dependencies" => Some(false),
+                "development_dependencies" => Some(true),
+                _ => None,
+            };
+            name_indent = None;
+            continue;
+        }
+        let Some(dev) = section else {
+            continue;
+        };
+
+        // Shard names sit at the section's first indentation level
+        if name_indent.is_none_or(|name_indent| indent <= name_indent) {
+            name_indent = Some(indent);
+            entries.push(ShardEntry {
+                name: key,
+                dev,
+                ..ShardEntry::default()
+            });
+        } else if let Some(entry) = entries.last_mut() {
+            match key {
+                "github" => entry.github = Some(value),
+                "version" => entry.version = Some((index, value)),
+                _ => {}
+            }
+        }
+    }
+
+    entries
+}
+
+impl ManifestParser for ShardYmlParser {
+    fn parse(&self, content: &str) -> Result<Vec<Dependency>, ManifestError> {
+        let parser = get_parser(Language::Crystal);
+        let mut dependencies = Vec::new();
+
+        for entry in shard_entries(content) {
+            let Some(spec) = entry.version.and_then(|(_, v)| parser.parse(v)) else {
+                continue;
+            };
+            let mut dep = if entry.dev {
+                Dependency::development(entry.name, spec, Language::Crystal)
+            } else {
+                Dependency::production(entry.name, spec, Language::Crystal)
+            };
+            dep = match entry.github {
+                Some(repository) => dep.with_alias_target(repository),
+                None => dep.with_skip_reason(SkipReason::NonRegistrySource),
+            };
+            dependencies.push(dep);
+        }
+
+        Ok(dependencies)
+    }
+
+    fn language(&self) -> Language {
+        Language::Crystal
+    }
+
+    fn update_version(
+        &self,
+        content: &str,
+        package: &str,
+        new_version: &str,
+    ) -> Result<String, ManifestError> {
+        let parser = get_parser(Language::Crystal);
+        let (target, old_value) = shard_entries(content)
+            .into_iter()
+            .find(|entry| entry.name == package)
+            .and_then(|entry| entry.version)
+            .ok_or_else(|| ManifestError::InvalidVersionSpec {
+                path: PathBuf::from("shard.yml"),
+                spec: package.to_string(),
+                message: "shard not found or has no version constraint".to_string(),
+            })?;
+        let new_value = parser
+            .parse(old_value)
+            .map(|spec| spec.format_updated(new_version))
+            .unwrap_or_else(|| new_version.to_string());
+
+        let lines: Vec<String> = content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                if index != target {
+                    return line.to_string();
+                }
+                // Only the value after `version:` is replaced, keeping quotes and comments
+                let value_start = line.find(':').map_or(0, |i| i + 1);
+                format!(
+                    "{}{}",
+                    &line[..value_start],
+                    line[value_start..].replacen(old_value, &new_value, 1)
+                )
+            })
+            .collect();
+
+        let mut result = lines.join("\n");
+        if content.ends_with('\n') {
+            result.push('\n');
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::VersionSpecKind;
+
+    const SHARD_YML: &str = r#"name: blog
+version: 0.1.0
+
+dependencies:
+  kemal:
+    github: kemalcr/kemal
+    version: ~> 1.4.0
+  pg:
+    github: will/crystal-pg
+    version: ">= 0.26.0, < 1.0"
+  redis:
+    gitlab: example/redis
+    version: 2.9.0
+  local_lib:
+    path: ../local_lib
+  db:
+    github: crystal-lang/crystal-db
+    branch: master
+
+development_dependencies:
+  ameba:
+    github: crystal-ameba/ameba
+    version: '~> 1.5.0' # linter
+
+crystal: ">= 1.10.0"
+"#;
+
+    #[test]
+    fn test_parse_shards() {
+        let deps = ShardYmlParser.parse(SHARD_YML).unwrap();
+        let names: Vec<&str> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["kemal", "pg", "redis", "ameba"]);
+
+        let kemal = &deps[0];
+        assert_eq!(kemal.version_spec.kind, VersionSpecKind::Tilde);
+        assert_eq!(kemal.version_spec.version, "1.4.0");
+        assert_eq!(kemal.lookup_name(), "kemalcr/kemal");
+        assert_eq!(kemal.language, Language::Crystal);
+        assert!(!kemal.is_dev);
+
+        assert_eq!(deps[1].version_spec.kind, VersionSpecKind::Range);
+        assert_eq!(deps[1].lookup_name(), "will/crystal-pg");
+        assert_eq!(deps[2].skip_reason, Some(SkipReason::NonRegistrySource));
+        assert!(deps[3].is_dev);
+        assert_eq!(deps[3].version_spec.version, "1.5.0");
+    }
+
+    #[test]
+    fn test_update_version_constraint() {
+        let result = ShardYmlParser
+            .update_version(SHARD_YML, "kemal", "1.5.0")
+            .unwrap();
+        assert!(result.contains("    github: kemalcr/kemal\n    version: ~> 1.5.0\n"));
+        assert!(result.contains("version: '~> 1.5.0'")); // ameba unchanged
+        assert!(result.ends_with("crystal: \">= 1.10.0\"\n"));
+
+        let result = ShardYmlParser
+            .update_version(SHARD_YML, "ameba", "1.6.1")
+            .unwrap();
+        assert!(result.contains("    version: '~> 1.6.1' # linter\n"));
+        assert!(result.contains("version: ~> 1.4.0\n"));
+    }
+
+    #[test]
+    fn test_update_missing_shard() {
+        assert!(ShardYmlParser
+            .update_version(SHARD_YML, "db", "0.13.0")
+            .is_err());
+        assert!(ShardYmlParser
+            .update_version(SHARD_YML, "lucky", "1.0.0")
+            .is_err());
+    }
+}
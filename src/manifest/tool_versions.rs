@@ -0,0 +1,141 @@
+//! .tool-versions (asdf) reader for runtime version pins
+//!
+//! Handles:
+//! - `<tool> <version> [fallback versions...]` lines
+//! - Comments (`#`) and blank lines
+//! - Mapping asdf plugin names to the runtimes depup can check (Node.js, Go)
+
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+
+/// asdf tool versions file name
+pub const TOOL_VERSIONS_FILENAME: &str = ".tool-versions";
+
+/// A runtime whose releases can be checked (--runtimes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Runtime {
+    /// Node.js (asdf plugin `nodejs`)
+    Node,
+    /// Go (asdf plugin `golang`)
+    Go,
+}
+
+impl Runtime {
+    /// Map an asdf plugin name to a runtime
+    pub fn from_tool(tool: &str) -> Option<Self> {
+        match tool {
+            "nodejs" | "node" => Some(Runtime::Node),
+            "golang" | "go" => Some(Runtime::Go),
+            _ => None,
+        }
+    }
+
+    /// Human-readable runtime name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Runtime::Node => "Node.js",
+            Runtime::Go => "Go",
+        }
+    }
+}
+
+impl fmt::Display for Runtime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// A single `.tool-versions` entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolVersion {
+    /// asdf plugin name (e.g., "nodejs")
+    pub tool: String,
+    /// Preferred (first) version listed for the tool
+    pub version: String,
+}
+
+impl ToolVersion {
+    /// The runtime this entry pins, if depup can check it
+    pub fn runtime(&self) -> Option<Runtime> {
+        Runtime::from_tool(&self.tool)
+    }
+}
+
+/// Parse `.tool-versions` content into entries
+pub fn parse_tool_versions(content: &str) -> Vec<ToolVersion> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut parts = line.split_whitespace();
+            let tool = parts.next()?;
+            let version = parts.next()?;
+            Some(ToolVersion {
+                tool: tool.to_string(),
+                version: version.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Read the `.tool-versions` file in a directory, if present
+pub fn read_tool_versions(dir: &Path) -> Option<Vec<ToolVersion>> {
+    let content = std::fs::read_to_string(dir.join(TOOL_VERSIONS_FILENAME)).ok()?;
+    Some(parse_tool_versions(&content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+# runtimes
+nodejs 20.10.0
+golang 1.21.0   # toolchain
+python 3.12.1 3.11.7
+
+ruby
+";
+
+    #[test]
+    fn test_parse_tool_versions() {
+        let entries = parse_tool_versions(SAMPLE);
+        assert_eq!(
+            entries,
+            vec![
+                ToolVersion {
+                    tool: "nodejs".to_string(),
+                    version: "20.10.0".to_string(),
+                },
+                ToolVersion {
+                    tool: "golang".to_string(),
+                    version: "1.21.0".to_string(),
+                },
+                ToolVersion {
+                    tool: "python".to_string(),
+                    version: "3.12.1".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tool_version_runtime() {
+        let runtimes: Vec<Option<Runtime>> = parse_tool_versions(SAMPLE)
+            .iter()
+            .map(ToolVersion::runtime)
+            .collect();
+        assert_eq!(runtimes, vec![Some(Runtime::Node), Some(Runtime::Go), None]);
+    }
+
+    #[test]
+    fn test_read_tool_versions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        assert!(read_tool_versions(temp_dir.path()).is_none());
+
+        std::fs::write(temp_dir.path().join(TOOL_VERSIONS_FILENAME), SAMPLE).unwrap();
+        assert_eq!(read_tool_versions(temp_dir.path()).unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,262 @@
+//! Unused dependency heuristics for --prune-report
+//!
+//! Handles:
+//! - Node.js: `import`/`export ... from`/`require()`/dynamic `import()` specifiers
+//! - Rust: `use crate_name`, `crate_name::` paths and `extern crate`
+//!
+//! Only production dependencies are checked: development dependencies are
+//! often tools invoked from scripts and never imported. This is a text search,
+//! not a resolver, so results are candidates for review rather than certainties.
+
+use crate::domain::{Dependency, Language};
+use crate::error::ManifestError;
+use crate::manifest::{parse_manifest, ManifestInfo};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Directories never scanned for source files
+const IGNORED_DIRS: &[&str] = &["node_modules", "target", "dist", "build", "vendor"];
+
+/// JavaScript/TypeScript source file extensions
+const JS_EXTENSIONS: &[&str] = &[
+    "js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts", "vue", "svelte",
+];
+
+/// Module specifiers in import/export/require statements
+static JS_SPECIFIER_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?:\bfrom\s*|\bimport\s*\(?\s*|\brequire\s*\(\s*)['"]([^'"]+)['"]"#).unwrap()
+});
+
+/// Crate paths (`name::`), `use name` and `extern crate name` in Rust source
+static RUST_CRATE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b([A-Za-z_]\w*)::|\buse\s+([A-Za-z_]\w*)\b|\bextern\s+crate\s+([A-Za-z_]\w*)\b")
+        .unwrap()
+});
+
+/// A declared dependency with no reference found in source files
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnusedDependency {
+    /// Manifest declaring the dependency
+    pub manifest: PathBuf,
+    /// Language of the manifest
+    pub language: Language,
+    /// Package name
+    pub name: String,
+}
+
+/// Find production dependencies of a manifest that no source file references
+///
+/// Source files are searched below the manifest's directory. Languages
+/// without a heuristic (everything but Node.js and Rust) and secondary
+/// manifests such as pnpm catalogs report nothing.
+pub fn find_unused_dependencies(
+    manifest: &ManifestInfo,
+) -> Result<Vec<UnusedDependency>, ManifestError> {
+    if manifest.path.file_name().and_then(|name| name.to_str())
+        != manifest.language.manifest_filename()
+    {
+        return Ok(Vec::new());
+    }
+
+    let extensions: &[&str] = match manifest.language {
+        Language::Node => JS_EXTENSIONS,
+        Language::Rust => &["rs"],
+        _ => return Ok(Vec::new()),
+    };
+
+    let dependencies: Vec<Dependency> = parse_manifest(&manifest.path)?
+        .into_iter()
+        .filter(|dep| !dep.is_dev)
+        .collect();
+    if dependencies.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let root = manifest.path.parent().unwrap_or(Path::new("."));
+    let mut sources = Vec::new();
+    collect_sources(root, extensions, &mut sources);
+
+    let used: HashSet<String> = match manifest.language {
+        Language::Node => sources
+            .iter()
+            .flat_map(|s| js_imported_packages(s))
+            .collect(),
+        _ => sources
+            .iter()
+            .flat_map(|s| rust_referenced_crates(s))
+            .collect(),
+    };
+
+    Ok(dependencies
+        .into_iter()
+        .filter(|dep| match manifest.language {
+            Language::Node => !used.contains(&dep.name),
+            // Crates are referenced with `-` spelled as `_`
+            _ => !used.contains(&dep.name.replace('-', "_")),
+        })
+        .map(|dep| UnusedDependency {
+            manifest: manifest.path.clone(),
+            language: manifest.language,
+            name: dep.name,
+        })
+        .collect())
+}
+
+/// Read every source file with one of `extensions` below `dir`
+///
+/// Hidden directories, build output and dependency folders are skipped.
+fn collect_sources(dir: &Path, extensions: &[&str], sources: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if path.is_dir() {
+            if !name.starts_with('.') && !IGNORED_DIRS.contains(&name.as_ref()) {
+                collect_sources(&path, extensions, sources);
+            }
+        } else if path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| extensions.contains(&e))
+        {
+            if let Ok(content) = std::fs::read_to_string(&path) {
+                sources.push(content);
+            }
+        }
+    }
+}
+
+/// Package names imported by a JavaScript/TypeScript source file
+///
+/// Relative and absolute paths are ignored; `@scope/pkg/sub` maps to `@scope/pkg`
+/// and `pkg/sub` to `pkg`. `@types/pkg` counts as used whenever `pkg` is.
+fn js_imported_packages(source: &str) -> Vec<String> {
+    let mut packages = Vec::new();
+    for caps in JS_SPECIFIER_RE.captures_iter(source) {
+        let specifier = caps[1].strip_prefix("node:").unwrap_or(&caps[1]);
+        if specifier.starts_with('.') || specifier.starts_with('/') {
+            continue;
+        }
+        let segments = if specifier.starts_with('@') { 2 } else { 1 };
+        let package: Vec<&str> = specifier.splitn(segments + 1, '/').take(segments).collect();
+        let package = package.join("/");
+        if !package.starts_with('@') {
+            packages.push(format!("@types/{}", package));
+        }
+        packages.push(package);
+    }
+    packages
+}
+
+/// Identifiers a Rust source file may reference as crates
+///
+/// Every path root counts, so modules and types show up too; only names
+/// matching a dependency matter.
+fn rust_referenced_crates(source: &str) -> Vec<String> {
+    RUST_CRATE_RE
+        .captures_iter(source)
+        .filter_map(|caps| caps.iter().skip(1).flatten().next())
+        .map(|ident| ident.as_str().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_js_imported_packages() {
+        let source = r#"
+import React from 'react';
+import { join } from "node:path";
+import "./styles.css";
+const { debounce } = require("lodash/debounce");
+export * from '@scope/pkg/sub';
+const lazy = await import('chart.js');
+"#;
+        let packages = js_imported_packages(source);
+        for name in [
+            "react",
+            "@types/react",
+            "path",
+            "lodash",
+            "@scope/pkg",
+            "chart.js",
+        ] {
+            assert!(packages.contains(&name.to_string()), "missing {}", name);
+        }
+        assert!(!packages.iter().any(|p| p.starts_with('.')));
+    }
+
+    #[test]
+    fn test_rust_referenced_crates() {
+        let references =
+            |source: &str, ident: &str| rust_referenced_crates(source).iter().any(|c| c == ident);
+        assert!(references("use serde::Deserialize;", "serde"));
+        assert!(references("let v = serde_json::json!({});", "serde_json"));
+        assert!(references("use async_trait::async_trait;", "async_trait"));
+        assert!(references("extern crate libc;", "libc"));
+        assert!(!references("use serde_json::Value;", "serde"));
+    }
+
+    #[test]
+    fn test_find_unused_node_dependencies() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("package.json"),
+            r#"{
+                "dependencies": { "react": "^18.2.0", "left-pad": "^1.3.0" },
+                "devDependencies": { "eslint": "^9.0.0" }
+            }"#,
+        )
+        .unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(
+            dir.path().join("src").join("index.tsx"),
+            "import React from 'react';\n",
+        )
+        .unwrap();
+        // Imports inside node_modules don't count
+        fs::create_dir_all(dir.path().join("node_modules").join("x")).unwrap();
+        fs::write(
+            dir.path().join("node_modules").join("x").join("index.js"),
+            "require('left-pad');\n",
+        )
+        .unwrap();
+
+        let info = ManifestInfo::new(dir.path().join("package.json"), Language::Node);
+        let unused = find_unused_dependencies(&info).unwrap();
+        let names: Vec<_> = unused.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(names, vec!["left-pad"]);
+    }
+
+    #[test]
+    fn test_find_unused_rust_dependencies() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = \"1.0\"\nanyhow = \"1.0\"\n",
+        )
+        .unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(
+            dir.path().join("src").join("main.rs"),
+            "use serde::Serialize;\n\nfn main() {}\n",
+        )
+        .unwrap();
+
+        let info = ManifestInfo::new(dir.path().join("Cargo.toml"), Language::Rust);
+        let unused = find_unused_dependencies(&info).unwrap();
+        let names: Vec<_> = unused.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(names, vec!["anyhow"]);
+    }
+}
@@ -5,8 +5,11 @@
 //! - Dry-run mode support (no actual file modifications)
 //! - Format preservation when updating versions
 //! - Parse error handling with graceful continuation
+//! - In-memory preview of the would-be-written content
+//! - Backup of the original content of rewritten files
+//! - Version prefix normalization (bare `4.17.21` -> `^4.17.21`)
 
-use crate::domain::{Language, ManifestUpdateResult, UpdateResult};
+use crate::domain::{Language, ManifestUpdateResult, SkipReason, UpdateResult, VersionSpecKind};
 use crate::error::ManifestError;
 use crate::manifest::ManifestParser;
 use std::fs;
@@ -16,6 +19,12 @@ use std::path::Path;
 pub struct ManifestWriter {
     /// Whether to run in dry-run mode (no file modifications)
     dry_run: bool,
+    /// Whether to keep the resulting content in each WriteResult
+    preview: bool,
+    /// Whether to keep the original content of rewritten files in each WriteResult
+    backup: bool,
+    /// Operator added to bare versions (--normalize-prefix)
+    normalize_prefix: Option<String>,
 }
 
 /// Result of applying updates to a manifest file
@@ -27,10 +36,16 @@ pub struct WriteResult {
     pub updates_applied: usize,
     /// Number of updates that failed
     pub updates_failed: usize,
+    /// Number of bare versions given the --normalize-prefix operator
+    pub prefixes_normalized: usize,
     /// Whether the file was actually modified
     pub file_modified: bool,
     /// Errors encountered during update
     pub errors: Vec<String>,
+    /// Resulting file content with all updates applied (when previewing)
+    pub preview: Option<String>,
+    /// File content before it was rewritten (when keeping backups)
+    pub original: Option<String>,
 }
 
 impl WriteResult {
@@ -40,8 +55,11 @@ impl WriteResult {
             path: path.into(),
             updates_applied: 0,
             updates_failed: 0,
+            prefixes_normalized: 0,
             file_modified: false,
             errors: Vec::new(),
+            preview: None,
+            original: None,
         }
     }
 
@@ -59,12 +77,43 @@ impl WriteResult {
 impl ManifestWriter {
     /// Create a new ManifestWriter
     pub fn new(dry_run: bool) -> Self {
-        Self { dry_run }
+        Self {
+            dry_run,
+            preview: false,
+            backup: false,
+            normalize_prefix: None,
+        }
     }
 
     /// Create a ManifestWriter in dry-run mode
     pub fn dry_run() -> Self {
-        Self { dry_run: true }
+        Self::new(true)
+    }
+
+    /// Keep the would-be-written content in each WriteResult
+    pub fn with_preview(mut self, preview: bool) -> Self {
+        self.preview = preview;
+        self
+    }
+
+    /// Keep the original content of each rewritten file in its WriteResult
+    pub fn with_backup(mut self, backup: bool) -> Self {
+        self.backup = backup;
+        self
+    }
+
+    /// Add `prefix` to bare versions, whether or not they are updated
+    ///
+    /// Only applies to manifests whose bare versions take a `^`/`~` operator
+    /// (package.json, Cargo.toml), and only to full `MAJOR.MINOR.PATCH` versions.
+    pub fn with_normalize_prefix(mut self, prefix: Option<String>) -> Self {
+        self.normalize_prefix = prefix;
+        self
+    }
+
+    /// Whether prefixes are normalized in manifests of `language`
+    fn normalizes(&self, language: Language) -> bool {
+        self.normalize_prefix.is_some() && matches!(language, Language::Node | Language::Rust)
     }
 
     /// Check if this writer is in dry-run mode
@@ -79,16 +128,40 @@ impl ManifestWriter {
         parser: &dyn ManifestParser,
     ) -> Result<WriteResult, ManifestError> {
         let path = &manifest_result.path;
-        let mut result = WriteResult::new(path);
 
-        // Read current file content
-        let content = fs::read_to_string(path).map_err(|e| ManifestError::ReadError {
-            path: path.clone(),
-            source: e,
-        })?;
+        // Read current file content; files that aren't valid UTF-8 are never rewritten
+        let content = read_manifest(path)?;
 
-        // Apply each update sequentially
-        let mut current_content = content.clone();
+        let (current_content, mut result) =
+            self.apply_updates_to_content(manifest_result, parser, &content);
+
+        // Write back to file if not in dry-run mode and there were changes
+        if (result.updates_applied > 0 || result.prefixes_normalized > 0) && !self.dry_run {
+            fs::write(path, &current_content).map_err(|e| ManifestError::WriteError {
+                path: path.clone(),
+                source: e,
+            })?;
+            result.file_modified = true;
+            if self.backup {
+                result.original = Some(content);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Apply updates to manifest content in memory, without touching the disk
+    ///
+    /// Each planned update is applied in sequence; failures are recorded in
+    /// the returned WriteResult and leave the content unchanged.
+    pub fn apply_updates_to_content(
+        &self,
+        manifest_result: &ManifestUpdateResult,
+        parser: &dyn ManifestParser,
+        content: &str,
+    ) -> (String, WriteResult) {
+        let mut result = WriteResult::new(&manifest_result.path);
+        let mut current_content = content.to_string();
 
         for update in manifest_result.results.iter() {
             if let UpdateResult::Update {
@@ -112,33 +185,44 @@ impl ManifestWriter {
             }
         }
 
-        // Write back to file if not in dry-run mode and there were changes
-        if result.updates_applied > 0 && !self.dry_run {
-            fs::write(path, &current_content).map_err(|e| ManifestError::WriteError {
-                path: path.clone(),
-                source: e,
-            })?;
-            result.file_modified = true;
+        if let Some(prefix) = self
+            .normalize_prefix
+            .as_deref()
+            .filter(|_| self.normalizes(manifest_result.language))
+        {
+            for (name, version) in manifest_result.results.iter().filter_map(bare_version) {
+                let normalized = format!("{}{}", prefix, version);
+                if let Ok(updated_content) =
+                    parser.update_version(&current_content, name, &normalized)
+                {
+                    current_content = updated_content;
+                    result.prefixes_normalized += 1;
+                }
+            }
         }
 
-        Ok(result)
+        if self.preview {
+            result.preview = Some(current_content.clone());
+        }
+
+        (current_content, result)
     }
 
     /// Apply updates to multiple manifest files
     pub fn apply_all_updates(
         &self,
         manifests: &[ManifestUpdateResult],
-        get_parser: impl Fn(Language) -> Box<dyn ManifestParser>,
+        get_parser: impl Fn(&Path, Language) -> Box<dyn ManifestParser>,
     ) -> Vec<WriteResult> {
         manifests
             .iter()
             .filter_map(|manifest| {
-                // Only process manifests that have updates
-                if !manifest.has_updates() {
+                // Only process manifests that have updates or prefixes to normalize
+                if !manifest.has_updates() && !self.normalizes(manifest.language) {
                     return None;
                 }
 
-                let parser = get_parser(manifest.language);
+                let parser = get_parser(&manifest.path, manifest.language);
                 match self.apply_updates(manifest, parser.as_ref()) {
                     Ok(result) => Some(result),
                     Err(e) => {
@@ -154,14 +238,59 @@ impl ManifestWriter {
     }
 }
 
+/// Name and version (after any update) of a dependency written as a bare `MAJOR.MINOR.PATCH`
+///
+/// Dependencies left alone on purpose (filtered out, bundled, patched or from a
+/// local source) are never normalized.
+fn bare_version(result: &UpdateResult) -> Option<(&str, &str)> {
+    let dependency = result.dependency();
+    let version = match result {
+        UpdateResult::Update { new_version, .. } => new_version.as_str(),
+        UpdateResult::Skip { reason, .. } => match reason {
+            SkipReason::Excluded
+            | SkipReason::NotInOnlyList
+            | SkipReason::LanguageFiltered
+            | SkipReason::NonRegistrySource
+            | SkipReason::Patched
+            | SkipReason::Bundled => return None,
+            _ => dependency.version(),
+        },
+    };
+    let spec = &dependency.version_spec;
+    let bare = spec.prefix.is_none()
+        && spec.suffix.is_none()
+        && matches!(spec.kind, VersionSpecKind::Exact | VersionSpecKind::Caret)
+        && version.split('.').count() == 3
+        && version.split('.').all(|part| part.parse::<u64>().is_ok());
+    bare.then_some((dependency.name.as_str(), version))
+}
+
 /// Read a manifest file content safely
+///
+/// Content that isn't valid UTF-8 is reported as `ManifestError::InvalidEncoding`.
 pub fn read_manifest(path: &Path) -> Result<String, ManifestError> {
-    fs::read_to_string(path).map_err(|e| ManifestError::ReadError {
+    let bytes = fs::read(path).map_err(|e| ManifestError::ReadError {
         path: path.to_path_buf(),
         source: e,
+    })?;
+    String::from_utf8(bytes).map_err(|e| ManifestError::InvalidEncoding {
+        path: path.to_path_buf(),
+        offset: e.utf8_error().valid_up_to(),
     })
 }
 
+/// Read a manifest file for checking only, replacing invalid UTF-8 with `U+FFFD`
+///
+/// The replaced content must never be written back; `ManifestWriter` re-reads
+/// the file strictly and refuses to rewrite it.
+pub fn read_manifest_lossy(path: &Path) -> Result<String, ManifestError> {
+    let bytes = fs::read(path).map_err(|e| ManifestError::ReadError {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
 /// Write content to a manifest file
 pub fn write_manifest(path: &Path, content: &str) -> Result<(), ManifestError> {
     fs::write(path, content).map_err(|e| ManifestError::WriteError {
@@ -173,7 +302,7 @@ pub fn write_manifest(path: &Path, content: &str) -> Result<(), ManifestError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{Dependency, VersionSpec, VersionSpecKind};
+    use crate::domain::{Dependency, VersionSpec};
     use std::io::Write;
     use tempfile::TempDir;
 
@@ -260,6 +389,37 @@ mod tests {
         assert!(!content.contains("4.18.0"));
     }
 
+    #[test]
+    fn test_apply_updates_to_content_preview() {
+        let original_content = r#"{
+  "dependencies": {
+    "lodash": "^4.17.21",
+    "express": "^4.18.0"
+  }
+}"#;
+
+        let mut manifest_result = ManifestUpdateResult::new("package.json", Language::Node);
+        manifest_result.add_result(UpdateResult::update(
+            sample_dependency("lodash", "4.17.21", Language::Node),
+            "4.18.0",
+        ));
+        manifest_result.add_result(UpdateResult::update(
+            sample_dependency("express", "4.18.0", Language::Node),
+            "5.0.0",
+        ));
+
+        let writer = ManifestWriter::dry_run().with_preview(true);
+        let parser = crate::manifest::PackageJsonParser;
+        let (content, result) =
+            writer.apply_updates_to_content(&manifest_result, &parser, original_content);
+
+        assert_eq!(result.updates_applied, 2);
+        let preview = result.preview.unwrap();
+        assert_eq!(preview, content);
+        assert!(preview.contains(r#""lodash": "^4.18.0""#));
+        assert!(preview.contains(r#""express": "^5.0.0""#));
+    }
+
     #[test]
     fn test_apply_updates_actual_write() {
         let temp_dir = TempDir::new().unwrap();
@@ -287,6 +447,101 @@ mod tests {
         assert!(!content.contains("4.17.21"));
     }
 
+    #[test]
+    fn test_apply_updates_keeps_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_content = r#"{"dependencies": {"lodash": "^4.17.21"}}"#;
+        let path = create_temp_package_json(&temp_dir, original_content);
+
+        let mut manifest_result = ManifestUpdateResult::new(&path, Language::Node);
+        let dep = sample_dependency("lodash", "4.17.21", Language::Node);
+        manifest_result.add_result(UpdateResult::update(dep, "4.18.0"));
+        let parser = crate::manifest::PackageJsonParser;
+
+        let result = ManifestWriter::new(false)
+            .with_backup(true)
+            .apply_updates(&manifest_result, &parser)
+            .unwrap();
+        assert!(result.file_modified);
+        assert_eq!(result.original.as_deref(), Some(original_content));
+
+        // Nothing is written in dry-run mode, so there is nothing to back up
+        let result = ManifestWriter::dry_run()
+            .with_backup(true)
+            .apply_updates(&manifest_result, &parser)
+            .unwrap();
+        assert!(result.original.is_none());
+    }
+
+    #[test]
+    fn test_normalize_prefix_bare_version() {
+        let content = r#"{
+  "dependencies": {
+    "lodash": "4.17.21",
+    "express": "4.18.0",
+    "react": "~18.2.0",
+    "local": "1.0.0"
+  }
+}"#;
+
+        let mut manifest_result = ManifestUpdateResult::new("package.json", Language::Node);
+        let bare = |name: &str, version: &str| {
+            let spec = VersionSpec::new(VersionSpecKind::Exact, version, version);
+            Dependency::new(name, spec, false, Language::Node)
+        };
+        manifest_result.add_result(UpdateResult::skip(
+            bare("lodash", "4.17.21"),
+            SkipReason::Pinned,
+        ));
+        manifest_result.add_result(UpdateResult::update(bare("express", "4.18.0"), "4.19.2"));
+        let tilde = VersionSpec::new(VersionSpecKind::Tilde, "~18.2.0", "18.2.0").with_prefix("~");
+        manifest_result.add_result(UpdateResult::skip(
+            Dependency::new("react", tilde, false, Language::Node),
+            SkipReason::AlreadyLatest,
+        ));
+        manifest_result.add_result(UpdateResult::skip(
+            bare("local", "1.0.0"),
+            SkipReason::Excluded,
+        ));
+
+        let writer = ManifestWriter::dry_run().with_normalize_prefix(Some("^".to_string()));
+        let parser = crate::manifest::PackageJsonParser;
+        let (content, result) = writer.apply_updates_to_content(&manifest_result, &parser, content);
+
+        assert_eq!(result.updates_applied, 1);
+        assert_eq!(result.prefixes_normalized, 2);
+        assert!(content.contains(r#""lodash": "^4.17.21""#));
+        assert!(content.contains(r#""express": "^4.19.2""#));
+        // Operators already chosen and excluded packages stay as written
+        assert!(content.contains(r#""react": "~18.2.0""#));
+        assert!(content.contains(r#""local": "1.0.0""#));
+    }
+
+    #[test]
+    fn test_normalize_prefix_writes_without_updates() {
+        let temp_dir = TempDir::new().unwrap();
+        let path =
+            create_temp_package_json(&temp_dir, r#"{"dependencies": {"lodash": "4.17.21"}}"#);
+
+        let mut manifest_result = ManifestUpdateResult::new(&path, Language::Node);
+        let spec = VersionSpec::new(VersionSpecKind::Exact, "4.17.21", "4.17.21");
+        manifest_result.add_result(UpdateResult::skip(
+            Dependency::new("lodash", spec, false, Language::Node),
+            SkipReason::Pinned,
+        ));
+
+        let results = ManifestWriter::new(false)
+            .with_normalize_prefix(Some("^".to_string()))
+            .apply_all_updates(&[manifest_result], crate::manifest::get_parser_for_path);
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].file_modified);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            r#"{"dependencies": {"lodash": "^4.17.21"}}"#
+        );
+    }
+
     #[test]
     fn test_apply_updates_multiple_packages() {
         let temp_dir = TempDir::new().unwrap();
@@ -401,6 +656,40 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_read_manifest_invalid_utf8() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("Gemfile");
+        // "caf\xe9" is Latin-1, not UTF-8
+        fs::write(&path, b"# caf\xe9\ngem 'rails', '~> 7.0'\n").unwrap();
+
+        match read_manifest(&path) {
+            Err(ManifestError::InvalidEncoding { offset, .. }) => assert_eq!(offset, 5),
+            other => panic!("expected an encoding error, got {:?}", other),
+        }
+
+        let lossy = read_manifest_lossy(&path).unwrap();
+        assert!(lossy.contains("gem 'rails', '~> 7.0'"));
+        assert!(lossy.contains('\u{FFFD}'));
+    }
+
+    #[test]
+    fn test_apply_updates_refuses_invalid_utf8() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("package.json");
+        let bytes = b"{\"description\": \"caf\xe9\", \"dependencies\": {\"lodash\": \"^4.17.0\"}}";
+        fs::write(&path, bytes).unwrap();
+
+        let mut manifest_result = ManifestUpdateResult::new(&path, Language::Node);
+        let dep = sample_dependency("lodash", "4.17.0", Language::Node);
+        manifest_result.add_result(UpdateResult::update(dep, "4.17.21"));
+
+        let parser = crate::manifest::PackageJsonParser;
+        let result = ManifestWriter::new(false).apply_updates(&manifest_result, &parser);
+        assert!(matches!(result, Err(ManifestError::InvalidEncoding { .. })));
+        assert_eq!(fs::read(&path).unwrap(), bytes);
+    }
+
     #[test]
     fn test_write_manifest() {
         let temp_dir = TempDir::new().unwrap();
@@ -5,27 +5,59 @@
 //! - Parallel registry queries with rate limiting
 //! - Dry-run mode support
 //! - Language and package filter application
+//! - Single-package fast path that skips all other lookups
 //! - Error handling with partial continuation
+//! - Overall deadline for the registry fetch loop
+//! - Optional GitHub release notes for updates
+//! - Update groups for related packages
+//! - Registry lookups shared across manifests of the same language
+//! - Streaming mode with a bounded number of manifests in flight
+//! - Progress events for embedding frontends
+//! - Per-package lookup timing to find slow registry lookups
 
 use crate::cli::CliArgs;
-use crate::domain::{Language, ManifestUpdateResult, SkipReason, UpdateResult, UpdateSummary};
+use crate::domain::{
+    Dependency, Language, ManifestUpdateResult, SkipReason, UpdateResult, UpdateSummary,
+};
+use crate::error::{ManifestError, RegistryError};
 use crate::manifest::{
-    detect_manifests, get_parser, has_pnpm_workspace, ManifestWriter, PnpmSettings, WriteResult,
+    detect_manifests, get_parser_for_path, has_pnpm_workspace, mark_path_repository_packages,
+    read_manifest, read_manifest_lossy, read_python_version, CargoLock, DepupConfig, ManifestInfo,
+    ManifestWriter, PackageLock, PnpmSettings, WriteResult,
 };
-use crate::progress::Progress;
+use crate::parser::matching_tag_versions;
+use crate::progress::{Progress, ProgressEvent};
 use crate::registry::{
-    CratesIoAdapter, GoProxyAdapter, HttpClient, MavenCentralAdapter, NpmAdapter, PackagistAdapter,
-    PyPIAdapter, RegistryAdapter, RubyGemsAdapter,
+    create_adapter_with_base_url, github_repository, minimum_fix_version, notes_between,
+    registry_host, AdvisorySource, CranAdapter, CratesIoAdapter, DenoAdapter, DockerHubAdapter,
+    GitHubClient, GitHubRelease, GoProxyAdapter, HttpClient, MavenCentralAdapter, MirroredAdapter,
+    Netrc, NimbleAdapter, NpmAdapter, OsvClient, PackagistAdapter, PreCommitAdapter, PyPIAdapter,
+    RegistryAdapter, ReleaseNotesSource, RubyGemsAdapter, ShardsAdapter, YarnRc, DEFAULT_DIST_TAG,
+};
+use crate::update::{
+    apply_groups, compare_versions, StabilityPolicy, UpdateFilter, UpdateJudge, VersionInfo,
 };
-use crate::update::{UpdateFilter, UpdateJudge, VersionInfo};
-use std::sync::Arc;
-use tokio::sync::Semaphore;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::{OnceCell, Semaphore};
+use tokio::task::{Id as TaskId, JoinError, JoinSet};
+use tokio::time::Instant;
 
 /// Default concurrency limit for registry requests
 const DEFAULT_CONCURRENCY: usize = 10;
 
-/// Concurrency limit for crates.io (rate limited)
-const CRATES_IO_CONCURRENCY: usize = 1;
+/// Upper bound for `--concurrency 0`, keeping open connections well below
+/// common file-descriptor limits
+const MAX_UNBOUNDED_CONCURRENCY: usize = 256;
+
+/// Concurrency limits for registry hosts with stricter rate limits
+const KNOWN_HOST_CONCURRENCY: &[(&str, usize)] = &[("crates.io", 1)];
+
+/// Skip message for dependencies not checked before the --deadline
+const DEADLINE_EXCEEDED: &str = "deadline exceeded";
 
 /// Orchestrator for coordinating the update workflow
 pub struct Orchestrator {
@@ -33,10 +65,67 @@ pub struct Orchestrator {
     args: CliArgs,
     /// HTTP client for registry requests
     client: HttpClient,
-    /// Semaphore for general concurrency control
-    general_semaphore: Arc<Semaphore>,
-    /// Semaphore for crates.io specific rate limiting
-    crates_io_semaphore: Arc<Semaphore>,
+    /// Concurrency limits per registry host
+    config: OrchestratorConfig,
+    /// Semaphore per registry host, created on first use from `config`
+    host_semaphores: Mutex<HashMap<String, Arc<Semaphore>>>,
+    /// Dependencies parsed so far, sizing unbounded hosts (`--concurrency 0`);
+    /// only changed while holding `host_semaphores`
+    dependency_count: AtomicUsize,
+    /// Fetched versions per (language, package), so a package declared in several
+    /// manifests (e.g., a workspace root and member) is looked up only once per run
+    version_cache: Mutex<VersionCache>,
+    /// Vulnerability advisories source (--only-vulnerable)
+    advisory_source: Option<Box<dyn AdvisorySource>>,
+    /// Adapters replacing the registry for a language (library API, tests)
+    adapter_overrides: HashMap<Language, Arc<dyn RegistryAdapter>>,
+    /// Registry lookups that took at least --slow-threshold, in completion order
+    slow_lookups: Mutex<Vec<SlowLookup>>,
+    /// Mirror URL per (language, package) whose versions came from a --registry-mirror
+    served_by_mirror: Mutex<HashMap<(Language, String), String>>,
+    /// npm registries configured by the target's `.yarnrc.yml`
+    yarnrc: Option<YarnRc>,
+    /// Manifests to process instead of detecting them (--manifests-from)
+    manifest_list: Option<Vec<ManifestInfo>>,
+    /// Settings from the target's package.json `"depup"` key
+    depup_config: DepupConfig,
+}
+
+/// A registry lookup and the time it took (--slow-threshold)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlowLookup {
+    /// Language of the package
+    pub language: Language,
+    /// Package name
+    pub package: String,
+    /// Time spent fetching versions, excluding the wait for a request slot
+    pub duration: Duration,
+}
+
+/// Successfully fetched registry versions per language and package
+///
+/// Each entry is a `OnceCell` so concurrent lookups of the same package wait for
+/// a single in-flight fetch instead of starting their own.
+type VersionCache = HashMap<(Language, String), Arc<OnceCell<Vec<VersionInfo>>>>;
+
+/// A manifest read and parsed, waiting for registry lookups
+struct ParsedManifest {
+    info: ManifestInfo,
+    dependencies: Vec<Dependency>,
+    excluded_versions: Vec<(String, String)>,
+    stability: Option<StabilityPolicy>,
+    requires_python: Option<String>,
+    /// Selected interpreter from `.python-version` (Python manifests only)
+    python_version: Option<String>,
+}
+
+/// Outcome of processing one manifest in streaming mode
+struct ProcessedManifest {
+    /// Update results, if the manifest could be parsed
+    result: Option<ManifestUpdateResult>,
+    /// Write result, if the manifest had updates
+    write_result: Option<WriteResult>,
+    errors: Vec<OrchestratorError>,
 }
 
 /// Result of running the orchestrator
@@ -56,12 +145,25 @@ pub enum OrchestratorError {
     HttpClientError(String),
     /// Failed to detect manifests
     ManifestDetectionError(String),
+    /// Invalid settings in package.json's `"depup"` key
+    InvalidConfig { path: String, message: String },
     /// Failed to parse manifest
     ManifestParseError { path: String, message: String },
     /// Failed to fetch versions from registry
     RegistryError { package: String, message: String },
     /// Failed to write manifest
     WriteError { path: String, message: String },
+    /// Checking a manifest stopped unexpectedly (streaming mode)
+    ManifestTaskFailed { path: String, message: String },
+    /// Package declared more than once in a manifest
+    DuplicateDependency { path: String, package: String },
+    /// Parsed version spec doesn't re-render to its original text (--validate)
+    RoundTripMismatch {
+        path: String,
+        package: String,
+        raw: String,
+        rendered: String,
+    },
 }
 
 impl std::fmt::Display for OrchestratorError {
@@ -71,6 +173,9 @@ impl std::fmt::Display for OrchestratorError {
             OrchestratorError::ManifestDetectionError(msg) => {
                 write!(f, "Manifest detection error: {}", msg)
             }
+            OrchestratorError::InvalidConfig { path, message } => {
+                write!(f, "Invalid depup settings in {}: {}", path, message)
+            }
             OrchestratorError::ManifestParseError { path, message } => {
                 write!(f, "Failed to parse {}: {}", path, message)
             }
@@ -80,33 +185,134 @@ impl std::fmt::Display for OrchestratorError {
             OrchestratorError::WriteError { path, message } => {
                 write!(f, "Failed to write {}: {}", path, message)
             }
+            OrchestratorError::ManifestTaskFailed { path, message } => {
+                write!(f, "Failed to check {}: {}", path, message)
+            }
+            OrchestratorError::DuplicateDependency { path, package } => {
+                write!(f, "Duplicate dependency {} in {}", package, path)
+            }
+            OrchestratorError::RoundTripMismatch {
+                path,
+                package,
+                raw,
+                rendered,
+            } => write!(
+                f,
+                "Version spec for {} in {} does not round-trip: {:?} re-renders as {:?}",
+                package, path, raw, rendered
+            ),
         }
     }
 }
 
 impl std::error::Error for OrchestratorError {}
 
+impl OrchestratorError {
+    /// Whether this error is a manifest that could not be read or parsed
+    pub fn is_parse_error(&self) -> bool {
+        matches!(self, OrchestratorError::ManifestParseError { .. })
+    }
+
+    /// Whether this is only a warning, reported without failing the run
+    ///
+    /// A duplicate declaration is still checked and updated, so the run succeeds.
+    pub fn is_warning(&self) -> bool {
+        matches!(self, OrchestratorError::DuplicateDependency { .. })
+    }
+}
+
 impl Orchestrator {
     /// Create a new orchestrator with the given CLI arguments
+    ///
+    /// Fails if the target's package.json has invalid `"depup"` settings.
     pub fn new(args: CliArgs) -> Result<Self, OrchestratorError> {
-        let client =
-            HttpClient::new().map_err(|e| OrchestratorError::HttpClientError(e.to_string()))?;
+        let depup_config = DepupConfig::from_dir(&args.path).map_err(|message| {
+            OrchestratorError::InvalidConfig {
+                path: args.path.join("package.json").display().to_string(),
+                message,
+            }
+        })?;
+        let client = HttpClient::new()
+            .map_err(|e| OrchestratorError::HttpClientError(e.to_string()))?
+            .with_allowed_hosts(args.allowed_hosts.clone())
+            .with_strict_tls(args.strict_tls);
+        let client = match Netrc::load() {
+            Some(netrc) => client.with_netrc(netrc),
+            None => client,
+        };
 
         Ok(Self {
-            args,
-            client,
-            general_semaphore: Arc::new(Semaphore::new(DEFAULT_CONCURRENCY)),
-            crates_io_semaphore: Arc::new(Semaphore::new(CRATES_IO_CONCURRENCY)),
+            depup_config,
+            ..Self::with_client(args, client)
         })
     }
 
     /// Create an orchestrator with a custom HTTP client (for testing)
+    ///
+    /// Invalid package.json `"depup"` settings are ignored.
     pub fn with_client(args: CliArgs, client: HttpClient) -> Self {
+        let advisory_source: Option<Box<dyn AdvisorySource>> = if args.only_vulnerable {
+            Some(Box::new(OsvClient::new(client.clone())))
+        } else {
+            None
+        };
+
+        let config = match args.concurrency {
+            Some(limit) => OrchestratorConfig::default().with_default_concurrency(limit),
+            None => OrchestratorConfig::default(),
+        };
+        let config = args
+            .host_concurrency
+            .iter()
+            .fold(config, |config, (host, limit)| {
+                config.with_host_concurrency(host.clone(), *limit)
+            });
+
+        let yarnrc = YarnRc::load(&args.path);
+        let depup_config = DepupConfig::from_dir(&args.path).unwrap_or_default();
+
         Self {
             args,
             client,
-            general_semaphore: Arc::new(Semaphore::new(DEFAULT_CONCURRENCY)),
-            crates_io_semaphore: Arc::new(Semaphore::new(CRATES_IO_CONCURRENCY)),
+            config,
+            host_semaphores: Mutex::new(HashMap::new()),
+            dependency_count: AtomicUsize::new(0),
+            version_cache: Mutex::new(HashMap::new()),
+            advisory_source,
+            adapter_overrides: HashMap::new(),
+            slow_lookups: Mutex::new(Vec::new()),
+            served_by_mirror: Mutex::new(HashMap::new()),
+            yarnrc,
+            manifest_list: None,
+            depup_config,
+        }
+    }
+
+    /// Use a custom advisory source for --only-vulnerable (for testing)
+    pub fn with_advisory_source(mut self, source: Box<dyn AdvisorySource>) -> Self {
+        self.advisory_source = Some(source);
+        self
+    }
+
+    /// Fetch versions for the adapter's language from `adapter` instead of the registry
+    ///
+    /// Mirrors configured for that language are not consulted.
+    pub fn with_adapter(mut self, adapter: Arc<dyn RegistryAdapter>) -> Self {
+        self.adapter_overrides.insert(adapter.language(), adapter);
+        self
+    }
+
+    /// Process exactly these manifests instead of detecting them under the target path
+    pub fn with_manifests(mut self, manifests: Vec<ManifestInfo>) -> Self {
+        self.manifest_list = Some(manifests);
+        self
+    }
+
+    /// Manifests to process: the given list, or those detected under the target path
+    fn manifests(&self) -> Vec<ManifestInfo> {
+        match &self.manifest_list {
+            Some(manifests) => manifests.clone(),
+            None => detect_manifests(&self.args.path),
         }
     }
 
@@ -117,13 +323,29 @@ impl Orchestrator {
 
     /// Run the update workflow with optional progress display
     pub async fn run_with_progress(&self, show_progress: bool) -> OrchestratorResult {
-        let mut progress = Progress::new(show_progress);
+        self.run_reporting(Progress::new(show_progress)).await
+    }
+
+    /// Run the update workflow, sending progress events to `events`
+    ///
+    /// For frontends embedding depup: events are sent as the run goes, and the
+    /// channel closes when the run is done. No progress bar is shown.
+    pub async fn run_with_events(
+        &self,
+        events: UnboundedSender<ProgressEvent>,
+    ) -> OrchestratorResult {
+        self.run_reporting(Progress::disabled().with_events(events))
+            .await
+    }
+
+    /// Run the update workflow, reporting to `progress`
+    async fn run_reporting(&self, mut progress: Progress) -> OrchestratorResult {
         let mut summary = UpdateSummary::new(self.args.dry_run);
         let mut errors = Vec::new();
 
         // Step 1: Detect manifest files
         progress.spinner("Detecting manifest files...");
-        let manifests = detect_manifests(&self.args.path);
+        let manifests = self.manifests();
         progress.finish_and_clear();
 
         if manifests.is_empty() {
@@ -136,102 +358,90 @@ impl Orchestrator {
 
         // Build update filter from CLI args
         let filter = self.build_filter();
-        let judge = UpdateJudge::new(filter);
 
         // Step 2: Parse manifests and collect all dependencies
         progress.spinner("Parsing manifests...");
         let mut parsed_manifests = Vec::new();
 
-        for manifest_info in &manifests {
+        for manifest_info in manifests {
             // Check language filter
             if !self.should_process_language(manifest_info.language) {
                 continue;
             }
+            progress.emit(|| ProgressEvent::ManifestDetected {
+                path: manifest_info.path.clone(),
+                language: manifest_info.language,
+            });
+            if let Some(parsed) = self.parse_manifest(manifest_info, &mut errors) {
+                parsed_manifests.push(parsed);
+            }
+        }
+        progress.finish_and_clear();
 
-            // Parse the manifest
-            let parser = get_parser(manifest_info.language);
-            let content = match std::fs::read_to_string(&manifest_info.path) {
-                Ok(c) => c,
-                Err(e) => {
-                    errors.push(OrchestratorError::ManifestParseError {
-                        path: manifest_info.path.display().to_string(),
-                        message: e.to_string(),
-                    });
-                    continue;
-                }
-            };
-
-            let dependencies = match parser.parse(&content) {
-                Ok(deps) => deps,
-                Err(e) => {
-                    errors.push(OrchestratorError::ManifestParseError {
-                        path: manifest_info.path.display().to_string(),
-                        message: e.to_string(),
-                    });
-                    continue;
-                }
+        // --strict-parse: a malformed manifest stops the run before any lookups or writes
+        if self.args.strict_parse && errors.iter().any(OrchestratorError::is_parse_error) {
+            return OrchestratorResult {
+                summary,
+                write_results: Vec::new(),
+                errors,
             };
-
-            parsed_manifests.push((manifest_info, dependencies));
         }
-        progress.finish_and_clear();
 
         // Count total dependencies for progress bar
-        let total_deps: usize = parsed_manifests.iter().map(|(_, deps)| deps.len()).sum();
+        let total_deps: usize = parsed_manifests.iter().map(|m| m.dependencies.len()).sum();
 
         // Step 3: Fetch versions and judge updates for each dependency
         progress.start(total_deps as u64, "Checking dependencies");
 
-        for (manifest_info, dependencies) in parsed_manifests {
-            let mut manifest_result =
-                ManifestUpdateResult::new(&manifest_info.path, manifest_info.language);
-            let adapter = self.get_adapter(manifest_info.language);
-
-            for dep in dependencies {
-                progress.set_message(&format!("Checking {}", &dep.name));
-
-                // Check if we should skip this dependency early
-                if let Some(reason) = judge.should_skip(&dep) {
-                    manifest_result.add_result(UpdateResult::skip(dep, reason));
-                    progress.inc();
-                    continue;
-                }
-
-                // Fetch versions from registry
-                let versions = match self.fetch_versions(&*adapter, &dep.name).await {
-                    Ok(v) => v,
-                    Err(e) => {
-                        errors.push(OrchestratorError::RegistryError {
-                            package: dep.name.clone(),
-                            message: e.to_string(),
-                        });
-                        manifest_result
-                            .add_result(UpdateResult::skip(dep, SkipReason::FetchFailed(e)));
-                        progress.inc();
-                        continue;
-                    }
-                };
-
-                // Judge whether to update
-                let result = judge.judge(&dep, &versions);
-                manifest_result.add_result(result);
-                progress.inc();
-            }
+        // Overall deadline for the fetch loop (--deadline)
+        let deadline = self
+            .args
+            .deadline
+            .map(|secs| Instant::now() + Duration::from_secs(secs));
 
+        for parsed in parsed_manifests {
+            let manifest_result = self
+                .check_manifest(parsed, &filter, deadline, &mut progress, &mut errors)
+                .await;
             summary.add_manifest(manifest_result);
         }
         progress.finish_and_clear();
 
+        // Coalesce related packages into update groups (--group)
+        if !self.args.groups.is_empty() {
+            apply_groups(
+                &mut summary.manifests,
+                &self.args.groups,
+                self.args.group_all_or_nothing,
+            );
+        }
+
+        // Attach release notes to updates (--notes)
+        if self.args.notes {
+            progress.spinner("Fetching release notes...");
+            let github = GitHubClient::new(self.client.clone());
+            attach_release_notes(&mut summary.manifests, &github).await;
+            progress.finish_and_clear();
+        }
+
         // Step 4: Apply updates (unless dry-run)
         if !self.args.dry_run {
             progress.spinner("Writing updates...");
         }
-        let writer = ManifestWriter::new(self.args.dry_run);
-        let write_results = writer.apply_all_updates(&summary.manifests, get_parser);
+        let writer = ManifestWriter::new(self.args.dry_run)
+            .with_preview(self.args.preview_content)
+            .with_backup(self.args.rollback_on_install_failure)
+            .with_normalize_prefix(self.args.normalize_prefix.clone());
+        let write_results = writer.apply_all_updates(&summary.manifests, get_parser_for_path);
         progress.finish_and_clear();
 
         // Collect write errors
         for result in &write_results {
+            if result.file_modified {
+                progress.emit(|| ProgressEvent::WriteApplied {
+                    path: result.path.clone(),
+                });
+            }
             for error in &result.errors {
                 errors.push(OrchestratorError::WriteError {
                     path: result.path.display().to_string(),
@@ -247,44 +457,525 @@ impl Orchestrator {
         }
     }
 
+    /// Run the update workflow, handing each manifest to `sink` as soon as it is done
+    ///
+    /// Unlike [`Orchestrator::run`], results aren't collected: at most `max_in_flight`
+    /// manifests are processing or waiting for their turn to be emitted, and each one
+    /// is written and passed to
+    /// `sink` in detection order, then dropped. Update groups and release notes only
+    /// see the manifest they belong to. Returns the errors encountered.
+    pub async fn run_streaming(
+        self: Arc<Self>,
+        max_in_flight: usize,
+        mut sink: impl FnMut(&ManifestUpdateResult, Option<&WriteResult>),
+    ) -> Vec<OrchestratorError> {
+        let mut errors = Vec::new();
+        let filter = self.build_filter();
+        let deadline = self
+            .args
+            .deadline
+            .map(|secs| Instant::now() + Duration::from_secs(secs));
+
+        let manifests = self
+            .manifests()
+            .into_iter()
+            .filter(|m| self.should_process_language(m.language));
+
+        let mut in_flight = JoinSet::new();
+        // Index and path of each task, so a task that panics still takes its place in order
+        let mut tasks = HashMap::new();
+        let mut finished = BTreeMap::new();
+        let mut next_index = 0;
+
+        for (index, manifest_info) in manifests.enumerate() {
+            // Finished manifests waiting behind a slow one count against the limit,
+            // so a stuck manifest can't make the buffer grow without bound
+            while in_flight.len() + finished.len() >= max_in_flight.max(1) {
+                let Some(joined) = in_flight.join_next().await else {
+                    break;
+                };
+                record_joined(joined, &mut tasks, &mut finished);
+                flush_in_order(&mut finished, &mut next_index, &mut sink, &mut errors);
+            }
+
+            let path = manifest_info.path.display().to_string();
+            let orchestrator = Arc::clone(&self);
+            let filter = filter.clone();
+            let handle = in_flight.spawn(async move {
+                let processed = orchestrator
+                    .process_manifest(manifest_info, &filter, deadline)
+                    .await;
+                (index, processed)
+            });
+            tasks.insert(handle.id(), (index, path));
+
+            flush_in_order(&mut finished, &mut next_index, &mut sink, &mut errors);
+        }
+
+        while let Some(joined) = in_flight.join_next().await {
+            record_joined(joined, &mut tasks, &mut finished);
+            flush_in_order(&mut finished, &mut next_index, &mut sink, &mut errors);
+        }
+
+        errors
+    }
+
+    /// Parse, check and write a single manifest (used by [`Orchestrator::run_streaming`])
+    async fn process_manifest(
+        &self,
+        manifest_info: ManifestInfo,
+        filter: &UpdateFilter,
+        deadline: Option<Instant>,
+    ) -> ProcessedManifest {
+        let mut errors = Vec::new();
+        let Some(parsed) = self.parse_manifest(manifest_info, &mut errors) else {
+            return ProcessedManifest {
+                result: None,
+                write_result: None,
+                errors,
+            };
+        };
+
+        let mut progress = Progress::disabled();
+        let mut manifest_result = self
+            .check_manifest(parsed, filter, deadline, &mut progress, &mut errors)
+            .await;
+        let manifests = std::slice::from_mut(&mut manifest_result);
+
+        if !self.args.groups.is_empty() {
+            apply_groups(manifests, &self.args.groups, self.args.group_all_or_nothing);
+        }
+        if self.args.notes {
+            let github = GitHubClient::new(self.client.clone());
+            attach_release_notes(manifests, &github).await;
+        }
+
+        let writer = ManifestWriter::new(self.args.dry_run)
+            .with_backup(self.args.rollback_on_install_failure)
+            .with_normalize_prefix(self.args.normalize_prefix.clone());
+        let write_result = writer
+            .apply_all_updates(manifests, get_parser_for_path)
+            .pop();
+        if let Some(result) = &write_result {
+            errors.extend(
+                result
+                    .errors
+                    .iter()
+                    .map(|error| OrchestratorError::WriteError {
+                        path: result.path.display().to_string(),
+                        message: error.clone(),
+                    }),
+            );
+        }
+
+        ProcessedManifest {
+            result: Some(manifest_result),
+            write_result,
+            errors,
+        }
+    }
+
+    /// Read and parse a manifest, applying the package selection
+    ///
+    /// Returns `None` if the manifest can't be read or parsed (the error is recorded)
+    /// or doesn't declare the `--package` being updated.
+    fn parse_manifest(
+        &self,
+        manifest_info: ManifestInfo,
+        errors: &mut Vec<OrchestratorError>,
+    ) -> Option<ParsedManifest> {
+        let parser = get_parser_for_path(&manifest_info.path, manifest_info.language);
+        // A manifest that isn't valid UTF-8 is still checked from a lossy copy;
+        // the writer refuses to rewrite it, so the copy is never written back
+        let (content, encoding_error) = match read_manifest(&manifest_info.path) {
+            Ok(c) => (c, None),
+            Err(e @ ManifestError::InvalidEncoding { .. }) => {
+                match read_manifest_lossy(&manifest_info.path) {
+                    Ok(c) => (c, Some(e)),
+                    Err(e) => {
+                        errors.push(OrchestratorError::ManifestParseError {
+                            path: manifest_info.path.display().to_string(),
+                            message: e.to_string(),
+                        });
+                        return None;
+                    }
+                }
+            }
+            Err(e) => {
+                errors.push(OrchestratorError::ManifestParseError {
+                    path: manifest_info.path.display().to_string(),
+                    message: e.to_string(),
+                });
+                return None;
+            }
+        };
+
+        let dependencies = match parser.parse(&content) {
+            Ok(deps) => deps,
+            Err(e) => {
+                // Report the encoding problem rather than the syntax error it caused
+                errors.push(OrchestratorError::ManifestParseError {
+                    path: manifest_info.path.display().to_string(),
+                    message: encoding_error.map_or_else(|| e.to_string(), |e| e.to_string()),
+                });
+                return None;
+            }
+        };
+
+        // Single-package fast path (--package): drop every other dependency
+        // before any registry lookup, and skip manifests that don't declare it
+        let dependencies = self.select_package(dependencies);
+        if dependencies.is_empty() && self.args.package.is_some() {
+            return None;
+        }
+
+        // composer.json `path` repositories are resolved on disk to the packages they serve
+        let dependencies = match (manifest_info.language, manifest_info.path.parent()) {
+            (Language::Php, Some(dir)) => {
+                mark_path_repository_packages(dependencies, &content, dir)
+            }
+            _ => dependencies,
+        };
+
+        // Record versions resolved in Cargo.lock / package-lock.json for drift reporting
+        let (dependencies, locked) = match manifest_info.language {
+            Language::Rust => match CargoLock::find(&manifest_info.path) {
+                Some(lock) => (lock.attach_resolved_versions(dependencies), true),
+                None => (dependencies, false),
+            },
+            Language::Node
+                if manifest_info
+                    .path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    == Language::Node.manifest_filename() =>
+            {
+                match PackageLock::find(&manifest_info.path) {
+                    Some(lock) => (lock.attach_resolved_versions(dependencies), true),
+                    None => (dependencies, false),
+                }
+            }
+            _ => (dependencies, false),
+        };
+
+        // Installed-only mode (--check-only-installed): the lock file decides which
+        // dependencies are checked; manifests without one are checked in full
+        let dependencies = if self.args.check_only_installed && locked {
+            dependencies
+                .into_iter()
+                .filter(|dep| dep.resolved_version.is_some())
+                .collect()
+        } else {
+            dependencies
+        };
+
+        // Surface packages declared in more than one section
+        for package in parser
+            .find_duplicates(&content)
+            .into_iter()
+            .filter(|package| self.is_selected_package(package))
+        {
+            errors.push(OrchestratorError::DuplicateDependency {
+                path: manifest_info.path.display().to_string(),
+                package,
+            });
+        }
+
+        // Catch parsers that lose part of a spec (--validate)
+        if self.args.validate {
+            errors.extend(validate_round_trips(
+                &manifest_info.path.display().to_string(),
+                &dependencies,
+            ));
+        }
+
+        self.count_dependencies(dependencies.len());
+
+        Some(ParsedManifest {
+            excluded_versions: parser.excluded_versions(&content),
+            stability: parser.stability_policy(&content),
+            requires_python: parser.requires_python(&content),
+            python_version: self.python_version_for(&manifest_info),
+            info: manifest_info,
+            dependencies,
+        })
+    }
+
+    /// Python version selected by a `.python-version` next to a Python manifest
+    fn python_version_for(&self, manifest_info: &ManifestInfo) -> Option<String> {
+        if manifest_info.language != Language::Python {
+            return None;
+        }
+        read_python_version(manifest_info.path.parent()?)
+    }
+
+    /// Fetch versions and judge updates for every dependency of a parsed manifest
+    async fn check_manifest(
+        &self,
+        parsed: ParsedManifest,
+        filter: &UpdateFilter,
+        deadline: Option<Instant>,
+        progress: &mut Progress,
+        errors: &mut Vec<OrchestratorError>,
+    ) -> ManifestUpdateResult {
+        let mut manifest_result =
+            ManifestUpdateResult::new(&parsed.info.path, parsed.info.language);
+        let adapter = self.get_adapter(parsed.info.language);
+
+        // Versions excluded by this manifest apply only to its own dependencies
+        let mut manifest_filter = parsed
+            .excluded_versions
+            .into_iter()
+            .fold(filter.clone(), |f, (package, version)| {
+                f.with_excluded_version(package, version)
+            });
+        if let Some(policy) = parsed.stability {
+            manifest_filter = manifest_filter.with_stability(policy);
+        }
+        if let Some(requires_python) = parsed.requires_python {
+            manifest_filter = manifest_filter.with_requires_python(requires_python);
+        }
+        if let Some(python_version) = parsed.python_version {
+            manifest_filter = manifest_filter.with_python_version(python_version);
+        }
+        // A dist-tag other than `latest` (e.g., --npm-tag next) usually points at a
+        // prerelease; it is followed for every dependency, stable or not
+        if parsed.info.language == Language::Node
+            && self
+                .args
+                .npm_tag
+                .as_deref()
+                .is_some_and(|tag| tag != DEFAULT_DIST_TAG)
+        {
+            manifest_filter = manifest_filter.with_include_prerelease(true);
+        }
+        let judge = UpdateJudge::new(manifest_filter);
+
+        self.check_dependencies(
+            &mut manifest_result,
+            parsed.dependencies,
+            &*adapter,
+            &judge,
+            deadline,
+            progress,
+            errors,
+        )
+        .await;
+
+        manifest_result
+    }
+
+    /// Fetch versions and judge updates for each dependency of a manifest
+    ///
+    /// Dependencies that are still pending when the deadline passes are recorded
+    /// as `FetchFailed("deadline exceeded")` instead of being fetched.
+    /// The time spent is recorded as the manifest's `duration`.
+    #[allow(clippy::too_many_arguments)]
+    async fn check_dependencies(
+        &self,
+        manifest_result: &mut ManifestUpdateResult,
+        dependencies: Vec<Dependency>,
+        adapter: &(dyn RegistryAdapter + Send + Sync),
+        judge: &UpdateJudge,
+        deadline: Option<Instant>,
+        progress: &mut Progress,
+        errors: &mut Vec<OrchestratorError>,
+    ) {
+        let started = Instant::now();
+
+        for dep in dependencies {
+            progress.set_message(&format!("Checking {}", &dep.name));
+            let result = self
+                .check_dependency(dep, adapter, judge, deadline, errors)
+                .await;
+            progress.inc();
+            progress.emit(|| ProgressEvent::DependencyChecked {
+                name: result.dependency().name.clone(),
+                result: Box::new(result.clone()),
+            });
+            manifest_result.add_result(result);
+        }
+
+        manifest_result.duration = Some(started.elapsed());
+    }
+
+    /// Fetch versions and judge the update for a single dependency
+    async fn check_dependency(
+        &self,
+        dep: Dependency,
+        adapter: &(dyn RegistryAdapter + Send + Sync),
+        judge: &UpdateJudge,
+        deadline: Option<Instant>,
+        errors: &mut Vec<OrchestratorError>,
+    ) -> UpdateResult {
+        // Check if we should skip this dependency early
+        if let Some(reason) = judge.should_skip(&dep) {
+            return UpdateResult::skip(dep, reason);
+        }
+
+        // Security mode (--only-vulnerable): only dependencies with a known
+        // advisory are updated, to the lowest version that fixes them
+        let fixed_version = match &self.advisory_source {
+            Some(source) => match self.fixed_version(source.as_ref(), &dep).await {
+                Ok(fixed) => Some(fixed),
+                Err(reason) => {
+                    if let SkipReason::FetchFailed(message) = &reason {
+                        errors.push(OrchestratorError::RegistryError {
+                            package: dep.name.clone(),
+                            message: message.clone(),
+                        });
+                    }
+                    return UpdateResult::skip(dep, reason);
+                }
+            },
+            None => None,
+        };
+
+        // Drift mode (--drift) compares against the lock file, so unlocked
+        // dependencies are skipped without a registry lookup
+        if self.args.drift && dep.resolved_version.is_none() {
+            return UpdateResult::skip(dep, SkipReason::NoDrift);
+        }
+
+        // Fetch versions from registry, bounded by the deadline if any
+        let fetched = match deadline {
+            Some(deadline) => {
+                tokio::time::timeout_at(deadline, self.fetch_versions(adapter, dep.lookup_name()))
+                    .await
+                    .ok()
+            }
+            None => Some(self.fetch_versions(adapter, dep.lookup_name()).await),
+        };
+
+        let versions = match fetched {
+            // Docker tags carry the image variant; only tags of the same
+            // variant are candidates, compared by their version
+            Some(Ok(v)) if dep.language == Language::Docker => {
+                matching_tag_versions(&dep.version_spec, v)
+            }
+            Some(Ok(v)) => v,
+            Some(Err(e)) => {
+                errors.push(OrchestratorError::RegistryError {
+                    package: dep.name.clone(),
+                    message: e.to_string(),
+                });
+                return UpdateResult::skip(dep, SkipReason::FetchFailed(e));
+            }
+            None => {
+                return UpdateResult::skip(
+                    dep,
+                    SkipReason::FetchFailed(DEADLINE_EXCEEDED.to_string()),
+                );
+            }
+        };
+
+        // Judge whether to update
+        let mut result = match &fixed_version {
+            Some(fixed) => judge.judge_fix(&dep, &versions, fixed),
+            None if self.args.drift => judge.judge_drift(&dep, &versions),
+            None => judge.judge(&dep, &versions),
+        };
+        if let Some(mirror) = self.mirror_for(dep.language, dep.lookup_name()) {
+            result.set_mirror(mirror);
+        }
+        result
+    }
+
+    /// Mirror that served a package's versions this run, if the primary registry failed
+    fn mirror_for(&self, language: Language, package: &str) -> Option<String> {
+        self.served_by_mirror
+            .lock()
+            .unwrap()
+            .get(&(language, package.to_string()))
+            .cloned()
+    }
+
+    /// Look up advisories for a dependency and return the lowest version fixing them
+    async fn fixed_version(
+        &self,
+        source: &dyn AdvisorySource,
+        dependency: &Dependency,
+    ) -> Result<String, SkipReason> {
+        if !source.covers(dependency) {
+            return Err(SkipReason::NoAdvisoryDatabase);
+        }
+        let advisories = source
+            .query(dependency)
+            .await
+            .map_err(|e| SkipReason::FetchFailed(e.to_string()))?;
+        if advisories.is_empty() {
+            return Err(SkipReason::NotVulnerable);
+        }
+
+        let current = dependency
+            .resolved_version
+            .as_deref()
+            .unwrap_or(dependency.version());
+        minimum_fix_version(&advisories, current).ok_or(SkipReason::NoSuitableVersion)
+    }
+
     /// Build an UpdateFilter from CLI arguments
     fn build_filter(&self) -> UpdateFilter {
         let mut filter = UpdateFilter::new();
 
         // Language filter
         if self.args.has_language_filter() {
-            let mut languages = Vec::new();
-            if self.args.node {
-                languages.push(Language::Node);
-            }
-            if self.args.python {
-                languages.push(Language::Python);
-            }
-            if self.args.rust_lang {
-                languages.push(Language::Rust);
-            }
-            if self.args.go {
-                languages.push(Language::Go);
-            }
+            let languages = Language::all()
+                .iter()
+                .copied()
+                .filter(|&language| self.should_process_language(language))
+                .collect();
             filter = filter.with_languages(languages);
         }
 
+        // Settings embedded in package.json ("depup" key), below CLI flags
+        let config = &self.depup_config;
+
         // Package filters
-        if !self.args.exclude.is_empty() {
-            filter = filter.with_exclude(self.args.exclude.clone());
+        let mut exclude = self.args.exclude.clone();
+        exclude.extend(config.exclude.iter().cloned());
+        if !exclude.is_empty() {
+            filter = filter.with_exclude(exclude);
         }
         if !self.args.only.is_empty() {
             filter = filter.with_only(self.args.only.clone());
         }
+        if !self.args.scopes.is_empty() {
+            filter = filter.with_scopes(self.args.scopes.clone());
+        }
 
         // Include pinned
         if self.args.include_pinned {
             filter = filter.with_include_pinned(true);
         }
 
+        // Unconstrained and range constraints
+        if self.args.force_complex {
+            filter = filter.with_force_complex(true);
+        }
+
+        // Indirect (transitive) dependencies
+        if self.args.only_direct {
+            filter = filter.with_only_direct(true);
+        }
+
+        // Go pseudo-versions
+        if self.args.upgrade_pseudo {
+            filter = filter.with_upgrade_pseudo(true);
+        }
+        filter = filter
+            .with_prerelease_channel(self.args.prerelease_channel)
+            .with_include_prerelease(self.args.include_prerelease)
+            .with_prefer_stable(self.args.prefer_stable);
+
+        // Release date cutoff
+        if let Some(since) = self.args.since {
+            filter = filter.with_since(since);
+        }
+
         // Age filter
-        // Priority: CLI --age > pnpm settings (for Node.js projects)
-        if let Some(age) = self.args.age {
+        // Priority: CLI --age > package.json "depup" > pnpm settings (for Node.js projects)
+        if let Some(age) = self.args.age.or(config.age) {
             filter = filter.with_min_age(age);
         } else if has_pnpm_workspace(&self.args.path) {
             // Read pnpm settings for minimum release age
@@ -293,14 +984,33 @@ impl Orchestrator {
                 filter = filter.with_min_age(age);
             }
         }
+        if let Some(age) = self.args.age_dev {
+            filter = filter.with_min_age_dev(age);
+        }
 
         filter
     }
 
+    /// Keep only the dependency selected with --package (all of them when unset)
+    fn select_package(&self, dependencies: Vec<Dependency>) -> Vec<Dependency> {
+        dependencies
+            .into_iter()
+            .filter(|dep| self.is_selected_package(&dep.name))
+            .collect()
+    }
+
+    /// Check if a package passes the single-package selection (--package)
+    fn is_selected_package(&self, name: &str) -> bool {
+        self.args
+            .package
+            .as_deref()
+            .is_none_or(|package| package == name)
+    }
+
     /// Check if a language should be processed based on CLI args
     fn should_process_language(&self, language: Language) -> bool {
         if !self.args.has_language_filter() {
-            return true;
+            return language.is_default();
         }
         match language {
             Language::Node => self.args.node,
@@ -310,58 +1020,378 @@ impl Orchestrator {
             Language::Ruby => self.args.ruby,
             Language::Php => self.args.php,
             Language::Java => self.args.java,
+            Language::Deno => self.args.deno,
+            Language::Nim => self.args.nim,
+            Language::Docker => self.args.docker,
+            Language::R => self.args.r,
+            Language::Crystal => self.args.crystal,
+            Language::PreCommit => self.args.pre_commit,
         }
     }
 
     /// Get the appropriate registry adapter for a language
+    ///
+    /// When mirrors are configured for the language, the primary registry is
+    /// wrapped in a `MirroredAdapter` that falls back to each mirror in order.
     fn get_adapter(&self, language: Language) -> Box<dyn RegistryAdapter + Send + Sync> {
-        match language {
-            Language::Node => Box::new(NpmAdapter::new(self.client.clone())),
+        if let Some(adapter) = self.adapter_overrides.get(&language) {
+            return Box::new(adapter.clone());
+        }
+
+        let primary: Box<dyn RegistryAdapter> = match language {
+            Language::Node => Box::new(self.npm_adapter()),
             Language::Python => Box::new(PyPIAdapter::new(self.client.clone())),
             Language::Rust => Box::new(CratesIoAdapter::new(self.client.clone())),
-            Language::Go => Box::new(GoProxyAdapter::new(self.client.clone())),
+            Language::Go => Box::new(
+                GoProxyAdapter::new(self.client.clone())
+                    .with_major_upgrades(self.args.go_major_upgrades),
+            ),
             Language::Ruby => Box::new(RubyGemsAdapter::new(self.client.clone())),
             Language::Php => Box::new(PackagistAdapter::new(self.client.clone())),
-            Language::Java => Box::new(MavenCentralAdapter::new(self.client.clone())),
+            Language::Java => Box::new(
+                MavenCentralAdapter::new(self.client.clone())
+                    .with_prereleases(self.args.include_prerelease),
+            ),
+            Language::Deno => {
+                Box::new(DenoAdapter::new(self.client.clone()).with_npm_adapter(self.npm_adapter()))
+            }
+            Language::Nim => Box::new(NimbleAdapter::new(self.client.clone())),
+            Language::Docker => Box::new(DockerHubAdapter::new(self.client.clone())),
+            Language::R => Box::new(CranAdapter::new(self.client.clone())),
+            Language::Crystal => Box::new(ShardsAdapter::new(self.client.clone())),
+            Language::PreCommit => Box::new(PreCommitAdapter::new(self.client.clone())),
+        };
+
+        let mirrors = self.args.mirrors_for(language);
+        if mirrors.is_empty() {
+            return primary;
+        }
+
+        let mut adapters = vec![primary];
+        for url in mirrors {
+            adapters.push(match language {
+                Language::Node => Box::new(
+                    self.configure_npm(NpmAdapter::with_base_url(self.client.clone(), url)),
+                ),
+                Language::Go => Box::new(
+                    GoProxyAdapter::with_base_url(self.client.clone(), url)
+                        .with_major_upgrades(self.args.go_major_upgrades),
+                ),
+                Language::Java => Box::new(
+                    MavenCentralAdapter::with_base_url(self.client.clone(), url)
+                        .with_prereleases(self.args.include_prerelease),
+                ),
+                _ => create_adapter_with_base_url(language, self.client.clone(), url),
+            });
+        }
+        Box::new(MirroredAdapter::new(adapters))
+    }
+
+    /// npm adapter for the registry configured by `.yarnrc.yml`, or the public one
+    fn npm_adapter(&self) -> NpmAdapter {
+        let adapter = match self.yarnrc.as_ref().and_then(|y| y.registry.as_deref()) {
+            Some(url) => NpmAdapter::with_base_url(self.client.clone(), url),
+            None => NpmAdapter::new(self.client.clone()),
+        };
+        self.configure_npm(adapter)
+    }
+
+    /// Apply the --npm-tag dist-tag and `.yarnrc.yml` scope registries to an npm adapter
+    fn configure_npm(&self, adapter: NpmAdapter) -> NpmAdapter {
+        let adapter = match &self.args.npm_tag {
+            Some(tag) => adapter.with_dist_tag(tag.clone()),
+            None => adapter,
+        };
+        self.yarnrc
+            .iter()
+            .flat_map(|yarnrc| &yarnrc.scopes)
+            .fold(adapter, |adapter, (scope, url)| {
+                adapter.with_scope_registry(scope.clone(), url.clone())
+            })
+    }
+
+    /// Semaphore limiting concurrent requests to a registry host
+    ///
+    /// An unbounded host (limit 0) allows one request per dependency parsed so far.
+    fn host_semaphore(&self, host: &str) -> Arc<Semaphore> {
+        self.host_semaphores
+            .lock()
+            .unwrap()
+            .entry(host.to_string())
+            .or_insert_with(|| {
+                let permits = match self.config.concurrency_for(host) {
+                    0 => OrchestratorConfig::unbounded_concurrency(
+                        self.dependency_count.load(Ordering::Relaxed),
+                    ),
+                    limit => limit,
+                };
+                Arc::new(Semaphore::new(permits))
+            })
+            .clone()
+    }
+
+    /// Add `count` parsed dependencies, widening unbounded hosts' semaphores to match
+    fn count_dependencies(&self, count: usize) {
+        let semaphores = self.host_semaphores.lock().unwrap();
+        let before = self.dependency_count.fetch_add(count, Ordering::Relaxed);
+        let added = OrchestratorConfig::unbounded_concurrency(before + count)
+            - OrchestratorConfig::unbounded_concurrency(before);
+        if added == 0 {
+            return;
+        }
+        for (host, semaphore) in semaphores.iter() {
+            if self.config.concurrency_for(host) == 0 {
+                semaphore.add_permits(added);
+            }
         }
     }
 
     /// Fetch versions from registry with concurrency control
+    ///
+    /// Successful results are cached per language and package for the rest of the run;
+    /// failures are not cached, so a later lookup of the same package retries.
     async fn fetch_versions(
         &self,
         adapter: &(dyn RegistryAdapter + Send + Sync),
         package: &str,
     ) -> Result<Vec<VersionInfo>, String> {
-        // Use appropriate semaphore based on registry
-        let semaphore = if adapter.language() == Language::Rust {
-            &self.crates_io_semaphore
-        } else {
-            &self.general_semaphore
-        };
+        let cell = self
+            .version_cache
+            .lock()
+            .unwrap()
+            .entry((adapter.language(), package.to_string()))
+            .or_default()
+            .clone();
+
+        cell.get_or_try_init(|| self.fetch_versions_uncached(adapter, package))
+            .await
+            .cloned()
+    }
 
+    /// Fetch versions from registry, bypassing the cache
+    async fn fetch_versions_uncached(
+        &self,
+        adapter: &(dyn RegistryAdapter + Send + Sync),
+        package: &str,
+    ) -> Result<Vec<VersionInfo>, String> {
+        // Limit concurrent requests per registry host
+        let semaphore = self.host_semaphore(registry_host(adapter.language()));
         let _permit = semaphore.acquire().await.unwrap();
 
-        adapter
-            .fetch_versions(package)
-            .await
-            .map_err(|e| e.to_string())
+        let started = Instant::now();
+        let result = adapter.fetch_versions(package).await;
+        let duration = started.elapsed();
+        if self
+            .args
+            .slow_threshold
+            .is_some_and(|threshold| duration >= Duration::from_millis(threshold))
+        {
+            self.slow_lookups.lock().unwrap().push(SlowLookup {
+                language: adapter.language(),
+                package: package.to_string(),
+                duration,
+            });
+        }
+
+        // Index 0 is the primary registry; mirrors follow in --registry-mirror order
+        if let Some(index) = result.as_ref().ok().and(adapter.served_by(package)) {
+            let mirrors = self.args.mirrors_for(adapter.language());
+            if let Some(url) = index.checked_sub(1).and_then(|i| mirrors.get(i)) {
+                self.served_by_mirror
+                    .lock()
+                    .unwrap()
+                    .insert((adapter.language(), package.to_string()), url.to_string());
+            }
+        }
+
+        result.map_err(|e| e.to_string())
     }
-}
 
-/// Configuration for the orchestrator
-#[derive(Debug, Clone)]
-pub struct OrchestratorConfig {
-    /// Maximum concurrent requests for general registries
-    pub general_concurrency: usize,
-    /// Maximum concurrent requests for crates.io
-    pub crates_io_concurrency: usize,
+    /// Registry lookups that took at least --slow-threshold, slowest first
+    ///
+    /// Empty without --slow-threshold. Covers the lookups made so far.
+    pub fn slow_lookups(&self) -> Vec<SlowLookup> {
+        let mut slow = self.slow_lookups.lock().unwrap().clone();
+        slow.sort_by_key(|lookup| std::cmp::Reverse(lookup.duration));
+        slow
+    }
+
+    /// Highest version the registry returned for a package during this run (--audit)
+    ///
+    /// `None` when the package was not looked up or the lookup failed.
+    pub fn latest_seen(&self, language: Language, package: &str) -> Option<String> {
+        let cache = self.version_cache.lock().unwrap();
+        let versions = cache.get(&(language, package.to_string()))?.get()?;
+        versions
+            .iter()
+            .map(|info| info.version.as_str())
+            .max_by(|a, b| compare_versions(a, b))
+            .map(String::from)
+    }
 }
 
-impl Default for OrchestratorConfig {
+/// Store a finished streaming task's outcome under its manifest index
+///
+/// A task that panicked or was cancelled is recorded as an error for its manifest.
+fn record_joined(
+    joined: Result<(usize, ProcessedManifest), JoinError>,
+    tasks: &mut HashMap<TaskId, (usize, String)>,
+    finished: &mut BTreeMap<usize, ProcessedManifest>,
+) {
+    match joined {
+        Ok((index, processed)) => {
+            finished.insert(index, processed);
+        }
+        Err(e) => {
+            if let Some((index, path)) = tasks.remove(&e.id()) {
+                finished.insert(
+                    index,
+                    ProcessedManifest {
+                        result: None,
+                        write_result: None,
+                        errors: vec![OrchestratorError::ManifestTaskFailed {
+                            path,
+                            message: e.to_string(),
+                        }],
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Hand finished manifests to the sink in detection order, dropping them afterwards
+fn flush_in_order(
+    finished: &mut BTreeMap<usize, ProcessedManifest>,
+    next_index: &mut usize,
+    sink: &mut impl FnMut(&ManifestUpdateResult, Option<&WriteResult>),
+    errors: &mut Vec<OrchestratorError>,
+) {
+    while let Some(processed) = finished.remove(next_index) {
+        if let Some(result) = &processed.result {
+            sink(result, processed.write_result.as_ref());
+        }
+        errors.extend(processed.errors);
+        *next_index += 1;
+    }
+}
+
+/// Check that each dependency's version spec re-renders to its original text
+///
+/// Dependencies skipped by the parser (e.g., path or VCS sources) are never
+/// rewritten, so they aren't checked.
+fn validate_round_trips(path: &str, dependencies: &[Dependency]) -> Vec<OrchestratorError> {
+    dependencies
+        .iter()
+        .filter(|dep| dep.skip_reason.is_none())
+        .filter_map(|dep| {
+            dep.version_spec.round_trip_mismatch().map(|rendered| {
+                OrchestratorError::RoundTripMismatch {
+                    path: path.to_string(),
+                    package: dep.name.clone(),
+                    raw: dep.version_spec.raw.clone(),
+                    rendered,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Attach GitHub release notes to every update whose repository is known
+///
+/// Notes are best-effort: failed lookups leave the update without notes, and
+/// hitting the GitHub rate limit stops further lookups for the run.
+async fn attach_release_notes(
+    manifests: &mut [ManifestUpdateResult],
+    source: &dyn ReleaseNotesSource,
+) {
+    let mut releases: HashMap<(String, String), Vec<GitHubRelease>> = HashMap::new();
+    let mut rate_limited = false;
+
+    for result in manifests.iter_mut().flat_map(|m| m.results.iter_mut()) {
+        let UpdateResult::Update {
+            dependency,
+            new_version,
+            ..
+        } = result
+        else {
+            continue;
+        };
+        let Some(repository) = github_repository(dependency) else {
+            continue;
+        };
+
+        if !releases.contains_key(&repository) {
+            if rate_limited {
+                continue;
+            }
+            match source.fetch_releases(&repository.0, &repository.1).await {
+                Ok(list) => {
+                    releases.insert(repository.clone(), list);
+                }
+                Err(RegistryError::RateLimitExceeded { .. }) => {
+                    rate_limited = true;
+                    continue;
+                }
+                Err(_) => {
+                    releases.insert(repository.clone(), Vec::new());
+                }
+            }
+        }
+
+        let notes = notes_between(&releases[&repository], dependency.version(), new_version);
+        if let Some(notes) = notes {
+            result.set_release_notes(notes);
+        }
+    }
+}
+
+/// Configuration for the orchestrator
+#[derive(Debug, Clone)]
+pub struct OrchestratorConfig {
+    /// Maximum concurrent requests for hosts without a specific limit
+    /// (0: one per dependency, see [`OrchestratorConfig::unbounded_concurrency`])
+    pub default_concurrency: usize,
+    /// Maximum concurrent requests per registry host
+    pub host_concurrency: HashMap<String, usize>,
+}
+
+impl OrchestratorConfig {
+    /// Set the maximum concurrent requests for hosts without a specific limit
+    pub fn with_default_concurrency(mut self, limit: usize) -> Self {
+        self.default_concurrency = limit;
+        self
+    }
+
+    /// Concurrency used for `--concurrency 0`: one request per dependency, capped
+    /// at `MAX_UNBOUNDED_CONCURRENCY`
+    pub fn unbounded_concurrency(dependency_count: usize) -> usize {
+        dependency_count.clamp(1, MAX_UNBOUNDED_CONCURRENCY)
+    }
+
+    /// Set the maximum concurrent requests for a registry host
+    pub fn with_host_concurrency(mut self, host: impl Into<String>, limit: usize) -> Self {
+        self.host_concurrency.insert(host.into(), limit);
+        self
+    }
+
+    /// Maximum concurrent requests for a registry host
+    pub fn concurrency_for(&self, host: &str) -> usize {
+        self.host_concurrency
+            .get(host)
+            .copied()
+            .unwrap_or(self.default_concurrency)
+    }
+}
+
+impl Default for OrchestratorConfig {
     fn default() -> Self {
         Self {
-            general_concurrency: DEFAULT_CONCURRENCY,
-            crates_io_concurrency: CRATES_IO_CONCURRENCY,
+            default_concurrency: DEFAULT_CONCURRENCY,
+            host_concurrency: KNOWN_HOST_CONCURRENCY
+                .iter()
+                .map(|(host, limit)| (host.to_string(), *limit))
+                .collect(),
         }
     }
 }
@@ -369,10 +1399,139 @@ impl Default for OrchestratorConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::{VersionSpec, VersionSpecKind};
+    use crate::registry::{Advisory, OsvResponse};
+    use async_trait::async_trait;
     use clap::Parser;
     use std::fs;
     use tempfile::TempDir;
 
+    /// Registry stand-in answering every lookup with a fixed version list
+    ///
+    /// Records each package it is asked about. Lookups can be slowed down (all
+    /// of them or per package) or made to fail.
+    struct RecordingAdapter {
+        language: Language,
+        versions: Vec<VersionInfo>,
+        delay: Duration,
+        package_delays: HashMap<&'static str, Duration>,
+        fail: bool,
+        fetched: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl Default for RecordingAdapter {
+        fn default() -> Self {
+            Self {
+                language: Language::Node,
+                versions: vec![VersionInfo::now("2.0.0")],
+                delay: Duration::ZERO,
+                package_delays: HashMap::new(),
+                fail: false,
+                fetched: Default::default(),
+            }
+        }
+    }
+
+    impl RecordingAdapter {
+        fn for_language(language: Language) -> Self {
+            Self {
+                language,
+                ..Default::default()
+            }
+        }
+
+        fn with_versions(self, versions: Vec<VersionInfo>) -> Self {
+            Self { versions, ..self }
+        }
+
+        fn with_delay(self, delay: Duration) -> Self {
+            Self { delay, ..self }
+        }
+
+        fn with_package_delay(mut self, package: &'static str, delay: Duration) -> Self {
+            self.package_delays.insert(package, delay);
+            self
+        }
+
+        fn failing(self) -> Self {
+            Self { fail: true, ..self }
+        }
+
+        /// Number of lookups made for `package`
+        fn fetch_count(&self, package: &str) -> usize {
+            self.fetched
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|p| *p == package)
+                .count()
+        }
+    }
+
+    #[async_trait]
+    impl RegistryAdapter for RecordingAdapter {
+        fn language(&self) -> Language {
+            self.language
+        }
+
+        fn registry_name(&self) -> &'static str {
+            "recording"
+        }
+
+        async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+            self.fetched.lock().unwrap().push(package.to_string());
+            let delay = self.package_delays.get(package).unwrap_or(&self.delay);
+            if !delay.is_zero() {
+                tokio::time::sleep(*delay).await;
+            }
+            if self.fail {
+                return Err(RegistryError::network_error(
+                    package,
+                    "recording",
+                    "offline",
+                ));
+            }
+            Ok(self.versions.clone())
+        }
+    }
+
+    /// crates.io stand-in answering `1.0.200` after a short delay, so lookups overlap
+    fn crates_io_adapter() -> RecordingAdapter {
+        RecordingAdapter::for_language(Language::Rust)
+            .with_versions(vec![VersionInfo::now("1.0.200")])
+            .with_delay(Duration::from_millis(20))
+    }
+
+    /// Registry adapter whose lookups wait until the gate opens, and panic for `boom`
+    struct GatedAdapter {
+        gate: Arc<tokio::sync::Semaphore>,
+        completed: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl RegistryAdapter for GatedAdapter {
+        fn language(&self) -> Language {
+            Language::Node
+        }
+
+        fn registry_name(&self) -> &'static str {
+            "gated"
+        }
+
+        async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+            let _permit = self.gate.acquire().await.unwrap();
+            assert_ne!(package, "boom", "registry lookup crashed");
+            self.completed
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(vec![VersionInfo::now("2.0.0")])
+        }
+    }
+
+    fn node_dependency(name: &str) -> Dependency {
+        let spec = VersionSpec::new(VersionSpecKind::Caret, "^1.0.0", "1.0.0").with_prefix("^");
+        Dependency::production(name, spec, Language::Node)
+    }
+
     fn make_args(args: &[&str]) -> CliArgs {
         CliArgs::parse_from(args)
     }
@@ -387,8 +1546,91 @@ mod tests {
     #[test]
     fn test_orchestrator_config_default() {
         let config = OrchestratorConfig::default();
-        assert_eq!(config.general_concurrency, 10);
-        assert_eq!(config.crates_io_concurrency, 1);
+        assert_eq!(config.default_concurrency, 10);
+        assert_eq!(config.concurrency_for("crates.io"), 1);
+        assert_eq!(config.concurrency_for("registry.npmjs.org"), 10);
+    }
+
+    #[test]
+    fn test_unbounded_concurrency() {
+        assert_eq!(OrchestratorConfig::unbounded_concurrency(40), 40);
+        assert_eq!(OrchestratorConfig::unbounded_concurrency(5000), 256);
+        assert_eq!(OrchestratorConfig::unbounded_concurrency(0), 1);
+    }
+
+    #[test]
+    fn test_concurrency_zero_uses_dependency_count() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("package.json"),
+            r#"{"dependencies": {"a": "^1.0.0", "b": "^1.0.0", "c": "^1.0.0"}}"#,
+        )
+        .unwrap();
+
+        let args = make_args_with_path(dir.path(), &["--concurrency", "0"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        let manifest = detect_manifests(dir.path()).remove(0);
+        // Dependencies are counted as manifests are parsed, not up front
+        let npm = orchestrator.host_semaphore(registry_host(Language::Node));
+        assert_eq!(npm.available_permits(), 1);
+
+        orchestrator.parse_manifest(manifest.clone(), &mut Vec::new());
+        assert_eq!(npm.available_permits(), 3);
+        orchestrator.parse_manifest(manifest, &mut Vec::new());
+        assert_eq!(npm.available_permits(), 6);
+        assert_eq!(
+            orchestrator
+                .host_semaphore(registry_host(Language::Python))
+                .available_permits(),
+            6
+        );
+        // Hosts with stricter rate limits keep them
+        assert_eq!(
+            orchestrator
+                .host_semaphore(registry_host(Language::Rust))
+                .available_permits(),
+            1
+        );
+
+        let args = make_args_with_path(dir.path(), &["--concurrency", "32"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        assert_eq!(orchestrator.config.default_concurrency, 32);
+    }
+
+    #[test]
+    fn test_host_semaphore_permits() {
+        let orchestrator = Orchestrator::new(CliArgs::parse_from(["depup"])).unwrap();
+        let npm = orchestrator.host_semaphore(registry_host(Language::Node));
+        let crates_io = orchestrator.host_semaphore(registry_host(Language::Rust));
+        assert_eq!(npm.available_permits(), 10);
+        assert_eq!(crates_io.available_permits(), 1);
+
+        // The same semaphore is shared by every lookup against a host
+        assert!(Arc::ptr_eq(
+            &crates_io,
+            &orchestrator.host_semaphore("crates.io")
+        ));
+
+        let args = CliArgs::parse_from([
+            "depup",
+            "--concurrency-per-host",
+            "crates.io=2",
+            "--concurrency-per-host",
+            "registry.npmjs.org=4",
+        ]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        assert_eq!(
+            orchestrator
+                .host_semaphore(registry_host(Language::Node))
+                .available_permits(),
+            4
+        );
+        assert_eq!(
+            orchestrator
+                .host_semaphore(registry_host(Language::Rust))
+                .available_permits(),
+            2
+        );
     }
 
     #[test]
@@ -414,6 +1656,16 @@ mod tests {
         assert!(filter.should_process_language(Language::Python));
         assert!(!filter.should_process_language(Language::Rust));
         assert!(!filter.should_process_language(Language::Go));
+
+        // Every language flag reaches the filter, not just the original four
+        let args = make_args(&["depup", "--java", "--crystal", "--pre-commit"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        let filter = orchestrator.build_filter();
+
+        assert!(filter.should_process_language(Language::Java));
+        assert!(filter.should_process_language(Language::Crystal));
+        assert!(filter.should_process_language(Language::PreCommit));
+        assert!(!filter.should_process_language(Language::Node));
     }
 
     #[test]
@@ -437,6 +1689,15 @@ mod tests {
         assert!(!filter.should_process_package("react"));
     }
 
+    #[test]
+    fn test_build_filter_with_upgrade_pseudo() {
+        let args = make_args(&["depup", "--upgrade-pseudo"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        let filter = orchestrator.build_filter();
+
+        assert!(filter.upgrade_pseudo);
+    }
+
     #[test]
     fn test_build_filter_with_include_pinned() {
         let args = make_args(&["depup", "--include-pinned"]);
@@ -446,6 +1707,38 @@ mod tests {
         assert!(filter.include_pinned);
     }
 
+    #[test]
+    fn test_build_filter_with_force_complex() {
+        let args = make_args(&["depup", "--force-complex"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        assert!(orchestrator.build_filter().force_complex);
+        assert!(
+            !Orchestrator::new(make_args(&["depup"]))
+                .unwrap()
+                .build_filter()
+                .force_complex
+        );
+    }
+
+    #[test]
+    fn test_build_filter_with_only_direct() {
+        let args = make_args(&["depup", "--only-direct"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        assert!(orchestrator.build_filter().only_direct);
+    }
+
+    #[test]
+    fn test_build_filter_with_since() {
+        let args = make_args(&["depup", "--since", "2024-06-01"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        let filter = orchestrator.build_filter();
+
+        assert_eq!(
+            filter.since.map(|d| d.format("%Y-%m-%d").to_string()),
+            Some("2024-06-01".to_string())
+        );
+    }
+
     #[test]
     fn test_build_filter_with_age() {
         let args = make_args(&["depup", "--age", "2w"]);
@@ -469,6 +1762,12 @@ mod tests {
         assert!(orchestrator.should_process_language(Language::Rust));
         assert!(orchestrator.should_process_language(Language::Go));
         assert!(orchestrator.should_process_language(Language::Java));
+        // Docker base images are opt-in
+        assert!(!orchestrator.should_process_language(Language::Docker));
+
+        let args = make_args(&["depup", "--docker"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        assert!(orchestrator.should_process_language(Language::Docker));
     }
 
     #[test]
@@ -531,6 +1830,19 @@ mod tests {
         assert_eq!(adapter.language(), Language::Java);
     }
 
+    #[test]
+    fn test_get_adapter_with_mirror() {
+        let args = make_args(&[
+            "depup",
+            "--registry-mirror",
+            "node=https://mirror.example.com",
+        ]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        let adapter = orchestrator.get_adapter(Language::Node);
+        assert_eq!(adapter.language(), Language::Node);
+        assert_eq!(adapter.registry_name(), "npm");
+    }
+
     #[test]
     fn test_orchestrator_error_display() {
         let err = OrchestratorError::HttpClientError("connection failed".to_string());
@@ -556,6 +1868,49 @@ mod tests {
             message: "permission denied".to_string(),
         };
         assert!(err.to_string().contains("Failed to write"));
+
+        let err = OrchestratorError::DuplicateDependency {
+            path: "/path/to/package.json".to_string(),
+            package: "react".to_string(),
+        };
+        assert!(err.to_string().contains("Duplicate dependency react"));
+        assert!(err.is_warning());
+        assert!(!err.is_parse_error());
+
+        let err = OrchestratorError::RoundTripMismatch {
+            path: "/path/to/package.json".to_string(),
+            package: "react".to_string(),
+            raw: "^18.0.0".to_string(),
+            rendered: "18.0.0".to_string(),
+        };
+        assert!(err.to_string().contains("does not round-trip"));
+    }
+
+    #[test]
+    fn test_validate_round_trips() {
+        let parser = crate::parser::get_parser(Language::Node);
+        let well_formed: Vec<Dependency> = ["^1.2.3", "~1.2.3", ">=1.0.0", "1.2.3"]
+            .iter()
+            .map(|raw| Dependency::production("pkg", parser.parse(raw).unwrap(), Language::Node))
+            .collect();
+        assert!(validate_round_trips("package.json", &well_formed).is_empty());
+
+        // A spec whose prefix was dropped by the parser
+        let broken = VersionSpec::new(VersionSpecKind::Caret, "^1.2.3", "1.2.3");
+        let errors = validate_round_trips(
+            "package.json",
+            &[Dependency::production("lodash", broken, Language::Node)],
+        );
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            OrchestratorError::RoundTripMismatch {
+                package, rendered, ..
+            } => {
+                assert_eq!(package, "lodash");
+                assert_eq!(rendered, "1.2.3");
+            }
+            other => panic!("Expected RoundTripMismatch, got {:?}", other),
+        }
     }
 
     #[test]
@@ -639,4 +1994,1065 @@ mod tests {
         // Should have no min_age
         assert!(filter.min_age.is_none());
     }
+
+    #[tokio::test]
+    async fn test_check_dependencies_records_duration() {
+        let orchestrator = Orchestrator::new(make_args(&["depup"])).unwrap();
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        let adapter = RecordingAdapter::default();
+
+        let mut manifest_result = ManifestUpdateResult::new("package.json", Language::Node);
+        assert!(manifest_result.duration.is_none());
+
+        orchestrator
+            .check_dependencies(
+                &mut manifest_result,
+                vec![node_dependency("react")],
+                &adapter,
+                &judge,
+                None,
+                &mut Progress::disabled(),
+                &mut Vec::new(),
+            )
+            .await;
+
+        assert_eq!(manifest_result.update_count(), 1);
+        let duration = manifest_result
+            .duration
+            .expect("duration should be recorded");
+        assert!(duration < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_check_dependencies_docker_keeps_variant() {
+        let orchestrator = Orchestrator::new(make_args(&["depup"])).unwrap();
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        let spec = crate::parser::get_parser(Language::Docker)
+            .parse("20.10.0-alpine")
+            .unwrap();
+        let dep = Dependency::production("node", spec, Language::Docker);
+        let adapter = RecordingAdapter::for_language(Language::Docker).with_versions(
+            ["20.11.1-alpine", "20.11.1", "22-alpine", "22.1.0-slim"]
+                .into_iter()
+                .map(VersionInfo::now)
+                .collect(),
+        );
+
+        let mut manifest_result = ManifestUpdateResult::new("Dockerfile", Language::Docker);
+        orchestrator
+            .check_dependencies(
+                &mut manifest_result,
+                vec![dep],
+                &adapter,
+                &judge,
+                None,
+                &mut Progress::disabled(),
+                &mut Vec::new(),
+            )
+            .await;
+
+        // Only `-alpine` tags with a full version are candidates
+        match &manifest_result.results[0] {
+            UpdateResult::Update { new_version, .. } => assert_eq!(new_version, "20.11.1"),
+            other => panic!("expected an update, got {:?}", other),
+        }
+    }
+
+    /// Writer that records how many bytes had been written at each flush
+    #[derive(Default)]
+    struct FlushRecorder {
+        buffer: Vec<u8>,
+        flush_points: Vec<usize>,
+    }
+
+    impl std::io::Write for FlushRecorder {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.buffer.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.flush_points.push(self.buffer.len());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_streaming_flushes_per_manifest() {
+        use crate::output::{JsonFormatter, OutputFormatter, Verbosity};
+        use std::io::Write;
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("pnpm-workspace.yaml"),
+            "packages:\n  - 'packages/*'\n",
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("package.json"), r#"{"name": "root"}"#).unwrap();
+        for i in 0..40 {
+            let dir = temp_dir
+                .path()
+                .join("packages")
+                .join(format!("pkg-{:02}", i));
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join("package.json"),
+                format!(r#"{{"dependencies": {{"dep-{:02}": "^1.0.0"}}}}"#, i),
+            )
+            .unwrap();
+        }
+
+        // Every registry lookup waits until the first manifest has been emitted
+        let gate = Arc::new(tokio::sync::Semaphore::new(0));
+        let completed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let adapter = GatedAdapter {
+            gate: Arc::clone(&gate),
+            completed: Arc::clone(&completed),
+        };
+        let args = make_args_with_path(temp_dir.path(), &["--dry-run", "--json"]);
+        let orchestrator = Arc::new(
+            Orchestrator::new(args)
+                .unwrap()
+                .with_adapter(Arc::new(adapter)),
+        );
+        let formatter = JsonFormatter::new(Verbosity::Normal).with_json_lines(true);
+        let mut recorder = FlushRecorder::default();
+        let mut pending_at_first_line = None;
+
+        // Holding every line back until the end would never open the gate
+        let streaming = orchestrator.run_streaming(4, |manifest, _| {
+            formatter.format_manifest(manifest, &mut recorder).unwrap();
+            recorder.flush().unwrap();
+            if pending_at_first_line.is_none() {
+                pending_at_first_line = Some(completed.load(std::sync::atomic::Ordering::SeqCst));
+                gate.add_permits(tokio::sync::Semaphore::MAX_PERMITS / 2);
+            }
+        });
+        let errors = tokio::time::timeout(Duration::from_secs(10), streaming)
+            .await
+            .expect("first manifest was not emitted while lookups were pending");
+
+        assert!(errors.is_empty());
+        // The root manifest was emitted before any package manifest finished its lookup
+        assert_eq!(pending_at_first_line, Some(0));
+        assert_eq!(completed.load(std::sync::atomic::Ordering::SeqCst), 40);
+        // One flush per manifest, each after exactly one more JSON line
+        assert_eq!(recorder.flush_points.len(), 41);
+        let output = String::from_utf8(recorder.buffer.clone()).unwrap();
+        for (i, point) in recorder.flush_points.iter().enumerate() {
+            assert_eq!(output[..*point].lines().count(), i + 1);
+        }
+
+        // Manifests are emitted in detection order
+        let paths: Vec<String> = output
+            .lines()
+            .map(|line| {
+                let manifest: serde_json::Value = serde_json::from_str(line).unwrap();
+                manifest["path"].as_str().unwrap().to_string()
+            })
+            .collect();
+        let detected: Vec<String> = detect_manifests(temp_dir.path())
+            .iter()
+            .map(|m| m.path.display().to_string())
+            .collect();
+        assert_eq!(paths, detected);
+    }
+
+    #[tokio::test]
+    async fn test_run_streaming_bounds_buffered_manifests() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("pnpm-workspace.yaml"),
+            "packages:\n  - 'packages/*'\n",
+        )
+        .unwrap();
+        for i in 0..10 {
+            let dir = temp_dir.path().join("packages").join(format!("pkg-{}", i));
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join("package.json"),
+                format!(r#"{{"dependencies": {{"fast-{}": "^1.0.0"}}}}"#, i),
+            )
+            .unwrap();
+        }
+        // The first manifest holds up the output; the rest finish right away
+        let first = detect_manifests(temp_dir.path())[0].path.clone();
+        std::fs::write(first, r#"{"dependencies": {"slow-0": "^1.0.0"}}"#).unwrap();
+        let adapter =
+            RecordingAdapter::default().with_package_delay("slow-0", Duration::from_millis(400));
+        let args = make_args_with_path(temp_dir.path(), &["--dry-run"]);
+        let orchestrator = Arc::new(
+            Orchestrator::new(args)
+                .unwrap()
+                .with_adapter(Arc::new(adapter)),
+        );
+        let mut emitted = 0;
+
+        let streaming = Arc::clone(&orchestrator).run_streaming(3, |_, _| emitted += 1);
+        let started_while_blocked = async {
+            tokio::time::sleep(Duration::from_millis(150)).await;
+            orchestrator.version_cache.lock().unwrap().len()
+        };
+        let (errors, started) = tokio::join!(streaming, started_while_blocked);
+
+        assert!(errors.is_empty());
+        // The slow manifest plus two finished ones waiting behind it
+        assert_eq!(started, 3);
+        assert_eq!(emitted, 10);
+    }
+
+    #[tokio::test]
+    async fn test_run_streaming_reports_failed_manifest_task() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("pnpm-workspace.yaml"),
+            "packages:\n  - 'packages/*'\n",
+        )
+        .unwrap();
+        for (name, dep) in [("a", "left-pad"), ("b", "boom"), ("c", "right-pad")] {
+            let dir = temp_dir.path().join("packages").join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join("package.json"),
+                format!(r#"{{"dependencies": {{"{}": "^1.0.0"}}}}"#, dep),
+            )
+            .unwrap();
+        }
+
+        let adapter = GatedAdapter {
+            gate: Arc::new(tokio::sync::Semaphore::new(100)),
+            completed: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        };
+        let args = make_args_with_path(temp_dir.path(), &["--dry-run"]);
+        let orchestrator = Arc::new(
+            Orchestrator::new(args)
+                .unwrap()
+                .with_adapter(Arc::new(adapter)),
+        );
+        let mut emitted = Vec::new();
+
+        let errors = orchestrator
+            .run_streaming(2, |manifest, _| {
+                emitted.push(manifest.path.display().to_string())
+            })
+            .await;
+
+        // The crashed manifest is reported and the ones after it are still emitted
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            OrchestratorError::ManifestTaskFailed { path, .. } if path.ends_with("b/package.json")
+        ));
+        let others: Vec<String> = detect_manifests(temp_dir.path())
+            .iter()
+            .map(|m| m.path.display().to_string())
+            .filter(|path| !path.ends_with("b/package.json"))
+            .collect();
+        assert_eq!(others.len(), 2);
+        assert_eq!(emitted, others);
+    }
+
+    #[tokio::test]
+    async fn test_check_dependencies_deadline_exceeded() {
+        let args = make_args(&["depup"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        let adapter = RecordingAdapter::default().with_delay(Duration::from_millis(40));
+        let dependencies: Vec<Dependency> = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|name| node_dependency(name))
+            .collect();
+
+        let mut manifest_result = ManifestUpdateResult::new("package.json", Language::Node);
+        let mut progress = Progress::disabled();
+        let mut errors = Vec::new();
+        let deadline = Instant::now() + Duration::from_millis(100);
+
+        orchestrator
+            .check_dependencies(
+                &mut manifest_result,
+                dependencies,
+                &adapter,
+                &judge,
+                Some(deadline),
+                &mut progress,
+                &mut errors,
+            )
+            .await;
+
+        // Every dependency gets a result, and the late ones are deadline-skipped
+        assert_eq!(manifest_result.results.len(), 5);
+        assert!(manifest_result.update_count() >= 1);
+        let deadline_skips = manifest_result
+            .skips()
+            .filter(|r| {
+                matches!(
+                    r,
+                    UpdateResult::Skip {
+                        reason: SkipReason::FetchFailed(msg),
+                        ..
+                    } if msg == DEADLINE_EXCEEDED
+                )
+            })
+            .count();
+        assert!(deadline_skips >= 1);
+        assert!(errors.is_empty());
+    }
+
+    /// Release notes source returning a canned GitHub API response
+    struct MockReleases {
+        response: &'static str,
+        rate_limited: bool,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ReleaseNotesSource for MockReleases {
+        async fn fetch_releases(
+            &self,
+            _owner: &str,
+            _repo: &str,
+        ) -> Result<Vec<GitHubRelease>, RegistryError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if self.rate_limited {
+                return Err(RegistryError::rate_limit_exceeded("GitHub"));
+            }
+            Ok(serde_json::from_str(self.response).unwrap())
+        }
+    }
+
+    fn go_update_manifest(modules: &[&str]) -> ManifestUpdateResult {
+        let mut manifest = ManifestUpdateResult::new("go.mod", Language::Go);
+        for module in modules {
+            let spec = VersionSpec::new(VersionSpecKind::Exact, "v1.9.1", "v1.9.1");
+            let dep = Dependency::production(*module, spec, Language::Go);
+            manifest.add_result(UpdateResult::update(dep, "v1.10.0"));
+        }
+        manifest
+    }
+
+    #[tokio::test]
+    async fn test_attach_release_notes() {
+        let source = MockReleases {
+            response: r#"[
+                {"tag_name": "v1.10.0", "body": "Add router groups", "draft": false},
+                {"tag_name": "v1.9.1", "body": "Old release", "draft": false}
+            ]"#,
+            rate_limited: false,
+            calls: Default::default(),
+        };
+        let mut manifests = vec![go_update_manifest(&[
+            "github.com/gin-gonic/gin",
+            "golang.org/x/net",
+        ])];
+
+        attach_release_notes(&mut manifests, &source).await;
+
+        let results = &manifests[0].results;
+        assert_eq!(
+            results[0].release_notes(),
+            Some("## v1.10.0\n\nAdd router groups")
+        );
+        assert!(results[1].release_notes().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_attach_release_notes_skips_unknown_repository() {
+        let source = MockReleases {
+            response: r#"[{"tag_name": "v2.0.0", "body": "Rewrite", "draft": false}]"#,
+            rate_limited: false,
+            calls: Default::default(),
+        };
+        let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+        let spec = VersionSpec::new(VersionSpecKind::Caret, "^1.0.0", "1.0.0").with_prefix("^");
+        let dep = Dependency::production("github.com/foo/bar", spec, Language::Node);
+        manifest.add_result(UpdateResult::update(dep, "2.0.0"));
+        let mut manifests = vec![manifest];
+
+        attach_release_notes(&mut manifests, &source).await;
+
+        // npm package names don't identify a repository, so nothing is looked up
+        assert_eq!(source.calls.load(std::sync::atomic::Ordering::SeqCst), 0);
+        assert!(manifests[0].results[0].release_notes().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_attach_release_notes_rate_limited() {
+        let source = MockReleases {
+            response: "[]",
+            rate_limited: true,
+            calls: Default::default(),
+        };
+        let mut manifests = vec![go_update_manifest(&[
+            "github.com/gin-gonic/gin",
+            "github.com/stretchr/testify",
+        ])];
+
+        attach_release_notes(&mut manifests, &source).await;
+
+        // Rate limiting stops further lookups without failing the run
+        assert_eq!(source.calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(manifests[0]
+            .results
+            .iter()
+            .all(|r| r.release_notes().is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_package_fast_path_fetches_only_named_package() {
+        let args = make_args(&["depup", "--package", "lodash"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        let adapter = RecordingAdapter::default();
+        let dependencies = orchestrator.select_package(
+            ["express", "lodash", "react"]
+                .iter()
+                .map(|name| node_dependency(name))
+                .collect(),
+        );
+
+        let mut manifest_result = ManifestUpdateResult::new("package.json", Language::Node);
+        let mut progress = Progress::disabled();
+        let mut errors = Vec::new();
+
+        orchestrator
+            .check_dependencies(
+                &mut manifest_result,
+                dependencies,
+                &adapter,
+                &judge,
+                None,
+                &mut progress,
+                &mut errors,
+            )
+            .await;
+
+        assert_eq!(*adapter.fetched.lock().unwrap(), vec!["lodash"]);
+        assert_eq!(manifest_result.results.len(), 1);
+        assert_eq!(manifest_result.results[0].package_name(), "lodash");
+    }
+
+    /// Advisory source answering with a canned OSV response for one package
+    struct MockAdvisories {
+        package: &'static str,
+        response: &'static str,
+    }
+
+    #[async_trait]
+    impl AdvisorySource for MockAdvisories {
+        async fn query(&self, dependency: &Dependency) -> Result<Vec<Advisory>, RegistryError> {
+            if dependency.name != self.package {
+                return Ok(Vec::new());
+            }
+            let response: OsvResponse = serde_json::from_str(self.response).unwrap();
+            Ok(response.into_advisories(&dependency.name))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_vulnerable_skips_uncovered_ecosystem() {
+        let orchestrator = Orchestrator::new(make_args(&["depup", "--only-vulnerable"])).unwrap();
+        let source = OsvClient::new(HttpClient::new().unwrap());
+        let spec = crate::parser::get_parser(Language::Docker)
+            .parse("20.10.0-alpine")
+            .unwrap();
+        let dep = Dependency::production("node", spec, Language::Docker);
+
+        // Reported as unchecked, not as free of vulnerabilities
+        assert_eq!(
+            orchestrator.fixed_version(&source, &dep).await,
+            Err(SkipReason::NoAdvisoryDatabase)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_only_vulnerable_updates_to_lowest_fix() {
+        let args = make_args(&["depup", "--only-vulnerable"]);
+        let orchestrator =
+            Orchestrator::new(args)
+                .unwrap()
+                .with_advisory_source(Box::new(MockAdvisories {
+                    package: "lodash",
+                    response: r#"{"vulns": [{
+                        "id": "GHSA-test-0001",
+                        "summary": "Prototype Pollution",
+                        "affected": [{
+                            "package": {"name": "lodash", "ecosystem": "npm"},
+                            "ranges": [{"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "1.2.0"}]}]
+                        }]
+                    }]}"#,
+                }));
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        let adapter = RecordingAdapter::default().with_versions(
+            ["1.0.0", "1.1.0", "1.2.0", "1.3.0", "2.0.0"]
+                .into_iter()
+                .map(VersionInfo::now)
+                .collect(),
+        );
+        let dependencies = vec![node_dependency("express"), node_dependency("lodash")];
+
+        let mut manifest_result = ManifestUpdateResult::new("package.json", Language::Node);
+        let mut progress = Progress::disabled();
+        let mut errors = Vec::new();
+
+        orchestrator
+            .check_dependencies(
+                &mut manifest_result,
+                dependencies,
+                &adapter,
+                &judge,
+                None,
+                &mut progress,
+                &mut errors,
+            )
+            .await;
+
+        // Unaffected packages are skipped without a registry lookup
+        assert_eq!(*adapter.fetched.lock().unwrap(), vec!["lodash"]);
+        assert!(matches!(
+            &manifest_result.results[0],
+            UpdateResult::Skip {
+                reason: SkipReason::NotVulnerable,
+                ..
+            }
+        ));
+        match &manifest_result.results[1] {
+            UpdateResult::Update { new_version, .. } => assert_eq!(new_version, "1.2.0"),
+            other => panic!("Expected update, got {:?}", other),
+        }
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_select_package_without_flag_keeps_all() {
+        let orchestrator = Orchestrator::new(make_args(&["depup"])).unwrap();
+        let dependencies = vec![node_dependency("express"), node_dependency("lodash")];
+        assert_eq!(orchestrator.select_package(dependencies).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_shared_dependency_fetched_once_across_manifests() {
+        let orchestrator = Orchestrator::new(make_args(&["depup"])).unwrap();
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        let adapter = RecordingAdapter::default();
+        let mut progress = Progress::disabled();
+        let mut errors = Vec::new();
+
+        // Two manifests of the same language declaring an overlapping package
+        let mut results = Vec::new();
+        for (path, names) in [
+            ("package.json", ["lodash", "express"]),
+            ("apps/web/package.json", ["lodash", "react"]),
+        ] {
+            let mut manifest_result = ManifestUpdateResult::new(path, Language::Node);
+            orchestrator
+                .check_dependencies(
+                    &mut manifest_result,
+                    names.iter().map(|name| node_dependency(name)).collect(),
+                    &adapter,
+                    &judge,
+                    None,
+                    &mut progress,
+                    &mut errors,
+                )
+                .await;
+            results.push(manifest_result);
+        }
+
+        // Both manifests are processed independently
+        assert_eq!(results[0].update_count(), 2);
+        assert_eq!(results[1].update_count(), 2);
+
+        // ...but the shared package is only looked up once
+        let fetched = adapter.fetched.lock().unwrap();
+        assert_eq!(fetched.iter().filter(|p| *p == "lodash").count(), 1);
+        assert_eq!(fetched.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_yarnrc_scope_registry_routing() {
+        use std::io::{Read, Write};
+
+        // Private registry that only knows the company's scoped package
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let registry_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let (status, body) = if request.starts_with("GET /@mycompany%2Fui ") {
+                    (
+                        "200 OK",
+                        r#"{"dist-tags": {"latest": "1.2.0"},
+                            "versions": {"1.0.0": {}, "1.2.0": {}},
+                            "time": {"1.0.0": "2024-01-01T00:00:00Z", "1.2.0": "2024-02-01T00:00:00Z"}}"#,
+                    )
+                } else {
+                    ("404 Not Found", "{}")
+                };
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(".yarnrc.yml"),
+            format!(
+                "npmScopes:\n  mycompany:\n    npmRegistryServer: \"{}\"\n",
+                registry_url
+            ),
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("package.json"),
+            r#"{"dependencies": {"@mycompany/ui": "^1.0.0"}}"#,
+        )
+        .unwrap();
+
+        let orchestrator =
+            Orchestrator::new(make_args_with_path(dir.path(), &["--dry-run"])).unwrap();
+        let result = orchestrator.run_with_progress(false).await;
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let update = result.summary.all_updates().next().unwrap();
+        assert!(matches!(
+            update,
+            UpdateResult::Update { new_version, .. } if new_version == "1.2.0"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_npm_tag_followed_from_stable_version() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let registry_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf);
+                let body = r#"{"dist-tags": {"latest": "18.2.0", "next": "19.0.0-rc.1"},
+                    "versions": {"18.2.0": {}, "19.0.0-canary.5": {}, "19.0.0-rc.1": {}, "19.1.0-canary.1": {}},
+                    "time": {"18.2.0": "2022-06-14T00:00:00Z", "19.0.0-canary.5": "2024-03-01T00:00:00Z",
+                             "19.0.0-rc.1": "2024-04-25T00:00:00Z", "19.1.0-canary.1": "2024-05-01T00:00:00Z"}}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(".yarnrc.yml"),
+            format!("npmRegistryServer: \"{}\"\n", registry_url),
+        )
+        .unwrap();
+        // The current version is stable, yet the `next` tag still decides the target
+        fs::write(
+            dir.path().join("package.json"),
+            r#"{"dependencies": {"react": "^18.2.0"}}"#,
+        )
+        .unwrap();
+
+        let orchestrator = Orchestrator::new(make_args_with_path(
+            dir.path(),
+            &["--dry-run", "--npm-tag", "next"],
+        ))
+        .unwrap();
+        let result = orchestrator.run_with_progress(false).await;
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let update = result.summary.all_updates().next().unwrap();
+        assert!(matches!(
+            update,
+            UpdateResult::Update { new_version, .. } if new_version == "19.0.0-rc.1"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_slow_lookups_reported() {
+        let args = make_args(&["depup", "--verbose", "--slow-threshold", "100"]);
+        let orchestrator = Orchestrator::new(args).unwrap();
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        let adapter = RecordingAdapter::default()
+            .with_package_delay("sluggish", Duration::from_millis(150))
+            .with_package_delay("glacial", Duration::from_millis(300));
+        let mut manifest_result = ManifestUpdateResult::new("package.json", Language::Node);
+
+        orchestrator
+            .check_dependencies(
+                &mut manifest_result,
+                ["lodash", "sluggish", "express", "glacial"]
+                    .iter()
+                    .map(|name| node_dependency(name))
+                    .collect(),
+                &adapter,
+                &judge,
+                None,
+                &mut Progress::disabled(),
+                &mut Vec::new(),
+            )
+            .await;
+
+        // Slowest first; fast lookups aren't listed
+        let slow = orchestrator.slow_lookups();
+        let names: Vec<&str> = slow.iter().map(|l| l.package.as_str()).collect();
+        assert_eq!(names, vec!["glacial", "sluggish"]);
+        assert!(slow[0].duration >= Duration::from_millis(300));
+        assert_eq!(slow[0].language, Language::Node);
+
+        // Without --slow-threshold lookups aren't recorded at all
+        let orchestrator = Orchestrator::new(make_args(&["depup"])).unwrap();
+        orchestrator
+            .fetch_versions(&adapter, "glacial")
+            .await
+            .unwrap();
+        assert!(orchestrator.slow_lookups.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_python_version_file_limits_updates() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join(".python-version"), "3.9\n").unwrap();
+        std::fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.poetry.dependencies]\nrequests = \"^1.0.0\"\n",
+        )
+        .unwrap();
+
+        let orchestrator = Orchestrator::new(make_args_with_path(dir.path(), &["--dry-run"]))
+            .unwrap()
+            .with_adapter(Arc::new(
+                RecordingAdapter::for_language(Language::Python).with_versions(vec![
+                    // The newest release needs Python 3.11
+                    VersionInfo::now("2.0.0").with_requires_python(">=3.8"),
+                    VersionInfo::now("3.0.0").with_requires_python(">=3.11"),
+                ]),
+            ));
+        let result = orchestrator.run_with_progress(false).await;
+
+        // 3.0.0 requires Python 3.11, but the project selects 3.9
+        let updates: Vec<&str> = result
+            .summary
+            .all_updates()
+            .filter_map(|update| match update {
+                UpdateResult::Update { new_version, .. } => Some(new_version.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(updates, vec!["2.0.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_events() {
+        let dir = TempDir::new().unwrap();
+        let manifest = dir.path().join("package.json");
+        std::fs::write(
+            &manifest,
+            r#"{"dependencies": {"lodash": "^1.0.0", "express": "^1.0.0"}}"#,
+        )
+        .unwrap();
+
+        let orchestrator = Orchestrator::new(make_args_with_path(dir.path(), &[]))
+            .unwrap()
+            .with_adapter(Arc::new(RecordingAdapter::default()));
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let result = orchestrator.run_with_events(tx).await;
+        assert_eq!(result.summary.total_updates(), 2);
+
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+
+        assert_eq!(events.len(), 4);
+        assert_eq!(
+            events[0],
+            ProgressEvent::ManifestDetected {
+                path: manifest.clone(),
+                language: Language::Node,
+            }
+        );
+        let checked: Vec<&str> = events[1..3]
+            .iter()
+            .map(|event| match event {
+                ProgressEvent::DependencyChecked { name, result } => {
+                    assert!(result.is_update());
+                    name.as_str()
+                }
+                other => panic!("unexpected event: {:?}", other),
+            })
+            .collect();
+        assert_eq!(checked, vec!["express", "lodash"]);
+        assert_eq!(events[3], ProgressEvent::WriteApplied { path: manifest });
+    }
+
+    #[tokio::test]
+    async fn test_run_with_manifest_list() {
+        let dir = TempDir::new().unwrap();
+        let mut paths = Vec::new();
+        for name in ["a", "b", "c"] {
+            let package_dir = dir.path().join(name);
+            std::fs::create_dir(&package_dir).unwrap();
+            let manifest = package_dir.join("package.json");
+            std::fs::write(&manifest, r#"{"dependencies": {"lodash": "^1.0.0"}}"#).unwrap();
+            paths.push(manifest);
+        }
+        // A manifest at the target path would be detected without the list
+        std::fs::write(
+            dir.path().join("package.json"),
+            r#"{"dependencies": {"express": "^1.0.0"}}"#,
+        )
+        .unwrap();
+
+        let list = format!("{}\n{}\n", paths[0].display(), paths[2].display());
+        let (manifests, unsupported) = crate::manifest::manifests_from_list(&list);
+        assert!(unsupported.is_empty());
+
+        let orchestrator = Orchestrator::new(make_args_with_path(dir.path(), &["--dry-run"]))
+            .unwrap()
+            .with_adapter(Arc::new(RecordingAdapter::default()))
+            .with_manifests(manifests);
+        let result = orchestrator.run_with_progress(false).await;
+
+        let processed: Vec<&std::path::Path> = result
+            .summary
+            .manifests
+            .iter()
+            .map(|m| m.path.as_path())
+            .collect();
+        assert_eq!(processed, vec![paths[0].as_path(), paths[2].as_path()]);
+        assert_eq!(result.summary.total_updates(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_latest_seen() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("package.json"),
+            r#"{"dependencies": {"lodash": "^1.0.0"}}"#,
+        )
+        .unwrap();
+
+        let orchestrator = Orchestrator::new(make_args_with_path(dir.path(), &["--dry-run"]))
+            .unwrap()
+            .with_adapter(Arc::new(RecordingAdapter::default()));
+        assert_eq!(orchestrator.latest_seen(Language::Node, "lodash"), None);
+
+        orchestrator.run_with_progress(false).await;
+        assert_eq!(
+            orchestrator
+                .latest_seen(Language::Node, "lodash")
+                .as_deref(),
+            Some("2.0.0")
+        );
+        assert_eq!(orchestrator.latest_seen(Language::Node, "express"), None);
+    }
+
+    #[tokio::test]
+    async fn test_check_only_installed() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("package.json"),
+            r#"{
+  "dependencies": {"lodash": "^1.0.0"},
+  "optionalDependencies": {"fsevents": "^1.0.0"}
+}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("package-lock.json"),
+            r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": {"dependencies": {"lodash": "^1.0.0"}},
+    "node_modules/lodash": {"version": "1.0.0"}
+  }
+}"#,
+        )
+        .unwrap();
+
+        let adapter = Arc::new(RecordingAdapter::default());
+        let orchestrator = Orchestrator::new(make_args_with_path(
+            dir.path(),
+            &["--dry-run", "--check-only-installed"],
+        ))
+        .unwrap()
+        .with_adapter(adapter.clone());
+        let result = orchestrator.run_with_progress(false).await;
+
+        let checked: Vec<&str> = result.summary.manifests[0]
+            .results
+            .iter()
+            .map(|r| r.package_name())
+            .collect();
+        assert_eq!(checked, vec!["lodash"]);
+        assert_eq!(*adapter.fetched.lock().unwrap(), vec!["lodash".to_string()]);
+
+        // Without the flag the optional dependency is checked too
+        let orchestrator = Orchestrator::new(make_args_with_path(dir.path(), &["--dry-run"]))
+            .unwrap()
+            .with_adapter(Arc::new(RecordingAdapter::default()));
+        let result = orchestrator.run_with_progress(false).await;
+        assert_eq!(result.summary.manifests[0].results.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_manifest_with_invalid_utf8() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("package.json");
+        std::fs::write(
+            &path,
+            b"{\"description\": \"caf\xe9\", \"dependencies\": {\"lodash\": \"^1.0.0\"}}",
+        )
+        .unwrap();
+
+        let adapter = Arc::new(RecordingAdapter::default());
+        let orchestrator = Orchestrator::new(make_args_with_path(dir.path(), &["--dry-run"]))
+            .unwrap()
+            .with_adapter(adapter.clone());
+        let result = orchestrator.run_with_progress(false).await;
+
+        // Checked from a lossy copy, but the update is never written back
+        assert_eq!(result.summary.manifests[0].results.len(), 1);
+        assert!(result
+            .errors
+            .iter()
+            .any(|e| e.to_string().contains("is not valid UTF-8")));
+
+        // Invalid bytes that break the syntax are reported as an encoding problem
+        std::fs::write(&path, b"{\"dependencies\": {\"lodash\xff\": }").unwrap();
+        let orchestrator = Orchestrator::new(make_args_with_path(dir.path(), &["--dry-run"]))
+            .unwrap()
+            .with_adapter(Arc::new(RecordingAdapter::default()));
+        let result = orchestrator.run_with_progress(false).await;
+        assert!(result.errors[0].is_parse_error());
+        assert!(result.errors[0].to_string().contains("is not valid UTF-8"));
+    }
+
+    #[tokio::test]
+    async fn test_package_json_depup_exclude() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("package.json"),
+            r#"{
+  "dependencies": {"react": "^1.0.0", "lodash": "^1.0.0"},
+  "depup": {"exclude": ["react"]}
+}"#,
+        )
+        .unwrap();
+
+        let adapter = Arc::new(RecordingAdapter::default());
+        let orchestrator = Orchestrator::new(make_args_with_path(dir.path(), &["--dry-run"]))
+            .unwrap()
+            .with_adapter(adapter.clone());
+        let result = orchestrator.run_with_progress(false).await;
+
+        let react = result.summary.manifests[0]
+            .results
+            .iter()
+            .find(|r| r.package_name() == "react")
+            .unwrap();
+        assert!(matches!(
+            react,
+            UpdateResult::Skip {
+                reason: SkipReason::Excluded,
+                ..
+            }
+        ));
+        assert_eq!(*adapter.fetched.lock().unwrap(), vec!["lodash".to_string()]);
+    }
+
+    #[test]
+    fn test_package_json_depup_invalid_age() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("package.json"),
+            r#"{"depup": {"age": "a week"}}"#,
+        )
+        .unwrap();
+
+        let Err(err) = Orchestrator::new(make_args_with_path(dir.path(), &[])) else {
+            panic!("invalid age was accepted");
+        };
+        assert!(matches!(err, OrchestratorError::InvalidConfig { .. }));
+        assert!(err
+            .to_string()
+            .contains("\"age\": invalid duration 'a week'"));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_fetches_of_same_package_share_one_request() {
+        let orchestrator = Orchestrator::new(make_args(&["depup"])).unwrap();
+        let adapter = crates_io_adapter();
+
+        let (a, b, c) = tokio::join!(
+            orchestrator.fetch_versions(&adapter, "serde"),
+            orchestrator.fetch_versions(&adapter, "serde"),
+            orchestrator.fetch_versions(&adapter, "tokio"),
+        );
+        assert_eq!(a.unwrap()[0].version, "1.0.200");
+        assert!(b.is_ok());
+        assert!(c.is_ok());
+
+        // A later lookup in the same run is served from the cache
+        orchestrator
+            .fetch_versions(&adapter, "serde")
+            .await
+            .unwrap();
+
+        assert_eq!(adapter.fetch_count("serde"), 1);
+        assert_eq!(adapter.fetch_count("tokio"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_records_mirror_that_served_versions() {
+        let orchestrator = Orchestrator::new(make_args(&[
+            "depup",
+            "--registry-mirror",
+            "rust=https://mirror.example.com",
+        ]))
+        .unwrap();
+        let adapter = MirroredAdapter::new(vec![
+            Box::new(crates_io_adapter().failing()),
+            Box::new(crates_io_adapter()),
+        ]);
+
+        orchestrator
+            .fetch_versions(&adapter, "serde")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            orchestrator.mirror_for(Language::Rust, "serde").as_deref(),
+            Some("https://mirror.example.com")
+        );
+        assert_eq!(orchestrator.mirror_for(Language::Rust, "tokio"), None);
+    }
+
+    #[tokio::test]
+    async fn test_failed_fetch_is_not_cached() {
+        let orchestrator = Orchestrator::new(make_args(&["depup"])).unwrap();
+        let adapter = crates_io_adapter().failing();
+
+        assert!(orchestrator
+            .fetch_versions(&adapter, "serde")
+            .await
+            .is_err());
+        assert!(orchestrator
+            .fetch_versions(&adapter, "serde")
+            .await
+            .is_err());
+        assert_eq!(adapter.fetch_count("serde"), 2);
+    }
 }
@@ -0,0 +1,184 @@
+//! Audit record of every dependency decision (--audit)
+//!
+//! This module provides:
+//! - Tool version and run timestamp for compliance records
+//! - One entry per examined dependency: the current version, the highest version
+//!   the registry returned, and the version chosen or the reason for skipping
+
+use crate::domain::{Dependency, UpdateResult};
+use crate::orchestrator::OrchestratorResult;
+use crate::output::JsonFormatter;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::io::Write;
+
+/// Audit file contents
+#[derive(Serialize)]
+struct AuditRecord<'a> {
+    /// Tool name
+    tool: &'static str,
+    /// Tool version
+    version: &'static str,
+    /// When the audit was written (RFC 3339)
+    timestamp: String,
+    /// Whether this was a dry-run
+    dry_run: bool,
+    /// Every dependency examined, in manifest order
+    dependencies: Vec<AuditEntry<'a>>,
+}
+
+/// Decision for one dependency
+#[derive(Serialize)]
+struct AuditEntry<'a> {
+    /// Manifest declaring the dependency
+    manifest: String,
+    /// Package name
+    name: &'a str,
+    /// Language of the manifest
+    language: &'static str,
+    /// Version in the manifest
+    current: &'a str,
+    /// Highest version the registry returned, if it was looked up
+    latest_seen: Option<String>,
+    /// "update" or "skip"
+    action: &'static str,
+    /// Version chosen for the update
+    chosen: Option<&'a str>,
+    /// Why the dependency was not updated
+    skip_reason: Option<String>,
+    /// When the chosen version was released
+    released_at: Option<DateTime<Utc>>,
+}
+
+/// Write the audit record for a run as pretty-printed JSON
+///
+/// `latest_seen` gives the highest registry version seen for a dependency
+/// (see `Orchestrator::latest_seen`).
+pub fn write_audit(
+    result: &OrchestratorResult,
+    latest_seen: impl Fn(&Dependency) -> Option<String>,
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    let dependencies = result
+        .summary
+        .manifests
+        .iter()
+        .flat_map(|manifest| {
+            manifest.results.iter().map(|r| {
+                let dependency = r.dependency();
+                let mut entry = AuditEntry {
+                    manifest: manifest.path.display().to_string(),
+                    name: &dependency.name,
+                    language: manifest.language.display_name(),
+                    current: dependency.version(),
+                    latest_seen: latest_seen(dependency),
+                    action: "skip",
+                    chosen: None,
+                    skip_reason: None,
+                    released_at: None,
+                };
+                match r {
+                    UpdateResult::Update {
+                        new_version,
+                        released_at,
+                        ..
+                    } => {
+                        entry.action = "update";
+                        entry.chosen = Some(new_version);
+                        entry.released_at = *released_at;
+                    }
+                    UpdateResult::Skip { reason, .. } => {
+                        entry.skip_reason = Some(JsonFormatter::skip_reason_to_string(reason));
+                    }
+                }
+                entry
+            })
+        })
+        .collect();
+
+    let record = AuditRecord {
+        tool: env!("CARGO_PKG_NAME"),
+        version: env!("CARGO_PKG_VERSION"),
+        timestamp: Utc::now().to_rfc3339(),
+        dry_run: result.summary.dry_run,
+        dependencies,
+    };
+    serde_json::to_writer_pretty(&mut *writer, &record)?;
+    writeln!(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        Language, ManifestUpdateResult, SkipReason, UpdateSummary, VersionSpec, VersionSpecKind,
+    };
+
+    fn dependency(name: &str, version: &str) -> Dependency {
+        let spec = VersionSpec::new(VersionSpecKind::Caret, format!("^{}", version), version)
+            .with_prefix("^");
+        Dependency::production(name, spec, Language::Node)
+    }
+
+    #[test]
+    fn test_write_audit_entry_per_dependency() {
+        let released_at = "2024-01-15T10:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+        manifest.add_result(UpdateResult::update_with_date(
+            dependency("lodash", "4.17.20"),
+            "4.17.21",
+            released_at,
+        ));
+        manifest.add_result(UpdateResult::skip(
+            dependency("react", "18.2.0"),
+            SkipReason::AlreadyLatest,
+        ));
+        manifest.add_result(UpdateResult::skip(
+            dependency("private-pkg", "1.0.0"),
+            SkipReason::FetchFailed("404".to_string()),
+        ));
+        let mut summary = UpdateSummary::new(true);
+        summary.add_manifest(manifest);
+        let result = OrchestratorResult {
+            summary,
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        let mut output = Vec::new();
+        write_audit(
+            &result,
+            |dep| (dep.name != "private-pkg").then(|| format!("{}-latest", dep.name)),
+            &mut output,
+        )
+        .unwrap();
+        let audit: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+        assert_eq!(audit["tool"], "depup");
+        assert_eq!(audit["version"], env!("CARGO_PKG_VERSION"));
+        assert!(audit["timestamp"]
+            .as_str()
+            .unwrap()
+            .parse::<DateTime<Utc>>()
+            .is_ok());
+        assert_eq!(audit["dry_run"], true);
+
+        let entries = audit["dependencies"].as_array().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0]["name"], "lodash");
+        assert_eq!(entries[0]["manifest"], "package.json");
+        assert_eq!(entries[0]["language"], "Node.js");
+        assert_eq!(entries[0]["current"], "4.17.20");
+        assert_eq!(entries[0]["latest_seen"], "lodash-latest");
+        assert_eq!(entries[0]["action"], "update");
+        assert_eq!(entries[0]["chosen"], "4.17.21");
+        assert_eq!(entries[0]["released_at"], "2024-01-15T10:30:00Z");
+        assert!(entries[0]["skip_reason"].is_null());
+
+        assert_eq!(entries[1]["action"], "skip");
+        assert!(entries[1]["chosen"].is_null());
+        assert_eq!(entries[1]["skip_reason"], "already_latest");
+        assert_eq!(entries[2]["skip_reason"], "fetch_failed: 404");
+        assert!(entries[2]["latest_seen"].is_null());
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides:
 //! - JSON serialization of update results
 //! - Structured file-by-file update/skip information
+//! - JSON Lines (one compact object per manifest) for streaming
 
 use crate::domain::{Language, ManifestUpdateResult, SkipReason, UpdateResult, UpdateSummary};
 use crate::orchestrator::OrchestratorResult;
@@ -14,12 +15,25 @@ use std::io::Write;
 pub struct JsonFormatter {
     /// Verbosity level affects detail in output
     verbosity: Verbosity,
+    /// Write compact single-line JSON instead of pretty-printed JSON
+    json_lines: bool,
 }
 
 impl JsonFormatter {
     /// Create a new JSON formatter
     pub fn new(verbosity: Verbosity) -> Self {
-        Self { verbosity }
+        Self {
+            verbosity,
+            json_lines: false,
+        }
+    }
+
+    /// Write one compact JSON object per line (JSON Lines)
+    ///
+    /// In streaming mode this is one line per manifest; a full result is one line.
+    pub fn with_json_lines(mut self, json_lines: bool) -> Self {
+        self.json_lines = json_lines;
+        self
     }
 }
 
@@ -35,6 +49,9 @@ struct JsonOutput {
     /// Errors encountered
     #[serde(skip_serializing_if = "Vec::is_empty")]
     errors: Vec<String>,
+    /// Problems reported without failing the run (e.g., duplicate dependencies)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
 }
 
 /// JSON representation of summary statistics
@@ -69,9 +86,14 @@ struct JsonManifest {
     language: String,
     /// List of updates
     updates: Vec<JsonUpdate>,
-    /// List of skips (only in verbose mode)
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// List of skips (always present, possibly empty)
     skips: Vec<JsonSkip>,
+    /// Full would-be-written file content (--preview-content)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preview: Option<String>,
+    /// Time spent fetching versions, in milliseconds (only in verbose mode)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_ms: Option<u128>,
 }
 
 /// JSON representation of an update
@@ -85,6 +107,18 @@ struct JsonUpdate {
     to: String,
     /// Whether it's a dev dependency
     dev: bool,
+    /// Version resolved in the lock file, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locked: Option<String>,
+    /// Release notes for the versions between from and to (--notes)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
+    /// Update group this package moves with (--group)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
+    /// Mirror that served the versions when the primary registry failed (--registry-mirror)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mirror: Option<String>,
 }
 
 /// JSON representation of a skip
@@ -94,13 +128,16 @@ struct JsonSkip {
     name: String,
     /// Current version
     version: String,
+    /// Version resolved in the lock file, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locked: Option<String>,
     /// Skip reason
     reason: String,
 }
 
 impl JsonFormatter {
     /// Convert skip reason to string
-    fn skip_reason_to_string(reason: &SkipReason) -> String {
+    pub(crate) fn skip_reason_to_string(reason: &SkipReason) -> String {
         match reason {
             SkipReason::Pinned => "pinned".to_string(),
             SkipReason::AlreadyLatest => "already_latest".to_string(),
@@ -110,6 +147,17 @@ impl JsonFormatter {
             SkipReason::LanguageFiltered => "language_filtered".to_string(),
             SkipReason::NoSuitableVersion => "no_suitable_version".to_string(),
             SkipReason::ParseError(msg) => format!("parse_error: {}", msg),
+            SkipReason::NonRegistrySource => "non_registry_source".to_string(),
+            SkipReason::NoReleaseSince(date) => format!("no_release_since: {}", date),
+            SkipReason::ComplexConstraint => "complex_constraint".to_string(),
+            SkipReason::PseudoVersion => "pseudo_version".to_string(),
+            SkipReason::NotVulnerable => "not_vulnerable".to_string(),
+            SkipReason::NoAdvisoryDatabase => "no_advisory_database".to_string(),
+            SkipReason::GroupBlocked(group) => format!("group_blocked: {}", group),
+            SkipReason::Patched => "patched".to_string(),
+            SkipReason::NoDrift => "no_drift".to_string(),
+            SkipReason::Indirect => "indirect".to_string(),
+            SkipReason::Bundled => "bundled".to_string(),
         }
     }
 
@@ -121,6 +169,9 @@ impl JsonFormatter {
                 if let UpdateResult::Update {
                     dependency,
                     new_version,
+                    release_notes,
+                    group,
+                    mirror,
                     ..
                 } = result
                 {
@@ -129,6 +180,10 @@ impl JsonFormatter {
                         from: dependency.version_spec.version.clone(),
                         to: new_version.clone(),
                         dev: dependency.is_dev,
+                        locked: dependency.resolved_version.clone(),
+                        notes: release_notes.clone(),
+                        group: group.clone(),
+                        mirror: mirror.clone(),
                     })
                 } else {
                     None
@@ -136,30 +191,33 @@ impl JsonFormatter {
             })
             .collect();
 
-        let skips: Vec<JsonSkip> = if self.verbosity == Verbosity::Verbose {
-            manifest
-                .skips()
-                .filter_map(|result| {
-                    if let UpdateResult::Skip { dependency, reason } = result {
-                        Some(JsonSkip {
-                            name: dependency.name.clone(),
-                            version: dependency.version_spec.version.clone(),
-                            reason: Self::skip_reason_to_string(reason),
-                        })
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-        } else {
-            Vec::new()
-        };
+        // Skips are listed at every verbosity; JSON consumers filter for themselves
+        let skips: Vec<JsonSkip> = manifest
+            .skips()
+            .filter_map(|result| {
+                if let UpdateResult::Skip { dependency, reason } = result {
+                    Some(JsonSkip {
+                        name: dependency.name.clone(),
+                        version: dependency.version_spec.version.clone(),
+                        locked: dependency.resolved_version.clone(),
+                        reason: Self::skip_reason_to_string(reason),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
 
         JsonManifest {
             path: manifest.path.display().to_string(),
             language: manifest.language.display_name().to_string(),
             updates,
             skips,
+            preview: None,
+            duration_ms: manifest
+                .duration
+                .filter(|_| self.verbosity == Verbosity::Verbose)
+                .map(|duration| duration.as_millis()),
         }
     }
 }
@@ -202,12 +260,36 @@ impl OutputFormatter for JsonFormatter {
                 .summary
                 .manifests
                 .iter()
-                .map(|m| self.manifest_to_json(m))
+                .map(|m| {
+                    let mut manifest = self.manifest_to_json(m);
+                    manifest.preview = result
+                        .write_results
+                        .iter()
+                        .find(|w| w.path == m.path)
+                        .and_then(|w| w.preview.clone());
+                    manifest
+                })
+                .collect(),
+            errors: result
+                .errors
+                .iter()
+                .filter(|e| !e.is_warning())
+                .map(|e| e.to_string())
+                .collect(),
+            warnings: result
+                .errors
+                .iter()
+                .filter(|e| e.is_warning())
+                .map(|e| e.to_string())
                 .collect(),
-            errors: result.errors.iter().map(|e| e.to_string()).collect(),
         };
 
-        let json = serde_json::to_string_pretty(&output).map_err(std::io::Error::other)?;
+        let json = if self.json_lines {
+            serde_json::to_string(&output)
+        } else {
+            serde_json::to_string_pretty(&output)
+        }
+        .map_err(std::io::Error::other)?;
 
         writeln!(writer, "{}", json)?;
 
@@ -242,7 +324,12 @@ impl OutputFormatter for JsonFormatter {
     ) -> std::io::Result<()> {
         let output = self.manifest_to_json(manifest);
 
-        let json = serde_json::to_string_pretty(&output).map_err(std::io::Error::other)?;
+        let json = if self.json_lines {
+            serde_json::to_string(&output)
+        } else {
+            serde_json::to_string_pretty(&output)
+        }
+        .map_err(std::io::Error::other)?;
 
         writeln!(writer, "{}", json)?;
 
@@ -254,6 +341,7 @@ impl OutputFormatter for JsonFormatter {
 mod tests {
     use super::*;
     use crate::domain::{Dependency, VersionSpec, VersionSpecKind};
+    use crate::manifest::{ManifestWriter, PackageJsonParser};
     use std::path::PathBuf;
 
     fn sample_dependency(name: &str, version: &str) -> Dependency {
@@ -324,6 +412,123 @@ mod tests {
         assert_eq!(parsed["manifests"][0]["updates"][0]["to"], "4.18.0");
     }
 
+    #[test]
+    fn test_format_json_release_notes() {
+        let formatter = JsonFormatter::new(Verbosity::Normal);
+        let mut result = create_test_result();
+        let notes = "## 4.18.0\n\n".to_string() + &"x".repeat(500);
+        result.summary.manifests[0].results[0].set_release_notes(notes.clone());
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+
+        // JSON carries the full, untruncated notes
+        assert_eq!(parsed["manifests"][0]["updates"][0]["notes"], notes);
+    }
+
+    #[test]
+    fn test_format_json_warnings() {
+        use crate::orchestrator::OrchestratorError;
+
+        let formatter = JsonFormatter::new(Verbosity::Normal);
+        let mut result = create_test_result();
+        result.errors.push(OrchestratorError::DuplicateDependency {
+            path: "package.json".to_string(),
+            package: "lodash".to_string(),
+        });
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+
+        assert_eq!(
+            parsed["warnings"][0],
+            "Duplicate dependency lodash in package.json"
+        );
+        assert!(parsed.get("errors").is_none());
+    }
+
+    #[test]
+    fn test_format_json_group() {
+        let formatter = JsonFormatter::new(Verbosity::Normal);
+        let mut result = create_test_result();
+        result.summary.manifests[0].results[0].set_group("web");
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+
+        assert_eq!(parsed["manifests"][0]["updates"][0]["group"], "web");
+    }
+
+    #[test]
+    fn test_format_json_mirror() {
+        let formatter = JsonFormatter::new(Verbosity::Normal);
+        let mut result = create_test_result();
+        result.summary.manifests[0].results[0].set_mirror("https://npm.example.com");
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+
+        assert_eq!(
+            parsed["manifests"][0]["updates"][0]["mirror"],
+            "https://npm.example.com"
+        );
+    }
+
+    #[test]
+    fn test_format_json_locked_version() {
+        let formatter = JsonFormatter::new(Verbosity::Normal);
+        let mut summary = UpdateSummary::new(false);
+        let mut manifest = ManifestUpdateResult::new(PathBuf::from("Cargo.toml"), Language::Rust);
+        let dep = sample_dependency("serde", "1.0.100").with_resolved_version("1.0.150");
+        manifest.add_result(UpdateResult::update(dep, "1.0.190"));
+        summary.add_manifest(manifest);
+        let result = OrchestratorResult {
+            summary,
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        };
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+
+        assert_eq!(parsed["manifests"][0]["updates"][0]["locked"], "1.0.150");
+    }
+
+    #[test]
+    fn test_format_json_preview() {
+        let formatter = JsonFormatter::new(Verbosity::Normal);
+        let mut result = create_test_result();
+        let manifest = &result.summary.manifests[0];
+        let (_, write_result) = ManifestWriter::dry_run()
+            .with_preview(true)
+            .apply_updates_to_content(
+                manifest,
+                &PackageJsonParser,
+                r#"{"dependencies": {"lodash": "^4.17.21"}}"#,
+            );
+        result.write_results.push(write_result);
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+
+        assert_eq!(
+            parsed["manifests"][0]["preview"],
+            r#"{"dependencies": {"lodash": "^4.18.0"}}"#
+        );
+    }
+
     #[test]
     fn test_format_json_verbose() {
         let formatter = JsonFormatter::new(Verbosity::Verbose);
@@ -353,6 +558,26 @@ mod tests {
             .is_empty());
     }
 
+    #[test]
+    fn test_format_json_duration_ms() {
+        let mut result = create_test_result();
+        result.summary.manifests[0].duration = Some(std::time::Duration::from_millis(1234));
+
+        let mut output = Vec::new();
+        JsonFormatter::new(Verbosity::Verbose)
+            .format(&result, &mut output)
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(parsed["manifests"][0]["duration_ms"], 1234);
+
+        let mut output = Vec::new();
+        JsonFormatter::new(Verbosity::Normal)
+            .format(&result, &mut output)
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert!(parsed["manifests"][0].get("duration_ms").is_none());
+    }
+
     #[test]
     fn test_format_json_quiet() {
         let formatter = JsonFormatter::new(Verbosity::Quiet);
@@ -364,9 +589,59 @@ mod tests {
 
         let parsed: serde_json::Value = serde_json::from_str(&output_str).unwrap();
 
-        // Quiet mode should not include skips (field is omitted or empty)
-        let skips = &parsed["manifests"][0]["skips"];
-        assert!(skips.is_null() || skips.as_array().map(|a| a.is_empty()).unwrap_or(true));
+        // Skips are included regardless of verbosity
+        assert_eq!(parsed["manifests"][0]["skips"][0]["name"], "express");
+    }
+
+    #[test]
+    fn test_format_json_skips_non_verbose() {
+        let formatter = JsonFormatter::new(Verbosity::Normal);
+        let mut result = create_test_result();
+        result.summary.add_manifest(ManifestUpdateResult::new(
+            PathBuf::from("Cargo.toml"),
+            Language::Rust,
+        ));
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+        let skips = parsed["manifests"][0]["skips"].as_array().unwrap();
+        assert_eq!(skips.len(), 1);
+        assert_eq!(skips[0]["name"], "express");
+        assert_eq!(skips[0]["reason"], "already_latest");
+        // A manifest without skips still carries an empty array
+        assert_eq!(parsed["manifests"][1]["skips"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_format_manifest_json_lines() {
+        let formatter = JsonFormatter::new(Verbosity::Normal).with_json_lines(true);
+        let result = create_test_result();
+        let mut output = Vec::new();
+
+        formatter
+            .format_manifest(&result.summary.manifests[0], &mut output)
+            .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+
+        assert_eq!(output_str.lines().count(), 1);
+        let parsed: serde_json::Value = serde_json::from_str(&output_str).unwrap();
+        assert_eq!(parsed["path"], "package.json");
+    }
+
+    #[test]
+    fn test_format_json_lines_single_line() {
+        let formatter = JsonFormatter::new(Verbosity::Normal).with_json_lines(true);
+        let result = create_test_result();
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+
+        assert_eq!(output_str.lines().count(), 1);
+        let parsed: serde_json::Value = serde_json::from_str(&output_str).unwrap();
+        assert_eq!(parsed["manifests"][0]["path"], "package.json");
     }
 
     #[test]
@@ -0,0 +1,99 @@
+//! Supported language listing for --list-languages
+//!
+//! This module provides:
+//! - One entry per supported language with its manifest file and registry
+//! - Text (aligned table) and JSON renderings
+
+use crate::domain::Language;
+use crate::error::RegistryError;
+use serde::Serialize;
+use std::io::Write;
+
+/// Capabilities of a single supported language
+#[derive(Debug, Serialize)]
+struct LanguageEntry {
+    /// Language identifier (as used by the CLI filters)
+    language: Language,
+    /// Human-readable name
+    display_name: &'static str,
+    /// Manifest file name, or a glob for manifests named after the package
+    manifest_filename: &'static str,
+    /// Registry that versions are fetched from
+    registry: &'static str,
+}
+
+/// JSON document for --list-languages --json
+#[derive(Debug, Serialize)]
+struct LanguageList {
+    languages: Vec<LanguageEntry>,
+}
+
+/// Collect an entry for every supported language
+fn language_entries() -> Vec<LanguageEntry> {
+    Language::all()
+        .iter()
+        .map(|&language| LanguageEntry {
+            language,
+            display_name: language.display_name(),
+            manifest_filename: language.manifest_pattern(),
+            registry: RegistryError::registry_name(language),
+        })
+        .collect()
+}
+
+/// Write the list of supported languages as text or JSON
+pub fn write_language_list(json: bool, writer: &mut dyn Write) -> std::io::Result<()> {
+    let entries = language_entries();
+
+    if json {
+        let output = LanguageList { languages: entries };
+        let json = serde_json::to_string_pretty(&output).map_err(std::io::Error::other)?;
+        writeln!(writer, "{}", json)?;
+        return Ok(());
+    }
+
+    for entry in &entries {
+        writeln!(
+            writer,
+            "{:<8} {:<16} {}",
+            entry.display_name, entry.manifest_filename, entry.registry
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_lists_all_languages() {
+        let mut output = Vec::new();
+        write_language_list(true, &mut output).unwrap();
+
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+        let languages = parsed["languages"].as_array().unwrap();
+        assert_eq!(languages.len(), Language::all().len());
+
+        for (entry, language) in languages.iter().zip(Language::all()) {
+            assert_eq!(entry["language"], serde_json::to_value(language).unwrap());
+            assert_eq!(entry["display_name"], language.display_name());
+            assert_eq!(entry["manifest_filename"], language.manifest_pattern());
+        }
+
+        let rust = languages.iter().find(|e| e["language"] == "rust").unwrap();
+        assert_eq!(rust["registry"], "crates.io");
+    }
+
+    #[test]
+    fn test_text_lists_all_languages() {
+        let mut output = Vec::new();
+        write_language_list(false, &mut output).unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text.lines().count(), Language::all().len());
+        assert!(text.contains("Cargo.toml"));
+        assert!(text.contains("Maven Central"));
+    }
+}
@@ -4,18 +4,35 @@
 //! - Text output for human-readable display
 //! - JSON output for machine processing
 //! - Diff output for showing changes
+//! - SARIF output for code scanning tools
+//! - Supported language listing (--list-languages)
+//! - Likely-unused dependency report (--prune-report)
+//! - Custom per-update lines from a template (--template)
 
+mod audit;
 mod diff;
 mod json;
+mod languages;
+mod prune;
+mod runtimes;
+mod sarif;
+mod template;
 mod text;
 
+pub use audit::write_audit;
 pub use diff::DiffFormatter;
 pub use json::JsonFormatter;
-pub use text::TextFormatter;
+pub use languages::write_language_list;
+pub use prune::write_prune_report;
+pub use runtimes::write_runtime_report;
+pub use sarif::SarifFormatter;
+pub use template::{Template, TemplateFormatter};
+pub use text::{TextFormatter, VersionChangeType};
 
 use crate::domain::{ManifestUpdateResult, UpdateSummary};
 use crate::orchestrator::OrchestratorResult;
 use std::io::Write;
+use std::path::PathBuf;
 
 /// Output format options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -27,6 +44,10 @@ pub enum OutputFormat {
     Json,
     /// Unified diff format
     Diff,
+    /// SARIF 2.1.0 for code scanning tools
+    Sarif,
+    /// One line per update rendered from a user template
+    Template,
 }
 
 /// Output verbosity level
@@ -44,7 +65,7 @@ pub enum Verbosity {
 /// Configuration for output formatting
 #[derive(Debug, Clone)]
 pub struct OutputConfig {
-    /// Output format (text, json, diff)
+    /// Output format (text, json, diff, sarif)
     pub format: OutputFormat,
     /// Verbosity level
     pub verbosity: Verbosity,
@@ -52,6 +73,28 @@ pub struct OutputConfig {
     pub dry_run: bool,
     /// Whether to use colors (when supported)
     pub color: bool,
+    /// Template for per-update lines (used with OutputFormat::Template)
+    pub template: Option<Template>,
+    /// Label 0.x minor bumps as major changes
+    pub zerover_minor_as_major: bool,
+    /// Write JSON manifests one per line (streaming mode)
+    pub json_lines: bool,
+    /// Collapse fetch failures into one summary line in text output
+    pub quiet_errors: bool,
+    /// Show the lock file's resolved version as the current version in text output
+    pub lock_versions: bool,
+    /// Number of updates per reported batch in text output
+    pub batch_size: Option<usize>,
+    /// List updates shared by several manifests once in text output
+    pub dedupe_manifests: bool,
+    /// Write the summary block after the updates in text output
+    pub show_summary: bool,
+    /// Leave already-latest packages out of the verbose skip listing in text output
+    pub hide_latest: bool,
+    /// Directory being scanned; SARIF locations are relative to it
+    pub scan_root: PathBuf,
+    /// Fit text update lines to the terminal width (off when writing to a file)
+    pub fit_width: bool,
 }
 
 impl Default for OutputConfig {
@@ -61,6 +104,17 @@ impl Default for OutputConfig {
             verbosity: Verbosity::default(),
             dry_run: false,
             color: true,
+            template: None,
+            zerover_minor_as_major: false,
+            json_lines: false,
+            quiet_errors: false,
+            lock_versions: false,
+            batch_size: None,
+            dedupe_manifests: false,
+            show_summary: true,
+            hide_latest: false,
+            scan_root: PathBuf::from("."),
+            fit_width: true,
         }
     }
 }
@@ -73,13 +127,33 @@ impl OutputConfig {
             verbosity,
             dry_run,
             color: true,
+            template: None,
+            zerover_minor_as_major: false,
+            json_lines: false,
+            quiet_errors: false,
+            lock_versions: false,
+            batch_size: None,
+            dedupe_manifests: false,
+            show_summary: true,
+            hide_latest: false,
+            scan_root: PathBuf::from("."),
+            fit_width: true,
         }
     }
 
     /// Create configuration from CLI arguments
-    pub fn from_cli(json: bool, diff: bool, verbose: bool, quiet: bool, dry_run: bool) -> Self {
+    pub fn from_cli(
+        json: bool,
+        diff: bool,
+        sarif: bool,
+        verbose: bool,
+        quiet: bool,
+        dry_run: bool,
+    ) -> Self {
         let format = if json {
             OutputFormat::Json
+        } else if sarif {
+            OutputFormat::Sarif
         } else if diff {
             OutputFormat::Diff
         } else {
@@ -99,8 +173,90 @@ impl OutputConfig {
             verbosity,
             dry_run,
             color: true,
+            template: None,
+            zerover_minor_as_major: false,
+            json_lines: false,
+            quiet_errors: false,
+            lock_versions: false,
+            batch_size: None,
+            dedupe_manifests: false,
+            show_summary: true,
+            hide_latest: false,
+            scan_root: PathBuf::from("."),
+            fit_width: true,
         }
     }
+
+    /// Render updates with a custom template instead of the selected format
+    pub fn with_template(mut self, template: Option<Template>) -> Self {
+        if template.is_some() {
+            self.format = OutputFormat::Template;
+        }
+        self.template = template;
+        self
+    }
+
+    /// Label 0.x minor bumps (e.g., 0.2 -> 0.3) as major changes
+    pub fn with_zerover_minor_as_major(mut self, enabled: bool) -> Self {
+        self.zerover_minor_as_major = enabled;
+        self
+    }
+
+    /// Collapse fetch-failed skips into a single "could not be resolved" line
+    pub fn with_quiet_errors(mut self, enabled: bool) -> Self {
+        self.quiet_errors = enabled;
+        self
+    }
+
+    /// Show locked versions instead of manifest versions as the current version (--drift)
+    pub fn with_lock_versions(mut self, enabled: bool) -> Self {
+        self.lock_versions = enabled;
+        self
+    }
+
+    /// Report updates in batches of this many in the text summary
+    pub fn with_batch_size(mut self, batch_size: Option<usize>) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Group identical updates across manifests into one line in the text output
+    pub fn with_dedupe_manifests(mut self, enabled: bool) -> Self {
+        self.dedupe_manifests = enabled;
+        self
+    }
+
+    /// Write the trailing summary block in the text output
+    pub fn with_summary(mut self, enabled: bool) -> Self {
+        self.show_summary = enabled;
+        self
+    }
+
+    /// Leave already-latest packages out of the verbose skip listing in the text output
+    pub fn with_hide_latest(mut self, enabled: bool) -> Self {
+        self.hide_latest = enabled;
+        self
+    }
+
+    /// Directory being scanned, used as the SARIF `%SRCROOT%` base
+    pub fn with_scan_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.scan_root = root.into();
+        self
+    }
+
+    /// Fit text update lines to the terminal width, eliding long names
+    ///
+    /// Disabled for --write-summary so the file keeps every name intact.
+    pub fn with_fit_width(mut self, enabled: bool) -> Self {
+        self.fit_width = enabled;
+        self
+    }
+
+    /// Write JSON manifests as JSON Lines (one object per line)
+    pub fn with_json_lines(mut self, enabled: bool) -> Self {
+        self.json_lines = enabled;
+        self
+    }
 }
 
 /// Trait for output formatters
@@ -125,10 +281,35 @@ pub trait OutputFormatter {
 
 /// Create an output formatter based on configuration
 pub fn create_formatter(config: OutputConfig) -> Box<dyn OutputFormatter> {
+    let text = || {
+        TextFormatter::new(config.verbosity, config.dry_run)
+            .with_zerover_minor_as_major(config.zerover_minor_as_major)
+            .with_quiet_errors(config.quiet_errors)
+            .with_lock_versions(config.lock_versions)
+            .with_batch_size(config.batch_size)
+            .with_dedupe_manifests(config.dedupe_manifests)
+            .with_summary(config.show_summary)
+            .with_hide_latest(config.hide_latest)
+            .with_width(if config.fit_width {
+                text::terminal_width()
+            } else {
+                usize::MAX
+            })
+    };
     match config.format {
-        OutputFormat::Text => Box::new(TextFormatter::new(config.verbosity, config.dry_run)),
-        OutputFormat::Json => Box::new(JsonFormatter::new(config.verbosity)),
+        OutputFormat::Text => Box::new(text()),
+        OutputFormat::Json => {
+            Box::new(JsonFormatter::new(config.verbosity).with_json_lines(config.json_lines))
+        }
         OutputFormat::Diff => Box::new(DiffFormatter::new(config.dry_run)),
+        OutputFormat::Sarif => Box::new(SarifFormatter::new().with_root(config.scan_root)),
+        OutputFormat::Template => match config.template {
+            Some(ref template) => Box::new(
+                TemplateFormatter::new(template.clone())
+                    .with_zerover_minor_as_major(config.zerover_minor_as_major),
+            ),
+            None => Box::new(text()),
+        },
     }
 }
 
@@ -153,6 +334,39 @@ mod tests {
         assert_eq!(config.verbosity, Verbosity::Normal);
         assert!(!config.dry_run);
         assert!(config.color);
+        assert!(config.fit_width);
+    }
+
+    #[test]
+    fn test_summary_file_keeps_long_names() {
+        use crate::domain::{
+            Dependency, Language, ManifestUpdateResult, UpdateResult, VersionSpec, VersionSpecKind,
+        };
+
+        let name = format!("@organization/{}", "very-long-package-name-".repeat(20));
+        let spec = VersionSpec::new(VersionSpecKind::Exact, "1.0.0", "1.0.0");
+        let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+        manifest.add_result(UpdateResult::update(
+            Dependency::new(&name, spec, false, Language::Node),
+            "1.2.0",
+        ));
+        let mut summary = UpdateSummary::new(true);
+        summary.add_manifest(manifest);
+
+        let result = OrchestratorResult {
+            summary,
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        let config = OutputConfig::default().with_fit_width(false);
+        let mut output = Vec::new();
+        create_formatter(config)
+            .format(&result, &mut output)
+            .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains(&name));
+        assert!(!output_str.contains('…'));
     }
 
     #[test]
@@ -165,32 +379,51 @@ mod tests {
 
     #[test]
     fn test_output_config_from_cli_json() {
-        let config = OutputConfig::from_cli(true, false, false, false, false);
+        let config = OutputConfig::from_cli(true, false, false, false, false, false);
         assert_eq!(config.format, OutputFormat::Json);
         assert_eq!(config.verbosity, Verbosity::Normal);
     }
 
     #[test]
     fn test_output_config_from_cli_diff() {
-        let config = OutputConfig::from_cli(false, true, false, false, false);
+        let config = OutputConfig::from_cli(false, true, false, false, false, false);
         assert_eq!(config.format, OutputFormat::Diff);
     }
 
+    #[test]
+    fn test_output_config_from_cli_sarif() {
+        let config = OutputConfig::from_cli(false, false, true, false, false, false);
+        assert_eq!(config.format, OutputFormat::Sarif);
+    }
+
+    #[test]
+    fn test_output_config_with_template() {
+        let template = Template::parse("{name} {new}").unwrap();
+        let config = OutputConfig::from_cli(false, false, false, false, false, false)
+            .with_template(Some(template.clone()));
+        assert_eq!(config.format, OutputFormat::Template);
+        assert_eq!(config.template, Some(template));
+
+        let config =
+            OutputConfig::from_cli(false, false, false, false, false, false).with_template(None);
+        assert_eq!(config.format, OutputFormat::Text);
+    }
+
     #[test]
     fn test_output_config_from_cli_verbose() {
-        let config = OutputConfig::from_cli(false, false, true, false, false);
+        let config = OutputConfig::from_cli(false, false, false, true, false, false);
         assert_eq!(config.verbosity, Verbosity::Verbose);
     }
 
     #[test]
     fn test_output_config_from_cli_quiet() {
-        let config = OutputConfig::from_cli(false, false, false, true, false);
+        let config = OutputConfig::from_cli(false, false, false, false, true, false);
         assert_eq!(config.verbosity, Verbosity::Quiet);
     }
 
     #[test]
     fn test_output_config_from_cli_dry_run() {
-        let config = OutputConfig::from_cli(false, false, false, false, true);
+        let config = OutputConfig::from_cli(false, false, false, false, false, true);
         assert!(config.dry_run);
     }
 }
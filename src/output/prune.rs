@@ -0,0 +1,87 @@
+//! Likely-unused dependency report for --prune-report
+//!
+//! This module provides:
+//! - One line per dependency with no reference found in source files
+//! - Text and JSON renderings
+
+use crate::manifest::UnusedDependency;
+use serde::Serialize;
+use std::io::Write;
+
+/// JSON document for --prune-report --json
+#[derive(Debug, Serialize)]
+struct PruneReport<'a> {
+    unused: &'a [UnusedDependency],
+}
+
+/// Write the likely-unused dependency report as text or JSON
+pub fn write_prune_report(
+    unused: &[UnusedDependency],
+    json: bool,
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    if json {
+        let report = PruneReport { unused };
+        let json = serde_json::to_string_pretty(&report).map_err(std::io::Error::other)?;
+        writeln!(writer, "{}", json)?;
+        return Ok(());
+    }
+
+    if unused.is_empty() {
+        writeln!(writer, "No unused dependencies found")?;
+        return Ok(());
+    }
+
+    for dep in unused {
+        writeln!(writer, "{}: {}", dep.manifest.display(), dep.name)?;
+    }
+    writeln!(
+        writer,
+        "{} dependency(ies) with no import found (review before removing)",
+        unused.len()
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Language;
+    use std::path::PathBuf;
+
+    fn sample_unused() -> Vec<UnusedDependency> {
+        vec![UnusedDependency {
+            manifest: PathBuf::from("package.json"),
+            language: Language::Node,
+            name: "left-pad".to_string(),
+        }]
+    }
+
+    #[test]
+    fn test_text_report() {
+        let mut output = Vec::new();
+        write_prune_report(&sample_unused(), false, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "package.json: left-pad\n1 dependency(ies) with no import found (review before removing)\n"
+        );
+
+        let mut output = Vec::new();
+        write_prune_report(&[], false, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "No unused dependencies found\n"
+        );
+    }
+
+    #[test]
+    fn test_json_report() {
+        let mut output = Vec::new();
+        write_prune_report(&sample_unused(), true, &mut output).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(parsed["unused"][0]["name"], "left-pad");
+        assert_eq!(parsed["unused"][0]["language"], "node");
+        assert_eq!(parsed["unused"][0]["manifest"], "package.json");
+    }
+}
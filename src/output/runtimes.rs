@@ -0,0 +1,109 @@
+//! Runtime pin report for --runtimes
+//!
+//! This module provides:
+//! - One line per runtime pinned in .tool-versions (current -> latest)
+//! - Text and JSON renderings
+
+use crate::registry::RuntimeCheck;
+use serde::Serialize;
+use std::io::Write;
+
+/// JSON document for --runtimes --json
+#[derive(Debug, Serialize)]
+struct RuntimeReport<'a> {
+    runtimes: Vec<JsonRuntime<'a>>,
+}
+
+/// JSON representation of a runtime check
+#[derive(Debug, Serialize)]
+struct JsonRuntime<'a> {
+    #[serde(flatten)]
+    check: &'a RuntimeCheck,
+    /// Whether a newer release exists
+    outdated: bool,
+}
+
+/// Write the runtime report as text or JSON
+pub fn write_runtime_report(
+    checks: &[RuntimeCheck],
+    json: bool,
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    if json {
+        let report = RuntimeReport {
+            runtimes: checks
+                .iter()
+                .map(|check| JsonRuntime {
+                    check,
+                    outdated: check.is_outdated(),
+                })
+                .collect(),
+        };
+        let json = serde_json::to_string_pretty(&report).map_err(std::io::Error::other)?;
+        writeln!(writer, "{}", json)?;
+        return Ok(());
+    }
+
+    for check in checks {
+        let status = match (&check.latest, &check.error) {
+            (_, Some(error)) => format!("(error: {})", error),
+            (Some(latest), None) if check.is_outdated() => format!("-> {}", latest),
+            _ => "(up to date)".to_string(),
+        };
+        writeln!(
+            writer,
+            "{:<8} {} {}",
+            check.runtime.display_name(),
+            check.current,
+            status
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::Runtime;
+
+    fn sample_checks() -> Vec<RuntimeCheck> {
+        vec![
+            RuntimeCheck {
+                runtime: Runtime::Node,
+                current: "20.10.0".to_string(),
+                latest: Some("21.5.0".to_string()),
+                error: None,
+            },
+            RuntimeCheck {
+                runtime: Runtime::Go,
+                current: "1.21.5".to_string(),
+                latest: Some("1.21.5".to_string()),
+                error: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_text_report() {
+        let mut output = Vec::new();
+        write_runtime_report(&sample_checks(), false, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Node.js  20.10.0 -> 21.5.0\nGo       1.21.5 (up to date)\n"
+        );
+    }
+
+    #[test]
+    fn test_json_report() {
+        let mut output = Vec::new();
+        write_runtime_report(&sample_checks(), true, &mut output).unwrap();
+
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+        let runtimes = parsed["runtimes"].as_array().unwrap();
+        assert_eq!(runtimes[0]["runtime"], "node");
+        assert_eq!(runtimes[0]["latest"], "21.5.0");
+        assert_eq!(runtimes[0]["outdated"], true);
+        assert_eq!(runtimes[1]["outdated"], false);
+    }
+}
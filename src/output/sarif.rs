@@ -0,0 +1,407 @@
+//! SARIF output formatter for code scanning integrations
+//!
+//! This module provides:
+//! - A minimal SARIF 2.1.0 log with one run for depup
+//! - One result per available update, located at the manifest file relative
+//!   to the scanned directory (`%SRCROOT%`)
+
+use crate::domain::{ManifestUpdateResult, UpdateResult, UpdateSummary};
+use crate::orchestrator::OrchestratorResult;
+use crate::output::OutputFormatter;
+use serde::Serialize;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// SARIF schema URI
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF specification version
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Rule id reported for every outdated dependency
+const OUTDATED_RULE_ID: &str = "depup/outdated-dependency";
+
+/// URI base id that artifact locations are relative to
+const SRCROOT: &str = "%SRCROOT%";
+
+/// SARIF formatter for code scanning tools
+pub struct SarifFormatter {
+    /// Directory being scanned
+    root: PathBuf,
+}
+
+impl SarifFormatter {
+    /// Create a new SARIF formatter rooted at the current directory
+    pub fn new() -> Self {
+        Self {
+            root: PathBuf::from("."),
+        }
+    }
+
+    /// Make artifact locations relative to the scanned directory (builder pattern)
+    pub fn with_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = root.into();
+        self
+    }
+
+    /// Manifest path relative to the scan root, as a forward-slash URI
+    fn manifest_uri(&self, manifest: &ManifestUpdateResult) -> String {
+        let path = manifest
+            .path
+            .strip_prefix(&self.root)
+            .unwrap_or(&manifest.path);
+        let path = path.display().to_string().replace('\\', "/");
+        path.strip_prefix("./").unwrap_or(&path).to_string()
+    }
+
+    /// Convert a manifest's updates to SARIF results
+    fn manifest_results(&self, manifest: &ManifestUpdateResult) -> Vec<SarifResult> {
+        let uri = self.manifest_uri(manifest);
+
+        manifest
+            .updates()
+            .filter_map(|result| {
+                if let UpdateResult::Update {
+                    dependency,
+                    new_version,
+                    ..
+                } = result
+                {
+                    Some(SarifResult {
+                        rule_id: OUTDATED_RULE_ID,
+                        level: "warning",
+                        message: SarifMessage {
+                            text: format!(
+                                "{} can be updated from {} to {}",
+                                dependency.name, dependency.version_spec.version, new_version
+                            ),
+                        },
+                        locations: vec![SarifLocation {
+                            physical_location: SarifPhysicalLocation {
+                                artifact_location: SarifArtifactLocation {
+                                    uri: uri.clone(),
+                                    uri_base_id: Some(SRCROOT),
+                                },
+                            },
+                        }],
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Build a SARIF log from a set of results
+    fn log(&self, results: Vec<SarifResult>) -> SarifLog {
+        SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "depup",
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: "https://github.com/owayo/depup",
+                        rules: vec![SarifRule {
+                            id: OUTDATED_RULE_ID,
+                            short_description: SarifMessage {
+                                text: "Dependency has a newer version available".to_string(),
+                            },
+                        }],
+                    },
+                },
+                original_uri_base_ids: root_uri(&self.root).map(|uri| SarifUriBaseIds {
+                    srcroot: SarifArtifactLocation {
+                        uri,
+                        uri_base_id: None,
+                    },
+                }),
+                results,
+            }],
+        }
+    }
+
+    /// Serialize a SARIF log to the writer
+    fn write_log(log: &SarifLog, writer: &mut dyn Write) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(log).map_err(std::io::Error::other)?;
+        writeln!(writer, "{}", json)
+    }
+}
+
+impl Default for SarifFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Absolute `file://` URI of the scan root (with a trailing slash), if it exists
+fn root_uri(root: &Path) -> Option<String> {
+    let root = root.canonicalize().ok()?;
+    let path = root.display().to_string().replace('\\', "/");
+    // Windows verbatim paths (\\?\C:\...) become //?/C:/...
+    let path = path.strip_prefix("//?/").unwrap_or(&path);
+    let path = path.trim_start_matches('/').replace(' ', "%20");
+    Some(format!("file:///{}/", path.trim_end_matches('/')))
+}
+
+/// Top-level SARIF log
+#[derive(Serialize)]
+struct SarifLog {
+    /// Schema URI
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    /// SARIF version
+    version: &'static str,
+    /// Tool runs
+    runs: Vec<SarifRun>,
+}
+
+/// A single tool run
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    /// Tool that produced the run
+    tool: SarifTool,
+    /// Absolute location of `%SRCROOT%`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_uri_base_ids: Option<SarifUriBaseIds>,
+    /// Findings
+    results: Vec<SarifResult>,
+}
+
+/// Tool description
+#[derive(Serialize)]
+struct SarifTool {
+    /// Driver component
+    driver: SarifDriver,
+}
+
+/// Tool driver metadata
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    /// Tool name
+    name: &'static str,
+    /// Tool version
+    version: &'static str,
+    /// Project homepage
+    information_uri: &'static str,
+    /// Rules the tool can report
+    rules: Vec<SarifRule>,
+}
+
+/// Reporting rule
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    /// Rule id
+    id: &'static str,
+    /// Short description
+    short_description: SarifMessage,
+}
+
+/// A single finding
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    /// Rule id
+    rule_id: &'static str,
+    /// Severity level
+    level: &'static str,
+    /// Finding message
+    message: SarifMessage,
+    /// Where the finding applies
+    locations: Vec<SarifLocation>,
+}
+
+/// Message text
+#[derive(Serialize)]
+struct SarifMessage {
+    /// Plain text
+    text: String,
+}
+
+/// Result location
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    /// Physical location
+    physical_location: SarifPhysicalLocation,
+}
+
+/// Physical file location
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    /// Artifact (file) location
+    artifact_location: SarifArtifactLocation,
+}
+
+/// Artifact location
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactLocation {
+    /// File URI
+    uri: String,
+    /// Base the URI is relative to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+/// URI base ids used by the run's artifact locations
+#[derive(Serialize)]
+struct SarifUriBaseIds {
+    /// The scanned directory
+    #[serde(rename = "%SRCROOT%")]
+    srcroot: SarifArtifactLocation,
+}
+
+impl OutputFormatter for SarifFormatter {
+    fn format(&self, result: &OrchestratorResult, writer: &mut dyn Write) -> std::io::Result<()> {
+        let results = result
+            .summary
+            .manifests
+            .iter()
+            .flat_map(|manifest| self.manifest_results(manifest))
+            .collect();
+
+        Self::write_log(&self.log(results), writer)
+    }
+
+    fn format_summary(
+        &self,
+        summary: &UpdateSummary,
+        writer: &mut dyn Write,
+    ) -> std::io::Result<()> {
+        let results = summary
+            .manifests
+            .iter()
+            .flat_map(|manifest| self.manifest_results(manifest))
+            .collect();
+
+        Self::write_log(&self.log(results), writer)
+    }
+
+    fn format_manifest(
+        &self,
+        manifest: &ManifestUpdateResult,
+        writer: &mut dyn Write,
+    ) -> std::io::Result<()> {
+        Self::write_log(&self.log(self.manifest_results(manifest)), writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Dependency, Language, SkipReason, VersionSpec, VersionSpecKind};
+    use std::path::PathBuf;
+
+    fn sample_dependency(name: &str, version: &str) -> Dependency {
+        let spec = VersionSpec::new(VersionSpecKind::Caret, format!("^{}", version), version)
+            .with_prefix("^");
+        Dependency::new(name, spec, false, Language::Node)
+    }
+
+    fn create_test_result() -> OrchestratorResult {
+        let mut summary = UpdateSummary::new(false);
+        let mut manifest =
+            ManifestUpdateResult::new(PathBuf::from("./web/package.json"), Language::Node);
+
+        manifest.add_result(UpdateResult::update(
+            sample_dependency("lodash", "4.17.20"),
+            "4.17.21",
+        ));
+        manifest.add_result(UpdateResult::skip(
+            sample_dependency("express", "4.18.0"),
+            SkipReason::AlreadyLatest,
+        ));
+        summary.add_manifest(manifest);
+
+        OrchestratorResult {
+            summary,
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_format_sarif() {
+        let formatter = SarifFormatter::new();
+        let mut output = Vec::new();
+
+        formatter
+            .format(&create_test_result(), &mut output)
+            .unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+
+        assert_eq!(parsed["$schema"], SARIF_SCHEMA);
+        assert_eq!(parsed["version"], "2.1.0");
+        assert_eq!(parsed["runs"][0]["tool"]["driver"]["name"], "depup");
+        assert_eq!(
+            parsed["runs"][0]["tool"]["driver"]["rules"][0]["id"],
+            OUTDATED_RULE_ID
+        );
+
+        let results = parsed["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], OUTDATED_RULE_ID);
+        assert_eq!(
+            results[0]["message"]["text"],
+            "lodash can be updated from 4.17.20 to 4.17.21"
+        );
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "web/package.json"
+        );
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uriBaseId"],
+            "%SRCROOT%"
+        );
+    }
+
+    #[test]
+    fn test_format_sarif_relative_to_root() {
+        let root = std::env::temp_dir();
+        let formatter = SarifFormatter::new().with_root(&root);
+        let mut manifest =
+            ManifestUpdateResult::new(root.join("web").join("package.json"), Language::Node);
+        manifest.add_result(UpdateResult::update(
+            sample_dependency("lodash", "4.17.20"),
+            "4.17.21",
+        ));
+        let mut output = Vec::new();
+
+        formatter.format_manifest(&manifest, &mut output).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+
+        let location = &parsed["runs"][0]["results"][0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "web/package.json");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        let base = parsed["runs"][0]["originalUriBaseIds"]["%SRCROOT%"]["uri"]
+            .as_str()
+            .unwrap();
+        assert!(base.starts_with("file:///"));
+        assert!(base.ends_with('/'));
+    }
+
+    #[test]
+    fn test_format_sarif_no_updates() {
+        let formatter = SarifFormatter::new();
+        let result = OrchestratorResult {
+            summary: UpdateSummary::new(false),
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        };
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+
+        assert!(parsed["runs"][0]["results"].as_array().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,298 @@
+//! Template output formatter for custom per-update lines
+//!
+//! This module provides:
+//! - A minimal `{placeholder}` template parser (`{{`/`}}` for literal braces)
+//! - One rendered line per available update
+//!
+//! Supported placeholders: `{file}`, `{name}`, `{old}`, `{new}`, `{type}`, `{date}`, `{lang}`
+
+use crate::domain::{ManifestUpdateResult, UpdateResult, UpdateSummary};
+use crate::orchestrator::OrchestratorResult;
+use crate::output::text::VersionChangeType;
+use crate::output::OutputFormatter;
+use std::io::Write;
+use std::str::FromStr;
+
+/// A value that can be substituted into a template
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placeholder {
+    /// Manifest file path
+    File,
+    /// Package name
+    Name,
+    /// Current version
+    Old,
+    /// New version
+    New,
+    /// Change type (major/minor/patch)
+    Type,
+    /// Release date of the new version (YYYY-MM-DD)
+    Date,
+    /// Language display name
+    Lang,
+}
+
+impl FromStr for Placeholder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "file" => Ok(Placeholder::File),
+            "name" => Ok(Placeholder::Name),
+            "old" => Ok(Placeholder::Old),
+            "new" => Ok(Placeholder::New),
+            "type" => Ok(Placeholder::Type),
+            "date" => Ok(Placeholder::Date),
+            "lang" => Ok(Placeholder::Lang),
+            _ => Err(format!(
+                "unknown placeholder '{{{}}}': expected one of {{file}}, {{name}}, {{old}}, {{new}}, {{type}}, {{date}}, {{lang}}",
+                s
+            )),
+        }
+    }
+}
+
+/// A parsed template segment
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// Literal text
+    Text(String),
+    /// Value substituted per update
+    Value(Placeholder),
+}
+
+/// A parsed output template such as `{file}: {name} {old} -> {new}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    /// Parse a template, rejecting unknown placeholders and unbalanced braces
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => return Err(format!("unclosed '{{{}' in template", name)),
+                        }
+                    }
+                    if !text.is_empty() {
+                        segments.push(Segment::Text(std::mem::take(&mut text)));
+                    }
+                    segments.push(Segment::Value(name.trim().parse()?));
+                }
+                '}' => return Err("unmatched '}' in template (use '}}' for a literal)".to_string()),
+                c => text.push(c),
+            }
+        }
+
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Render the template for a single update
+    fn render(
+        &self,
+        manifest: &ManifestUpdateResult,
+        result: &UpdateResult,
+        zerover_minor_as_major: bool,
+    ) -> Option<String> {
+        let UpdateResult::Update {
+            dependency,
+            new_version,
+            released_at,
+            ..
+        } = result
+        else {
+            return None;
+        };
+
+        let mut line = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => line.push_str(text),
+                Segment::Value(Placeholder::File) => {
+                    line.push_str(&manifest.path.display().to_string())
+                }
+                Segment::Value(Placeholder::Name) => line.push_str(&dependency.name),
+                Segment::Value(Placeholder::Old) => line.push_str(dependency.version()),
+                Segment::Value(Placeholder::New) => line.push_str(new_version),
+                Segment::Value(Placeholder::Type) => line.push_str(
+                    VersionChangeType::classify(
+                        dependency.version(),
+                        new_version,
+                        zerover_minor_as_major,
+                    )
+                    .label(),
+                ),
+                Segment::Value(Placeholder::Date) => {
+                    if let Some(date) = released_at {
+                        line.push_str(&date.format("%Y-%m-%d").to_string());
+                    }
+                }
+                Segment::Value(Placeholder::Lang) => {
+                    line.push_str(manifest.language.display_name())
+                }
+            }
+        }
+        Some(line)
+    }
+}
+
+/// Template formatter rendering one line per update (--template)
+pub struct TemplateFormatter {
+    /// Template rendered for each update
+    template: Template,
+    /// Whether `{type}` reports 0.x minor bumps as major
+    zerover_minor_as_major: bool,
+}
+
+impl TemplateFormatter {
+    /// Create a new template formatter
+    pub fn new(template: Template) -> Self {
+        Self {
+            template,
+            zerover_minor_as_major: false,
+        }
+    }
+
+    /// Report 0.x minor bumps as major in `{type}` (builder pattern)
+    pub fn with_zerover_minor_as_major(mut self, enabled: bool) -> Self {
+        self.zerover_minor_as_major = enabled;
+        self
+    }
+}
+
+impl OutputFormatter for TemplateFormatter {
+    fn format(&self, result: &OrchestratorResult, writer: &mut dyn Write) -> std::io::Result<()> {
+        for manifest in &result.summary.manifests {
+            self.format_manifest(manifest, writer)?;
+        }
+        Ok(())
+    }
+
+    fn format_summary(
+        &self,
+        summary: &UpdateSummary,
+        writer: &mut dyn Write,
+    ) -> std::io::Result<()> {
+        for manifest in &summary.manifests {
+            self.format_manifest(manifest, writer)?;
+        }
+        Ok(())
+    }
+
+    fn format_manifest(
+        &self,
+        manifest: &ManifestUpdateResult,
+        writer: &mut dyn Write,
+    ) -> std::io::Result<()> {
+        for result in manifest.updates() {
+            if let Some(line) = self
+                .template
+                .render(manifest, result, self.zerover_minor_as_major)
+            {
+                writeln!(writer, "{}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Dependency, Language, SkipReason, VersionSpec, VersionSpecKind};
+    use chrono::TimeZone;
+    use std::path::PathBuf;
+
+    fn sample_dependency(name: &str, version: &str) -> Dependency {
+        let spec = VersionSpec::new(VersionSpecKind::Caret, format!("^{}", version), version)
+            .with_prefix("^");
+        Dependency::new(name, spec, false, Language::Node)
+    }
+
+    fn create_test_result() -> OrchestratorResult {
+        let mut summary = UpdateSummary::new(false);
+        let mut manifest = ManifestUpdateResult::new(PathBuf::from("package.json"), Language::Node);
+
+        let released = chrono::Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        manifest.add_result(UpdateResult::update_with_date(
+            sample_dependency("lodash", "4.17.20"),
+            "4.17.21",
+            released,
+        ));
+        manifest.add_result(UpdateResult::update(
+            sample_dependency("react", "17.0.2"),
+            "18.2.0",
+        ));
+        manifest.add_result(UpdateResult::skip(
+            sample_dependency("express", "4.18.0"),
+            SkipReason::AlreadyLatest,
+        ));
+        summary.add_manifest(manifest);
+
+        OrchestratorResult {
+            summary,
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn render(template: &str) -> String {
+        let formatter = TemplateFormatter::new(Template::parse(template).unwrap());
+        let mut output = Vec::new();
+        formatter
+            .format(&create_test_result(), &mut output)
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_render_all_placeholders() {
+        let output = render("{file}\t{lang}\t{name}\t{old}\t{new}\t{type}\t{date}");
+        assert_eq!(
+            output,
+            "package.json\tNode.js\tlodash\t4.17.20\t4.17.21\tpatch\t2024-03-01\n\
+             package.json\tNode.js\treact\t17.0.2\t18.2.0\tmajor\t\n"
+        );
+    }
+
+    #[test]
+    fn test_render_escaped_braces() {
+        let output = render("{{{name}}}");
+        assert_eq!(output, "{lodash}\n{react}\n");
+    }
+
+    #[test]
+    fn test_parse_unknown_placeholder() {
+        let err = Template::parse("{name} {version}").unwrap_err();
+        assert!(err.contains("{version}"));
+    }
+
+    #[test]
+    fn test_parse_unbalanced_braces() {
+        assert!(Template::parse("{name").is_err());
+        assert!(Template::parse("name}").is_err());
+    }
+}
@@ -5,15 +5,66 @@
 //! - Semantic version change type indication (major/minor/patch)
 //! - Production vs development dependency grouping
 //! - Skipped package display with reasons
+//! - Truncated release notes in verbose mode
 //! - Summary with detailed breakdown
-
-use crate::domain::{Language, ManifestUpdateResult, SkipReason, UpdateResult, UpdateSummary};
-use crate::orchestrator::OrchestratorResult;
+//! - Most outdated dependencies in verbose mode
+//! - Usage counts for packages declared in several manifests
+//! - Batch boundaries for reviewing large update sets (--batch-size)
+//! - Fitting update lines to the terminal width (eliding names, wrapping dates)
+//! - Grouping identical updates shared by several manifests (--dedupe-manifests)
+
+use crate::domain::{
+    Dependency, Language, ManifestUpdateResult, SkipReason, UpdateResult, UpdateSummary,
+};
+use crate::orchestrator::{OrchestratorError, OrchestratorResult};
 use crate::output::{OutputFormatter, Verbosity};
 use chrono::{DateTime, Utc};
 use colored::Colorize;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
 
+/// (language, package name, current version, new version) identifying an update
+type UpdateKey<'a> = (Language, &'a str, &'a str, &'a str);
+
+/// Maximum number of release note lines shown per update in verbose mode
+const NOTES_MAX_LINES: usize = 5;
+
+/// Maximum characters shown per release note line in verbose mode
+const NOTES_MAX_LINE_LEN: usize = 100;
+
+/// Maximum number of packages listed under "Most outdated" in verbose mode
+const MOST_OUTDATED_LIMIT: usize = 5;
+
+/// Line width used when stdout is not a terminal
+const DEFAULT_WIDTH: usize = 100;
+
+/// Package names are never elided below this many characters
+const MIN_NAME_WIDTH: usize = 12;
+
+/// Width of the terminal attached to stdout, or `DEFAULT_WIDTH` when not a TTY
+pub fn terminal_width() -> usize {
+    terminal_size::terminal_size()
+        .map(|(terminal_size::Width(w), _)| w as usize)
+        .filter(|&w| w > 0)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+/// Display width of plain (uncolored) text, counting emoji as two columns
+fn display_width(s: &str) -> usize {
+    s.chars()
+        .map(|c| if c as u32 >= 0x1F000 { 2 } else { 1 })
+        .sum()
+}
+
+/// Shorten `name` to `width` characters, ending in `…` when cut
+fn elide(name: &str, width: usize) -> String {
+    if name.chars().count() <= width {
+        return name.to_string();
+    }
+    let kept: String = name.chars().take(width.saturating_sub(1)).collect();
+    format!("{}…", kept)
+}
+
 /// Semantic version change type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VersionChangeType {
@@ -32,33 +83,25 @@ pub enum VersionChangeType {
 impl VersionChangeType {
     /// Determine the change type between two versions
     pub fn from_versions(old: &str, new: &str) -> Self {
+        Self::classify(old, new, false)
+    }
+
+    /// Determine the change type, optionally treating 0.x minor bumps as major
+    ///
+    /// Under Cargo's caret rules `0.2` -> `0.3` is a breaking change, so with
+    /// `zerover_minor_as_major` such a bump is classified as `Major`.
+    pub fn classify(old: &str, new: &str, zerover_minor_as_major: bool) -> Self {
         // If old version is empty or "-", this is a new version being added
         if old.is_empty() || old == "-" {
             return VersionChangeType::New;
         }
 
-        let parse = |v: &str| -> Option<(u64, u64, u64)> {
-            let v = v.strip_prefix('v').unwrap_or(v);
-            // Split by . and - to handle prerelease suffixes
-            let parts: Vec<&str> = v.split(['.', '-']).collect();
-            if parts.len() >= 3 {
-                Some((
-                    parts[0].parse().ok()?,
-                    parts[1].parse().ok()?,
-                    parts[2].parse().ok()?,
-                ))
-            } else if parts.len() == 2 {
-                Some((parts[0].parse().ok()?, parts[1].parse().ok()?, 0))
-            } else if parts.len() == 1 {
-                Some((parts[0].parse().ok()?, 0, 0))
-            } else {
-                None
-            }
-        };
-
-        match (parse(old), parse(new)) {
+        match (parse_version_triple(old), parse_version_triple(new)) {
             (Some((old_major, old_minor, _)), Some((new_major, new_minor, _))) => {
-                if new_major != old_major {
+                // A 0.x minor bump is breaking under Cargo's caret rules
+                let zerover_breaking =
+                    zerover_minor_as_major && old_major == 0 && new_minor != old_minor;
+                if new_major != old_major || zerover_breaking {
                     VersionChangeType::Major
                 } else if new_minor != old_minor {
                     VersionChangeType::Minor
@@ -93,6 +136,39 @@ impl VersionChangeType {
     }
 }
 
+/// Parse a version into (major, minor, patch), padding missing components with 0
+fn parse_version_triple(v: &str) -> Option<(u64, u64, u64)> {
+    let v = v.strip_prefix('v').unwrap_or(v);
+    // Split by . and - to handle prerelease suffixes
+    let parts: Vec<&str> = v.split(['.', '-']).collect();
+    if parts.len() >= 3 {
+        Some((
+            parts[0].parse().ok()?,
+            parts[1].parse().ok()?,
+            parts[2].parse().ok()?,
+        ))
+    } else if parts.len() == 2 {
+        Some((parts[0].parse().ok()?, parts[1].parse().ok()?, 0))
+    } else if parts.len() == 1 {
+        Some((parts[0].parse().ok()?, 0, 0))
+    } else {
+        None
+    }
+}
+
+/// How far `old` is behind `new`, as (majors behind, minors behind)
+///
+/// Across a major boundary the minor distance counts the new major's minor releases.
+fn version_gap(old: &str, new: &str) -> Option<(u64, u64)> {
+    let (old_major, old_minor, _) = parse_version_triple(old)?;
+    let (new_major, new_minor, _) = parse_version_triple(new)?;
+    if new_major != old_major {
+        Some((new_major.saturating_sub(old_major), new_minor))
+    } else {
+        Some((0, new_minor.saturating_sub(old_minor)))
+    }
+}
+
 /// Text formatter for human-readable output
 pub struct TextFormatter {
     /// Verbosity level
@@ -101,6 +177,22 @@ pub struct TextFormatter {
     dry_run: bool,
     /// Whether to use colors
     color: bool,
+    /// Whether 0.x minor bumps are labelled as major
+    zerover_minor_as_major: bool,
+    /// Whether fetch failures are collapsed into a single summary line
+    quiet_errors: bool,
+    /// Whether the locked version is shown as the current version
+    lock_versions: bool,
+    /// Number of updates per reported batch
+    batch_size: Option<usize>,
+    /// Whether identical updates across manifests are listed once
+    dedupe_manifests: bool,
+    /// Whether the summary block follows the update listing
+    show_summary: bool,
+    /// Maximum width of an update line
+    width: usize,
+    /// Whether already-latest packages are left out of the verbose skip listing
+    hide_latest: bool,
 }
 
 impl TextFormatter {
@@ -110,6 +202,14 @@ impl TextFormatter {
             verbosity,
             dry_run,
             color: true,
+            zerover_minor_as_major: false,
+            quiet_errors: false,
+            lock_versions: false,
+            batch_size: None,
+            dedupe_manifests: false,
+            show_summary: true,
+            width: DEFAULT_WIDTH,
+            hide_latest: false,
         }
     }
 
@@ -119,9 +219,136 @@ impl TextFormatter {
             verbosity,
             dry_run,
             color,
+            zerover_minor_as_major: false,
+            quiet_errors: false,
+            lock_versions: false,
+            batch_size: None,
+            dedupe_manifests: false,
+            show_summary: true,
+            width: DEFAULT_WIDTH,
+            hide_latest: false,
         }
     }
 
+    /// Label 0.x minor bumps (e.g., 0.2 -> 0.3) as major (builder pattern)
+    pub fn with_zerover_minor_as_major(mut self, enabled: bool) -> Self {
+        self.zerover_minor_as_major = enabled;
+        self
+    }
+
+    /// Collapse fetch-failed skips into one "could not be resolved" line (builder pattern)
+    pub fn with_quiet_errors(mut self, enabled: bool) -> Self {
+        self.quiet_errors = enabled;
+        self
+    }
+
+    /// Show the lock file's resolved version as the current version (builder pattern)
+    pub fn with_lock_versions(mut self, enabled: bool) -> Self {
+        self.lock_versions = enabled;
+        self
+    }
+
+    /// Report updates in batches of this many, in the order they are applied (builder pattern)
+    pub fn with_batch_size(mut self, batch_size: Option<usize>) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// List updates shared by several manifests once, with a manifest count (builder pattern)
+    pub fn with_dedupe_manifests(mut self, enabled: bool) -> Self {
+        self.dedupe_manifests = enabled;
+        self
+    }
+
+    /// Write the summary block after the update listing (builder pattern)
+    pub fn with_summary(mut self, enabled: bool) -> Self {
+        self.show_summary = enabled;
+        self
+    }
+
+    /// Fit update lines into this many columns (builder pattern)
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Lay out an update line within the configured width
+    ///
+    /// `rest_width` is the width after the name column, excluding the date. The date
+    /// moves to its own line first; if the line is still too wide, the name is elided.
+    /// Returns the (possibly elided) name, the name column width and whether the
+    /// date is written on its own line.
+    fn fit_update_line(
+        &self,
+        name: &str,
+        max_name_len: usize,
+        rest_width: usize,
+        date_width: usize,
+    ) -> (String, usize, bool) {
+        // Two leading spaces before the name
+        let available = |rest: usize| self.width.saturating_sub(2 + rest);
+        let name_len = name.chars().count();
+
+        // Alignment padding is given up before anything else
+        let inline = available(rest_width + date_width);
+        if name_len <= inline {
+            return (name.to_string(), max_name_len.min(inline), false);
+        }
+
+        let date_on_own_line = date_width > 0;
+        let column = available(rest_width).max(MIN_NAME_WIDTH);
+        (
+            elide(name, column),
+            max_name_len.min(column),
+            date_on_own_line,
+        )
+    }
+
+    /// Current version shown on an update line ("-" for unversioned dependencies)
+    fn current_version<'a>(&self, dependency: &'a Dependency) -> &'a str {
+        match dependency.resolved_version.as_deref() {
+            Some(locked) if self.lock_versions => locked,
+            _ if dependency.version_spec.version.is_empty() => "-",
+            _ => &dependency.version_spec.version,
+        }
+    }
+
+    /// Leave already-latest packages out of the verbose skip listing (builder pattern)
+    ///
+    /// They are still counted in the manifest header and summary.
+    pub fn with_hide_latest(mut self, enabled: bool) -> Self {
+        self.hide_latest = enabled;
+        self
+    }
+
+    /// Whether a skip is left out of the verbose skip listing
+    fn is_hidden_skip(&self, reason: &SkipReason) -> bool {
+        self.hide_latest && *reason == SkipReason::AlreadyLatest
+    }
+
+    /// Skips that are listed and counted (fetch failures are hidden with quiet errors)
+    fn visible_skips<'a>(
+        &self,
+        manifest: &'a ManifestUpdateResult,
+    ) -> impl Iterator<Item = &'a UpdateResult> {
+        let quiet_errors = self.quiet_errors;
+        manifest.skips().filter(move |result| {
+            !(quiet_errors
+                && matches!(
+                    result,
+                    UpdateResult::Skip {
+                        reason: SkipReason::FetchFailed(_),
+                        ..
+                    }
+                ))
+        })
+    }
+
+    /// Classify a version change using this formatter's settings
+    fn change_type(&self, old: &str, new: &str) -> VersionChangeType {
+        VersionChangeType::classify(old, new, self.zerover_minor_as_major)
+    }
+
     /// Get the dry-run prefix if applicable
     fn dry_run_prefix(&self) -> String {
         if self.dry_run {
@@ -146,6 +373,17 @@ impl TextFormatter {
             SkipReason::LanguageFiltered => "filtered".to_string(),
             SkipReason::NoSuitableVersion => "no suitable version".to_string(),
             SkipReason::ParseError(msg) => format!("parse error: {}", msg),
+            SkipReason::NonRegistrySource => "local source".to_string(),
+            SkipReason::NoReleaseSince(date) => format!("no release since {}", date),
+            SkipReason::ComplexConstraint => "complex constraint".to_string(),
+            SkipReason::PseudoVersion => "pseudo-version".to_string(),
+            SkipReason::NotVulnerable => "no known vulnerability".to_string(),
+            SkipReason::NoAdvisoryDatabase => "no advisory database".to_string(),
+            SkipReason::GroupBlocked(group) => format!("group {} blocked", group),
+            SkipReason::Patched => "patched".to_string(),
+            SkipReason::NoDrift => "lock up to date".to_string(),
+            SkipReason::Indirect => "indirect".to_string(),
+            SkipReason::Bundled => "bundled".to_string(),
         }
     }
 
@@ -171,10 +409,12 @@ impl TextFormatter {
         is_dev: bool,
         released_at: Option<DateTime<Utc>>,
         variable_name: Option<&str>,
+        group: Option<&str>,
+        used_in: usize,
         max_name_len: usize,
         writer: &mut dyn Write,
     ) -> std::io::Result<()> {
-        let change_type = VersionChangeType::from_versions(old_version, new_version);
+        let change_type = self.change_type(old_version, new_version);
         let dev_marker = if is_dev { " 🔧" } else { "" };
 
         // Format release date
@@ -187,52 +427,118 @@ impl TextFormatter {
             .map(|v| format!(" via ${}", v))
             .unwrap_or_default();
 
+        // Format update group indicator
+        let group_display = group
+            .map(|g| format!(" (group: {})", g))
+            .unwrap_or_default();
+
+        // Packages shared across manifests have a wider blast radius
+        let usage_display = if used_in > 1 {
+            format!(" (used in {} files)", used_in)
+        } else {
+            String::new()
+        };
+
+        // Everything after the name column is measured uncolored
+        let arrow = if self.color { "→" } else { "->" };
+        let rest_width = display_width(&format!(
+            " {} {} {} [{}]{}{}{}{}",
+            old_version,
+            arrow,
+            new_version,
+            change_type.label(),
+            var_display,
+            group_display,
+            usage_display,
+            dev_marker
+        ));
+        let (name, max_name_len, date_on_own_line) =
+            self.fit_update_line(name, max_name_len, rest_width, display_width(&date_display));
+        let (inline_date, own_line_date) = if date_on_own_line {
+            (String::new(), date_display.trim_start().to_string())
+        } else {
+            (date_display, String::new())
+        };
+
         if self.color {
             let name_display = format!("{:width$}", name, width = max_name_len);
-            let arrow = "→".dimmed();
+            let arrow = arrow.dimmed();
             let change_label = change_type.colored_label();
             let dev_display = if is_dev {
                 " 🔧".dimmed().to_string()
             } else {
                 String::new()
             };
-            let date_colored = released_at
-                .map(|d| {
-                    format!(" ({})", d.format("%Y/%m/%d %H:%M"))
-                        .dimmed()
-                        .to_string()
-                })
-                .unwrap_or_default();
             let var_colored = variable_name
                 .map(|v| format!(" via ${}", v).cyan().to_string())
                 .unwrap_or_default();
 
             writeln!(
                 writer,
-                "  {} {} {} {} [{}]{}{}{}",
+                "  {} {} {} {} [{}]{}{}{}{}{}",
                 name_display,
                 old_version.dimmed(),
                 arrow,
                 new_version.bright_white().bold(),
                 change_label,
-                date_colored,
+                inline_date.dimmed(),
                 var_colored,
+                group_display.magenta(),
+                usage_display.yellow(),
                 dev_display
-            )
+            )?;
+            if !own_line_date.is_empty() {
+                writeln!(writer, "      {}", own_line_date.dimmed())?;
+            }
         } else {
             writeln!(
                 writer,
-                "  {:width$} {} -> {} [{}]{}{}{}",
+                "  {:width$} {} -> {} [{}]{}{}{}{}{}",
                 name,
                 old_version,
                 new_version,
                 change_type.label(),
-                date_display,
+                inline_date,
                 var_display,
+                group_display,
+                usage_display,
                 dev_marker,
                 width = max_name_len
-            )
+            )?;
+            if !own_line_date.is_empty() {
+                writeln!(writer, "      {}", own_line_date)?;
+            }
         }
+
+        Ok(())
+    }
+
+    /// Format release notes below an update line, truncated for display
+    fn format_release_notes(&self, notes: &str, writer: &mut dyn Write) -> std::io::Result<()> {
+        let lines: Vec<&str> = notes.lines().filter(|l| !l.trim().is_empty()).collect();
+
+        for line in lines.iter().take(NOTES_MAX_LINES) {
+            let line = match line.char_indices().nth(NOTES_MAX_LINE_LEN) {
+                Some((end, _)) => format!("{}…", &line[..end]),
+                None => line.to_string(),
+            };
+            if self.color {
+                writeln!(writer, "      {}", line.dimmed())?;
+            } else {
+                writeln!(writer, "      {}", line)?;
+            }
+        }
+
+        if lines.len() > NOTES_MAX_LINES {
+            let more = format!("… ({} more lines)", lines.len() - NOTES_MAX_LINES);
+            if self.color {
+                writeln!(writer, "      {}", more.dimmed())?;
+            } else {
+                writeln!(writer, "      {}", more)?;
+            }
+        }
+
+        Ok(())
     }
 
     /// Format a single skip line
@@ -264,17 +570,32 @@ impl TextFormatter {
         }
     }
 
+    /// Fetch timing appended to a manifest header in verbose mode (e.g., " (fetched in 1.2s)")
+    fn timing_suffix(&self, manifest: &ManifestUpdateResult) -> String {
+        match manifest.duration {
+            Some(duration) if self.verbosity == Verbosity::Verbose => {
+                format!(" (fetched in {:.1}s)", duration.as_secs_f64())
+            }
+            _ => String::new(),
+        }
+    }
+
     /// Format manifest with grouped updates
+    ///
+    /// `usage` maps (language, package name) to the number of manifests declaring it.
+    /// Updates keyed in `shared` are counted in the header but listed elsewhere.
     fn format_manifest_grouped(
         &self,
         manifest: &ManifestUpdateResult,
+        usage: &HashMap<(Language, &str), usize>,
+        shared: &HashSet<UpdateKey<'_>>,
         writer: &mut dyn Write,
     ) -> std::io::Result<()> {
         let prefix = self.dry_run_prefix();
 
         // Collect updates and skips
         let updates: Vec<_> = manifest.updates().collect();
-        let skips: Vec<_> = manifest.skips().collect();
+        let skips: Vec<_> = self.visible_skips(manifest).collect();
 
         // Skip truly empty manifests (no updates and no skips)
         if updates.is_empty() && skips.is_empty() {
@@ -292,26 +613,31 @@ impl TextFormatter {
                 let lang_display = format!("({})", manifest.language);
                 writeln!(
                     writer,
-                    "{} {} — {} updates, {} {}",
+                    "{} {} — {} updates, {} {}{}",
                     path_display.bold(),
                     lang_display.dimmed(),
                     "0".dimmed(),
                     skip_count.to_string().yellow(),
-                    if skip_count == 1 { "skip" } else { "skips" }
+                    if skip_count == 1 { "skip" } else { "skips" },
+                    self.timing_suffix(manifest).dimmed()
                 )?;
             } else {
                 writeln!(
                     writer,
-                    "{} ({}) — 0 updates, {} skips",
-                    path_display, manifest.language, skip_count
+                    "{} ({}) — 0 updates, {} skips{}",
+                    path_display,
+                    manifest.language,
+                    skip_count,
+                    self.timing_suffix(manifest)
                 )?;
             }
 
             // Show skip reasons summary
             let skip_reasons = self.summarize_skip_reasons(&skips);
             for (reason, count, packages) in &skip_reasons {
+                let list_packages = self.verbosity == Verbosity::Verbose && !packages.is_empty();
                 if self.color {
-                    if self.verbosity == Verbosity::Verbose {
+                    if list_packages {
                         // Verbose: show package names
                         writeln!(
                             writer,
@@ -330,7 +656,7 @@ impl TextFormatter {
                             reason.dimmed()
                         )?;
                     }
-                } else if self.verbosity == Verbosity::Verbose {
+                } else if list_packages {
                     writeln!(writer, "  {} {}: {}", count, reason, packages.join(", "))?;
                 } else {
                     writeln!(writer, "  {} {}", count, reason)?;
@@ -340,9 +666,11 @@ impl TextFormatter {
             return Ok(());
         }
 
-        // Separate production and dev dependencies
-        let (prod_updates, dev_updates): (Vec<&UpdateResult>, Vec<&UpdateResult>) =
-            updates.into_iter().partition(|r| {
+        // Separate production and dev dependencies, leaving out shared updates
+        let (prod_updates, dev_updates): (Vec<&UpdateResult>, Vec<&UpdateResult>) = updates
+            .into_iter()
+            .filter(|r| self.update_key(r).is_none_or(|key| !shared.contains(&key)))
+            .partition(|r| {
                 if let UpdateResult::Update { dependency, .. } = r {
                     !dependency.is_dev
                 } else {
@@ -359,7 +687,7 @@ impl TextFormatter {
             write!(writer, " {}", lang_display.dimmed())?;
             writeln!(
                 writer,
-                " — {} {}, {} {}",
+                " — {} {}, {} {}{}",
                 update_count.to_string().green(),
                 if update_count == 1 {
                     "update"
@@ -367,13 +695,19 @@ impl TextFormatter {
                     "updates"
                 },
                 skip_count.to_string().dimmed(),
-                if skip_count == 1 { "skip" } else { "skips" }
+                if skip_count == 1 { "skip" } else { "skips" },
+                self.timing_suffix(manifest).dimmed()
             )?;
         } else {
             writeln!(
                 writer,
-                "{}{} ({}) — {} updates, {} skips",
-                prefix, path_display, manifest.language, update_count, skip_count
+                "{}{} ({}) — {} updates, {} skips{}",
+                prefix,
+                path_display,
+                manifest.language,
+                update_count,
+                skip_count,
+                self.timing_suffix(manifest)
             )?;
         }
 
@@ -392,15 +726,13 @@ impl TextFormatter {
                     dependency,
                     new_version,
                     released_at,
+                    release_notes,
+                    group,
+                    mirror,
                     ..
                 } = result
                 {
-                    // Show "-" for unversioned dependencies
-                    let old_version = if dependency.version_spec.version.is_empty() {
-                        "-"
-                    } else {
-                        &dependency.version_spec.version
-                    };
+                    let old_version = self.current_version(dependency);
                     self.format_update_line(
                         &dependency.name,
                         old_version,
@@ -408,9 +740,22 @@ impl TextFormatter {
                         false,
                         *released_at,
                         dependency.variable_name.as_deref(),
+                        group.as_deref(),
+                        usage
+                            .get(&(dependency.language, dependency.name.as_str()))
+                            .copied()
+                            .unwrap_or(1),
                         max_name_len,
                         writer,
                     )?;
+                    if self.verbosity == Verbosity::Verbose {
+                        if let Some(mirror) = mirror {
+                            writeln!(writer, "      served by mirror {}", mirror)?;
+                        }
+                        if let Some(notes) = release_notes {
+                            self.format_release_notes(notes, writer)?;
+                        }
+                    }
                 }
             }
         }
@@ -422,15 +767,13 @@ impl TextFormatter {
                     dependency,
                     new_version,
                     released_at,
+                    release_notes,
+                    group,
+                    mirror,
                     ..
                 } = result
                 {
-                    // Show "-" for unversioned dependencies
-                    let old_version = if dependency.version_spec.version.is_empty() {
-                        "-"
-                    } else {
-                        &dependency.version_spec.version
-                    };
+                    let old_version = self.current_version(dependency);
                     self.format_update_line(
                         &dependency.name,
                         old_version,
@@ -438,24 +781,44 @@ impl TextFormatter {
                         true,
                         *released_at,
                         dependency.variable_name.as_deref(),
+                        group.as_deref(),
+                        usage
+                            .get(&(dependency.language, dependency.name.as_str()))
+                            .copied()
+                            .unwrap_or(1),
                         max_name_len,
                         writer,
                     )?;
+                    if self.verbosity == Verbosity::Verbose {
+                        if let Some(mirror) = mirror {
+                            writeln!(writer, "      served by mirror {}", mirror)?;
+                        }
+                        if let Some(notes) = release_notes {
+                            self.format_release_notes(notes, writer)?;
+                        }
+                    }
                 }
             }
         }
 
         // Write skips in verbose mode
-        if self.verbosity == Verbosity::Verbose && !skips.is_empty() {
+        let listed_skips: Vec<&UpdateResult> = skips
+            .iter()
+            .copied()
+            .filter(|r| match r {
+                UpdateResult::Skip { reason, .. } => !self.is_hidden_skip(reason),
+                _ => true,
+            })
+            .collect();
+        if self.verbosity == Verbosity::Verbose && !listed_skips.is_empty() {
             writeln!(writer)?;
             if self.color {
                 writeln!(writer, "  {}", "Skipped:".dimmed())?;
             } else {
                 writeln!(writer, "  Skipped:")?;
             }
-            let skip_results: Vec<&UpdateResult> = skips.to_vec();
-            let skip_max_len = self.max_name_length(&skip_results).max(20);
-            for result in &skips {
+            let skip_max_len = self.max_name_length(&listed_skips).max(20);
+            for result in &listed_skips {
                 if let UpdateResult::Skip { dependency, reason } = result {
                     self.format_skip_line(&dependency.name, reason, skip_max_len, writer)?;
                 }
@@ -466,6 +829,211 @@ impl TextFormatter {
         Ok(())
     }
 
+    /// Split updates into batches, in manifest order
+    ///
+    /// Returns (first package, last package, update count) per batch.
+    fn batches<'a>(
+        &self,
+        summary: &'a UpdateSummary,
+        size: usize,
+    ) -> Vec<(&'a str, &'a str, usize)> {
+        let names: Vec<&str> = summary
+            .manifests
+            .iter()
+            .flat_map(|m| m.updates())
+            .map(|r| r.package_name())
+            .collect();
+        names
+            .chunks(size)
+            .map(|chunk| (chunk[0], chunk[chunk.len() - 1], chunk.len()))
+            .collect()
+    }
+
+    /// Write batch boundaries under the summary
+    fn format_batches(
+        &self,
+        summary: &UpdateSummary,
+        writer: &mut dyn Write,
+    ) -> std::io::Result<()> {
+        let Some(size) = self.batch_size else {
+            return Ok(());
+        };
+        let batches = self.batches(summary, size);
+        if batches.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(writer)?;
+        if self.color {
+            writeln!(writer, "{}:", format!("Batches of {}", size).dimmed())?;
+        } else {
+            writeln!(writer, "Batches of {}:", size)?;
+        }
+        let total = batches.len();
+        for (index, (first, last, count)) in batches.into_iter().enumerate() {
+            let range = if count == 1 {
+                first.to_string()
+            } else {
+                format!("{} .. {}", first, last)
+            };
+            let noun = if count == 1 { "update" } else { "updates" };
+            if self.color {
+                writeln!(
+                    writer,
+                    "  {}/{}: {} {}",
+                    index + 1,
+                    total,
+                    range.bold(),
+                    format!("({} {})", count, noun).dimmed()
+                )?;
+            } else {
+                writeln!(
+                    writer,
+                    "  {}/{}: {} ({} {})",
+                    index + 1,
+                    total,
+                    range,
+                    count,
+                    noun
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// (language, name, current version, new version) of an update
+    fn update_key<'a>(&self, result: &'a UpdateResult) -> Option<UpdateKey<'a>> {
+        match result {
+            UpdateResult::Update {
+                dependency,
+                new_version,
+                ..
+            } => Some((
+                dependency.language,
+                dependency.name.as_str(),
+                self.current_version(dependency),
+                new_version.as_str(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Updates of the same package and versions, in the same ecosystem, in several manifests
+    ///
+    /// Returns (key, manifest count), sorted by name.
+    fn shared_updates<'a>(&self, summary: &'a UpdateSummary) -> Vec<(UpdateKey<'a>, usize)> {
+        if !self.dedupe_manifests {
+            return Vec::new();
+        }
+        let mut counts: HashMap<UpdateKey, usize> = HashMap::new();
+        for manifest in &summary.manifests {
+            let keys: HashSet<_> = manifest
+                .updates()
+                .filter_map(|r| self.update_key(r))
+                .collect();
+            for key in keys {
+                *counts.entry(key).or_insert(0) += 1;
+            }
+        }
+        let mut shared: Vec<_> = counts.into_iter().filter(|(_, count)| *count > 1).collect();
+        shared.sort_unstable_by_key(|((language, name, old, new), _)| {
+            (*name, *old, *new, language.display_name())
+        });
+        shared
+    }
+
+    /// Write updates shared by several manifests, one line each
+    fn format_shared_updates(
+        &self,
+        shared: &[(UpdateKey, usize)],
+        writer: &mut dyn Write,
+    ) -> std::io::Result<()> {
+        if shared.is_empty() {
+            return Ok(());
+        }
+
+        if self.color {
+            writeln!(writer, "{}", "Shared updates:".bold())?;
+        } else {
+            writeln!(writer, "Shared updates:")?;
+        }
+        for ((_, name, old, new), count) in shared {
+            let change_type = self.change_type(old, new);
+            let label = format!("{} ({} manifests):", name, count);
+            if self.color {
+                writeln!(
+                    writer,
+                    "  {} {} {} {} [{}]",
+                    label,
+                    old.dimmed(),
+                    "→".dimmed(),
+                    new.bright_white().bold(),
+                    change_type.colored_label()
+                )?;
+            } else {
+                writeln!(
+                    writer,
+                    "  {} {} -> {} [{}]",
+                    label,
+                    old,
+                    new,
+                    change_type.label()
+                )?;
+            }
+        }
+        writeln!(writer)?;
+        Ok(())
+    }
+
+    /// Number of manifests declaring each package, updated or skipped, per ecosystem
+    fn manifest_usage<'a>(
+        &self,
+        summary: &'a UpdateSummary,
+    ) -> HashMap<(Language, &'a str), usize> {
+        let mut usage = HashMap::new();
+        for manifest in &summary.manifests {
+            let packages: HashSet<(Language, &str)> = manifest
+                .results
+                .iter()
+                .map(|r| (r.dependency().language, r.dependency().name.as_str()))
+                .collect();
+            for package in packages {
+                *usage.entry(package).or_insert(0) += 1;
+            }
+        }
+        usage
+    }
+
+    /// Updates furthest behind, ranked by major distance, then minor distance
+    ///
+    /// Patch-only updates are left out. Returns (name, old, new, gap).
+    fn most_outdated<'a>(
+        &self,
+        summary: &'a UpdateSummary,
+    ) -> Vec<(&'a str, &'a str, &'a str, (u64, u64))> {
+        let mut ranked: Vec<_> = summary
+            .manifests
+            .iter()
+            .flat_map(|m| m.updates())
+            .filter_map(|result| match result {
+                UpdateResult::Update {
+                    dependency,
+                    new_version,
+                    ..
+                } => {
+                    let old = dependency.version_spec.version.as_str();
+                    version_gap(old, new_version)
+                        .filter(|gap| *gap != (0, 0))
+                        .map(|gap| (dependency.name.as_str(), old, new_version.as_str(), gap))
+                }
+                _ => None,
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.3.cmp(&a.3).then_with(|| a.0.cmp(b.0)));
+        ranked.truncate(MOST_OUTDATED_LIMIT);
+        ranked
+    }
+
     /// Count updates by change type
     fn count_by_change_type(&self, summary: &UpdateSummary) -> (usize, usize, usize, usize, usize) {
         let mut major = 0;
@@ -482,10 +1050,7 @@ impl TextFormatter {
                     ..
                 } = result
                 {
-                    match VersionChangeType::from_versions(
-                        &dependency.version_spec.version,
-                        new_version,
-                    ) {
+                    match self.change_type(&dependency.version_spec.version, new_version) {
                         VersionChangeType::Major => major += 1,
                         VersionChangeType::Minor => minor += 1,
                         VersionChangeType::Patch => patch += 1,
@@ -505,7 +1070,7 @@ impl TextFormatter {
         let mut counts: HashMap<String, usize> = HashMap::new();
 
         for manifest in &summary.manifests {
-            for result in manifest.skips() {
+            for result in self.visible_skips(manifest) {
                 if let UpdateResult::Skip { reason, .. } = result {
                     let key = self.format_skip_reason(reason);
                     *counts.entry(key).or_insert(0) += 1;
@@ -514,31 +1079,34 @@ impl TextFormatter {
         }
 
         let mut result: Vec<_> = counts.into_iter().collect();
-        result.sort_by(|a, b| b.1.cmp(&a.1)); // Sort by count descending
+        result.sort_by_key(|r| std::cmp::Reverse(r.1)); // Sort by count descending
         result
     }
 
     /// Summarize skip reasons from a list of skip results
     /// Returns: Vec<(reason_string, count, package_names)>
+    ///
+    /// Hidden skips (--hide-latest) are counted but their package names are left out.
     fn summarize_skip_reasons(&self, skips: &[&UpdateResult]) -> Vec<(String, usize, Vec<String>)> {
         use std::collections::HashMap;
-        let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+        let mut groups: HashMap<String, (usize, Vec<String>)> = HashMap::new();
 
         for result in skips {
             if let UpdateResult::Skip { dependency, reason } = result {
                 let key = self.format_skip_reason(reason);
-                groups.entry(key).or_default().push(dependency.name.clone());
+                let (count, packages) = groups.entry(key).or_default();
+                *count += 1;
+                if !self.is_hidden_skip(reason) {
+                    packages.push(dependency.name.clone());
+                }
             }
         }
 
         let mut result: Vec<_> = groups
             .into_iter()
-            .map(|(reason, packages)| {
-                let count = packages.len();
-                (reason, count, packages)
-            })
+            .map(|(reason, (count, packages))| (reason, count, packages))
             .collect();
-        result.sort_by(|a, b| b.1.cmp(&a.1)); // Sort by count descending
+        result.sort_by_key(|r| std::cmp::Reverse(r.1)); // Sort by count descending
         result
     }
 }
@@ -551,18 +1119,44 @@ impl OutputFormatter for TextFormatter {
         }
 
         // Format each manifest
+        let usage = self.manifest_usage(&result.summary);
+        let shared = self.shared_updates(&result.summary);
+        let shared_keys: HashSet<_> = shared.iter().map(|(key, _)| *key).collect();
         for manifest in &result.summary.manifests {
-            self.format_manifest_grouped(manifest, writer)?;
+            self.format_manifest_grouped(manifest, &usage, &shared_keys, writer)?;
         }
+        self.format_shared_updates(&shared, writer)?;
 
-        // Format errors if any
-        if !result.errors.is_empty() && self.verbosity != Verbosity::Quiet {
+        // Format errors if any (registry failures are aggregated with quiet errors)
+        let (warnings, errors): (Vec<_>, Vec<_>) = result
+            .errors
+            .iter()
+            .filter(|e| {
+                !(self.quiet_errors && matches!(e, OrchestratorError::RegistryError { .. }))
+            })
+            .partition(|e| e.is_warning());
+        if !warnings.is_empty() && self.verbosity != Verbosity::Quiet {
+            if self.color {
+                writeln!(writer, "{}:", "Warnings".yellow().bold())?;
+            } else {
+                writeln!(writer, "Warnings:")?;
+            }
+            for warning in warnings {
+                if self.color {
+                    writeln!(writer, "  {} {}", "!".yellow(), warning)?;
+                } else {
+                    writeln!(writer, "  - {}", warning)?;
+                }
+            }
+            writeln!(writer)?;
+        }
+        if !errors.is_empty() && self.verbosity != Verbosity::Quiet {
             if self.color {
                 writeln!(writer, "{}:", "Errors".red().bold())?;
             } else {
                 writeln!(writer, "Errors:")?;
             }
-            for error in &result.errors {
+            for error in errors {
                 if self.color {
                     writeln!(writer, "  {} {}", "✗".red(), error)?;
                 } else {
@@ -573,7 +1167,9 @@ impl OutputFormatter for TextFormatter {
         }
 
         // Format summary
-        self.format_summary(&result.summary, writer)?;
+        if self.show_summary {
+            self.format_summary(&result.summary, writer)?;
+        }
 
         Ok(())
     }
@@ -585,7 +1181,12 @@ impl OutputFormatter for TextFormatter {
     ) -> std::io::Result<()> {
         let prefix = self.dry_run_prefix();
         let updates = summary.total_updates();
-        let skips = summary.total_skips();
+        let skips: usize = summary
+            .manifests
+            .iter()
+            .map(|m| self.visible_skips(m).count())
+            .sum();
+        let unresolved = summary.total_skips() - skips;
 
         if self.verbosity == Verbosity::Quiet {
             // Minimal output
@@ -661,6 +1262,13 @@ impl OutputFormatter for TextFormatter {
                 }
                 writeln!(writer)?;
             }
+            if unresolved > 0 {
+                writeln!(
+                    writer,
+                    "  {} package(s) could not be resolved",
+                    unresolved.to_string().red()
+                )?;
+            }
         } else {
             writeln!(writer, "{}Summary:", prefix)?;
             if updates > 0 {
@@ -690,8 +1298,13 @@ impl OutputFormatter for TextFormatter {
                 writeln!(writer, "  No packages updated")?;
             }
             writeln!(writer, "  {} package(s) skipped", skips)?;
+            if unresolved > 0 {
+                writeln!(writer, "  {} package(s) could not be resolved", unresolved)?;
+            }
         }
 
+        self.format_batches(summary, writer)?;
+
         // Verbose: show breakdown by language
         if self.verbosity == Verbosity::Verbose {
             writeln!(writer)?;
@@ -704,7 +1317,10 @@ impl OutputFormatter for TextFormatter {
                 let manifests: Vec<_> = summary.by_language(*language).collect();
                 if !manifests.is_empty() {
                     let lang_updates: usize = manifests.iter().map(|m| m.update_count()).sum();
-                    let lang_skips: usize = manifests.iter().map(|m| m.skip_count()).sum();
+                    let lang_skips: usize = manifests
+                        .iter()
+                        .map(|m| self.visible_skips(m).count())
+                        .sum();
                     if self.color {
                         writeln!(
                             writer,
@@ -722,6 +1338,36 @@ impl OutputFormatter for TextFormatter {
                     }
                 }
             }
+
+            let outdated = self.most_outdated(summary);
+            if !outdated.is_empty() {
+                writeln!(writer)?;
+                if self.color {
+                    writeln!(writer, "{}:", "Most outdated".dimmed())?;
+                } else {
+                    writeln!(writer, "Most outdated:")?;
+                }
+                for (name, old, new, (majors, minors)) in outdated {
+                    let behind = if majors > 0 {
+                        format!("{} major behind", majors)
+                    } else {
+                        format!("{} minor behind", minors)
+                    };
+                    if self.color {
+                        writeln!(
+                            writer,
+                            "  {} {} {} {} ({})",
+                            name.bold(),
+                            old.dimmed(),
+                            "→".dimmed(),
+                            new.green(),
+                            behind
+                        )?;
+                    } else {
+                        writeln!(writer, "  {} {} -> {} ({})", name, old, new, behind)?;
+                    }
+                }
+            }
         }
 
         Ok(())
@@ -732,7 +1378,7 @@ impl OutputFormatter for TextFormatter {
         manifest: &ManifestUpdateResult,
         writer: &mut dyn Write,
     ) -> std::io::Result<()> {
-        self.format_manifest_grouped(manifest, writer)
+        self.format_manifest_grouped(manifest, &HashMap::new(), &HashSet::new(), writer)
     }
 }
 
@@ -797,6 +1443,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_version_change_type_zerover_minor() {
+        assert_eq!(
+            VersionChangeType::classify("0.2.0", "0.3.0", false),
+            VersionChangeType::Minor
+        );
+        assert_eq!(
+            VersionChangeType::classify("0.2.0", "0.3.0", true),
+            VersionChangeType::Major
+        );
+        // Only 0.x series are affected
+        assert_eq!(
+            VersionChangeType::classify("1.2.0", "1.3.0", true),
+            VersionChangeType::Minor
+        );
+        assert_eq!(
+            VersionChangeType::classify("0.2.0", "0.2.5", true),
+            VersionChangeType::Patch
+        );
+    }
+
     #[test]
     fn test_version_change_type_patch() {
         assert_eq!(
@@ -903,6 +1570,22 @@ mod tests {
         assert!(!output_str.contains("Summary:"));
     }
 
+    #[test]
+    fn test_format_without_summary() {
+        let formatter =
+            TextFormatter::with_color(Verbosity::Normal, false, false).with_summary(false);
+        let result = create_test_result();
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+
+        assert!(output_str.contains("4.17.21 -> 4.18.0 [minor]"));
+        assert!(output_str.contains("typescript"));
+        assert!(!output_str.contains("Summary:"));
+        assert!(!output_str.contains("package(s) updated"));
+    }
+
     #[test]
     fn test_format_verbose() {
         let formatter = TextFormatter::with_color(Verbosity::Verbose, false, false);
@@ -919,6 +1602,425 @@ mod tests {
         assert!(output_str.contains("By language:"));
     }
 
+    #[test]
+    fn test_most_outdated_ranking() {
+        let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+        manifest.add_result(UpdateResult::update(
+            sample_dependency("minor-behind", "2.3.0", false),
+            "2.4.0",
+        ));
+        manifest.add_result(UpdateResult::update(
+            sample_dependency("patch-only", "1.0.0", false),
+            "1.0.5",
+        ));
+        manifest.add_result(UpdateResult::update(
+            sample_dependency("majors-behind", "1.2.0", false),
+            "4.0.0",
+        ));
+        let mut summary = UpdateSummary::new(false);
+        summary.add_manifest(manifest);
+
+        let formatter = TextFormatter::with_color(Verbosity::Verbose, false, false);
+        let ranked = formatter.most_outdated(&summary);
+        let names: Vec<_> = ranked.iter().map(|r| r.0).collect();
+        assert_eq!(names, vec!["majors-behind", "minor-behind"]);
+        assert_eq!(ranked[0].3, (3, 0));
+        assert_eq!(ranked[1].3, (0, 1));
+
+        let mut output = Vec::new();
+        formatter.format_summary(&summary, &mut output).unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains("Most outdated:"));
+        assert!(output_str.contains("  majors-behind 1.2.0 -> 4.0.0 (3 major behind)"));
+    }
+
+    #[test]
+    fn test_format_hide_latest_omits_already_latest_skips() {
+        let mut result = create_test_result();
+        let pinned = sample_dependency("react", "18.2.0", false);
+        result.summary.manifests[0].add_result(UpdateResult::skip(pinned, SkipReason::Pinned));
+
+        let formatter =
+            TextFormatter::with_color(Verbosity::Verbose, false, false).with_hide_latest(true);
+        let mut output = Vec::new();
+        formatter.format(&result, &mut output).unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+
+        // Already-latest packages are counted but not listed
+        assert!(output_str.contains("2 updates, 2 skips"));
+        assert!(output_str.contains("Skipped:"));
+        assert!(output_str.contains("react"));
+        assert!(!output_str.contains("express"));
+
+        // Without the flag they are listed as before
+        let formatter = TextFormatter::with_color(Verbosity::Verbose, false, false);
+        let mut output = Vec::new();
+        formatter.format(&result, &mut output).unwrap();
+        assert!(String::from_utf8(output).unwrap().contains("express"));
+    }
+
+    #[test]
+    fn test_format_quiet_errors_collapses_fetch_failures() {
+        let mut result = create_test_result();
+        for name in ["@internal/auth", "@internal/db", "@internal/ui"] {
+            let dep = sample_dependency(name, "1.0.0", false);
+            result.summary.manifests[0]
+                .add_result(UpdateResult::skip_fetch_failed(dep, "404 Not Found"));
+            result.errors.push(OrchestratorError::RegistryError {
+                package: name.to_string(),
+                message: "404 Not Found".to_string(),
+            });
+        }
+
+        let formatter =
+            TextFormatter::with_color(Verbosity::Verbose, false, false).with_quiet_errors(true);
+        let mut output = Vec::new();
+        formatter.format(&result, &mut output).unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+
+        // Fetch failures are neither listed nor counted as skips, only aggregated
+        assert!(!output_str.contains("@internal"));
+        assert!(!output_str.contains("fetch failed"));
+        assert!(!output_str.contains("Errors:"));
+        assert!(output_str.contains("2 updates, 1 skips"));
+        assert!(output_str.contains("  1 package(s) skipped\n"));
+        assert_eq!(
+            output_str
+                .matches("3 package(s) could not be resolved")
+                .count(),
+            1
+        );
+        assert!(output_str.contains("lodash"));
+
+        // Without the flag every failure is listed
+        let mut output = Vec::new();
+        TextFormatter::with_color(Verbosity::Verbose, false, false)
+            .format(&result, &mut output)
+            .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains("@internal/db"));
+        assert!(!output_str.contains("could not be resolved"));
+    }
+
+    #[test]
+    fn test_format_duplicate_dependency_as_warning() {
+        let mut result = create_test_result();
+        result.errors.push(OrchestratorError::DuplicateDependency {
+            path: "package.json".to_string(),
+            package: "lodash".to_string(),
+        });
+
+        let formatter = TextFormatter::with_color(Verbosity::Normal, false, false);
+        let mut output = Vec::new();
+        formatter.format(&result, &mut output).unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+
+        assert!(output_str.contains("Warnings:\n  - Duplicate dependency lodash in package.json\n"));
+        assert!(!output_str.contains("Errors:"));
+    }
+
+    #[test]
+    fn test_format_lock_versions() {
+        let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+        let dep = sample_dependency("lodash", "4.17.0", false).with_resolved_version("4.17.5");
+        manifest.add_result(UpdateResult::update(dep, "4.17.21"));
+        let mut summary = UpdateSummary::new(true);
+        summary.add_manifest(manifest);
+        let result = OrchestratorResult {
+            summary,
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        let mut output = Vec::new();
+        TextFormatter::with_color(Verbosity::Normal, true, false)
+            .with_lock_versions(true)
+            .format(&result, &mut output)
+            .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains(" 4.17.5 -> 4.17.21 [patch]"));
+    }
+
+    #[test]
+    fn test_format_usage_across_manifests() {
+        let mut summary = UpdateSummary::new(true);
+        for path in ["apps/web/package.json", "apps/api/package.json"] {
+            let mut manifest = ManifestUpdateResult::new(path, Language::Node);
+            let dep = sample_dependency("react", "17.0.2", false);
+            manifest.add_result(UpdateResult::update(dep, "18.2.0"));
+            summary.add_manifest(manifest);
+        }
+        let mut manifest = ManifestUpdateResult::new("apps/cli/package.json", Language::Node);
+        let dep = sample_dependency("chalk", "4.1.0", false);
+        manifest.add_result(UpdateResult::update(dep, "5.3.0"));
+        summary.add_manifest(manifest);
+        let result = OrchestratorResult {
+            summary,
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        let mut output = Vec::new();
+        TextFormatter::with_color(Verbosity::Normal, true, false)
+            .format(&result, &mut output)
+            .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains("17.0.2 -> 18.2.0 [major] (used in 2 files)"));
+        assert!(output_str.contains("4.1.0 -> 5.3.0 [major]\n"));
+    }
+
+    #[test]
+    fn test_format_dedupe_manifests() {
+        let mut summary = UpdateSummary::new(true);
+        for path in [
+            "packages/a/package.json",
+            "packages/b/package.json",
+            "packages/c/package.json",
+        ] {
+            let mut manifest = ManifestUpdateResult::new(path, Language::Node);
+            let dep = sample_dependency("typescript", "5.3.3", true);
+            manifest.add_result(UpdateResult::update(dep, "5.4.2"));
+            summary.add_manifest(manifest);
+        }
+        let mut manifest = ManifestUpdateResult::new("packages/d/package.json", Language::Node);
+        let dep = sample_dependency("typescript", "5.2.2", true);
+        manifest.add_result(UpdateResult::update(dep, "5.4.2"));
+        summary.add_manifest(manifest);
+        let result = OrchestratorResult {
+            summary,
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        let mut output = Vec::new();
+        TextFormatter::with_color(Verbosity::Normal, true, false)
+            .with_dedupe_manifests(true)
+            .format(&result, &mut output)
+            .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str
+            .contains("Shared updates:\n  typescript (3 manifests): 5.3.3 -> 5.4.2 [minor]\n"));
+        assert_eq!(output_str.matches("5.3.3 -> 5.4.2").count(), 1);
+        // A different current version is not grouped
+        assert!(output_str.contains("5.2.2 -> 5.4.2 [minor]"));
+        // Manifest headers still count the shared update
+        assert!(output_str.contains("packages/a/package.json (Node.js) — 1 updates"));
+    }
+
+    #[test]
+    fn test_format_same_name_across_languages() {
+        let mut summary = UpdateSummary::new(true);
+        let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+        let dep = sample_dependency("debug", "1.0.0", false);
+        manifest.add_result(UpdateResult::update(dep, "2.0.0"));
+        summary.add_manifest(manifest);
+        let mut manifest = ManifestUpdateResult::new("Cargo.toml", Language::Rust);
+        let spec = VersionSpec::new(VersionSpecKind::Exact, "1.0.0", "1.0.0");
+        let dep = Dependency::new("debug", spec, false, Language::Rust);
+        manifest.add_result(UpdateResult::update(dep, "2.0.0"));
+        summary.add_manifest(manifest);
+        let result = OrchestratorResult {
+            summary,
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        let mut output = Vec::new();
+        TextFormatter::with_color(Verbosity::Normal, true, false)
+            .with_dedupe_manifests(true)
+            .format(&result, &mut output)
+            .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        // npm's debug and the crate debug are different packages
+        assert!(!output_str.contains("Shared updates:"));
+        assert!(!output_str.contains("used in 2 files"));
+        assert_eq!(output_str.matches("1.0.0 -> 2.0.0 [major]").count(), 2);
+    }
+
+    #[test]
+    fn test_format_fits_terminal_width() {
+        let released_at = "2024-01-15T10:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let mut summary = UpdateSummary::new(true);
+        let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+        let long = sample_dependency(
+            "@organization/very-long-package-name-for-testing",
+            "1.0.0",
+            false,
+        );
+        manifest.add_result(UpdateResult::update_with_date(long, "1.2.0", released_at));
+        let short = sample_dependency("lodash", "4.17.20", true);
+        manifest.add_result(UpdateResult::update_with_date(
+            short,
+            "4.17.21",
+            released_at,
+        ));
+        summary.add_manifest(manifest);
+        let result = OrchestratorResult {
+            summary,
+            write_results: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        let ansi = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+        for color in [false, true] {
+            let mut output = Vec::new();
+            TextFormatter::with_color(Verbosity::Normal, true, color)
+                .with_width(50)
+                .format(&result, &mut output)
+                .unwrap();
+            let output_str = String::from_utf8(output).unwrap();
+            let plain = ansi.replace_all(&output_str, "");
+
+            // Update lines and their wrapped dates
+            for line in plain.lines().filter(|l| l.starts_with("  ")) {
+                assert!(display_width(line) <= 50, "too wide: {:?}", line);
+            }
+            // The long name is elided and its date moved to its own line
+            let arrow = if color { "→" } else { "->" };
+            assert!(plain.contains(&format!("… 1.0.0 {} 1.2.0 [minor]\n", arrow)));
+            assert!(plain.contains("\n      (2024/01/15 10:30)\n"));
+            assert!(!plain.contains("very-long-package-name-for-testing"));
+        }
+
+        // Wide terminals keep the line intact
+        let mut output = Vec::new();
+        TextFormatter::with_color(Verbosity::Normal, true, false)
+            .with_width(120)
+            .format(&result, &mut output)
+            .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains(
+            "@organization/very-long-package-name-for-testing 1.0.0 -> 1.2.0 [minor] (2024/01/15 10:30)"
+        ));
+    }
+
+    #[test]
+    fn test_format_batch_boundaries() {
+        let mut summary = UpdateSummary::new(true);
+        let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+        for i in 0..12 {
+            let dep = sample_dependency(&format!("pkg-{:02}", i), "1.0.0", false);
+            manifest.add_result(UpdateResult::update(dep, "1.1.0"));
+        }
+        summary.add_manifest(manifest);
+
+        let mut output = Vec::new();
+        TextFormatter::with_color(Verbosity::Normal, true, false)
+            .with_batch_size(Some(5))
+            .format_summary(&summary, &mut output)
+            .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains(
+            "Batches of 5:\n  1/3: pkg-00 .. pkg-04 (5 updates)\n  2/3: pkg-05 .. pkg-09 (5 updates)\n  3/3: pkg-10 .. pkg-11 (2 updates)\n"
+        ));
+
+        // Without --batch-size no boundaries are reported
+        let mut output = Vec::new();
+        TextFormatter::with_color(Verbosity::Normal, true, false)
+            .format_summary(&summary, &mut output)
+            .unwrap();
+        assert!(!String::from_utf8(output).unwrap().contains("Batches"));
+    }
+
+    #[test]
+    fn test_format_verbose_fetch_timing() {
+        let mut result = create_test_result();
+        result.summary.manifests[0].duration = Some(std::time::Duration::from_millis(1234));
+
+        let mut output = Vec::new();
+        TextFormatter::with_color(Verbosity::Verbose, false, false)
+            .format(&result, &mut output)
+            .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains("skips (fetched in 1.2s)"));
+
+        // Only shown in verbose mode
+        let mut output = Vec::new();
+        TextFormatter::with_color(Verbosity::Normal, false, false)
+            .format(&result, &mut output)
+            .unwrap();
+        assert!(!String::from_utf8(output).unwrap().contains("fetched in"));
+    }
+
+    #[test]
+    fn test_format_verbose_release_notes_truncated() {
+        let formatter = TextFormatter::with_color(Verbosity::Verbose, false, false);
+        let mut result = create_test_result();
+        let notes = (1..=8)
+            .map(|i| format!("line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let update = result.summary.manifests[0]
+            .results
+            .iter_mut()
+            .find(|r| r.is_update())
+            .unwrap();
+        update.set_release_notes(notes);
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+
+        assert!(output_str.contains("line 5"));
+        assert!(!output_str.contains("line 6"));
+        assert!(output_str.contains("(3 more lines)"));
+
+        // Normal verbosity omits notes entirely
+        let formatter = TextFormatter::with_color(Verbosity::Normal, false, false);
+        let mut output = Vec::new();
+        formatter.format(&result, &mut output).unwrap();
+        assert!(!String::from_utf8(output).unwrap().contains("line 1"));
+    }
+
+    #[test]
+    fn test_format_update_group() {
+        let formatter = TextFormatter::with_color(Verbosity::Normal, false, false);
+        let mut result = create_test_result();
+        for update in result.summary.manifests[0]
+            .results
+            .iter_mut()
+            .filter(|r| r.is_update())
+        {
+            update.set_group("web");
+        }
+        let mut output = Vec::new();
+
+        formatter.format(&result, &mut output).unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+
+        assert_eq!(output_str.matches("(group: web)").count(), 2);
+    }
+
+    #[test]
+    fn test_format_update_mirror_verbose() {
+        let mut result = create_test_result();
+        result.summary.manifests[0]
+            .results
+            .iter_mut()
+            .find(|r| r.is_update())
+            .unwrap()
+            .set_mirror("https://npm.example.com");
+
+        let formatter = TextFormatter::with_color(Verbosity::Verbose, false, false);
+        let mut output = Vec::new();
+        formatter.format(&result, &mut output).unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output_str
+                .matches("served by mirror https://npm.example.com")
+                .count(),
+            1
+        );
+
+        // Normal verbosity leaves the mirror to the JSON output
+        let formatter = TextFormatter::with_color(Verbosity::Normal, false, false);
+        let mut output = Vec::new();
+        formatter.format(&result, &mut output).unwrap();
+        assert!(!String::from_utf8(output)
+            .unwrap()
+            .contains("served by mirror"));
+    }
+
     #[test]
     fn test_format_dry_run() {
         let formatter = TextFormatter::with_color(Verbosity::Normal, true, false);
@@ -3,11 +3,47 @@
 //! This module provides:
 //! - Detection of installed package managers
 //! - Execution of install commands for each language
+//! - Targeted updates of just the changed packages where the package manager supports it
+//! - Detection of lock files left stale by manifest updates
+//! - Restoring the original manifests when an install fails
+//! - Execution of user-supplied post-update hook commands
 
 use crate::domain::Language;
-use std::path::Path;
+use crate::manifest::find_nimble_file;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Output};
 
+/// Composer lock file name
+const COMPOSER_LOCK_FILENAME: &str = "composer.lock";
+
+/// A package changed by this run, for targeted update commands
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdatedPackage {
+    /// Package name as written in the manifest
+    pub name: String,
+    /// Version requirement now written in the manifest (e.g., `~4.17.21` or `4.17.21`)
+    pub version: String,
+    /// Whether the requirement pins an exact version rather than a range
+    pub exact: bool,
+}
+
+impl UpdatedPackage {
+    /// Create a new updated package with a range requirement
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            exact: false,
+        }
+    }
+
+    /// Mark whether the requirement pins an exact version
+    pub fn with_exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+}
+
 /// Result of a package manager installation
 #[derive(Debug, Clone)]
 pub struct InstallResult {
@@ -62,20 +98,82 @@ impl InstallResult {
 pub trait PackageManagerRunner {
     /// Run the install command for a language in the specified directory
     fn run_install(&self, language: Language, working_dir: &Path) -> InstallResult;
+
+    /// Update only the given packages, falling back to a full install by default
+    fn run_update(
+        &self,
+        language: Language,
+        working_dir: &Path,
+        _packages: &[UpdatedPackage],
+    ) -> InstallResult {
+        self.run_install(language, working_dir)
+    }
+}
+
+/// Read the Corepack `packageManager` field (e.g., "pnpm@8.15.0") from package.json
+fn declared_node_pm(working_dir: &Path) -> Option<&'static str> {
+    let content = std::fs::read_to_string(working_dir.join("package.json")).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let field = json.get("packageManager")?.as_str()?;
+    let name = field.split('@').next().unwrap_or("").trim();
+    match name {
+        "npm" => Some("npm"),
+        "yarn" => Some("yarn"),
+        "pnpm" => Some("pnpm"),
+        "bun" => Some("bun"),
+        _ => None,
+    }
+}
+
+/// Runs a command in a directory and captures its output
+type CommandRunner = fn(&[&str], &Path) -> std::io::Result<Output>;
+
+/// Run a command and capture output
+fn run_command(command: &[&str], working_dir: &Path) -> std::io::Result<Output> {
+    if command.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Empty command",
+        ));
+    }
+
+    Command::new(command[0])
+        .args(&command[1..])
+        .current_dir(working_dir)
+        .output()
 }
 
 /// Default package manager runner that executes real commands
-#[derive(Debug, Default)]
-pub struct SystemPackageManager;
+#[derive(Debug)]
+pub struct SystemPackageManager {
+    run_command: CommandRunner,
+}
+
+impl Default for SystemPackageManager {
+    fn default() -> Self {
+        Self { run_command }
+    }
+}
 
 impl SystemPackageManager {
     /// Create a new system package manager
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Run commands through `run_command` instead of spawning them
+    #[cfg(test)]
+    fn with_command_runner(run_command: CommandRunner) -> Self {
+        Self { run_command }
     }
 
     /// Detect the Node.js package manager to use
     fn detect_node_pm(&self, working_dir: &Path) -> Option<&'static str> {
+        // The Corepack `packageManager` field declares the manager explicitly,
+        // so it takes precedence over any lockfile
+        if let Some(pm) = declared_node_pm(working_dir) {
+            return Some(pm);
+        }
         // Check for lockfiles in order of preference
         if working_dir.join("pnpm-lock.yaml").exists() {
             return Some("pnpm");
@@ -144,6 +242,14 @@ impl SystemPackageManager {
             "bundle" => vec!["bundle", "install"],
             // PHP
             "composer" => vec!["composer", "install"],
+            // Deno
+            "deno" => vec!["deno", "install"],
+            // Nim
+            "nimble" => vec!["nimble", "install", "--depsOnly"],
+            // R
+            "Rscript" => vec!["Rscript", "-e", "renv::restore()"],
+            "shards" => vec!["shards", "install"],
+            "pre-commit" => vec!["pre-commit", "install-hooks"],
             // Java/Gradle
             "gradle" => vec!["gradle", "dependencies"],
             "./gradlew" => vec!["./gradlew", "dependencies"],
@@ -151,25 +257,39 @@ impl SystemPackageManager {
         }
     }
 
-    /// Run a command and capture output
-    fn run_command(&self, command: &[&str], working_dir: &Path) -> std::io::Result<Output> {
-        if command.is_empty() {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Empty command",
-            ));
+    /// Get the command updating just `packages`, if the package manager supports it
+    ///
+    /// Targeted updates leave the rest of the lock file untouched, unlike a
+    /// blanket install or update. npm saves an explicit range as written but
+    /// gives an exact version its default `^`, so exact requirements are
+    /// installed with `--save-exact`. That flag would also pin ranges to the
+    /// resolved version, so a mix of both falls back to a full install.
+    fn get_update_command(&self, pm: &str, packages: &[UpdatedPackage]) -> Option<Vec<String>> {
+        if packages.is_empty() {
+            return None;
         }
-
-        Command::new(command[0])
-            .args(&command[1..])
-            .current_dir(working_dir)
-            .output()
+        let mut command: Vec<String> = match pm {
+            "npm" if packages.iter().all(|p| p.exact) => {
+                vec!["npm".into(), "install".into(), "--save-exact".into()]
+            }
+            "npm" if packages.iter().all(|p| !p.exact) => vec!["npm".into(), "install".into()],
+            "composer" => vec!["composer".into(), "update".into()],
+            "cargo" => vec!["cargo".into(), "update".into()],
+            _ => return None,
+        };
+        for package in packages {
+            match pm {
+                "npm" => command.push(format!("{}@{}", package.name, package.version)),
+                "cargo" => command.extend(["-p".to_string(), package.name.clone()]),
+                _ => command.push(package.name.clone()),
+            }
+        }
+        Some(command)
     }
-}
 
-impl PackageManagerRunner for SystemPackageManager {
-    fn run_install(&self, language: Language, working_dir: &Path) -> InstallResult {
-        let pm = match language {
+    /// Detect the package manager for a language in a directory
+    fn detect_pm(&self, language: Language, working_dir: &Path) -> Option<&'static str> {
+        match language {
             Language::Node => self.detect_node_pm(working_dir),
             Language::Python => self.detect_python_pm(working_dir),
             Language::Rust => {
@@ -212,20 +332,45 @@ impl PackageManagerRunner for SystemPackageManager {
                     None
                 }
             }
-        };
-
-        let Some(pm) = pm else {
-            return InstallResult::skipped(language);
-        };
-
-        let command_parts = self.get_install_command(pm);
-        if command_parts.is_empty() {
-            return InstallResult::skipped(language);
+            Language::Deno => {
+                if working_dir.join("deno.json").exists() {
+                    Some("deno")
+                } else {
+                    None
+                }
+            }
+            Language::Nim => find_nimble_file(working_dir).map(|_| "nimble"),
+            // Base images are pulled at build time; there is nothing to install
+            Language::Docker => None,
+            Language::R => {
+                if working_dir.join("renv.lock").exists() {
+                    Some("Rscript")
+                } else {
+                    None
+                }
+            }
+            Language::Crystal => {
+                if working_dir.join("shard.yml").exists() {
+                    Some("shards")
+                } else {
+                    None
+                }
+            }
+            Language::PreCommit => {
+                if working_dir.join(".pre-commit-config.yaml").exists() {
+                    Some("pre-commit")
+                } else {
+                    None
+                }
+            }
         }
+    }
 
-        let command_str = command_parts.join(" ");
+    /// Run a package manager command, reporting it as an install result
+    fn execute(&self, language: Language, command: &[&str], working_dir: &Path) -> InstallResult {
+        let command_str = command.join(" ");
 
-        match self.run_command(&command_parts, working_dir) {
+        match (self.run_command)(command, working_dir) {
             Ok(output) => {
                 let stdout = String::from_utf8_lossy(&output.stdout).to_string();
                 let stderr = String::from_utf8_lossy(&output.stderr).to_string();
@@ -246,6 +391,40 @@ impl PackageManagerRunner for SystemPackageManager {
     }
 }
 
+impl PackageManagerRunner for SystemPackageManager {
+    fn run_install(&self, language: Language, working_dir: &Path) -> InstallResult {
+        let Some(pm) = self.detect_pm(language, working_dir) else {
+            return InstallResult::skipped(language);
+        };
+
+        let command_parts = self.get_install_command(pm);
+        if command_parts.is_empty() {
+            return InstallResult::skipped(language);
+        }
+
+        self.execute(language, &command_parts, working_dir)
+    }
+
+    fn run_update(
+        &self,
+        language: Language,
+        working_dir: &Path,
+        packages: &[UpdatedPackage],
+    ) -> InstallResult {
+        let Some(pm) = self.detect_pm(language, working_dir) else {
+            return InstallResult::skipped(language);
+        };
+
+        match self.get_update_command(pm, packages) {
+            Some(command) => {
+                let command_parts: Vec<&str> = command.iter().map(String::as_str).collect();
+                self.execute(language, &command_parts, working_dir)
+            }
+            None => self.run_install(language, working_dir),
+        }
+    }
+}
+
 /// Run install commands for all specified languages
 pub fn run_installs<R: PackageManagerRunner>(
     runner: &R,
@@ -258,6 +437,108 @@ pub fn run_installs<R: PackageManagerRunner>(
         .collect()
 }
 
+/// Update the changed packages of each language, in order
+pub fn run_updates<R: PackageManagerRunner>(
+    runner: &R,
+    updates: &[(Language, Vec<UpdatedPackage>)],
+    working_dir: &Path,
+) -> Vec<InstallResult> {
+    updates
+        .iter()
+        .map(|(lang, packages)| runner.run_update(*lang, working_dir, packages))
+        .collect()
+}
+
+/// Whether any install that actually ran failed (skipped installs don't count)
+pub fn any_install_failed(results: &[InstallResult]) -> bool {
+    results.iter().any(|r| !r.command.is_empty() && !r.success)
+}
+
+/// Write back the content manifests had before they were updated
+///
+/// Used by --rollback-on-install-failure. Every manifest is attempted; the ones
+/// that couldn't be restored are returned with their error.
+pub fn restore_manifests(originals: &[(PathBuf, String)]) -> Vec<(PathBuf, std::io::Error)> {
+    originals
+        .iter()
+        .filter_map(|(path, content)| {
+            std::fs::write(path, content)
+                .err()
+                .map(|e| (path.clone(), e))
+        })
+        .collect()
+}
+
+/// composer.lock files next to rewritten composer.json manifests
+///
+/// Composer installs from the lock file, so these no longer match the manifest
+/// until `composer update` is run for the changed packages.
+pub fn stale_composer_locks(written_files: &[PathBuf]) -> Vec<PathBuf> {
+    written_files
+        .iter()
+        .filter(|path| path.file_name().is_some_and(|name| name == "composer.json"))
+        .filter_map(|path| {
+            let lock = path.with_file_name(COMPOSER_LOCK_FILENAME);
+            lock.is_file().then_some(lock)
+        })
+        .collect()
+}
+
+/// Result of a post-update hook command (--post-update)
+#[derive(Debug, Clone)]
+pub struct HookResult {
+    /// The command line that was executed
+    pub command: String,
+    /// Whether the command exited successfully
+    pub success: bool,
+    /// Standard output from the command
+    pub stdout: String,
+    /// Standard error from the command
+    pub stderr: String,
+}
+
+/// Run a single hook command line through the platform shell
+pub fn run_hook(command: &str, working_dir: &Path) -> HookResult {
+    let mut shell = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    };
+
+    match shell.current_dir(working_dir).output() {
+        Ok(output) => HookResult {
+            command: command.to_string(),
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+        },
+        Err(e) => HookResult {
+            command: command.to_string(),
+            success: false,
+            stdout: String::new(),
+            stderr: format!("Failed to execute command: {}", e),
+        },
+    }
+}
+
+/// Run hook commands in order, stopping at the first one that fails
+pub fn run_post_update_hooks(commands: &[String], working_dir: &Path) -> Vec<HookResult> {
+    let mut results = Vec::new();
+    for command in commands {
+        let result = run_hook(command, working_dir);
+        let failed = !result.success;
+        results.push(result);
+        if failed {
+            break;
+        }
+    }
+    results
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,6 +620,31 @@ mod tests {
         assert!(!result.success);
     }
 
+    #[test]
+    fn test_rollback_after_failed_install() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let manifest = dir.path().join("package.json");
+        let original = r#"{"dependencies": {"lodash": "^4.17.20"}}"#;
+        std::fs::write(&manifest, r#"{"dependencies": {"lodash": "^4.17.21"}}"#).unwrap();
+
+        let updates = vec![(
+            Language::Node,
+            vec![UpdatedPackage::new("lodash", "4.17.21")],
+        )];
+        let results = run_updates(&MockPackageManager::new(false), &updates, dir.path());
+        assert!(any_install_failed(&results));
+
+        let failed = restore_manifests(&[(manifest.clone(), original.to_string())]);
+        assert!(failed.is_empty());
+        assert_eq!(std::fs::read_to_string(&manifest).unwrap(), original);
+
+        let results = run_updates(&MockPackageManager::new(true), &updates, dir.path());
+        assert!(!any_install_failed(&results));
+        assert!(!any_install_failed(&[InstallResult::skipped(
+            Language::Rust
+        )]));
+    }
+
     #[test]
     fn test_run_installs() {
         let runner = MockPackageManager::new(true);
@@ -350,6 +656,191 @@ mod tests {
         assert!(results[1].success);
     }
 
+    #[cfg(unix)]
+    thread_local! {
+        /// Commands passed to `recording_runner` on this test thread
+        static RECORDED: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    /// Command runner recording each command and reporting success
+    #[cfg(unix)]
+    fn recording_runner(command: &[&str], _working_dir: &Path) -> std::io::Result<Output> {
+        use std::os::unix::process::ExitStatusExt;
+        RECORDED.with(|recorded| recorded.borrow_mut().push(command.join(" ")));
+        Ok(Output {
+            status: std::process::ExitStatus::from_raw(0),
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        })
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_updates_targets_updated_packages() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = |name: &str, files: &[&str]| {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(&path).unwrap();
+            for file in files {
+                std::fs::write(path.join(file), "{}").unwrap();
+            }
+            path
+        };
+        let php = project("php", &["composer.json"]);
+        let npm = project("npm", &["package.json", "package-lock.json"]);
+        let pnpm = project("pnpm", &["package.json", "pnpm-lock.yaml"]);
+        let rust = project("rust", &["Cargo.toml"]);
+        let poetry = project("poetry", &["pyproject.toml", "poetry.lock"]);
+
+        let pm = SystemPackageManager::with_command_runner(recording_runner);
+        let run = |language: Language, dir: &Path, packages: &[UpdatedPackage]| {
+            pm.run_update(language, dir, packages).command
+        };
+        let lodash = [
+            UpdatedPackage::new("lodash", "~4.17.21"),
+            UpdatedPackage::new("react", "^18.3.1"),
+        ];
+
+        assert_eq!(
+            run(
+                Language::Php,
+                &php,
+                &[
+                    UpdatedPackage::new("monolog/monolog", "^3.5.0"),
+                    UpdatedPackage::new("symfony/console", "^7.0.1"),
+                ]
+            ),
+            "composer update monolog/monolog symfony/console"
+        );
+        assert_eq!(
+            run(Language::Node, &npm, &lodash),
+            "npm install lodash@~4.17.21 react@^18.3.1"
+        );
+        assert_eq!(
+            run(
+                Language::Rust,
+                &rust,
+                &[
+                    UpdatedPackage::new("serde", "1.0.200"),
+                    UpdatedPackage::new("tokio", "1.37.0"),
+                ]
+            ),
+            "cargo update -p serde -p tokio"
+        );
+        // Package managers without targeted updates fall back to a full install
+        assert_eq!(run(Language::Node, &pnpm, &lodash), "pnpm install");
+        assert_eq!(
+            run(
+                Language::Python,
+                &poetry,
+                &[UpdatedPackage::new("requests", ">=2.31.0")]
+            ),
+            "poetry install"
+        );
+
+        RECORDED.with(|recorded| {
+            assert_eq!(
+                recorded.borrow().as_slice(),
+                [
+                    "composer update monolog/monolog symfony/console",
+                    "npm install lodash@~4.17.21 react@^18.3.1",
+                    "cargo update -p serde -p tokio",
+                    "pnpm install",
+                    "poetry install",
+                ]
+            )
+        });
+    }
+
+    #[test]
+    fn test_get_update_command_npm_save_exact() {
+        let pm = SystemPackageManager::new();
+        let exact = UpdatedPackage::new("react", "18.3.1").with_exact(true);
+        let caret = UpdatedPackage::new("lodash", "^4.17.21");
+        let tilde = UpdatedPackage::new("express", "~4.19.2");
+
+        // Only exact requirements get --save-exact, each spec passed as written
+        assert_eq!(
+            pm.get_update_command("npm", std::slice::from_ref(&exact)),
+            Some(vec![
+                "npm".to_string(),
+                "install".to_string(),
+                "--save-exact".to_string(),
+                "react@18.3.1".to_string(),
+            ])
+        );
+        assert_eq!(
+            pm.get_update_command("npm", &[caret.clone(), tilde]),
+            Some(vec![
+                "npm".to_string(),
+                "install".to_string(),
+                "lodash@^4.17.21".to_string(),
+                "express@~4.19.2".to_string(),
+            ])
+        );
+        // No single flag fits a mix, so it falls back to a full install
+        assert_eq!(pm.get_update_command("npm", &[exact, caret]), None);
+    }
+
+    #[test]
+    fn test_get_update_command_unsupported() {
+        let pm = SystemPackageManager::new();
+        let packages = vec![UpdatedPackage::new("requests", "2.31.0")];
+        assert_eq!(pm.get_update_command("poetry", &packages), None);
+        assert_eq!(pm.get_update_command("npm", &[]), None);
+    }
+
+    #[test]
+    fn test_stale_composer_locks() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let with_lock = temp_dir.path().join("app");
+        let without_lock = temp_dir.path().join("lib");
+        std::fs::create_dir_all(&with_lock).unwrap();
+        std::fs::create_dir_all(&without_lock).unwrap();
+        std::fs::write(with_lock.join("composer.lock"), "{}").unwrap();
+        std::fs::write(with_lock.join("package-lock.json"), "{}").unwrap();
+
+        let written = vec![
+            with_lock.join("composer.json"),
+            without_lock.join("composer.json"),
+            with_lock.join("package.json"),
+        ];
+        assert_eq!(
+            stale_composer_locks(&written),
+            vec![with_lock.join("composer.lock")]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_hook_captures_output() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let result = run_hook("echo hooked && pwd", temp_dir.path());
+        assert!(result.success);
+        assert_eq!(result.command, "echo hooked && pwd");
+        assert!(result.stdout.starts_with("hooked\n"));
+        let dir_name = temp_dir.path().file_name().unwrap().to_str().unwrap();
+        assert!(result.stdout.trim_end().ends_with(dir_name));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_post_update_hooks_stops_on_failure() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let commands = vec![
+            "echo first".to_string(),
+            "echo broken >&2; exit 3".to_string(),
+            "touch never-run".to_string(),
+        ];
+
+        let results = run_post_update_hooks(&commands, temp_dir.path());
+        assert_eq!(results.len(), 2);
+        assert!(results[0].success);
+        assert!(!results[1].success);
+        assert_eq!(results[1].stderr, "broken\n");
+        assert!(!temp_dir.path().join("never-run").exists());
+    }
+
     #[test]
     fn test_system_package_manager_new() {
         let _pm = SystemPackageManager::new();
@@ -463,6 +954,47 @@ mod tests {
         assert_eq!(pm.detect_node_pm(temp_dir.path()), Some("npm"));
     }
 
+    #[test]
+    fn test_detect_node_pm_package_manager_field() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"name": "app", "packageManager": "yarn@4.1.0"}"#,
+        )
+        .unwrap();
+
+        let pm = SystemPackageManager::new();
+        assert_eq!(pm.detect_node_pm(temp_dir.path()), Some("yarn"));
+    }
+
+    #[test]
+    fn test_detect_node_pm_package_manager_field_overrides_lockfile() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"packageManager": "pnpm@8.15.0+sha256.abc123"}"#,
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("package-lock.json"), "{}").unwrap();
+
+        let pm = SystemPackageManager::new();
+        assert_eq!(pm.detect_node_pm(temp_dir.path()), Some("pnpm"));
+    }
+
+    #[test]
+    fn test_detect_node_pm_unknown_package_manager_field() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"packageManager": "unknown@1.0.0"}"#,
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("yarn.lock"), "").unwrap();
+
+        let pm = SystemPackageManager::new();
+        assert_eq!(pm.detect_node_pm(temp_dir.path()), Some("yarn"));
+    }
+
     #[test]
     fn test_detect_node_pm_none() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,51 @@
+//! Crystal (shards) version specification parser
+//!
+//! Shards uses Bundler's constraint syntax, so parsing is delegated to
+//! `RubyVersionParser`:
+//! - Pessimistic constraints: `~> 1.4`, `~> 1.4.0`
+//! - Comparison operators: `>= 1.0.0`, `< 2.0`
+//! - Compound constraints: `>= 1.0, < 2.0`
+//! - Bare versions: `1.2.3`
+
+use crate::domain::{Language, VersionSpec};
+use crate::parser::{RubyVersionParser, VersionParser};
+
+/// Parser for shards version constraints
+pub struct CrystalVersionParser;
+
+impl VersionParser for CrystalVersionParser {
+    fn parse(&self, version_str: &str) -> Option<VersionSpec> {
+        RubyVersionParser.parse(version_str)
+    }
+
+    fn language(&self) -> Language {
+        Language::Crystal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::VersionSpecKind;
+
+    #[test]
+    fn test_parse_constraints() {
+        let spec = CrystalVersionParser.parse("~> 1.4.0").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Tilde);
+        assert_eq!(spec.version, "1.4.0");
+        assert_eq!(spec.format_updated("1.5.2"), "~> 1.5.2");
+
+        let spec = CrystalVersionParser.parse(">= 1.0, < 2.0").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Range);
+        assert_eq!(spec.version, "1.0");
+
+        let spec = CrystalVersionParser.parse("0.9.1").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Exact);
+    }
+
+    #[test]
+    fn test_parse_invalid_constraints() {
+        assert!(CrystalVersionParser.parse("").is_none());
+        assert!(CrystalVersionParser.parse("main").is_none());
+    }
+}
@@ -0,0 +1,47 @@
+//! Deno (JSR/npm specifier) version specification parser
+//!
+//! The version segment of `jsr:@scope/pkg@<range>` and `npm:pkg@<range>`
+//! specifiers uses npm semver ranges, so parsing is delegated to the
+//! Node.js parser.
+
+use crate::domain::{Language, VersionSpec};
+use crate::parser::{NodeVersionParser, VersionParser};
+
+/// Deno specifier version parser
+pub struct DenoVersionParser;
+
+impl VersionParser for DenoVersionParser {
+    fn parse(&self, version_str: &str) -> Option<VersionSpec> {
+        NodeVersionParser.parse(version_str)
+    }
+
+    fn language(&self) -> Language {
+        Language::Deno
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::VersionSpecKind;
+
+    #[test]
+    fn test_parse_caret() {
+        let spec = DenoVersionParser.parse("^1.0.8").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Caret);
+        assert_eq!(spec.version, "1.0.8");
+        assert_eq!(spec.format_updated("1.2.0"), "^1.2.0");
+    }
+
+    #[test]
+    fn test_parse_exact() {
+        let spec = DenoVersionParser.parse("5.3.0").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Exact);
+        assert!(spec.is_pinned());
+    }
+
+    #[test]
+    fn test_language() {
+        assert_eq!(DenoVersionParser.language(), Language::Deno);
+    }
+}
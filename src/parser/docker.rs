@@ -0,0 +1,130 @@
+//! Docker image tag parser
+//!
+//! Handles:
+//! - Version tags: `20.10.0`, `3.12`, `v1.2.3`
+//! - Variant suffixes: `20.10.0-alpine`, `3.12-slim-bookworm` (kept as the suffix)
+//!
+//! Tags without a leading version (e.g., `latest`, `alpine`) are not parsed.
+//! A tag names one exact image, so every parsed tag is `Exact`.
+
+use crate::domain::{Language, VersionSpec, VersionSpecKind};
+use crate::parser::VersionParser;
+use crate::update::VersionInfo;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Parser for Docker image tags
+pub struct DockerVersionParser;
+
+// Optional `v`, a dotted version, then an optional `-variant`
+static TAG_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(v?)(\d+(?:\.\d+)*)(-[A-Za-z0-9][A-Za-z0-9._-]*)?$").unwrap());
+
+impl VersionParser for DockerVersionParser {
+    fn parse(&self, version_str: &str) -> Option<VersionSpec> {
+        let trimmed = version_str.trim();
+        let caps = TAG_RE.captures(trimmed)?;
+
+        let mut spec = VersionSpec::new(VersionSpecKind::Exact, trimmed, caps.get(2)?.as_str());
+        if let Some(prefix) = caps.get(1).filter(|m| !m.is_empty()) {
+            spec = spec.with_prefix(prefix.as_str());
+        }
+        if let Some(suffix) = caps.get(3) {
+            spec = spec.with_suffix(suffix.as_str());
+        }
+        Some(spec)
+    }
+
+    fn language(&self) -> Language {
+        Language::Docker
+    }
+}
+
+/// Versions of the tags that follow the same pattern as `spec`
+///
+/// A tag matches when it has the same prefix and variant suffix and the same
+/// number of version components, so `20.10.0-alpine` is offered `20.11.0-alpine`
+/// but not `20.11.0`, `20.11.0-slim` or the floating `20-alpine`. Matching tags
+/// are returned with just their version (`20.11.0`).
+pub fn matching_tag_versions(spec: &VersionSpec, tags: Vec<VersionInfo>) -> Vec<VersionInfo> {
+    let components = spec.version.split('.').count();
+    let prefix = spec.prefix.as_deref().unwrap_or("");
+    let suffix = spec.suffix.as_deref().unwrap_or("");
+
+    tags.into_iter()
+        .filter_map(|mut info| {
+            let caps = TAG_RE.captures(&info.version)?;
+            let version = caps.get(2)?.as_str();
+            let same_pattern = caps.get(1).map_or("", |m| m.as_str()) == prefix
+                && caps.get(3).map_or("", |m| m.as_str()) == suffix
+                && version.split('.').count() == components;
+            if !same_pattern {
+                return None;
+            }
+            info.version = version.to_string();
+            Some(info)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(tag: &str) -> Option<VersionSpec> {
+        DockerVersionParser.parse(tag)
+    }
+
+    #[test]
+    fn test_parse_version_tag() {
+        let spec = parse("20.10.0").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Exact);
+        assert_eq!(spec.version, "20.10.0");
+        assert!(spec.suffix.is_none());
+    }
+
+    #[test]
+    fn test_parse_variant_tag() {
+        let spec = parse("20.10.0-alpine").unwrap();
+        assert_eq!(spec.version, "20.10.0");
+        assert_eq!(spec.suffix.as_deref(), Some("-alpine"));
+        assert_eq!(spec.format_updated("20.11.1"), "20.11.1-alpine");
+
+        let spec = parse("3.12-slim-bookworm").unwrap();
+        assert_eq!(spec.version, "3.12");
+        assert_eq!(spec.suffix.as_deref(), Some("-slim-bookworm"));
+
+        let spec = parse("v1.2.3").unwrap();
+        assert_eq!(spec.version, "1.2.3");
+        assert_eq!(spec.format_updated("1.3.0"), "v1.3.0");
+    }
+
+    #[test]
+    fn test_parse_non_version_tag() {
+        assert!(parse("latest").is_none());
+        assert!(parse("alpine").is_none());
+        assert!(parse("").is_none());
+    }
+
+    #[test]
+    fn test_matching_tag_versions_keep_variant() {
+        let spec = parse("20.10.0-alpine").unwrap();
+        let tags = [
+            "20.11.0-alpine",
+            "20.11.0",
+            "20.11.0-slim",
+            "20-alpine",
+            "21.0.0-alpine",
+            "alpine",
+        ]
+        .iter()
+        .map(|tag| VersionInfo::now(*tag))
+        .collect();
+
+        let versions: Vec<String> = matching_tag_versions(&spec, tags)
+            .into_iter()
+            .map(|v| v.version)
+            .collect();
+        assert_eq!(versions, vec!["20.11.0", "21.0.0"]);
+    }
+}
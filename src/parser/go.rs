@@ -3,7 +3,7 @@
 //! Handles version formats:
 //! - Semantic version: `v1.2.3`
 //! - Prerelease: `v1.2.3-beta.1`
-//! - Pseudo-version: `v0.0.0-20210101120000-abcdef123456`
+//! - Pseudo-version: `v0.0.0-20210101120000-abcdef123456`, `v1.2.4-0.20210101120000-abcdef123456`
 //!
 //! Note: Go modules use `// pinned` comment to indicate pinned versions,
 //! which is handled at the manifest parsing level, not here.
@@ -21,9 +21,56 @@ pub struct GoVersionParser;
 static SEMVER_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^v(\d+\.\d+\.\d+(?:-[\w.]+)?)$").unwrap());
 
-// Pseudo-version: v0.0.0-20210101120000-abcdef123456
-static PSEUDO_VERSION_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^v(\d+\.\d+\.\d+-\d{14}-[a-f0-9]{12})$").unwrap());
+// Pseudo-version: v0.0.0-20210101120000-abcdef123456, v1.2.4-0.20210101120000-abcdef123456,
+// v1.2.3-pre.0.20210101120000-abcdef123456
+static PSEUDO_VERSION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^v(\d+\.\d+\.\d+-(?:[0-9A-Za-z.]+\.)?\d{14}-[a-f0-9]{12})$").unwrap()
+});
+
+/// Check if a Go version is a pseudo-version referencing an untagged commit
+pub fn is_pseudo_version(version: &str) -> bool {
+    let version = version.trim();
+    let version = version.strip_suffix("+incompatible").unwrap_or(version);
+    PSEUDO_VERSION_RE.is_match(version)
+}
+
+// Major version suffix of a module path: example.com/mod/v2
+static MAJOR_SUFFIX_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"/v(\d+)$").unwrap());
+
+/// Major version a module path is published under (`/v3` → 3, no suffix → 1)
+///
+/// `gopkg.in` paths encode the major differently (`.v2`) and are reported as `None`.
+pub fn module_major(module: &str) -> Option<u64> {
+    if module.starts_with("gopkg.in/") {
+        return None;
+    }
+    Some(major_suffix(module).map_or(1, |(major, _)| major))
+}
+
+/// Module path for a major version (`example.com/mod/v2`, 3 → `example.com/mod/v3`)
+///
+/// Majors 0 and 1 have no suffix. `gopkg.in` paths are returned unchanged.
+pub fn module_path_for_major(module: &str, major: u64) -> String {
+    if module_major(module).is_none() {
+        return module.to_string();
+    }
+    let base = match major_suffix(module) {
+        Some((_, suffix_len)) => &module[..module.len() - suffix_len],
+        None => module,
+    };
+    if major >= 2 {
+        format!("{}/v{}", base, major)
+    } else {
+        base.to_string()
+    }
+}
+
+/// The `/vN` (N >= 2) suffix of a module path, as (major, suffix length)
+fn major_suffix(module: &str) -> Option<(u64, usize)> {
+    let caps = MAJOR_SUFFIX_RE.captures(module)?;
+    let major: u64 = caps[1].parse().ok()?;
+    (major >= 2).then_some((major, caps[0].len()))
+}
 
 // Incompatible module versions: v2.0.0+incompatible
 static INCOMPATIBLE_RE: LazyLock<Regex> =
@@ -78,6 +125,34 @@ impl VersionParser for GoVersionParser {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_module_major() {
+        assert_eq!(module_major("github.com/foo/bar"), Some(1));
+        assert_eq!(module_major("github.com/foo/bar/v2"), Some(2));
+        assert_eq!(module_major("github.com/foo/v1"), Some(1));
+        assert_eq!(module_major("gopkg.in/yaml.v3"), None);
+    }
+
+    #[test]
+    fn test_module_path_for_major() {
+        assert_eq!(
+            module_path_for_major("github.com/foo/bar/v2", 3),
+            "github.com/foo/bar/v3"
+        );
+        assert_eq!(
+            module_path_for_major("github.com/foo/bar", 2),
+            "github.com/foo/bar/v2"
+        );
+        assert_eq!(
+            module_path_for_major("github.com/foo/bar/v2", 1),
+            "github.com/foo/bar"
+        );
+        assert_eq!(
+            module_path_for_major("gopkg.in/yaml.v2", 3),
+            "gopkg.in/yaml.v2"
+        );
+    }
+
     fn parse(version: &str) -> Option<VersionSpec> {
         GoVersionParser.parse(version)
     }
@@ -116,6 +191,26 @@ mod tests {
         assert!(spec.is_pinned());
     }
 
+    #[test]
+    fn test_parse_pseudo_version_after_tag() {
+        let spec = parse("v1.2.4-0.20231010123456-abcdef123456").unwrap();
+        assert_eq!(spec.version, "1.2.4-0.20231010123456-abcdef123456");
+
+        let spec = parse("v1.3.0-rc.1.0.20231010123456-abcdef123456").unwrap();
+        assert_eq!(spec.version, "1.3.0-rc.1.0.20231010123456-abcdef123456");
+    }
+
+    #[test]
+    fn test_is_pseudo_version() {
+        assert!(is_pseudo_version("v0.0.0-20231010123456-abcdef123456"));
+        assert!(is_pseudo_version("v1.2.4-0.20231010123456-abcdef123456"));
+        assert!(is_pseudo_version(
+            "v2.0.1-0.20231010123456-abcdef123456+incompatible"
+        ));
+        assert!(!is_pseudo_version("v1.2.3"));
+        assert!(!is_pseudo_version("v1.2.3-beta.1"));
+    }
+
     #[test]
     fn test_parse_incompatible() {
         let spec = parse("v2.0.0+incompatible").unwrap();
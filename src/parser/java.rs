@@ -5,6 +5,7 @@
 //! - Prefix versions: `1.2.+` (matches any version starting with 1.2)
 //! - Dynamic versions: `latest.release`, `latest.integration`
 //! - Maven-style ranges: `[1.0,2.0]`, `[1.0,)`, `(,2.0]`, `[1.0,2.0)`
+//!   (the lower bound is kept as the updatable version, the rest as prefix/suffix)
 //!
 //! Note: Variable references (e.g., `$version`, `${version}`)
 //! are resolved by the manifest parser.
@@ -49,17 +50,18 @@ impl VersionParser for JavaVersionParser {
         if MAVEN_RANGE_RE.is_match(trimmed) {
             // Extract the lower bound as the base version, if present
             if let Some(caps) = MAVEN_RANGE_RE.captures(trimmed) {
-                let lower = caps.get(1).map(|m| m.as_str()).unwrap_or("");
+                // The lower bound is updated in place: [1.0,2.0) -> [1.5,2.0)
+                if let Some(lower) = caps.get(1) {
+                    return Some(
+                        VersionSpec::new(VersionSpecKind::Range, trimmed, lower.as_str())
+                            .with_prefix(&trimmed[..lower.start()])
+                            .with_suffix(&trimmed[lower.end()..]),
+                    );
+                }
+                // Without a lower bound there is nothing to move, so use the upper bound
+                // for reference only (no prefix/suffix)
                 let upper = caps.get(2).map(|m| m.as_str()).unwrap_or("");
-                // Use lower bound as version if present, otherwise use upper
-                let version = if !lower.is_empty() {
-                    lower
-                } else if !upper.is_empty() {
-                    upper
-                } else {
-                    ""
-                };
-                return Some(VersionSpec::new(VersionSpecKind::Range, trimmed, version));
+                return Some(VersionSpec::new(VersionSpecKind::Range, trimmed, upper));
             }
         }
 
@@ -164,6 +166,7 @@ mod tests {
         let spec = parse("5.0.0.RELEASE").unwrap();
         assert_eq!(spec.kind, VersionSpecKind::Exact);
         assert_eq!(spec.version, "5.0.0.RELEASE");
+        assert_eq!(spec.format_updated("5.0.1.RELEASE"), "5.0.1.RELEASE");
     }
 
     #[test]
@@ -292,6 +295,28 @@ mod tests {
         assert!(!spec.is_pinned());
     }
 
+    #[test]
+    fn test_maven_range_updates_lower_bound() {
+        let spec = parse("[1.0,2.0)").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Range);
+        assert_eq!(spec.version, "1.0");
+        assert_eq!(spec.prefix.as_deref(), Some("["));
+        assert_eq!(spec.suffix.as_deref(), Some(",2.0)"));
+        assert_eq!(spec.format_updated("1.5"), "[1.5,2.0)");
+
+        let spec = parse("[5.2.0, 5.3.8]").unwrap();
+        assert_eq!(spec.format_updated("5.3.1"), "[5.3.1, 5.3.8]");
+    }
+
+    #[test]
+    fn test_maven_range_without_lower_bound() {
+        let spec = parse("(,1.0]").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Range);
+        assert_eq!(spec.version, "1.0");
+        assert!(spec.prefix.is_none());
+        assert!(spec.suffix.is_none());
+    }
+
     #[test]
     fn test_parse_prefix_version_single_segment() {
         let spec = parse("1.+").unwrap();
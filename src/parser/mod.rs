@@ -8,24 +8,44 @@
 //! - Ruby (bundler)
 //! - PHP (composer)
 //! - Java (gradle)
+//! - Deno (JSR/npm specifiers)
+//! - Nim (nimble)
+//! - Docker (image tags)
+//! - R (renv.lock versions)
+//! - Crystal (shards)
+//! - pre-commit (hook revisions)
 
+mod crystal;
+mod deno;
+mod docker;
 mod go;
 mod java;
+mod nim;
 mod node;
 mod php;
+mod pre_commit;
 mod python;
+mod r;
 mod ruby;
 mod rust;
 
-pub use go::GoVersionParser;
+pub use crystal::CrystalVersionParser;
+pub use deno::DenoVersionParser;
+pub use docker::{matching_tag_versions, DockerVersionParser};
+pub use go::{is_pseudo_version, module_major, module_path_for_major, GoVersionParser};
 pub use java::JavaVersionParser;
+pub use nim::NimVersionParser;
 pub use node::NodeVersionParser;
 pub use php::PhpVersionParser;
+pub use pre_commit::PreCommitVersionParser;
 pub use python::PythonVersionParser;
+pub use r::RVersionParser;
 pub use ruby::RubyVersionParser;
 pub use rust::RustVersionParser;
 
-use crate::domain::{Language, VersionSpec};
+use crate::domain::{Language, VersionSpec, VersionSpecKind};
+use crate::update::{compare_versions, extract_range_upper_bound};
+use std::cmp::Ordering;
 
 /// Trait for parsing version specifications
 pub trait VersionParser {
@@ -34,6 +54,54 @@ pub trait VersionParser {
 
     /// Returns the language this parser handles
     fn language(&self) -> Language;
+
+    /// Returns true if `version` satisfies the spec
+    ///
+    /// By default caret and tilde follow npm semantics (`^1.2.3` allows `<2.0.0`,
+    /// `^0.2.3` allows `<0.3.0`, `~1.2.3` allows `<1.3.0`). Specs that aren't
+    /// ranges only allow their own version.
+    fn matches(&self, spec: &VersionSpec, version: &str) -> bool {
+        let components = |v: &str| -> Vec<u64> {
+            v.split(['.', '-', '+'])
+                .take(3)
+                .map(|part| part.parse().unwrap_or(0))
+                .collect()
+        };
+        let base = components(&spec.version);
+        let candidate = components(version);
+        let ordering = compare_versions(version, &spec.version);
+        let at_least = ordering != Ordering::Less;
+        // The first `n` components of the candidate must match the spec's
+        let same_prefix =
+            |n: usize| candidate.get(..n).is_some() && candidate.get(..n) == base.get(..n);
+
+        match spec.kind {
+            VersionSpecKind::Caret => {
+                // Components up to and including the first non-zero one are fixed
+                let fixed = base
+                    .iter()
+                    .position(|&part| part != 0)
+                    .map_or(base.len(), |index| index + 1);
+                at_least && same_prefix(fixed)
+            }
+            VersionSpecKind::Tilde => at_least && same_prefix(base.len().clamp(1, 2)),
+            VersionSpecKind::GreaterOrEqual => at_least,
+            VersionSpecKind::Greater => ordering == Ordering::Greater,
+            VersionSpecKind::Wildcard => same_prefix(base.len()),
+            VersionSpecKind::Any => true,
+            VersionSpecKind::Range => {
+                at_least
+                    && extract_range_upper_bound(&spec.raw).is_none_or(|(upper, inclusive)| {
+                        match compare_versions(version, &upper) {
+                            Ordering::Less => true,
+                            Ordering::Equal => inclusive,
+                            Ordering::Greater => false,
+                        }
+                    })
+            }
+            _ => ordering == Ordering::Equal,
+        }
+    }
 }
 
 /// Get a version parser for the specified language
@@ -46,6 +114,12 @@ pub fn get_parser(language: Language) -> Box<dyn VersionParser> {
         Language::Ruby => Box::new(RubyVersionParser),
         Language::Php => Box::new(PhpVersionParser),
         Language::Java => Box::new(JavaVersionParser),
+        Language::Deno => Box::new(DenoVersionParser),
+        Language::Nim => Box::new(NimVersionParser),
+        Language::Docker => Box::new(DockerVersionParser),
+        Language::R => Box::new(RVersionParser),
+        Language::Crystal => Box::new(CrystalVersionParser),
+        Language::PreCommit => Box::new(PreCommitVersionParser),
     }
 }
 
@@ -76,4 +150,75 @@ mod tests {
         let parser = get_parser(Language::Go);
         assert_eq!(parser.language(), Language::Go);
     }
+
+    #[test]
+    fn test_default_matches() {
+        let caret = VersionSpec::new(VersionSpecKind::Caret, "^4.17.0", "4.17.0");
+        assert!(NodeVersionParser.matches(&caret, "4.17.21"));
+        assert!(NodeVersionParser.matches(&caret, "4.18.0"));
+        assert!(!NodeVersionParser.matches(&caret, "5.0.0"));
+        assert!(!NodeVersionParser.matches(&caret, "4.16.0"));
+
+        let zerover = VersionSpec::new(VersionSpecKind::Caret, "^0.2.3", "0.2.3");
+        assert!(NodeVersionParser.matches(&zerover, "0.2.9"));
+        assert!(!NodeVersionParser.matches(&zerover, "0.3.0"));
+
+        let tilde = VersionSpec::new(VersionSpecKind::Tilde, "~1.2.3", "1.2.3");
+        assert!(NodeVersionParser.matches(&tilde, "1.2.9"));
+        assert!(!NodeVersionParser.matches(&tilde, "1.3.0"));
+
+        let range = VersionSpec::new(VersionSpecKind::Range, ">=1.0,<2.0", "1.0");
+        assert!(NodeVersionParser.matches(&range, "1.9.9"));
+        assert!(!NodeVersionParser.matches(&range, "2.0.0"));
+
+        let exact = VersionSpec::new(VersionSpecKind::Exact, "1.2.3", "1.2.3");
+        assert!(NodeVersionParser.matches(&exact, "1.2.3"));
+        assert!(!NodeVersionParser.matches(&exact, "1.2.4"));
+    }
+
+    #[test]
+    fn test_parsed_specs_round_trip() {
+        let cases: &[(Language, &[&str])] = &[
+            (
+                Language::Node,
+                &[
+                    "^1.2.3",
+                    "~1.2.3",
+                    ">=1.0.0",
+                    "1.2.3",
+                    "1.x",
+                    ">=1.0.0 <2.0.0",
+                ],
+            ),
+            (
+                Language::Python,
+                &["==1.2.3", "^1.2.3", "~=1.2", ">=1.0", ">=1.0,<2.0", "1.*"],
+            ),
+            (
+                Language::Rust,
+                &["1.0", "^1.2.3", "~1.2.3", "=1.2.3", ">=1.0"],
+            ),
+            (Language::Go, &["v1.2.3", "v3.0.0+incompatible"]),
+            (Language::Ruby, &["~> 1.2", ">= 1.0", "1.2.3"]),
+            (
+                Language::Php,
+                &["^1.2", "~1.2.3", "1.3.*", ">=2.0", "^1.0 || ^2.0"],
+            ),
+            (Language::Java, &["1.2.3", "[1.0,2.0)", "1.2.+"]),
+        ];
+
+        for (language, specs) in cases {
+            let parser = get_parser(*language);
+            for raw in *specs {
+                let spec = parser.parse(raw).unwrap();
+                assert_eq!(
+                    spec.round_trip_mismatch(),
+                    None,
+                    "{:?} spec {} did not round-trip",
+                    language,
+                    raw
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,113 @@
+//! Nim (Nimble) version specification parser
+//!
+//! Handles:
+//! - Comparison operators: `>= 1.2.3`, `> 1.2.3`, `<= 1.2.3`, `< 1.2.3`
+//! - Exact versions: `== 1.2.3` and bare `1.2.3`
+//! - Caret and tilde constraints: `^= 1.2`, `~= 1.2.3`
+//! - Compound ranges: `>= 1.0 & < 2.0`
+//!
+//! The operator is kept as written (including spacing) so updates preserve it.
+
+use crate::domain::{Language, VersionSpec, VersionSpecKind};
+use crate::parser::VersionParser;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Parser for Nimble version constraints
+pub struct NimVersionParser;
+
+// Operator with optional whitespace, followed by a version: ">= 1.2.3"
+static CONSTRAINT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^((?:>=|<=|==|\^=|~=|>|<)?\s*)(\d+(?:\.\d+)*)$").unwrap());
+
+impl VersionParser for NimVersionParser {
+    fn parse(&self, version_str: &str) -> Option<VersionSpec> {
+        let trimmed = version_str.trim();
+
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        // Compound range: ">= 1.0 & < 2.0" (the lower bound is the reference version)
+        if trimmed.contains('&') {
+            let first = trimmed.split('&').next()?;
+            let caps = CONSTRAINT_RE.captures(first.trim())?;
+            return Some(VersionSpec::new(
+                VersionSpecKind::Range,
+                trimmed,
+                caps.get(2)?.as_str(),
+            ));
+        }
+
+        let caps = CONSTRAINT_RE.captures(trimmed)?;
+        let prefix = caps.get(1)?.as_str();
+        let version = caps.get(2)?.as_str();
+        let kind = match prefix.trim() {
+            ">=" => VersionSpecKind::GreaterOrEqual,
+            ">" => VersionSpecKind::Greater,
+            "<=" => VersionSpecKind::LessOrEqual,
+            "<" => VersionSpecKind::Less,
+            "^=" => VersionSpecKind::Caret,
+            "~=" => VersionSpecKind::Tilde,
+            _ => VersionSpecKind::Exact,
+        };
+
+        let spec = VersionSpec::new(kind, trimmed, version);
+        if prefix.is_empty() {
+            Some(spec)
+        } else {
+            Some(spec.with_prefix(prefix))
+        }
+    }
+
+    fn language(&self) -> Language {
+        Language::Nim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_operators() {
+        let cases = [
+            (">= 1.2.3", VersionSpecKind::GreaterOrEqual, ">= "),
+            (">1.2.3", VersionSpecKind::Greater, ">"),
+            ("<= 1.2.3", VersionSpecKind::LessOrEqual, "<= "),
+            ("< 1.2.3", VersionSpecKind::Less, "< "),
+            ("== 1.2.3", VersionSpecKind::Exact, "== "),
+            ("^= 1.2.3", VersionSpecKind::Caret, "^= "),
+            ("~= 1.2.3", VersionSpecKind::Tilde, "~= "),
+        ];
+        for (input, kind, prefix) in cases {
+            let spec = NimVersionParser.parse(input).unwrap();
+            assert_eq!(spec.kind, kind, "{}", input);
+            assert_eq!(spec.version, "1.2.3");
+            assert_eq!(spec.prefix.as_deref(), Some(prefix));
+            assert_eq!(spec.format_updated("2.0.0"), format!("{}2.0.0", prefix));
+        }
+    }
+
+    #[test]
+    fn test_parse_bare_version() {
+        let spec = NimVersionParser.parse("0.5").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Exact);
+        assert_eq!(spec.version, "0.5");
+        assert_eq!(spec.format_updated("0.6"), "0.6");
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let spec = NimVersionParser.parse(">= 1.0 & < 2.0").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Range);
+        assert_eq!(spec.version, "1.0");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(NimVersionParser.parse("").is_none());
+        assert!(NimVersionParser.parse("#head").is_none());
+        assert!(NimVersionParser.parse(">= abc").is_none());
+    }
+}
@@ -18,19 +18,19 @@ pub struct NodeVersionParser;
 
 // Regex patterns for Node.js version specifications
 static CARET_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\^(\d+\.\d+\.\d+(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^\^(\d+\.\d+\.\d+(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static TILDE_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^~(\d+\.\d+\.\d+(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^~(\d+\.\d+\.\d+(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static GTE_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^>=(\d+\.\d+\.\d+(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^>=(\d+\.\d+\.\d+(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static GT_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^>(\d+\.\d+\.\d+(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^>(\d+\.\d+\.\d+(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static LTE_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^<=(\d+\.\d+\.\d+(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^<=(\d+\.\d+\.\d+(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static LT_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^<(\d+\.\d+\.\d+(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^<(\d+\.\d+\.\d+(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static EXACT_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^(\d+\.\d+\.\d+(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^(\d+\.\d+\.\d+(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static WILDCARD_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^(\d+(?:\.\d+)?\.)?[x*]$|^\*$").unwrap());
 static RANGE_RE: LazyLock<Regex> = LazyLock::new(|| {
@@ -163,6 +163,17 @@ mod tests {
         assert!(spec.is_pinned());
     }
 
+    #[test]
+    fn test_parse_exact_with_build_metadata() {
+        let spec = parse("1.2.3+build.5").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Exact);
+        assert_eq!(spec.version, "1.2.3+build.5");
+
+        let spec = parse("^1.2.3-rc.1+sha.abc").unwrap();
+        assert_eq!(spec.version, "1.2.3-rc.1+sha.abc");
+        assert_eq!(spec.format_updated("1.3.0+build.7"), "^1.3.0+build.7");
+    }
+
     #[test]
     fn test_parse_caret() {
         let spec = parse("^1.2.3").unwrap();
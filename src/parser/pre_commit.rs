@@ -0,0 +1,61 @@
+//! pre-commit hook revision parser
+//!
+//! Handles:
+//! - Release tags: `v4.5.0`, `23.12.1`, `v0.1.9` (the `v` is kept as the prefix)
+//!
+//! Commit SHAs and other refs (e.g., `stable`) are not parsed. A `rev` names one
+//! exact tag, so every parsed revision is `Exact`.
+
+use crate::domain::{Language, VersionSpec, VersionSpecKind};
+use crate::parser::VersionParser;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Parser for pre-commit `rev` values
+pub struct PreCommitVersionParser;
+
+// Optional `v`, then a dotted version with at least two components
+static REV_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(v?)(\d+(?:\.\d+)+)$").unwrap());
+
+impl VersionParser for PreCommitVersionParser {
+    fn parse(&self, version_str: &str) -> Option<VersionSpec> {
+        let trimmed = version_str.trim();
+        let caps = REV_RE.captures(trimmed)?;
+
+        let spec = VersionSpec::new(VersionSpecKind::Exact, trimmed, caps.get(2)?.as_str());
+        match caps.get(1).filter(|m| !m.is_empty()) {
+            Some(prefix) => Some(spec.with_prefix(prefix.as_str())),
+            None => Some(spec),
+        }
+    }
+
+    fn language(&self) -> Language {
+        Language::PreCommit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tags() {
+        let spec = PreCommitVersionParser.parse("v4.5.0").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Exact);
+        assert_eq!(spec.version, "4.5.0");
+        assert_eq!(spec.format_updated("4.6.0"), "v4.6.0");
+
+        let spec = PreCommitVersionParser.parse("23.12.1").unwrap();
+        assert_eq!(spec.version, "23.12.1");
+        assert_eq!(spec.format_updated("24.1.0"), "24.1.0");
+    }
+
+    #[test]
+    fn test_parse_non_tag_revs() {
+        assert!(PreCommitVersionParser
+            .parse("3298ddab3c13dd77d6ce1fc0baf97691430d84b0")
+            .is_none());
+        assert!(PreCommitVersionParser.parse("stable").is_none());
+        assert!(PreCommitVersionParser.parse("").is_none());
+    }
+}
@@ -7,10 +7,14 @@
 //! - Comparison: `>=1.2.3`, `>1.2.3`, `<=1.2.3`, `<1.2.3`, `!=1.2.3`
 //! - Wildcard: `*`, `1.*`
 //! - Range: `>=1.0,<2.0`
+//!
+//! Range matching follows Poetry for `^`/`~` (e.g., `^0.2.3` means `>=0.2.3,<0.3.0`)
+//! and PEP 440 for `~=`.
 
 use crate::domain::{Language, VersionSpec, VersionSpecKind};
 use crate::parser::VersionParser;
 use regex::Regex;
+use std::cmp::Ordering;
 use std::sync::LazyLock;
 
 /// Python version specification parser
@@ -146,6 +150,100 @@ impl VersionParser for PythonVersionParser {
     fn language(&self) -> Language {
         Language::Python
     }
+
+    /// Returns true if `version` satisfies the spec
+    ///
+    /// Caret and tilde bounds follow Poetry:
+    /// - `^1.2.3` → `>=1.2.3,<2.0.0`, `^0.2.3` → `>=0.2.3,<0.3.0`, `^0.0.3` → `>=0.0.3,<0.0.4`
+    /// - `~1.2.3` and `~1.2` → `<1.3.0`, `~1` → `<2.0.0`
+    /// - `~=1.2.3` (PEP 440) → `<1.3`, `~=1.2` → `<2`
+    fn matches(&self, spec: &VersionSpec, version: &str) -> bool {
+        let candidate = release(version);
+        let base = release(&spec.version);
+
+        match spec.kind {
+            VersionSpecKind::Exact => compare(&candidate, &base) == Ordering::Equal,
+            VersionSpecKind::Caret | VersionSpecKind::Tilde => {
+                let upper = if spec.kind == VersionSpecKind::Caret {
+                    caret_upper_bound(&base)
+                } else if spec.prefix.as_deref() == Some("~=") {
+                    compatible_upper_bound(&base)
+                } else {
+                    tilde_upper_bound(&base)
+                };
+                compare(&candidate, &base) != Ordering::Less
+                    && compare(&candidate, &upper) == Ordering::Less
+            }
+            VersionSpecKind::GreaterOrEqual => compare(&candidate, &base) != Ordering::Less,
+            VersionSpecKind::Greater => compare(&candidate, &base) == Ordering::Greater,
+            VersionSpecKind::LessOrEqual => compare(&candidate, &base) != Ordering::Greater,
+            VersionSpecKind::Less => compare(&candidate, &base) == Ordering::Less,
+            VersionSpecKind::Range => spec.raw.split(',').all(|part| {
+                let part = part.trim();
+                match part.strip_prefix("!=") {
+                    Some(excluded) => compare(&candidate, &release(excluded)) != Ordering::Equal,
+                    None => self
+                        .parse(part)
+                        .is_some_and(|bound| self.matches(&bound, version)),
+                }
+            }),
+            VersionSpecKind::Wildcard => {
+                let prefix = release(spec.raw.trim_end_matches('*'));
+                candidate.len() >= prefix.len() && candidate[..prefix.len()] == prefix[..]
+            }
+            VersionSpecKind::Any => true,
+            VersionSpecKind::GoPinned => false,
+        }
+    }
+}
+
+/// Numeric release segments of a version (`1.2.3a1` → `[1, 2, 3]`)
+fn release(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map_while(|segment| {
+            let digits: String = segment.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse().ok()
+        })
+        .collect()
+}
+
+/// Compare release segments, padding the shorter one with zeros
+fn compare(a: &[u64], b: &[u64]) -> Ordering {
+    let len = a.len().max(b.len());
+    let at = |v: &[u64], i: usize| v.get(i).copied().unwrap_or(0);
+    (0..len)
+        .map(|i| at(a, i).cmp(&at(b, i)))
+        .find(|o| o.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Increment the segment at `index` and drop everything after it
+fn bump(base: &[u64], index: usize) -> Vec<u64> {
+    if base.is_empty() {
+        return Vec::new();
+    }
+    let mut upper = base[..=index].to_vec();
+    upper[index] += 1;
+    upper
+}
+
+/// Poetry caret: bump the first non-zero segment, or the last given one if all are zero
+fn caret_upper_bound(base: &[u64]) -> Vec<u64> {
+    match base.iter().position(|&n| n != 0) {
+        Some(index) => bump(base, index),
+        None => bump(base, base.len().saturating_sub(1)),
+    }
+}
+
+/// Poetry tilde: bump the minor when given, otherwise the major
+fn tilde_upper_bound(base: &[u64]) -> Vec<u64> {
+    bump(base, if base.len() >= 2 { 1 } else { 0 })
+}
+
+/// PEP 440 compatible release: bump the second-to-last segment
+fn compatible_upper_bound(base: &[u64]) -> Vec<u64> {
+    bump(base, base.len().saturating_sub(2))
 }
 
 #[cfg(test)]
@@ -287,6 +385,67 @@ mod tests {
         assert_eq!(PythonVersionParser.language(), Language::Python);
     }
 
+    fn matches(spec: &str, version: &str) -> bool {
+        PythonVersionParser.matches(&parse(spec).unwrap(), version)
+    }
+
+    #[test]
+    fn test_matches_poetry_caret() {
+        // ^1.2.3 := >=1.2.3,<2.0.0
+        assert!(!matches("^1.2.3", "1.2.2"));
+        assert!(matches("^1.2.3", "1.2.3"));
+        assert!(matches("^1.2.3", "1.9.9"));
+        assert!(!matches("^1.2.3", "2.0.0"));
+
+        // ^0.2.3 := >=0.2.3,<0.3.0 (0.x minor bumps are breaking)
+        assert!(!matches("^0.2.3", "0.2.2"));
+        assert!(matches("^0.2.3", "0.2.3"));
+        assert!(matches("^0.2.3", "0.2.9"));
+        assert!(!matches("^0.2.3", "0.3.0"));
+
+        // ^0.0.3 := >=0.0.3,<0.0.4
+        assert!(matches("^0.0.3", "0.0.3"));
+        assert!(!matches("^0.0.3", "0.0.4"));
+    }
+
+    #[test]
+    fn test_matches_poetry_tilde() {
+        // ~1.2 := >=1.2.0,<1.3.0
+        assert!(!matches("~1.2", "1.1.9"));
+        assert!(matches("~1.2", "1.2.0"));
+        assert!(matches("~1.2", "1.2.9"));
+        assert!(!matches("~1.2", "1.3.0"));
+
+        // ~1.2.3 := >=1.2.3,<1.3.0
+        assert!(!matches("~1.2.3", "1.2.2"));
+        assert!(matches("~1.2.3", "1.2.3"));
+        assert!(matches("~1.2.3", "1.2.10"));
+        assert!(!matches("~1.2.3", "1.3.0"));
+
+        // ~1 := >=1.0.0,<2.0.0
+        assert!(matches("~1", "1.9.0"));
+        assert!(!matches("~1", "2.0.0"));
+    }
+
+    #[test]
+    fn test_matches_pep440_compatible_release() {
+        // ~=1.2.3 := >=1.2.3,<1.3 but ~=1.2 := >=1.2,<2
+        assert!(matches("~=1.2.3", "1.2.9"));
+        assert!(!matches("~=1.2.3", "1.3.0"));
+        assert!(matches("~=1.2", "1.9.0"));
+        assert!(!matches("~=1.2", "2.0"));
+    }
+
+    #[test]
+    fn test_matches_comparisons_and_ranges() {
+        assert!(matches("==1.2.3", "1.2.3"));
+        assert!(!matches("==1.2.3", "1.2.4"));
+        assert!(matches(">=3.5.0,<4.0.0", "3.9.1"));
+        assert!(!matches(">=3.5.0,<4.0.0", "4.0.0"));
+        assert!(matches("1.*", "1.4.2"));
+        assert!(!matches("1.*", "2.0.0"));
+    }
+
     #[test]
     fn test_parse_range_extracts_first_version() {
         let spec = parse(">=3.5.0,<4.0.0").unwrap();
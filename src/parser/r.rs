@@ -0,0 +1,55 @@
+//! R package version parser
+//!
+//! Handles:
+//! - Dotted versions: `1.1.2`, `3.5.0`
+//! - Dash-separated components: `0.4-3`, `1.7-1.1`
+//!
+//! renv.lock records the one installed version, so every parsed version is `Exact`.
+
+use crate::domain::{Language, VersionSpec, VersionSpecKind};
+use crate::parser::VersionParser;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Parser for R package versions
+pub struct RVersionParser;
+
+// Numeric components separated by `.` or `-`
+static VERSION_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d+(?:[.-]\d+)*$").unwrap());
+
+impl VersionParser for RVersionParser {
+    fn parse(&self, version_str: &str) -> Option<VersionSpec> {
+        let trimmed = version_str.trim();
+        if !VERSION_RE.is_match(trimmed) {
+            return None;
+        }
+        Some(VersionSpec::new(VersionSpecKind::Exact, trimmed, trimmed))
+    }
+
+    fn language(&self) -> Language {
+        Language::R
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_versions() {
+        let spec = RVersionParser.parse("1.1.2").unwrap();
+        assert_eq!(spec.kind, VersionSpecKind::Exact);
+        assert_eq!(spec.version, "1.1.2");
+
+        let spec = RVersionParser.parse("0.4-3").unwrap();
+        assert_eq!(spec.version, "0.4-3");
+        assert_eq!(spec.format_updated("0.4-4"), "0.4-4");
+    }
+
+    #[test]
+    fn test_parse_invalid_versions() {
+        assert!(RVersionParser.parse("").is_none());
+        assert!(RVersionParser.parse("latest").is_none());
+        assert!(RVersionParser.parse(">= 1.0").is_none());
+    }
+}
@@ -18,21 +18,21 @@ pub struct RustVersionParser;
 
 // Regex patterns for Rust version specifications
 static EXACT_PINNED_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^=([\d]+(?:\.[\d]+)*(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^=([\d]+(?:\.[\d]+)*(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static CARET_EXPLICIT_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\^([\d]+(?:\.[\d]+)*(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^\^([\d]+(?:\.[\d]+)*(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static TILDE_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^~([\d]+(?:\.[\d]+)*(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^~([\d]+(?:\.[\d]+)*(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static GTE_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^>=([\d]+(?:\.[\d]+)*(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^>=([\d]+(?:\.[\d]+)*(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static GT_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^>([\d]+(?:\.[\d]+)*(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^>([\d]+(?:\.[\d]+)*(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static LTE_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^<=([\d]+(?:\.[\d]+)*(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^<=([\d]+(?:\.[\d]+)*(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static LT_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^<([\d]+(?:\.[\d]+)*(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^<([\d]+(?:\.[\d]+)*(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static BARE_VERSION_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^([\d]+(?:\.[\d]+)*(?:-[\w.]+)?)$").unwrap());
+    LazyLock::new(|| Regex::new(r"^([\d]+(?:\.[\d]+)*(?:-[\w.]+)?(?:\+[\w.]+)?)$").unwrap());
 static RANGE_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^[<>=]+[\d]+(?:\.[\d]+)*,\s*[<>=]+[\d]+(?:\.[\d]+)*$").unwrap());
 static WILDCARD_RE: LazyLock<Regex> =
@@ -145,6 +145,21 @@ impl VersionParser for RustVersionParser {
     fn language(&self) -> Language {
         Language::Rust
     }
+
+    /// Returns true if `version` satisfies the spec, following Cargo's rules
+    ///
+    /// Caret bounds stop at the first non-zero component:
+    /// - `^1.2.3` → `>=1.2.3,<2.0.0`, `^0.2` → `>=0.2.0,<0.3.0`, `^0.0.3` → `>=0.0.3,<0.0.4`
+    /// - `~1.2.3` and `~1.2` → `<1.3.0`, `~1` → `<2.0.0`
+    ///
+    /// Pre-releases only match a spec naming a pre-release of the same version.
+    /// Versions that are not full semver (`1.2`) never match.
+    fn matches(&self, spec: &VersionSpec, version: &str) -> bool {
+        let Ok(candidate) = semver::Version::parse(version) else {
+            return false;
+        };
+        semver::VersionReq::parse(&spec.raw).is_ok_and(|req| req.matches(&candidate))
+    }
 }
 
 #[cfg(test)]
@@ -162,6 +177,10 @@ mod tests {
         assert_eq!(spec.version, "1.2.3");
         assert_eq!(spec.prefix, Some("=".to_string()));
         assert!(spec.is_pinned());
+
+        let spec = parse("=1.2.3+build.5").unwrap();
+        assert_eq!(spec.version, "1.2.3+build.5");
+        assert_eq!(spec.format_updated("1.2.4+build.6"), "=1.2.4+build.6");
     }
 
     #[test]
@@ -303,6 +322,68 @@ mod tests {
         assert_eq!(spec.format_updated("1.3.0"), "~1.3.0");
     }
 
+    fn matches(spec: &str, version: &str) -> bool {
+        RustVersionParser.matches(&parse(spec).unwrap(), version)
+    }
+
+    #[test]
+    fn test_matches_caret() {
+        assert!(matches("^1.2.3", "1.2.3"));
+        assert!(matches("^1.2.3", "1.9.0"));
+        assert!(!matches("^1.2.3", "1.2.2"));
+        assert!(!matches("^1.2.3", "2.0.0"));
+        // Bare versions are carets
+        assert!(matches("1.2.3", "1.99.99"));
+        assert!(!matches("1.2.3", "2.0.0"));
+    }
+
+    #[test]
+    fn test_matches_caret_zero_major() {
+        assert!(matches("^0.2", "0.2.0"));
+        assert!(matches("^0.2", "0.2.9"));
+        assert!(!matches("^0.2", "0.3.0"));
+        assert!(!matches("^0.2", "0.1.9"));
+        assert!(matches("0.2.1", "0.2.5"));
+        assert!(!matches("0.2.1", "0.3.0"));
+    }
+
+    #[test]
+    fn test_matches_caret_zero_minor() {
+        assert!(matches("^0.0.3", "0.0.3"));
+        assert!(!matches("^0.0.3", "0.0.4"));
+        assert!(!matches("^0.0.3", "0.1.0"));
+        assert!(matches("^0.0", "0.0.7"));
+        assert!(!matches("^0.0", "0.1.0"));
+    }
+
+    #[test]
+    fn test_matches_tilde() {
+        assert!(matches("~0.2.1", "0.2.1"));
+        assert!(matches("~0.2.1", "0.2.9"));
+        assert!(!matches("~0.2.1", "0.2.0"));
+        assert!(!matches("~0.2.1", "0.3.0"));
+        assert!(matches("~1", "1.9.0"));
+        assert!(!matches("~1", "2.0.0"));
+    }
+
+    #[test]
+    fn test_matches_other_kinds() {
+        assert!(matches("=1.2.3", "1.2.3"));
+        assert!(!matches("=1.2.3", "1.2.4"));
+        assert!(matches(">=1.0, <2.0", "1.5.0"));
+        assert!(!matches(">=1.0, <2.0", "2.0.0"));
+        assert!(matches("1.*", "1.4.0"));
+        assert!(!matches("1.*", "2.0.0"));
+        assert!(matches("*", "3.0.0"));
+    }
+
+    #[test]
+    fn test_matches_prerelease() {
+        assert!(!matches("^1.2.3", "1.3.0-beta.1"));
+        assert!(matches("^1.2.3-alpha.1", "1.2.3-beta.1"));
+        assert!(!matches("^1.2.3", "1.3"));
+    }
+
     #[test]
     fn test_language() {
         assert_eq!(RustVersionParser.language(), Language::Rust);
@@ -1,9 +1,37 @@
 //! Progress display for dependency updates
 //!
-//! Provides visual feedback during the update workflow using indicatif.
+//! Provides visual feedback during the update workflow using indicatif, and
+//! machine-readable progress events for frontends embedding the library.
 
+use crate::domain::{Language, UpdateResult};
 use indicatif::{ProgressBar, ProgressStyle};
+use std::path::PathBuf;
 use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Progress event of an update run (see `Orchestrator::run_with_events`)
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProgressEvent {
+    /// A manifest was found and will be processed
+    ManifestDetected {
+        /// Path to the manifest file
+        path: PathBuf,
+        /// Language of the manifest
+        language: Language,
+    },
+    /// A dependency was checked against its registry
+    DependencyChecked {
+        /// Package name
+        name: String,
+        /// Whether the dependency is updated or skipped, and why
+        result: Box<UpdateResult>,
+    },
+    /// Updates were written to a manifest
+    WriteApplied {
+        /// Path to the rewritten manifest
+        path: PathBuf,
+    },
+}
 
 /// Progress reporter for the update workflow
 pub struct Progress {
@@ -11,12 +39,33 @@ pub struct Progress {
     enabled: bool,
     /// Current progress bar
     bar: Option<ProgressBar>,
+    /// Receiver of progress events, if a frontend is listening
+    events: Option<UnboundedSender<ProgressEvent>>,
 }
 
 impl Progress {
     /// Create a new progress reporter
     pub fn new(enabled: bool) -> Self {
-        Self { enabled, bar: None }
+        Self {
+            enabled,
+            bar: None,
+            events: None,
+        }
+    }
+
+    /// Also send progress events to `events`
+    pub fn with_events(mut self, events: UnboundedSender<ProgressEvent>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Send an event, if anyone is listening
+    ///
+    /// The event is only built when there is a receiver; a closed receiver is ignored.
+    pub fn emit(&self, event: impl FnOnce() -> ProgressEvent) {
+        if let Some(events) = &self.events {
+            let _ = events.send(event());
+        }
     }
 
     /// Create a disabled progress reporter
@@ -120,4 +169,22 @@ mod tests {
         progress.inc();
         progress.finish_and_clear();
     }
+
+    #[test]
+    fn test_progress_events() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let progress = Progress::disabled().with_events(tx);
+        progress.emit(|| ProgressEvent::WriteApplied {
+            path: PathBuf::from("package.json"),
+        });
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            ProgressEvent::WriteApplied {
+                path: PathBuf::from("package.json")
+            }
+        );
+
+        // Without a receiver, events are never built
+        Progress::disabled().emit(|| unreachable!());
+    }
 }
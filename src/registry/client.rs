@@ -3,10 +3,17 @@
 //! This module provides a shared HTTP client with:
 //! - Configurable timeout and User-Agent
 //! - Exponential backoff retry logic (max 3 retries)
-//! - Rate limit error handling
+//! - Rate limit error handling, honoring `Retry-After`
+//! - Optional bearer token authentication, with netrc basic-auth credentials as a fallback
+//! - Optional registry host allowlist and HTTPS-only (strict TLS) enforcement,
+//!   applied to every redirect hop as well as the requested URL
+//! - JSON POST requests for query APIs (e.g., OSV)
 
+use super::{Netrc, NetrcCredentials};
 use crate::error::RegistryError;
-use reqwest::Client;
+use reqwest::redirect::Policy;
+use reqwest::{Client, Url};
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Default timeout for HTTP requests (30 seconds)
@@ -21,11 +28,25 @@ const MAX_RETRIES: u32 = 3;
 /// Base delay for exponential backoff (in milliseconds)
 const BASE_DELAY_MS: u64 = 100;
 
+/// Maximum number of redirects followed per request (reqwest's default)
+const MAX_REDIRECTS: usize = 10;
+
 /// HTTP client wrapper with retry logic
 #[derive(Clone)]
 pub struct HttpClient {
     client: Client,
+    timeout: Duration,
+    user_agent: String,
+    /// Why the client could not be rebuilt for the current URL policy, if it failed
+    build_error: Option<String>,
     max_retries: u32,
+    bearer_token: Option<String>,
+    /// Basic-auth credentials per host, used when no bearer token is set
+    netrc: Option<Arc<Netrc>>,
+    allowed_hosts: Vec<String>,
+    strict_tls: bool,
+    /// Return 429 responses to the caller instead of retrying them
+    rate_limit_passthrough: bool,
 }
 
 impl HttpClient {
@@ -36,19 +57,25 @@ impl HttpClient {
 
     /// Create a new HTTP client with custom configuration
     pub fn with_config(timeout: Duration, user_agent: &str) -> Result<Self, RegistryError> {
-        let client = Client::builder()
-            .timeout(timeout)
-            .user_agent(user_agent)
-            .build()
-            .map_err(|e| RegistryError::NetworkError {
+        let client = build_client(timeout, user_agent, Vec::new(), false).map_err(|e| {
+            RegistryError::NetworkError {
                 package: String::new(),
                 registry: "HTTP client".to_string(),
                 message: format!("failed to create HTTP client: {}", e),
-            })?;
+            }
+        })?;
 
         Ok(Self {
             client,
+            timeout,
+            user_agent: user_agent.to_string(),
+            build_error: None,
             max_retries: MAX_RETRIES,
+            bearer_token: None,
+            netrc: None,
+            allowed_hosts: Vec::new(),
+            strict_tls: false,
+            rate_limit_passthrough: false,
         })
     }
 
@@ -58,6 +85,86 @@ impl HttpClient {
         self
     }
 
+    /// Send an `Authorization: Bearer` header with every request
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+
+    /// Send basic-auth credentials from a netrc file to matching hosts
+    ///
+    /// An explicit bearer token takes priority over netrc credentials.
+    pub fn with_netrc(mut self, netrc: Netrc) -> Self {
+        self.netrc = Some(Arc::new(netrc));
+        self
+    }
+
+    /// Only allow requests to these hosts (an empty list allows any host)
+    pub fn with_allowed_hosts<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_hosts = hosts
+            .into_iter()
+            .map(|h| h.into().to_ascii_lowercase())
+            .collect();
+        self.rebuild_client()
+    }
+
+    /// Reject plain-HTTP URLs when enabled
+    pub fn with_strict_tls(mut self, strict_tls: bool) -> Self {
+        self.strict_tls = strict_tls;
+        self.rebuild_client()
+    }
+
+    /// Rebuild the reqwest client so redirects follow the current URL policy
+    ///
+    /// The redirect policy is fixed when a reqwest client is built. If the
+    /// rebuild fails, every request fails rather than using the old policy.
+    fn rebuild_client(mut self) -> Self {
+        match build_client(
+            self.timeout,
+            &self.user_agent,
+            self.allowed_hosts.clone(),
+            self.strict_tls,
+        ) {
+            Ok(client) => {
+                self.client = client;
+                self.build_error = None;
+            }
+            Err(e) => self.build_error = Some(format!("failed to create HTTP client: {}", e)),
+        }
+        self
+    }
+
+    /// Fail 429 responses immediately with `RateLimitExceeded` instead of retrying
+    ///
+    /// For adapters that coordinate their own backoff across concurrent
+    /// requests (e.g., crates.io).
+    pub fn with_rate_limit_passthrough(mut self, enabled: bool) -> Self {
+        self.rate_limit_passthrough = enabled;
+        self
+    }
+
+    /// Check a URL against the host allowlist and TLS policy before sending
+    fn check_url(&self, url: &str) -> Result<(), RegistryError> {
+        let blocked = |reason: String| RegistryError::blocked_url(url, reason);
+
+        let parsed = Url::parse(url).map_err(|e| blocked(format!("invalid URL: {}", e)))?;
+        match url_violation(&parsed, &self.allowed_hosts, self.strict_tls) {
+            Some(reason) => Err(blocked(reason)),
+            None => Ok(()),
+        }
+    }
+
+    /// Netrc credentials for the host of `url`
+    fn netrc_credentials(&self, url: &str) -> Option<&NetrcCredentials> {
+        let netrc = self.netrc.as_ref()?;
+        let parsed = reqwest::Url::parse(url).ok()?;
+        netrc.credentials_for(parsed.host_str()?)
+    }
+
     /// Get the underlying reqwest client
     pub fn inner(&self) -> &Client {
         &self.client
@@ -75,17 +182,97 @@ impl HttpClient {
         package: &str,
         registry: &str,
     ) -> Result<reqwest::Response, RegistryError> {
+        self.send_with_context(reqwest::Method::GET, url, None, package, registry)
+            .await
+    }
+
+    /// Perform a POST request with a JSON body and parse the JSON response
+    pub async fn post_json<B: serde::Serialize, T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        body: &B,
+        package: &str,
+        registry: &str,
+    ) -> Result<T, RegistryError> {
+        let invalid = |message: String| RegistryError::InvalidResponse {
+            package: package.to_string(),
+            registry: registry.to_string(),
+            message,
+        };
+
+        let body = serde_json::to_value(body)
+            .map_err(|e| invalid(format!("failed to encode request: {}", e)))?;
+        let response = self
+            .send_with_context(reqwest::Method::POST, url, Some(&body), package, registry)
+            .await?;
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| invalid(format!("failed to parse JSON: {}", e)))
+    }
+
+    /// Send a request with retry logic and error context
+    async fn send_with_context(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&serde_json::Value>,
+        package: &str,
+        registry: &str,
+    ) -> Result<reqwest::Response, RegistryError> {
+        self.check_url(url)?;
+        if let Some(message) = &self.build_error {
+            return Err(RegistryError::NetworkError {
+                package: package.to_string(),
+                registry: registry.to_string(),
+                message: message.clone(),
+            });
+        }
+
         let mut last_error = None;
         let mut delay = BASE_DELAY_MS;
 
         for attempt in 0..=self.max_retries {
-            match self.client.get(url).send().await {
+            let mut request = self.client.request(method.clone(), url);
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+            if let Some(token) = &self.bearer_token {
+                request = request.bearer_auth(token);
+            } else if let Some(credentials) = self.netrc_credentials(url) {
+                request = request.basic_auth(&credentials.login, Some(&credentials.password));
+            }
+
+            match request.send().await {
                 Ok(response) => {
+                    // GitHub signals an exhausted quota with 403 and no remaining requests;
+                    // retrying won't help until the window resets
+                    if response.status() == reqwest::StatusCode::FORBIDDEN
+                        && response
+                            .headers()
+                            .get("x-ratelimit-remaining")
+                            .is_some_and(|v| v == "0")
+                    {
+                        return Err(RegistryError::RateLimitExceeded {
+                            registry: registry.to_string(),
+                            retry_after: None,
+                        });
+                    }
+
                     // Check for rate limiting
                     if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
-                        last_error = Some(RegistryError::RateLimitExceeded {
+                        let rate_limited = RegistryError::RateLimitExceeded {
                             registry: registry.to_string(),
-                        });
+                            retry_after: response
+                                .headers()
+                                .get(reqwest::header::RETRY_AFTER)
+                                .and_then(|v| v.to_str().ok())
+                                .and_then(parse_retry_after),
+                        };
+                        if self.rate_limit_passthrough {
+                            return Err(rate_limited);
+                        }
+                        last_error = Some(rate_limited);
 
                         if attempt < self.max_retries {
                             // Wait before retrying with exponential backoff
@@ -116,6 +303,16 @@ impl HttpClient {
                     return Ok(response);
                 }
                 Err(e) => {
+                    // A redirect to a disallowed URL is a policy decision; retrying won't help
+                    if e.is_redirect() {
+                        let reason = std::error::Error::source(&e)
+                            .map_or_else(|| e.to_string(), |source| source.to_string());
+                        return Err(RegistryError::blocked_url(
+                            e.url().map_or(url, |u| u.as_str()),
+                            reason,
+                        ));
+                    }
+
                     // Check for timeout
                     if e.is_timeout() {
                         last_error = Some(RegistryError::Timeout {
@@ -245,10 +442,76 @@ impl Default for HttpClient {
     }
 }
 
+/// Build a reqwest client whose redirects are checked against the URL policy
+fn build_client(
+    timeout: Duration,
+    user_agent: &str,
+    allowed_hosts: Vec<String>,
+    strict_tls: bool,
+) -> reqwest::Result<Client> {
+    let redirect = Policy::custom(move |attempt| {
+        if attempt.previous().len() >= MAX_REDIRECTS {
+            return attempt.error("too many redirects");
+        }
+        match url_violation(attempt.url(), &allowed_hosts, strict_tls) {
+            Some(reason) => attempt.error(format!("redirect blocked: {}", reason)),
+            None => attempt.follow(),
+        }
+    });
+
+    Client::builder()
+        .timeout(timeout)
+        .user_agent(user_agent)
+        .redirect(redirect)
+        .build()
+}
+
+/// Why a URL breaks the host allowlist or TLS policy, if it does
+fn url_violation(url: &Url, allowed_hosts: &[String], strict_tls: bool) -> Option<String> {
+    if strict_tls && url.scheme() != "https" {
+        return Some(format!("{} is not allowed with --strict-tls", url.scheme()));
+    }
+
+    if !allowed_hosts.is_empty() {
+        let host = url.host_str().unwrap_or("").to_ascii_lowercase();
+        if !allowed_hosts.contains(&host) {
+            return Some(format!("host '{}' is not in the allowlist", host));
+        }
+    }
+
+    None
+}
+
+/// Parse a `Retry-After` header: delay seconds or an HTTP date
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (at.with_timezone(&chrono::Utc) - chrono::Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("2"), Some(Duration::from_secs(2)));
+        assert_eq!(parse_retry_after(" 120 "), Some(Duration::from_secs(120)));
+        // Dates in the past mean "retry now"
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
     #[test]
     fn test_http_client_creation() {
         let client = HttpClient::new();
@@ -267,6 +530,188 @@ mod tests {
         assert_eq!(client.max_retries, 5);
     }
 
+    #[test]
+    fn test_http_client_with_bearer_token() {
+        let client = HttpClient::new().unwrap();
+        assert!(client.bearer_token.is_none());
+
+        let client = client.with_bearer_token("secret");
+        assert_eq!(client.bearer_token.as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn test_netrc_credentials_match_host() {
+        let netrc = Netrc::parse(
+            "machine registry.example.com login deploy password s3cret\n\
+             machine other.example.com login ci password token\n",
+        );
+        let client = HttpClient::new().unwrap().with_netrc(netrc);
+
+        let credentials = client
+            .netrc_credentials("https://registry.example.com/simple/pkg")
+            .unwrap();
+        assert_eq!(credentials.login, "deploy");
+        assert!(client
+            .netrc_credentials("https://unknown.example.com/pkg")
+            .is_none());
+        assert!(HttpClient::new()
+            .unwrap()
+            .netrc_credentials("https://registry.example.com/pkg")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_netrc_sends_basic_auth_below_bearer_token() {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/pkg", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let mut headers = Vec::new();
+            for _ in 0..2 {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]).to_string();
+                headers.push(request.lines().find_map(|l| {
+                    l.to_ascii_lowercase()
+                        .starts_with("authorization:")
+                        .then(|| l["authorization:".len()..].trim().to_string())
+                }));
+                stream
+                    .write_all(
+                        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}",
+                    )
+                    .unwrap();
+            }
+            headers
+        });
+
+        let netrc = Netrc::parse("machine 127.0.0.1 login deploy password s3cret\n");
+        let client = HttpClient::new().unwrap().with_netrc(netrc);
+        client.get(&url).await.unwrap();
+        client.with_bearer_token("token").get(&url).await.unwrap();
+
+        let headers = server.join().unwrap();
+        // base64("deploy:s3cret")
+        assert_eq!(headers[0].as_deref(), Some("Basic ZGVwbG95OnMzY3JldA=="));
+        assert_eq!(headers[1].as_deref(), Some("Bearer token"));
+    }
+
+    #[tokio::test]
+    async fn test_disallowed_host_blocked_before_request() {
+        let client = HttpClient::new()
+            .unwrap()
+            .with_allowed_hosts(["registry.npmjs.org"]);
+
+        // An unroutable host would fail with a network error if a request were sent
+        let result = client
+            .get_with_context("https://blocked.invalid/pkg", "pkg", "npm")
+            .await;
+        match result {
+            Err(RegistryError::BlockedUrl { url, reason }) => {
+                assert_eq!(url, "https://blocked.invalid/pkg");
+                assert!(reason.contains("blocked.invalid"));
+            }
+            other => panic!("Expected BlockedUrl, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_json_respects_allowlist() {
+        let client = HttpClient::new()
+            .unwrap()
+            .with_allowed_hosts(["registry.npmjs.org"]);
+
+        let result: Result<serde_json::Value, _> = client
+            .post_json(
+                "https://api.osv.dev/v1/query",
+                &serde_json::json!({"version": "1.0.0"}),
+                "pkg",
+                "OSV",
+            )
+            .await;
+        assert!(matches!(result, Err(RegistryError::BlockedUrl { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_redirect_to_disallowed_host_blocked() {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // `localhost` is not in the allowlist even though it resolves to the same machine
+        let blocked = TcpListener::bind("127.0.0.1:0").unwrap();
+        let blocked_port = blocked.local_addr().unwrap().port();
+        let blocked_hits = Arc::new(AtomicUsize::new(0));
+        let hits = blocked_hits.clone();
+        std::thread::spawn(move || {
+            for mut stream in blocked.incoming().flatten() {
+                hits.fetch_add(1, Ordering::SeqCst);
+                let _ = stream.write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}",
+                );
+            }
+        });
+
+        let allowed = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/pkg", allowed.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for mut stream in allowed.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf);
+                let response = format!(
+                    "HTTP/1.1 302 Found\r\nLocation: http://localhost:{}/pkg\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    blocked_port
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let client = HttpClient::new()
+            .unwrap()
+            .with_allowed_hosts(["127.0.0.1"])
+            .with_max_retries(0);
+        let result = client.get_with_context(&url, "pkg", "npm").await;
+        match result {
+            Err(RegistryError::BlockedUrl { reason, .. }) => {
+                assert!(
+                    reason.contains("localhost"),
+                    "unexpected reason: {}",
+                    reason
+                );
+            }
+            other => panic!("Expected BlockedUrl, got {:?}", other.map(|_| ())),
+        }
+        assert_eq!(blocked_hits.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_allowed_host_passes_check() {
+        let client = HttpClient::new()
+            .unwrap()
+            .with_allowed_hosts(["Registry.NPMJS.org"]);
+        assert!(client
+            .check_url("https://registry.npmjs.org/lodash")
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_strict_tls_rejects_http() {
+        let client = HttpClient::new().unwrap().with_strict_tls(true);
+
+        let result = client.get("http://localhost:1/pkg").await;
+        assert!(matches!(result, Err(RegistryError::BlockedUrl { .. })));
+        assert!(client.check_url("https://localhost:1/pkg").is_ok());
+    }
+
+    #[test]
+    fn test_no_policy_allows_http() {
+        let client = HttpClient::new().unwrap();
+        assert!(client.check_url("http://localhost:8080/pkg").is_ok());
+    }
+
     #[test]
     fn test_http_client_default() {
         let client = HttpClient::default();
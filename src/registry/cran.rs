@@ -0,0 +1,144 @@
+//! CRAN adapter for R packages
+//!
+//! Fetches the release history of a package from the CRAN package database (crandb).
+//! API endpoint: https://crandb.r-pkg.org/{package}/all
+//!
+//! Every version in the package's `timeline` is returned, dated by its CRAN
+//! publication. Packages removed from CRAN (`archived`) are still reported with
+//! their last release.
+
+use crate::domain::Language;
+use crate::error::RegistryError;
+use crate::registry::{HttpClient, RegistryAdapter};
+use crate::update::VersionInfo;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// CRAN package database base URL
+const CRANDB_API_URL: &str = "https://crandb.r-pkg.org";
+
+/// CRAN adapter
+pub struct CranAdapter {
+    client: HttpClient,
+    base_url: String,
+}
+
+/// Package record from crandb
+#[derive(Debug, Deserialize)]
+struct CranPackage {
+    /// Publication timestamp per version (e.g., "1.1.2" -> "2023-04-21T07:40:02+00:00")
+    #[serde(default)]
+    timeline: HashMap<String, String>,
+}
+
+impl CranAdapter {
+    /// Create a new CRAN adapter
+    pub fn new(client: HttpClient) -> Self {
+        Self::with_base_url(client, CRANDB_API_URL)
+    }
+
+    /// Create a new CRAN adapter for a custom base URL (e.g., a mirror)
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+        }
+    }
+
+    /// Build the URL for a package
+    fn build_url(&self, package: &str) -> String {
+        format!("{}/{}/all", self.base_url, package)
+    }
+}
+
+#[async_trait]
+impl RegistryAdapter for CranAdapter {
+    fn language(&self) -> Language {
+        Language::R
+    }
+
+    fn registry_name(&self) -> &'static str {
+        "CRAN"
+    }
+
+    async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        let url = self.build_url(package);
+        let response: CranPackage = self
+            .client
+            .get_json(&url, package, self.registry_name())
+            .await?;
+
+        let mut versions: Vec<VersionInfo> = response
+            .timeline
+            .into_iter()
+            .filter_map(|(version, published)| {
+                let released_at = published.parse::<DateTime<Utc>>().ok()?;
+                Some(VersionInfo::new(version, released_at))
+            })
+            .collect();
+
+        // Sort by version
+        versions.sort();
+
+        Ok(versions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    #[test]
+    fn test_cran_adapter_registry_name() {
+        let adapter = CranAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(adapter.language(), Language::R);
+        assert_eq!(adapter.registry_name(), "CRAN");
+    }
+
+    #[test]
+    fn test_build_url() {
+        let adapter = CranAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(
+            adapter.build_url("dplyr"),
+            "https://crandb.r-pkg.org/dplyr/all"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_versions_from_timeline() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf);
+                let body = r#"{
+                    "_id": "zoo",
+                    "name": "zoo",
+                    "versions": {},
+                    "timeline": {
+                        "1.8-11": "2022-09-09T08:40:02+00:00",
+                        "1.8-12": "2023-04-13T16:30:02+00:00",
+                        "1.8-9": "2021-03-09T15:50:02+00:00",
+                        "broken": "not a date"
+                    },
+                    "archived": false
+                }"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let adapter = CranAdapter::with_base_url(HttpClient::new().unwrap(), base_url);
+        let versions = adapter.fetch_versions("zoo").await.unwrap();
+        let numbers: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(numbers, vec!["1.8-9", "1.8-11", "1.8-12"]);
+    }
+}
@@ -4,7 +4,8 @@
 //! API endpoint: https://crates.io/api/v1/crates/{crate}
 //!
 //! Note: crates.io requires a User-Agent header (handled by HttpClient)
-//! and has rate limiting (1 request/second).
+//! and has rate limiting (1 request/second). A 429 response pauses every
+//! crates.io request for the `Retry-After` delay before the request is retried.
 
 use crate::domain::Language;
 use crate::error::RegistryError;
@@ -23,11 +24,20 @@ const CRATES_IO_API_URL: &str = "https://crates.io/api/v1/crates";
 /// Rate limit: 1 request per second
 const RATE_LIMIT_INTERVAL: Duration = Duration::from_secs(1);
 
+/// Maximum number of retries after a 429 response
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Longest `Retry-After` delay honored before retrying
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
 /// crates.io adapter with rate limiting
 pub struct CratesIoAdapter {
     client: HttpClient,
+    base_url: String,
     rate_limiter: Arc<Semaphore>,
     last_request: std::sync::Mutex<Option<Instant>>,
+    /// No request may start before this time (set from `Retry-After`)
+    blocked_until: std::sync::Mutex<Option<Instant>>,
 }
 
 /// crates.io crate response
@@ -51,37 +61,56 @@ struct CrateVersion {
 impl CratesIoAdapter {
     /// Create a new crates.io adapter
     pub fn new(client: HttpClient) -> Self {
+        Self::with_base_url(client, CRATES_IO_API_URL)
+    }
+
+    /// Create a new crates.io adapter for a custom base URL (e.g., a mirror)
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
         Self {
-            client,
+            client: client.with_rate_limit_passthrough(true),
+            base_url: base_url.into(),
             rate_limiter: Arc::new(Semaphore::new(1)),
             last_request: std::sync::Mutex::new(None),
+            blocked_until: std::sync::Mutex::new(None),
         }
     }
 
     /// Build the URL for a crate
     fn build_url(&self, crate_name: &str) -> String {
-        format!("{}/{}", CRATES_IO_API_URL, crate_name)
+        format!("{}/{}", self.base_url, crate_name)
     }
 
     /// Apply rate limiting before making a request
+    ///
+    /// The wait happens while holding the single permit, so a `Retry-After`
+    /// pause holds back every concurrent crates.io request.
     async fn apply_rate_limit(&self) {
         let _permit = self.rate_limiter.acquire().await.unwrap();
 
-        // Check if we need to wait
-        let elapsed = {
-            let last_request = self.last_request.lock().unwrap();
-            last_request.map(|t| t.elapsed())
-        };
-
-        if let Some(elapsed) = elapsed {
-            if elapsed < RATE_LIMIT_INTERVAL {
-                tokio::time::sleep(RATE_LIMIT_INTERVAL - elapsed).await;
-            }
+        // Wait for the request interval and any Retry-After pause, whichever ends later
+        let next_interval = self
+            .last_request
+            .lock()
+            .unwrap()
+            .map(|t| t + RATE_LIMIT_INTERVAL);
+        let blocked_until = *self.blocked_until.lock().unwrap();
+        if let Some(until) = next_interval.max(blocked_until) {
+            tokio::time::sleep_until(until).await;
         }
 
         // Update last request time
         *self.last_request.lock().unwrap() = Some(Instant::now());
     }
+
+    /// Pause all requests for the registry's `Retry-After` delay
+    fn back_off(&self, retry_after: Option<Duration>) {
+        let delay = retry_after
+            .unwrap_or(RATE_LIMIT_INTERVAL)
+            .min(MAX_RETRY_AFTER);
+        let until = Instant::now() + delay;
+        let mut blocked_until = self.blocked_until.lock().unwrap();
+        *blocked_until = Some(blocked_until.map_or(until, |current| current.max(until)));
+    }
 }
 
 #[async_trait]
@@ -95,14 +124,26 @@ impl RegistryAdapter for CratesIoAdapter {
     }
 
     async fn fetch_versions(&self, crate_name: &str) -> Result<Vec<VersionInfo>, RegistryError> {
-        // Apply rate limiting
-        self.apply_rate_limit().await;
-
         let url = self.build_url(crate_name);
-        let response: CratesIoResponse = self
-            .client
-            .get_json(&url, crate_name, self.registry_name())
-            .await?;
+        let mut attempt = 0;
+        let response: CratesIoResponse = loop {
+            // Apply rate limiting
+            self.apply_rate_limit().await;
+
+            match self
+                .client
+                .get_json(&url, crate_name, self.registry_name())
+                .await
+            {
+                Err(RegistryError::RateLimitExceeded { retry_after, .. })
+                    if attempt < MAX_RATE_LIMIT_RETRIES =>
+                {
+                    attempt += 1;
+                    self.back_off(retry_after);
+                }
+                result => break result?,
+            }
+        };
 
         let mut versions = Vec::new();
 
@@ -166,4 +207,46 @@ mod tests {
     fn test_rate_limit_constants() {
         assert_eq!(RATE_LIMIT_INTERVAL, Duration::from_secs(1));
     }
+
+    /// Serve one 429 with `Retry-After: 2`, then the crate's versions
+    fn serve_rate_limited_crate() -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let body = r#"{"versions": [{"num": "1.0.200", "created_at": "2024-05-01T00:00:00Z", "yanked": false}]}"#;
+            let responses = [
+                "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 2\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                ),
+            ];
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buffer = [0u8; 4096];
+                let _ = stream.read(&mut buffer);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        base_url
+    }
+
+    #[tokio::test]
+    async fn test_fetch_versions_honors_retry_after() {
+        let base_url = serve_rate_limited_crate();
+        let adapter = CratesIoAdapter::with_base_url(HttpClient::new().unwrap(), base_url);
+
+        let started = Instant::now();
+        let versions = adapter.fetch_versions("serde").await.unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].version, "1.0.200");
+        // The retry waited for the server's Retry-After, not just the 1s interval
+        assert!(started.elapsed() >= Duration::from_secs(2));
+
+        // Later requests wait too, coordinated through the shared limiter
+        assert!(adapter.blocked_until.lock().unwrap().is_some());
+    }
 }
@@ -0,0 +1,221 @@
+//! Docker Hub adapter for base image tags
+//!
+//! Fetches image tags from the Docker Hub API.
+//! API endpoint: https://hub.docker.com/v2/repositories/{namespace}/{repo}/tags
+//!
+//! Official images (`node`) live in the `library` namespace. Tags are read newest
+//! first, up to `MAX_PAGES` pages. Each tag is dated by its last push, which can be
+//! later than the original release for rebuilt images. Images on other registries
+//! (`ghcr.io/...`) are not supported.
+
+use crate::domain::Language;
+use crate::error::RegistryError;
+use crate::registry::{HttpClient, RegistryAdapter};
+use crate::update::VersionInfo;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Docker Hub API base URL
+const DOCKER_HUB_API_URL: &str = "https://hub.docker.com";
+
+/// Tags requested per page (the API maximum)
+const PAGE_SIZE: u32 = 100;
+
+/// Maximum number of tag pages read per image
+const MAX_PAGES: usize = 5;
+
+/// Registry host names that refer to Docker Hub
+const DOCKER_HUB_HOSTS: [&str; 2] = ["docker.io", "index.docker.io"];
+
+/// Docker Hub adapter
+pub struct DockerHubAdapter {
+    client: HttpClient,
+    base_url: String,
+}
+
+/// Page of the tags listing
+#[derive(Debug, Deserialize)]
+struct TagsPage {
+    /// URL of the next page, if any
+    next: Option<String>,
+    /// Tags on this page
+    results: Vec<TagInfo>,
+}
+
+/// Tag entry from the tags listing
+#[derive(Debug, Deserialize)]
+struct TagInfo {
+    /// Tag name (e.g., "20.11.0-alpine")
+    name: String,
+    /// Last push timestamp in ISO 8601 format
+    last_updated: Option<String>,
+}
+
+impl DockerHubAdapter {
+    /// Create a new Docker Hub adapter
+    pub fn new(client: HttpClient) -> Self {
+        Self::with_base_url(client, DOCKER_HUB_API_URL)
+    }
+
+    /// Create a new Docker Hub adapter for a custom base URL (e.g., a mirror)
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+        }
+    }
+
+    /// Docker Hub repository (`namespace/repo`) for an image name
+    fn repository(&self, image: &str) -> Result<String, RegistryError> {
+        let mut parts: Vec<&str> = image.split('/').collect();
+
+        // A first component with a dot, a port or `localhost` is a registry host
+        let first = parts[0];
+        if parts.len() > 1 && (first.contains(['.', ':']) || first == "localhost") {
+            if !DOCKER_HUB_HOSTS.contains(&first) {
+                return Err(RegistryError::InvalidPackageName {
+                    name: image.to_string(),
+                    registry: self.registry_name().to_string(),
+                    reason: format!("registry '{}' is not supported", first),
+                });
+            }
+            parts.remove(0);
+        }
+
+        match parts.as_slice() {
+            [repo] => Ok(format!("library/{}", repo)),
+            _ => Ok(parts.join("/")),
+        }
+    }
+
+    /// Build the first tags page URL for an image
+    fn build_url(&self, image: &str) -> Result<String, RegistryError> {
+        Ok(format!(
+            "{}/v2/repositories/{}/tags?page_size={}&ordering=last_updated",
+            self.base_url,
+            self.repository(image)?,
+            PAGE_SIZE
+        ))
+    }
+}
+
+#[async_trait]
+impl RegistryAdapter for DockerHubAdapter {
+    fn language(&self) -> Language {
+        Language::Docker
+    }
+
+    fn registry_name(&self) -> &'static str {
+        "Docker Hub"
+    }
+
+    async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        let mut url = Some(self.build_url(package)?);
+        let mut versions = Vec::new();
+
+        for _ in 0..MAX_PAGES {
+            let Some(page_url) = url.take() else {
+                break;
+            };
+            let page: TagsPage = self
+                .client
+                .get_json(&page_url, package, self.registry_name())
+                .await?;
+
+            versions.extend(page.results.into_iter().filter_map(|tag| {
+                let released_at = tag.last_updated?.parse::<DateTime<Utc>>().ok()?;
+                Some(VersionInfo::new(tag.name, released_at))
+            }));
+            url = page.next;
+        }
+
+        // Sort by version
+        versions.sort();
+
+        Ok(versions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    #[test]
+    fn test_docker_hub_adapter_registry_name() {
+        let adapter = DockerHubAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(adapter.language(), Language::Docker);
+        assert_eq!(adapter.registry_name(), "Docker Hub");
+    }
+
+    #[test]
+    fn test_repository() {
+        let adapter = DockerHubAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(adapter.repository("node").unwrap(), "library/node");
+        assert_eq!(
+            adapter.repository("bitnami/redis").unwrap(),
+            "bitnami/redis"
+        );
+        assert_eq!(
+            adapter.repository("docker.io/library/node").unwrap(),
+            "library/node"
+        );
+        assert_eq!(
+            adapter.repository("docker.io/node").unwrap(),
+            "library/node"
+        );
+        assert!(adapter.repository("ghcr.io/owner/app").is_err());
+        assert!(adapter.repository("localhost:5000/base").is_err());
+    }
+
+    #[test]
+    fn test_build_url() {
+        let adapter = DockerHubAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(
+            adapter.build_url("node").unwrap(),
+            "https://hub.docker.com/v2/repositories/library/node/tags?page_size=100&ordering=last_updated"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_versions_follows_pages() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let next = format!("{}/page2", base_url);
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let body = if request.starts_with("GET /page2") {
+                    r#"{"next": null, "results": [
+                        {"name": "20.10.0-alpine", "last_updated": "2023-11-22T10:00:00Z"}
+                    ]}"#
+                    .to_string()
+                } else {
+                    format!(
+                        r#"{{"next": "{}", "results": [
+                            {{"name": "20.11.0-alpine", "last_updated": "2024-01-10T10:00:00Z"}},
+                            {{"name": "20.11.0", "last_updated": "2024-01-10T10:00:00Z"}},
+                            {{"name": "broken", "last_updated": null}}
+                        ]}}"#,
+                        next
+                    )
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let adapter = DockerHubAdapter::with_base_url(HttpClient::new().unwrap(), base_url);
+        let versions = adapter.fetch_versions("node").await.unwrap();
+        let mut tags: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        tags.sort();
+        assert_eq!(tags, vec!["20.10.0-alpine", "20.11.0", "20.11.0-alpine"]);
+    }
+}
@@ -0,0 +1,416 @@
+//! GitHub Releases client for release notes, and git tags as versions
+//!
+//! Fetches release notes from the GitHub Releases API for packages whose
+//! source repository is on GitHub, and versions from a repository's tags for
+//! ecosystems without a version registry (Nimble, Crystal shards, pre-commit hooks).
+//! API endpoints:
+//! - List releases: https://api.github.com/repos/{owner}/{repo}/releases
+//! - List tags: https://api.github.com/repos/{owner}/{repo}/tags
+//! - Tag commit (release time): https://api.github.com/repos/{owner}/{repo}/commits/{sha}
+//!
+//! Tags are read page by page, following the `Link` header, up to `MAX_TAG_PAGES`
+//! pages. Requests are authenticated with `GITHUB_TOKEN` when it is set.
+
+use crate::domain::{Dependency, Language};
+use crate::error::RegistryError;
+use crate::registry::HttpClient;
+use crate::update::{compare_versions, VersionInfo};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::cmp::Ordering;
+
+/// GitHub REST API base URL
+pub(crate) const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Environment variable holding the GitHub API token
+pub(crate) const GITHUB_TOKEN_ENV: &str = "GITHUB_TOKEN";
+
+/// Maximum number of tags whose release time is fetched, newest first
+const MAX_DATED_TAGS: usize = 10;
+
+/// Maximum number of tag pages (100 tags each) read per repository
+const MAX_TAG_PAGES: usize = 5;
+
+/// Git tag from the GitHub tags API
+#[derive(Debug, Deserialize)]
+struct TagResponse {
+    name: String,
+    commit: TagCommit,
+}
+
+/// Commit a tag points to
+#[derive(Debug, Deserialize)]
+struct TagCommit {
+    sha: String,
+}
+
+/// Commit from the GitHub commits API
+#[derive(Debug, Deserialize)]
+struct CommitResponse {
+    commit: CommitDetail,
+}
+
+/// Git data of a commit
+#[derive(Debug, Deserialize)]
+struct CommitDetail {
+    committer: CommitSignature,
+}
+
+/// Committer signature carrying the commit time
+#[derive(Debug, Deserialize)]
+struct CommitSignature {
+    date: String,
+}
+
+/// A single GitHub release
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubRelease {
+    /// Git tag of the release (e.g., "v1.2.3")
+    pub tag_name: String,
+    /// Markdown release notes
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Whether the release is an unpublished draft
+    #[serde(default)]
+    pub draft: bool,
+}
+
+/// Source of GitHub releases for a repository
+#[async_trait]
+pub trait ReleaseNotesSource: Send + Sync {
+    /// Fetch the releases of `owner/repo`
+    async fn fetch_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<GitHubRelease>, RegistryError>;
+}
+
+/// GitHub Releases API client
+pub struct GitHubClient {
+    client: HttpClient,
+    base_url: String,
+}
+
+impl GitHubClient {
+    /// Create a new GitHub client, authenticating with `GITHUB_TOKEN` if set
+    pub fn new(client: HttpClient) -> Self {
+        let client = match std::env::var(GITHUB_TOKEN_ENV) {
+            Ok(token) if !token.is_empty() => client.with_bearer_token(token),
+            _ => client,
+        };
+        Self::with_base_url(client, GITHUB_API_URL)
+    }
+
+    /// Create a new GitHub client for a custom API base URL
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+        }
+    }
+
+    /// Build the URL for listing releases
+    fn build_releases_url(&self, owner: &str, repo: &str) -> String {
+        format!(
+            "{}/repos/{}/{}/releases?per_page=100",
+            self.base_url, owner, repo
+        )
+    }
+}
+
+#[async_trait]
+impl ReleaseNotesSource for GitHubClient {
+    async fn fetch_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<GitHubRelease>, RegistryError> {
+        let url = self.build_releases_url(owner, repo);
+        let package = format!("{}/{}", owner, repo);
+        self.client.get_json(&url, &package, "GitHub").await
+    }
+}
+
+/// Returns the GitHub `(owner, repo)` hosting a dependency, if known
+///
+/// Only dependencies named by their repository are known: Go modules
+/// (`github.com/owner/repo[/subpackage]`), Crystal shards (`github: owner/repo`)
+/// and pre-commit hook repositories (`https://github.com/owner/repo`). Other
+/// registries' package names don't identify a repository.
+pub fn github_repository(dependency: &Dependency) -> Option<(String, String)> {
+    let path = match dependency.language {
+        Language::Go => dependency.name.strip_prefix("github.com/")?,
+        Language::Crystal => dependency.lookup_name(),
+        Language::PreCommit => dependency.name.split_once("github.com/")?.1,
+        _ => return None,
+    };
+
+    let mut parts = path.split('/');
+    let owner = parts.next().filter(|s| !s.is_empty())?;
+    let repo = parts.next().filter(|s| !s.is_empty())?;
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+    Some((owner.to_string(), repo.to_string()))
+}
+
+/// Version named by a release tag (`v1.2.3` or `1.2.3`), if it is one
+pub(crate) fn tag_version(tag: &str) -> Option<&str> {
+    let version = tag.strip_prefix('v').unwrap_or(tag);
+    version
+        .starts_with(|c: char| c.is_ascii_digit())
+        .then_some(version)
+}
+
+/// URL of the `rel="next"` page in a GitHub `Link` header, if any
+fn next_page_url(link: &str) -> Option<String> {
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        params
+            .split(';')
+            .any(|p| p.trim() == r#"rel="next""#)
+            .then(|| {
+                url.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+    })
+}
+
+/// Versions of a GitHub repository's release tags, dated by their commits
+///
+/// Tags are read up to `MAX_TAG_PAGES` pages. Each release time costs a request,
+/// so only the newest tags are dated and returned. `package` and `registry`
+/// label errors.
+pub(crate) async fn fetch_tag_versions(
+    client: &HttpClient,
+    github_url: &str,
+    owner: &str,
+    repo: &str,
+    package: &str,
+    registry: &str,
+) -> Result<Vec<VersionInfo>, RegistryError> {
+    let mut url = Some(format!(
+        "{}/repos/{}/{}/tags?per_page=100",
+        github_url, owner, repo
+    ));
+    let mut tags: Vec<TagResponse> = Vec::new();
+    for _ in 0..MAX_TAG_PAGES {
+        let Some(page_url) = url.take() else {
+            break;
+        };
+        let response = client
+            .get_with_context(&page_url, package, registry)
+            .await?;
+        url = response
+            .headers()
+            .get(reqwest::header::LINK)
+            .and_then(|link| link.to_str().ok())
+            .and_then(next_page_url);
+        let page: Vec<TagResponse> =
+            response
+                .json()
+                .await
+                .map_err(|e| RegistryError::InvalidResponse {
+                    package: package.to_string(),
+                    registry: registry.to_string(),
+                    message: format!("failed to parse JSON: {}", e),
+                })?;
+        tags.extend(page);
+    }
+
+    let mut tagged: Vec<(&str, &str)> = tags
+        .iter()
+        .filter_map(|t| tag_version(&t.name).map(|v| (v, t.commit.sha.as_str())))
+        .collect();
+    tagged.sort_by(|a, b| compare_versions(b.0, a.0));
+
+    let mut versions = Vec::new();
+    for (version, sha) in tagged.into_iter().take(MAX_DATED_TAGS) {
+        let commit_url = format!("{}/repos/{}/{}/commits/{}", github_url, owner, repo, sha);
+        match client
+            .get_json::<CommitResponse>(&commit_url, package, registry)
+            .await
+        {
+            Ok(commit) => {
+                if let Ok(released_at) = commit.commit.committer.date.parse::<DateTime<Utc>>() {
+                    versions.push(VersionInfo::new(version, released_at));
+                }
+            }
+            Err(_) => {
+                // If we can't date a specific tag, skip it
+                continue;
+            }
+        }
+    }
+
+    // Sort by version
+    versions.sort();
+
+    Ok(versions)
+}
+
+/// Collects the notes of releases newer than `from` up to and including `to`
+///
+/// Releases are ordered oldest first, each under a `## <tag>` heading.
+/// Returns `None` when no matching release has a non-empty body.
+pub fn notes_between(releases: &[GitHubRelease], from: &str, to: &str) -> Option<String> {
+    let mut matching: Vec<&GitHubRelease> = releases
+        .iter()
+        .filter(|r| !r.draft)
+        .filter(|r| r.body.as_deref().is_some_and(|b| !b.trim().is_empty()))
+        .filter(|r| {
+            compare_versions(&r.tag_name, from) == Ordering::Greater
+                && compare_versions(&r.tag_name, to) != Ordering::Greater
+        })
+        .collect();
+
+    if matching.is_empty() {
+        return None;
+    }
+
+    matching.sort_by(|a, b| compare_versions(&a.tag_name, &b.tag_name));
+
+    let sections: Vec<String> = matching
+        .iter()
+        .map(|r| {
+            format!(
+                "## {}\n\n{}",
+                r.tag_name,
+                r.body.as_deref().unwrap_or_default().trim()
+            )
+        })
+        .collect();
+
+    Some(sections.join("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{VersionSpec, VersionSpecKind};
+
+    fn go_dependency(name: &str) -> Dependency {
+        Dependency::new(
+            name,
+            VersionSpec::new(VersionSpecKind::Exact, "v1.0.0", "v1.0.0"),
+            false,
+            Language::Go,
+        )
+    }
+
+    fn release(tag: &str, body: &str) -> GitHubRelease {
+        GitHubRelease {
+            tag_name: tag.to_string(),
+            body: Some(body.to_string()),
+            draft: false,
+        }
+    }
+
+    #[test]
+    fn test_build_releases_url() {
+        let client = GitHubClient::with_base_url(HttpClient::new().unwrap(), GITHUB_API_URL);
+        assert_eq!(
+            client.build_releases_url("gin-gonic", "gin"),
+            "https://api.github.com/repos/gin-gonic/gin/releases?per_page=100"
+        );
+    }
+
+    #[test]
+    fn test_github_repository() {
+        assert_eq!(
+            github_repository(&go_dependency("github.com/gin-gonic/gin")),
+            Some(("gin-gonic".to_string(), "gin".to_string()))
+        );
+        assert_eq!(
+            github_repository(&go_dependency("github.com/go-redis/redis/v9")),
+            Some(("go-redis".to_string(), "redis".to_string()))
+        );
+        assert_eq!(github_repository(&go_dependency("golang.org/x/net")), None);
+
+        let spec = VersionSpec::new(VersionSpecKind::Exact, "1.0.0", "1.0.0");
+        let shard = Dependency::production("kemal", spec.clone(), Language::Crystal)
+            .with_alias_target("kemalcr/kemal");
+        assert_eq!(
+            github_repository(&shard),
+            Some(("kemalcr".to_string(), "kemal".to_string()))
+        );
+        let hook = Dependency::production(
+            "https://github.com/pre-commit/pre-commit-hooks.git",
+            spec.clone(),
+            Language::PreCommit,
+        );
+        assert_eq!(
+            github_repository(&hook),
+            Some(("pre-commit".to_string(), "pre-commit-hooks".to_string()))
+        );
+        let gitlab_hook =
+            Dependency::production("https://gitlab.com/pycqa/flake8", spec, Language::PreCommit);
+        assert_eq!(github_repository(&gitlab_hook), None);
+
+        let node = Dependency::new(
+            "github.com/foo/bar",
+            VersionSpec::new(VersionSpecKind::Exact, "1.0.0", "1.0.0"),
+            false,
+            Language::Node,
+        );
+        assert_eq!(github_repository(&node), None);
+    }
+
+    #[test]
+    fn test_parse_releases_response() {
+        let json = r#"[
+            {"tag_name": "v1.10.0", "body": "New router", "draft": false, "prerelease": false},
+            {"tag_name": "v1.9.2", "body": null, "draft": false, "prerelease": false}
+        ]"#;
+
+        let releases: Vec<GitHubRelease> = serde_json::from_str(json).unwrap();
+        assert_eq!(releases.len(), 2);
+        assert_eq!(releases[0].tag_name, "v1.10.0");
+        assert_eq!(releases[0].body.as_deref(), Some("New router"));
+        assert!(releases[1].body.is_none());
+    }
+
+    #[test]
+    fn test_notes_between() {
+        let releases = vec![
+            release("v1.10.0", "New router"),
+            release("v1.9.2", "Fix panic"),
+            release("v1.9.1", "Current release"),
+            release("v1.11.0", "Too new"),
+        ];
+
+        let notes = notes_between(&releases, "v1.9.1", "v1.10.0").unwrap();
+        assert_eq!(notes, "## v1.9.2\n\nFix panic\n\n## v1.10.0\n\nNew router");
+    }
+
+    #[test]
+    fn test_notes_between_skips_drafts_and_empty_bodies() {
+        let mut draft = release("v1.9.3", "Draft notes");
+        draft.draft = true;
+        let releases = vec![draft, release("v1.9.2", "   ")];
+
+        assert_eq!(notes_between(&releases, "v1.9.1", "v1.10.0"), None);
+    }
+
+    #[test]
+    fn test_tag_version() {
+        assert_eq!(tag_version("v0.5.0"), Some("0.5.0"));
+        assert_eq!(tag_version("1.2"), Some("1.2"));
+        assert_eq!(tag_version("latest"), None);
+    }
+
+    #[test]
+    fn test_next_page_url() {
+        let link = r#"<https://api.github.com/repositories/1/tags?per_page=100&page=2>; rel="next", <https://api.github.com/repositories/1/tags?per_page=100&page=5>; rel="last""#;
+        assert_eq!(
+            next_page_url(link).as_deref(),
+            Some("https://api.github.com/repositories/1/tags?per_page=100&page=2")
+        );
+
+        let last =
+            r#"<https://api.github.com/repositories/1/tags?per_page=100&page=4>; rel="prev""#;
+        assert_eq!(next_page_url(last), None);
+    }
+}
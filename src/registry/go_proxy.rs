@@ -7,6 +7,7 @@
 
 use crate::domain::Language;
 use crate::error::RegistryError;
+use crate::parser::{module_major, module_path_for_major};
 use crate::registry::{HttpClient, RegistryAdapter};
 use crate::update::VersionInfo;
 use async_trait::async_trait;
@@ -16,9 +17,15 @@ use serde::Deserialize;
 /// Go Module Proxy base URL
 const GO_PROXY_URL: &str = "https://proxy.golang.org";
 
+/// Maximum number of successive major version paths probed per module
+const MAX_MAJOR_PROBES: u64 = 5;
+
 /// Go Module Proxy adapter
 pub struct GoProxyAdapter {
     client: HttpClient,
+    base_url: String,
+    /// Also look for releases under later `/vN` module paths
+    major_upgrades: bool,
 }
 
 /// Version info response
@@ -35,42 +42,58 @@ struct VersionInfoResponse {
 impl GoProxyAdapter {
     /// Create a new Go Proxy adapter
     pub fn new(client: HttpClient) -> Self {
-        Self { client }
+        Self::with_base_url(client, GO_PROXY_URL)
+    }
+
+    /// Create a new Go Proxy adapter for a custom base URL (e.g., a mirror)
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+            major_upgrades: false,
+        }
+    }
+
+    /// Also report releases published under later major version module paths
+    ///
+    /// For `example.com/mod/v2` this probes `example.com/mod/v3`, `/v4`, ... and
+    /// stops at the first path the proxy doesn't know.
+    pub fn with_major_upgrades(mut self, enabled: bool) -> Self {
+        self.major_upgrades = enabled;
+        self
     }
 
     /// Build the URL for listing versions
     fn build_list_url(&self, module: &str) -> String {
-        // URL encode the module path (replace / with %2F for case-insensitive lookup)
-        let encoded_module = Self::encode_module_path(module);
+        // Slashes stay path separators; only uppercase letters are escaped
+        let encoded_module = self.encode_module_path(module);
         format!("{}/@v/list", encoded_module)
     }
 
     /// Build the URL for version info
     fn build_info_url(&self, module: &str, version: &str) -> String {
-        let encoded_module = Self::encode_module_path(module);
-        format!("{}/@v/{}.info", encoded_module, version)
+        let encoded_module = self.encode_module_path(module);
+        format!("{}/@v/{}.info", encoded_module, case_encode(version))
     }
 
     /// Encode module path for the Go Proxy URL
-    fn encode_module_path(module: &str) -> String {
-        // Go Proxy uses case-encoded paths where uppercase letters become !lowercase
-        let mut encoded = String::with_capacity(module.len() + GO_PROXY_URL.len() + 1);
-        encoded.push_str(GO_PROXY_URL);
-        encoded.push('/');
-
-        for ch in module.chars() {
-            if ch.is_uppercase() {
-                encoded.push('!');
-                for lower in ch.to_lowercase() {
-                    encoded.push(lower);
-                }
-            } else {
-                encoded.push(ch);
-            }
-        }
+    fn encode_module_path(&self, module: &str) -> String {
+        format!("{}/{}", self.base_url, case_encode(module))
+    }
+}
 
-        encoded
+/// Apply the module proxy case encoding, where uppercase letters become !lowercase
+fn case_encode(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for ch in path.chars() {
+        if ch.is_uppercase() {
+            encoded.push('!');
+            encoded.extend(ch.to_lowercase());
+        } else {
+            encoded.push(ch);
+        }
     }
+    encoded
 }
 
 #[async_trait]
@@ -84,6 +107,30 @@ impl RegistryAdapter for GoProxyAdapter {
     }
 
     async fn fetch_versions(&self, module: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        let mut versions = self.fetch_module_versions(module).await?;
+
+        if self.major_upgrades {
+            if let Some(current) = module_major(module) {
+                for major in (current.max(1) + 1)..=(current.max(1) + MAX_MAJOR_PROBES) {
+                    let next = module_path_for_major(module, major);
+                    match self.fetch_module_versions(&next).await {
+                        Ok(found) if !found.is_empty() => versions.extend(found),
+                        _ => break,
+                    }
+                }
+            }
+        }
+
+        // Sort by version
+        versions.sort();
+
+        Ok(versions)
+    }
+}
+
+impl GoProxyAdapter {
+    /// Fetch every listed version of a single module path, with release times
+    async fn fetch_module_versions(&self, module: &str) -> Result<Vec<VersionInfo>, RegistryError> {
         // First, get the list of versions
         let list_url = self.build_list_url(module);
         let version_list = self
@@ -91,16 +138,10 @@ impl RegistryAdapter for GoProxyAdapter {
             .get_text(&list_url, module, self.registry_name())
             .await?;
 
-        let version_strings: Vec<&str> = version_list.lines().collect();
-
-        if version_strings.is_empty() {
-            return Ok(Vec::new());
-        }
-
         // For each version, fetch the info to get the release time
         let mut versions = Vec::new();
 
-        for version_str in version_strings {
+        for version_str in version_list.lines() {
             let version_str = version_str.trim();
             if version_str.is_empty() {
                 continue;
@@ -124,9 +165,6 @@ impl RegistryAdapter for GoProxyAdapter {
             }
         }
 
-        // Sort by version
-        versions.sort();
-
         Ok(versions)
     }
 }
@@ -151,8 +189,10 @@ mod tests {
 
     #[test]
     fn test_encode_module_path_simple() {
+        let client = HttpClient::new().unwrap();
+        let adapter = GoProxyAdapter::new(client);
         assert_eq!(
-            GoProxyAdapter::encode_module_path("github.com/gin-gonic/gin"),
+            adapter.encode_module_path("github.com/gin-gonic/gin"),
             "https://proxy.golang.org/github.com/gin-gonic/gin"
         );
     }
@@ -160,12 +200,32 @@ mod tests {
     #[test]
     fn test_encode_module_path_with_uppercase() {
         // Uppercase letters should be encoded as !lowercase
+        let client = HttpClient::new().unwrap();
+        let adapter = GoProxyAdapter::new(client);
         assert_eq!(
-            GoProxyAdapter::encode_module_path("github.com/Azure/azure-sdk-for-go"),
+            adapter.encode_module_path("github.com/Azure/azure-sdk-for-go"),
             "https://proxy.golang.org/github.com/!azure/azure-sdk-for-go"
         );
     }
 
+    #[test]
+    fn test_encode_module_path_with_base_url() {
+        let client = HttpClient::new().unwrap();
+        let adapter = GoProxyAdapter::with_base_url(client, "https://goproxy.io");
+        assert_eq!(
+            adapter.encode_module_path("github.com/gin-gonic/gin"),
+            "https://goproxy.io/github.com/gin-gonic/gin"
+        );
+    }
+
+    #[test]
+    fn test_major_upgrades_opt_in() {
+        let client = HttpClient::new().unwrap();
+        let adapter = GoProxyAdapter::new(client);
+        assert!(!adapter.major_upgrades);
+        assert!(adapter.with_major_upgrades(true).major_upgrades);
+    }
+
     #[test]
     fn test_build_list_url() {
         let client = HttpClient::new().unwrap();
@@ -184,5 +244,9 @@ mod tests {
             adapter.build_info_url("github.com/gin-gonic/gin", "v1.9.0"),
             "https://proxy.golang.org/github.com/gin-gonic/gin/@v/v1.9.0.info"
         );
+        assert_eq!(
+            adapter.build_info_url("github.com/BurntSushi/toml", "v1.3.2-RC1"),
+            "https://proxy.golang.org/github.com/!burnt!sushi/toml/@v/v1.3.2-!r!c1.info"
+        );
     }
 }
@@ -0,0 +1,135 @@
+//! Gradle Plugin Portal adapter
+//!
+//! Fetches Gradle plugin versions from the plugin marker artifact's Maven metadata.
+//! API endpoint: https://plugins.gradle.org/m2/{id as path}/{id}.gradle.plugin/maven-metadata.xml
+//!
+//! The metadata has no per-version publish dates, so every version is dated by
+//! `<lastUpdated>` (the newest publish). Age filters therefore never let a version
+//! through too early, but may hold back older versions. Snapshots, qualified
+//! versions and anything newer than `<release>` are skipped unless prereleases
+//! are allowed, in which case versions up to `<latest>` are offered.
+
+use crate::domain::Language;
+use crate::error::RegistryError;
+use crate::registry::maven_metadata::metadata_versions;
+use crate::registry::{HttpClient, RegistryAdapter};
+use crate::update::VersionInfo;
+use async_trait::async_trait;
+
+/// Gradle Plugin Portal Maven repository URL
+const GRADLE_PLUGIN_PORTAL_URL: &str = "https://plugins.gradle.org/m2";
+
+/// Gradle Plugin Portal adapter, keyed by plugin id (e.g., `org.springframework.boot`)
+pub struct GradlePluginPortalAdapter {
+    client: HttpClient,
+    base_url: String,
+    allow_prereleases: bool,
+}
+
+impl GradlePluginPortalAdapter {
+    /// Create a new Gradle Plugin Portal adapter
+    pub fn new(client: HttpClient) -> Self {
+        Self::with_base_url(client, GRADLE_PLUGIN_PORTAL_URL)
+    }
+
+    /// Create a new Gradle Plugin Portal adapter for a custom base URL (e.g., a mirror)
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+            allow_prereleases: false,
+        }
+    }
+
+    /// Also offer snapshots, qualified versions and versions up to `<latest>`
+    pub fn with_prereleases(mut self, allowed: bool) -> Self {
+        self.allow_prereleases = allowed;
+        self
+    }
+
+    /// Build the marker artifact's maven-metadata.xml URL for a plugin id
+    fn build_url(&self, plugin_id: &str) -> String {
+        format!(
+            "{}/{}/{}.gradle.plugin/maven-metadata.xml",
+            self.base_url,
+            plugin_id.replace('.', "/"),
+            plugin_id
+        )
+    }
+}
+
+#[async_trait]
+impl RegistryAdapter for GradlePluginPortalAdapter {
+    fn language(&self) -> Language {
+        Language::Java
+    }
+
+    fn registry_name(&self) -> &'static str {
+        "Gradle Plugin Portal"
+    }
+
+    async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        let url = self.build_url(package);
+        let metadata = self
+            .client
+            .get_text(&url, package, self.registry_name())
+            .await?;
+
+        Ok(metadata_versions(&metadata, self.allow_prereleases))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Timelike};
+
+    /// Recorded (trimmed) metadata for the Spring Boot plugin marker
+    const METADATA: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata>
+  <groupId>org.springframework.boot</groupId>
+  <artifactId>org.springframework.boot.gradle.plugin</artifactId>
+  <versioning>
+    <latest>3.0.1</latest>
+    <release>3.0.1</release>
+    <versions>
+      <version>2.7.6</version>
+      <version>3.0.0</version>
+      <version>3.0.1</version>
+    </versions>
+    <lastUpdated>20221222103015</lastUpdated>
+  </versioning>
+</metadata>
+"#;
+
+    #[test]
+    fn test_gradle_plugin_portal_adapter_registry_name() {
+        let adapter = GradlePluginPortalAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(adapter.language(), Language::Java);
+        assert_eq!(adapter.registry_name(), "Gradle Plugin Portal");
+    }
+
+    #[test]
+    fn test_build_url() {
+        let adapter = GradlePluginPortalAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(
+            adapter.build_url("org.springframework.boot"),
+            "https://plugins.gradle.org/m2/org/springframework/boot/org.springframework.boot.gradle.plugin/maven-metadata.xml"
+        );
+    }
+
+    #[test]
+    fn test_metadata_versions() {
+        let versions = metadata_versions(METADATA, false);
+        let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(names, vec!["2.7.6", "3.0.0", "3.0.1"]);
+
+        let released_at = versions[0].released_at;
+        assert_eq!(
+            (released_at.year(), released_at.month(), released_at.day()),
+            (2022, 12, 22)
+        );
+        assert_eq!(released_at.hour(), 10);
+        assert!(versions.iter().all(|v| v.released_at == released_at));
+    }
+}
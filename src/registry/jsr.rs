@@ -0,0 +1,177 @@
+//! Deno registry adapter (JSR and npm specifiers)
+//!
+//! Routes Deno dependencies by specifier scheme:
+//! - `jsr:@scope/pkg`: https://jsr.io/@scope/pkg/meta.json
+//! - `npm:pkg`: the npm registry adapter
+
+use crate::domain::Language;
+use crate::error::RegistryError;
+use crate::registry::{HttpClient, NpmAdapter, RegistryAdapter};
+use crate::update::VersionInfo;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// JSR registry base URL
+const JSR_API_URL: &str = "https://jsr.io";
+
+/// Deno registry adapter
+pub struct DenoAdapter {
+    client: HttpClient,
+    base_url: String,
+    npm: NpmAdapter,
+}
+
+/// JSR package metadata response (meta.json)
+#[derive(Debug, Deserialize)]
+struct JsrMetaResponse {
+    /// Published versions
+    versions: HashMap<String, JsrVersionInfo>,
+}
+
+/// Version entry from JSR meta.json
+#[derive(Debug, Deserialize)]
+struct JsrVersionInfo {
+    /// Publish timestamp in ISO 8601 format
+    #[serde(rename = "createdAt")]
+    created_at: Option<String>,
+    /// Whether the version has been yanked
+    #[serde(default)]
+    yanked: bool,
+}
+
+impl DenoAdapter {
+    /// Create a new Deno adapter
+    pub fn new(client: HttpClient) -> Self {
+        Self::with_base_url(client, JSR_API_URL)
+    }
+
+    /// Create a new Deno adapter for a custom JSR base URL (e.g., a mirror)
+    ///
+    /// `npm:` packages still use the default npm registry.
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            npm: NpmAdapter::new(client.clone()),
+            client,
+            base_url: base_url.into(),
+        }
+    }
+
+    /// Use a specific npm adapter for `npm:` packages (e.g., one following --npm-tag)
+    pub fn with_npm_adapter(mut self, npm: NpmAdapter) -> Self {
+        self.npm = npm;
+        self
+    }
+
+    /// Build the meta.json URL for a JSR package (`@scope/pkg`)
+    fn build_url(&self, package: &str) -> String {
+        format!("{}/{}/meta.json", self.base_url, package)
+    }
+
+    /// Fetch versions of a JSR package
+    async fn fetch_jsr_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        let url = self.build_url(package);
+        let response: JsrMetaResponse = self
+            .client
+            .get_json(&url, package, self.registry_name())
+            .await?;
+
+        Ok(jsr_versions(response))
+    }
+}
+
+#[async_trait]
+impl RegistryAdapter for DenoAdapter {
+    fn language(&self) -> Language {
+        Language::Deno
+    }
+
+    fn registry_name(&self) -> &'static str {
+        "jsr"
+    }
+
+    async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        match package.split_once(':') {
+            Some(("jsr", name)) => self.fetch_jsr_versions(name).await,
+            Some(("npm", name)) => self.npm.fetch_versions(name).await,
+            _ => Err(RegistryError::InvalidPackageName {
+                name: package.to_string(),
+                registry: self.registry_name().to_string(),
+                reason: "expected 'jsr:@scope/pkg' or 'npm:pkg'".to_string(),
+            }),
+        }
+    }
+}
+
+/// Collect non-yanked versions with a publish date
+fn jsr_versions(response: JsrMetaResponse) -> Vec<VersionInfo> {
+    let mut versions: Vec<VersionInfo> = response
+        .versions
+        .into_iter()
+        .filter(|(_, info)| !info.yanked)
+        .filter_map(|(version, info)| {
+            let released_at = info.created_at?.parse::<DateTime<Utc>>().ok()?;
+            Some(VersionInfo::new(version, released_at))
+        })
+        .collect();
+
+    // Sort by version
+    versions.sort();
+
+    versions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Recorded (trimmed) JSR meta.json for @std/assert
+    const META_RESPONSE: &str = r#"{
+        "scope": "std",
+        "name": "assert",
+        "latest": "1.0.13",
+        "versions": {
+            "1.0.13": {"createdAt": "2025-04-23T07:04:38.613Z"},
+            "1.0.12": {"createdAt": "2025-03-19T11:19:34.115Z"},
+            "1.0.11": {"createdAt": "2025-01-21T04:25:08.201Z", "yanked": true},
+            "0.226.0": {"createdAt": "2024-05-21T04:19:48.339Z"}
+        }
+    }"#;
+
+    #[test]
+    fn test_deno_adapter_language() {
+        let adapter = DenoAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(adapter.language(), Language::Deno);
+        assert_eq!(adapter.registry_name(), "jsr");
+    }
+
+    #[test]
+    fn test_build_url() {
+        let adapter = DenoAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(
+            adapter.build_url("@std/assert"),
+            "https://jsr.io/@std/assert/meta.json"
+        );
+    }
+
+    #[test]
+    fn test_jsr_versions_skips_yanked() {
+        let response: JsrMetaResponse = serde_json::from_str(META_RESPONSE).unwrap();
+        let versions: Vec<String> = jsr_versions(response)
+            .into_iter()
+            .map(|v| v.version)
+            .collect();
+        assert_eq!(versions, vec!["0.226.0", "1.0.12", "1.0.13"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_versions_rejects_unknown_scheme() {
+        let adapter = DenoAdapter::new(HttpClient::new().unwrap());
+        let result = adapter.fetch_versions("https://deno.land/x/oak").await;
+        assert!(matches!(
+            result,
+            Err(RegistryError::InvalidPackageName { .. })
+        ));
+    }
+}
@@ -1,57 +1,68 @@
-//! Maven Central Search API adapter
+//! Maven Central adapter
 //!
-//! Fetches Java package version information from Maven Central.
-//! API endpoint: https://search.maven.org/solrsearch/select
+//! Fetches Java package versions from the artifact's Maven metadata.
+//! API endpoint: https://repo1.maven.org/maven2/{groupId as path}/{artifactId}/maven-metadata.xml
 //!
-//! Query format: q=g:{groupId}+AND+a:{artifactId}&core=gav&rows=100&wt=json
+//! Gradle plugin ids (no `:`, e.g. `org.springframework.boot`) are looked up
+//! in the Gradle Plugin Portal instead, from its maven-metadata.xml.
+//!
+//! Versions are bounded by `<release>` (newest stable release), or by `<latest>`
+//! (newest upload, possibly a snapshot) when prereleases are allowed. Snapshots
+//! and qualified versions (`-M1`, `.CR1`, `-ea`, ...) are skipped unless
+//! prereleases are allowed. The metadata has no per-version publish dates, so
+//! every version is dated by `<lastUpdated>`.
 
 use crate::domain::Language;
 use crate::error::RegistryError;
-use crate::registry::{HttpClient, RegistryAdapter};
+use crate::registry::maven_metadata::metadata_versions;
+use crate::registry::{GradlePluginPortalAdapter, HttpClient, RegistryAdapter};
 use crate::update::VersionInfo;
 use async_trait::async_trait;
-use chrono::{DateTime, TimeZone, Utc};
-use serde::Deserialize;
-
-/// Maven Central Search API base URL
-const MAVEN_CENTRAL_API_URL: &str = "https://search.maven.org/solrsearch/select";
 
-/// Maximum number of versions to fetch
-const MAX_VERSIONS: u32 = 100;
+/// Maven Central repository URL
+const MAVEN_CENTRAL_URL: &str = "https://repo1.maven.org/maven2";
 
 /// Maven Central adapter
 pub struct MavenCentralAdapter {
     client: HttpClient,
-}
-
-/// Maven Central search response
-#[derive(Debug, Deserialize)]
-struct MavenSearchResponse {
-    response: MavenResponseBody,
-}
-
-/// Maven Central response body
-#[derive(Debug, Deserialize)]
-struct MavenResponseBody {
-    docs: Vec<MavenVersionDoc>,
-}
-
-/// Maven Central version document
-#[derive(Debug, Deserialize)]
-struct MavenVersionDoc {
-    /// Version string
-    v: String,
-    /// Timestamp in milliseconds since epoch
-    timestamp: i64,
+    base_url: String,
+    plugins: GradlePluginPortalAdapter,
+    allow_prereleases: bool,
 }
 
 impl MavenCentralAdapter {
     /// Create a new Maven Central adapter
     pub fn new(client: HttpClient) -> Self {
-        Self { client }
+        Self {
+            plugins: GradlePluginPortalAdapter::new(client.clone()),
+            ..Self::with_base_url(client, MAVEN_CENTRAL_URL)
+        }
     }
 
-    /// Build search URL for group:artifact
+    /// Create a new Maven Central adapter for a custom base URL (e.g., a mirror)
+    ///
+    /// Gradle plugins are looked up under the same base URL, so a mirror must
+    /// also proxy the Gradle Plugin Portal.
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into();
+        Self {
+            plugins: GradlePluginPortalAdapter::with_base_url(client.clone(), &base_url),
+            client,
+            base_url,
+            allow_prereleases: false,
+        }
+    }
+
+    /// Also offer snapshots and qualified versions (Gradle plugins included)
+    pub fn with_prereleases(self, allowed: bool) -> Self {
+        Self {
+            plugins: self.plugins.with_prereleases(allowed),
+            allow_prereleases: allowed,
+            ..self
+        }
+    }
+
+    /// Build the maven-metadata.xml URL for group:artifact
     fn build_url(&self, package: &str) -> Result<String, RegistryError> {
         // package format: "group:artifact" (e.g., "org.apache.wicket:wicket-core")
         let parts: Vec<&str> = package.split(':').collect();
@@ -64,15 +75,12 @@ impl MavenCentralAdapter {
         }
         let (group, artifact) = (parts[0], parts[1]);
         Ok(format!(
-            "{}?q=g:{}+AND+a:{}&core=gav&rows={}&wt=json",
-            MAVEN_CENTRAL_API_URL, group, artifact, MAX_VERSIONS
+            "{}/{}/{}/maven-metadata.xml",
+            self.base_url,
+            group.replace('.', "/"),
+            artifact
         ))
     }
-
-    /// Convert timestamp in milliseconds to DateTime<Utc>
-    fn timestamp_to_datetime(timestamp_ms: i64) -> Option<DateTime<Utc>> {
-        Utc.timestamp_millis_opt(timestamp_ms).single()
-    }
 }
 
 #[async_trait]
@@ -86,31 +94,46 @@ impl RegistryAdapter for MavenCentralAdapter {
     }
 
     async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        if !package.contains(':') {
+            return self.plugins.fetch_versions(package).await;
+        }
+
         let url = self.build_url(package)?;
-        let response: MavenSearchResponse = self
+        let metadata = self
             .client
-            .get_json(&url, package, self.registry_name())
+            .get_text(&url, package, self.registry_name())
             .await?;
 
-        let mut versions = Vec::new();
-
-        for doc in response.response.docs {
-            if let Some(released_at) = Self::timestamp_to_datetime(doc.timestamp) {
-                versions.push(VersionInfo::new(&doc.v, released_at));
-            }
-        }
-
-        // Sort by version
-        versions.sort();
-
-        Ok(versions)
+        Ok(metadata_versions(&metadata, self.allow_prereleases))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Datelike;
+    use std::io::{Read, Write};
+
+    /// Serve `body` as XML for every request, reporting each request line
+    fn serve_metadata(body: &'static str) -> (String, std::sync::mpsc::Receiver<String>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/maven2", listener.local_addr().unwrap());
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]).to_string();
+                let _ = tx.send(request.lines().next().unwrap_or_default().to_string());
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/xml\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        (base_url, rx)
+    }
 
     #[test]
     fn test_maven_central_adapter_language() {
@@ -131,10 +154,10 @@ mod tests {
         let client = HttpClient::new().unwrap();
         let adapter = MavenCentralAdapter::new(client);
         let url = adapter.build_url("org.apache.wicket:wicket-core").unwrap();
-        assert!(url.starts_with("https://search.maven.org/solrsearch/select"));
-        assert!(url.contains("q=g:org.apache.wicket+AND+a:wicket-core"));
-        assert!(url.contains("core=gav"));
-        assert!(url.contains("wt=json"));
+        assert_eq!(
+            url,
+            "https://repo1.maven.org/maven2/org/apache/wicket/wicket-core/maven-metadata.xml"
+        );
     }
 
     #[test]
@@ -151,40 +174,46 @@ mod tests {
         assert!(result.is_err());
     }
 
-    #[test]
-    fn test_timestamp_to_datetime() {
-        // 2024-01-15T10:30:00Z = 1705314600000 ms
-        let timestamp_ms = 1705314600000_i64;
-        let dt = MavenCentralAdapter::timestamp_to_datetime(timestamp_ms).unwrap();
-        assert_eq!(dt.year(), 2024);
-        assert_eq!(dt.month(), 1);
-        assert_eq!(dt.day(), 15);
-    }
-
-    #[test]
-    fn test_timestamp_to_datetime_zero() {
-        let dt = MavenCentralAdapter::timestamp_to_datetime(0).unwrap();
-        assert_eq!(dt.year(), 1970);
-        assert_eq!(dt.month(), 1);
-        assert_eq!(dt.day(), 1);
+    #[tokio::test]
+    async fn test_fetch_versions_excludes_snapshots_by_default() {
+        let (base_url, rx) = serve_metadata(
+            "<metadata><versioning><latest>3.1.0-SNAPSHOT</latest><release>3.0.0</release>\
+             <versions><version>3.0.0</version><version>3.1.0-M1</version>\
+             <version>3.1.0-SNAPSHOT</version></versions>\
+             <lastUpdated>20240115103000</lastUpdated></versioning></metadata>",
+        );
+
+        let adapter = MavenCentralAdapter::with_base_url(HttpClient::new().unwrap(), &base_url);
+        let versions = adapter.fetch_versions("org.example:widget").await.unwrap();
+        let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(names, vec!["3.0.0"]);
+        assert_eq!(
+            rx.recv().unwrap(),
+            "GET /maven2/org/example/widget/maven-metadata.xml HTTP/1.1"
+        );
+
+        let adapter = MavenCentralAdapter::with_base_url(HttpClient::new().unwrap(), &base_url)
+            .with_prereleases(true);
+        let versions = adapter.fetch_versions("org.example:widget").await.unwrap();
+        let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(names, vec!["3.0.0", "3.1.0-M1", "3.1.0-SNAPSHOT"]);
     }
 
-    #[test]
-    fn test_deserialize_response() {
-        let json = r#"
-        {
-            "response": {
-                "docs": [
-                    {"v": "9.12.0", "timestamp": 1705314600000},
-                    {"v": "9.11.0", "timestamp": 1702722600000}
-                ]
-            }
-        }
-        "#;
-
-        let response: MavenSearchResponse = serde_json::from_str(json).unwrap();
-        assert_eq!(response.response.docs.len(), 2);
-        assert_eq!(response.response.docs[0].v, "9.12.0");
-        assert_eq!(response.response.docs[0].timestamp, 1705314600000);
+    #[tokio::test]
+    async fn test_mirror_serves_gradle_plugins() {
+        let (base_url, rx) = serve_metadata(
+            "<metadata><versioning><release>1.1.0</release><versions>\
+             <version>1.0.0</version><version>1.1.0</version>\
+             </versions><lastUpdated>20240115103000</lastUpdated></versioning></metadata>",
+        );
+
+        let adapter = MavenCentralAdapter::with_base_url(HttpClient::new().unwrap(), &base_url);
+        let versions = adapter.fetch_versions("org.example.plugin").await.unwrap();
+        let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(names, vec!["1.0.0", "1.1.0"]);
+        assert_eq!(
+            rx.recv().unwrap(),
+            "GET /maven2/org/example/plugin/org.example.plugin.gradle.plugin/maven-metadata.xml HTTP/1.1"
+        );
     }
 }
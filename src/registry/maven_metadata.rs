@@ -0,0 +1,201 @@
+//! maven-metadata.xml reader for Maven repository adapters
+//!
+//! Handles:
+//! - `<versions>` entries
+//! - `<release>` (newest stable release) and `<latest>` (newest upload, which may be a snapshot)
+//! - `<lastUpdated>` timestamps
+//!
+//! By default only stable versions up to `<release>` are candidates: `-SNAPSHOT`
+//! builds and qualified versions (milestones, release candidates, early access)
+//! are left out unless prereleases are allowed, in which case anything up to
+//! `<latest>` is offered.
+
+use crate::update::{compare_versions, is_prerelease_version, VersionInfo};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use regex::Regex;
+use std::sync::LazyLock;
+
+// <version>1.2.3</version> entries
+static VERSION_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<version>\s*([^<\s]+)\s*</version>").unwrap());
+
+// <release>1.2.3</release>
+static RELEASE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<release>\s*([^<\s]+)\s*</release>").unwrap());
+
+// <latest>1.3.0-SNAPSHOT</latest>
+static LATEST_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<latest>\s*([^<\s]+)\s*</latest>").unwrap());
+
+// <lastUpdated>20221124123456</lastUpdated>
+static LAST_UPDATED_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<lastUpdated>\s*(\d{14})\s*</lastUpdated>").unwrap());
+
+// Maven qualifiers not covered by the common prerelease words: milestones (`-M1`),
+// candidate releases (`.CR2`) and early access builds (`-ea`)
+static QUALIFIER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)[.-](?:m|cr|ea)\d*(?:[.-]|$)").unwrap());
+
+/// Versioning information from a maven-metadata.xml document
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MavenMetadata {
+    /// Newest upload of any kind (`<latest>`)
+    pub latest: Option<String>,
+    /// Newest stable release (`<release>`)
+    pub release: Option<String>,
+    /// Every listed version, in document order
+    pub versions: Vec<String>,
+    /// When the metadata was last updated
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
+impl MavenMetadata {
+    /// Parse maven-metadata.xml content
+    pub fn parse(xml: &str) -> Self {
+        let tag = |re: &Regex| re.captures(xml).map(|caps| caps[1].to_string());
+        Self {
+            latest: tag(&LATEST_RE),
+            release: tag(&RELEASE_RE),
+            versions: VERSION_RE
+                .captures_iter(xml)
+                .map(|caps| caps[1].to_string())
+                .collect(),
+            last_updated: LAST_UPDATED_RE
+                .captures(xml)
+                .and_then(|caps| NaiveDateTime::parse_from_str(&caps[1], "%Y%m%d%H%M%S").ok())
+                .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc)),
+        }
+    }
+
+    /// Versions to offer as updates
+    ///
+    /// Without prereleases, snapshots and qualified versions are dropped and
+    /// nothing newer than `<release>` is offered; with them, nothing newer than
+    /// `<latest>` is.
+    pub fn candidate_versions(&self, allow_prereleases: bool) -> Vec<&str> {
+        let bound = if allow_prereleases {
+            self.latest.as_deref()
+        } else {
+            self.release.as_deref()
+        };
+        self.versions
+            .iter()
+            .map(String::as_str)
+            .filter(|version| {
+                (allow_prereleases || !is_maven_prerelease(version))
+                    && bound.is_none_or(|bound| {
+                        compare_versions(version, bound) != std::cmp::Ordering::Greater
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Collect the candidate versions listed in maven-metadata.xml, dated by `<lastUpdated>`
+pub fn metadata_versions(metadata: &str, allow_prereleases: bool) -> Vec<VersionInfo> {
+    let metadata = MavenMetadata::parse(metadata);
+    let released_at = metadata.last_updated.unwrap_or_else(Utc::now);
+
+    let mut versions: Vec<VersionInfo> = metadata
+        .candidate_versions(allow_prereleases)
+        .into_iter()
+        .map(|version| VersionInfo::new(version, released_at))
+        .collect();
+
+    // Sort by version
+    versions.sort();
+
+    versions
+}
+
+/// Returns true for snapshots and qualified (non-final) Maven versions
+pub fn is_maven_prerelease(version: &str) -> bool {
+    is_prerelease_version(version) || QUALIFIER_RE.is_match(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata>
+  <groupId>org.example</groupId>
+  <artifactId>widget</artifactId>
+  <versioning>
+    <latest>2.1.0-SNAPSHOT</latest>
+    <release>2.0.1</release>
+    <versions>
+      <version>1.9.0</version>
+      <version>2.0.0-M1</version>
+      <version>2.0.0.CR1</version>
+      <version>2.0.0</version>
+      <version>2.0.1</version>
+      <version>2.1.0-SNAPSHOT</version>
+    </versions>
+    <lastUpdated>20240115103000</lastUpdated>
+  </versioning>
+</metadata>
+"#;
+
+    #[test]
+    fn test_parse_metadata() {
+        let metadata = MavenMetadata::parse(METADATA);
+        assert_eq!(metadata.latest.as_deref(), Some("2.1.0-SNAPSHOT"));
+        assert_eq!(metadata.release.as_deref(), Some("2.0.1"));
+        assert_eq!(metadata.versions.len(), 6);
+        assert_eq!(
+            metadata.last_updated,
+            Some("2024-01-15T10:30:00Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_candidate_versions_exclude_snapshots_by_default() {
+        let metadata = MavenMetadata::parse(METADATA);
+        assert_eq!(
+            metadata.candidate_versions(false),
+            vec!["1.9.0", "2.0.0", "2.0.1"]
+        );
+        assert_eq!(metadata.candidate_versions(true).len(), 6);
+    }
+
+    #[test]
+    fn test_candidate_versions_bounded_by_latest_with_prereleases() {
+        let metadata = MavenMetadata::parse(
+            "<latest>2.0.0</latest><release>1.0.0</release>\
+             <versions><version>1.0.0</version><version>2.0.0-M1</version>\
+             <version>2.0.0</version><version>3.0.0-SNAPSHOT</version></versions>",
+        );
+        assert_eq!(metadata.candidate_versions(false), vec!["1.0.0"]);
+        assert_eq!(
+            metadata.candidate_versions(true),
+            vec!["1.0.0", "2.0.0-M1", "2.0.0"]
+        );
+    }
+
+    #[test]
+    fn test_metadata_versions() {
+        let versions = metadata_versions(METADATA, false);
+        let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(names, vec!["1.9.0", "2.0.0", "2.0.1"]);
+        assert!(versions
+            .iter()
+            .all(|v| v.released_at == "2024-01-15T10:30:00Z".parse::<DateTime<Utc>>().unwrap()));
+    }
+
+    #[test]
+    fn test_is_maven_prerelease() {
+        for version in [
+            "1.0.0-SNAPSHOT",
+            "3.0.0-M2",
+            "5.0.0.CR1",
+            "21-ea",
+            "1.0-RC1",
+        ] {
+            assert!(is_maven_prerelease(version), "{}", version);
+        }
+        for version in ["1.2.3", "5.3.0.RELEASE", "6.4.0.Final", "32.1.3-jre"] {
+            assert!(!is_maven_prerelease(version), "{}", version);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Mirror fallback adapter
+//!
+//! Wraps an ordered list of registry adapters (primary first, then mirrors)
+//! and tries each in turn until one returns versions. The index of the
+//! adapter that served each package is recorded for reporting.
+
+use crate::domain::Language;
+use crate::error::RegistryError;
+use crate::registry::RegistryAdapter;
+use crate::update::VersionInfo;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Registry adapter that falls back to mirrors when the primary fails
+pub struct MirroredAdapter {
+    /// Adapters in priority order (primary first)
+    adapters: Vec<Box<dyn RegistryAdapter>>,
+    /// Index of the adapter that served each package
+    served_by: Mutex<HashMap<String, usize>>,
+}
+
+impl MirroredAdapter {
+    /// Create a new mirrored adapter
+    ///
+    /// # Panics
+    ///
+    /// Panics if `adapters` is empty.
+    pub fn new(adapters: Vec<Box<dyn RegistryAdapter>>) -> Self {
+        assert!(
+            !adapters.is_empty(),
+            "MirroredAdapter requires at least one adapter"
+        );
+        Self {
+            adapters,
+            served_by: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl RegistryAdapter for MirroredAdapter {
+    fn language(&self) -> Language {
+        self.adapters[0].language()
+    }
+
+    fn registry_name(&self) -> &'static str {
+        self.adapters[0].registry_name()
+    }
+
+    async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        let mut last_error = None;
+
+        for (index, adapter) in self.adapters.iter().enumerate() {
+            match adapter.fetch_versions(package).await {
+                Ok(versions) => {
+                    self.served_by
+                        .lock()
+                        .unwrap()
+                        .insert(package.to_string(), index);
+                    return Ok(versions);
+                }
+                // A missing package is authoritative; mirrors won't have it either
+                Err(e @ RegistryError::PackageNotFound { .. }) => return Err(e),
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        Err(last_error.expect("at least one adapter was tried"))
+    }
+
+    fn served_by(&self, package: &str) -> Option<usize> {
+        self.served_by.lock().unwrap().get(package).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Adapter returning a fixed result and counting calls
+    struct StubAdapter {
+        result: Option<&'static str>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl RegistryAdapter for StubAdapter {
+        fn language(&self) -> Language {
+            Language::Node
+        }
+
+        fn registry_name(&self) -> &'static str {
+            "npm"
+        }
+
+        async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            match self.result {
+                Some(version) => Ok(vec![VersionInfo::now(version)]),
+                None => Err(RegistryError::NetworkError {
+                    package: package.to_string(),
+                    registry: "npm".to_string(),
+                    message: "connection reset".to_string(),
+                }),
+            }
+        }
+    }
+
+    fn stub(result: Option<&'static str>) -> (Box<dyn RegistryAdapter>, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let adapter = StubAdapter {
+            result,
+            calls: calls.clone(),
+        };
+        (Box::new(adapter), calls)
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_mirror() {
+        let (primary, primary_calls) = stub(None);
+        let (mirror, mirror_calls) = stub(Some("2.0.0"));
+        let adapter = MirroredAdapter::new(vec![primary, mirror]);
+
+        let versions = adapter.fetch_versions("lodash").await.unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].version, "2.0.0");
+        assert_eq!(adapter.served_by("lodash"), Some(1));
+        assert_eq!(primary_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(mirror_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_primary_success_skips_mirrors() {
+        let (primary, _) = stub(Some("1.0.0"));
+        let (mirror, mirror_calls) = stub(Some("2.0.0"));
+        let adapter = MirroredAdapter::new(vec![primary, mirror]);
+
+        let versions = adapter.fetch_versions("lodash").await.unwrap();
+        assert_eq!(versions[0].version, "1.0.0");
+        assert_eq!(adapter.served_by("lodash"), Some(0));
+        assert_eq!(mirror_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_all_adapters_fail() {
+        let (primary, _) = stub(None);
+        let (mirror, _) = stub(None);
+        let adapter = MirroredAdapter::new(vec![primary, mirror]);
+
+        let result = adapter.fetch_versions("lodash").await;
+        assert!(matches!(result, Err(RegistryError::NetworkError { .. })));
+        assert_eq!(adapter.served_by("lodash"), None);
+    }
+
+    #[test]
+    fn test_language_and_name_from_primary() {
+        let (primary, _) = stub(None);
+        let adapter = MirroredAdapter::new(vec![primary]);
+        assert_eq!(adapter.language(), Language::Node);
+        assert_eq!(adapter.registry_name(), "npm");
+    }
+}
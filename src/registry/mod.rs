@@ -2,34 +2,79 @@
 //!
 //! This module provides:
 //! - HTTP client shared foundation with retry logic
+//! - netrc credentials for registry basic auth
 //! - npm Registry adapter
 //! - PyPI JSON API adapter
 //! - crates.io API adapter
 //! - Go Module Proxy adapter
 //! - Maven Central adapter
+//! - Gradle Plugin Portal adapter (Gradle plugins)
+//! - Deno adapter (JSR and npm specifiers)
+//! - Nimble adapter (packages index and GitHub tags)
+//! - Docker Hub adapter (base image tags)
+//! - CRAN adapter (R packages)
+//! - Shards adapter (Crystal packages via GitHub tags)
+//! - pre-commit adapter (hook repositories via GitHub/GitLab tags)
+//! - Mirror fallback chain wrapper
+//! - `${ENV_VAR}` expansion in registry base URLs
+//! - GitHub Releases client for release notes
+//! - OSV vulnerability database client
 
 mod client;
+mod cran;
 mod crates_io;
+mod docker_hub;
+mod github;
 mod go_proxy;
+mod gradle_plugin_portal;
+mod jsr;
 mod maven_central;
+mod maven_metadata;
+mod mirrored;
+mod netrc;
+mod nimble;
 mod npm;
+mod osv;
 mod packagist;
+mod pre_commit;
 mod pypi;
 mod rubygems;
+mod runtimes;
+mod shards;
+mod yarnrc;
 
 pub use client::HttpClient;
+pub use cran::CranAdapter;
 pub use crates_io::CratesIoAdapter;
+pub use docker_hub::DockerHubAdapter;
+pub use github::{
+    github_repository, notes_between, GitHubClient, GitHubRelease, ReleaseNotesSource,
+};
 pub use go_proxy::GoProxyAdapter;
+pub use gradle_plugin_portal::GradlePluginPortalAdapter;
+pub use jsr::DenoAdapter;
 pub use maven_central::MavenCentralAdapter;
-pub use npm::NpmAdapter;
+pub use mirrored::MirroredAdapter;
+pub use netrc::{Netrc, NetrcCredentials};
+pub use nimble::NimbleAdapter;
+pub use npm::{NpmAdapter, DEFAULT_DIST_TAG, JSR_SCOPE};
+pub use osv::{minimum_fix_version, Advisory, AdvisorySource, OsvClient, OsvResponse};
 pub use packagist::PackagistAdapter;
+pub use pre_commit::PreCommitAdapter;
 pub use pypi::PyPIAdapter;
 pub use rubygems::RubyGemsAdapter;
+pub use runtimes::{
+    latest_go_release, latest_node_release, GoRelease, NodeRelease, RuntimeCheck,
+    RuntimeReleaseClient,
+};
+pub use shards::ShardsAdapter;
+pub use yarnrc::{YarnRc, YARNRC_FILENAME};
 
 use crate::domain::Language;
 use crate::error::RegistryError;
 use crate::update::VersionInfo;
 use async_trait::async_trait;
+use std::sync::Arc;
 
 /// Trait for registry adapters
 #[async_trait]
@@ -42,6 +87,34 @@ pub trait RegistryAdapter: Send + Sync {
 
     /// Fetch available versions for a package
     async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError>;
+
+    /// Index of the registry that served the last successful fetch of a package
+    ///
+    /// `0` is the primary registry; higher indices are --registry-mirror fallbacks
+    /// in configuration order. Adapters without mirrors return `None`.
+    fn served_by(&self, _package: &str) -> Option<usize> {
+        None
+    }
+}
+
+/// Shared adapters (e.g., supplied through the library API) delegate to the inner adapter
+#[async_trait]
+impl<T: RegistryAdapter + ?Sized> RegistryAdapter for Arc<T> {
+    fn language(&self) -> Language {
+        (**self).language()
+    }
+
+    fn registry_name(&self) -> &'static str {
+        (**self).registry_name()
+    }
+
+    async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        (**self).fetch_versions(package).await
+    }
+
+    fn served_by(&self, package: &str) -> Option<usize> {
+        (**self).served_by(package)
+    }
 }
 
 /// Create a registry adapter for the given language
@@ -54,5 +127,131 @@ pub fn create_adapter(language: Language, client: HttpClient) -> Box<dyn Registr
         Language::Ruby => Box::new(RubyGemsAdapter::new(client)),
         Language::Php => Box::new(PackagistAdapter::new(client)),
         Language::Java => Box::new(MavenCentralAdapter::new(client)),
+        Language::Deno => Box::new(DenoAdapter::new(client)),
+        Language::Nim => Box::new(NimbleAdapter::new(client)),
+        Language::Docker => Box::new(DockerHubAdapter::new(client)),
+        Language::R => Box::new(CranAdapter::new(client)),
+        Language::Crystal => Box::new(ShardsAdapter::new(client)),
+        Language::PreCommit => Box::new(PreCommitAdapter::new(client)),
+    }
+}
+
+/// Default registry host for a language, used to key per-host concurrency limits
+pub fn registry_host(language: Language) -> &'static str {
+    match language {
+        Language::Node => "registry.npmjs.org",
+        Language::Python => "pypi.org",
+        Language::Rust => "crates.io",
+        Language::Go => "proxy.golang.org",
+        Language::Ruby => "rubygems.org",
+        Language::Php => "repo.packagist.org",
+        Language::Java => "repo1.maven.org",
+        Language::Deno => "jsr.io",
+        Language::Nim => "api.github.com",
+        Language::Docker => "hub.docker.com",
+        Language::R => "crandb.r-pkg.org",
+        Language::Crystal => "api.github.com",
+        Language::PreCommit => "api.github.com",
+    }
+}
+
+/// Create a registry adapter for the given language using a custom base URL
+pub fn create_adapter_with_base_url(
+    language: Language,
+    client: HttpClient,
+    base_url: &str,
+) -> Box<dyn RegistryAdapter> {
+    match language {
+        Language::Node => Box::new(NpmAdapter::with_base_url(client, base_url)),
+        Language::Python => Box::new(PyPIAdapter::with_base_url(client, base_url)),
+        Language::Rust => Box::new(CratesIoAdapter::with_base_url(client, base_url)),
+        Language::Go => Box::new(GoProxyAdapter::with_base_url(client, base_url)),
+        Language::Ruby => Box::new(RubyGemsAdapter::with_base_url(client, base_url)),
+        Language::Php => Box::new(PackagistAdapter::with_base_url(client, base_url)),
+        Language::Java => Box::new(MavenCentralAdapter::with_base_url(client, base_url)),
+        Language::Deno => Box::new(DenoAdapter::with_base_url(client, base_url)),
+        Language::Nim => Box::new(NimbleAdapter::with_base_url(client, base_url)),
+        Language::Docker => Box::new(DockerHubAdapter::with_base_url(client, base_url)),
+        Language::R => Box::new(CranAdapter::with_base_url(client, base_url)),
+        Language::Crystal => Box::new(ShardsAdapter::with_base_url(client, base_url)),
+        Language::PreCommit => Box::new(PreCommitAdapter::with_base_url(client, base_url)),
+    }
+}
+
+/// Expand `${ENV_VAR}` placeholders in a registry base URL
+///
+/// Fails with a message naming the variable if it isn't set, or if a
+/// placeholder is left unterminated.
+pub fn expand_registry_url(url: &str) -> Result<String, String> {
+    expand_registry_url_with(url, |name| std::env::var(name).ok())
+}
+
+fn expand_registry_url_with(
+    url: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut expanded = String::with_capacity(url.len());
+    let mut rest = url;
+
+    while let Some(start) = rest.find("${") {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find('}').ok_or_else(|| {
+            format!(
+                "unterminated ${{...}} placeholder in registry URL '{}'",
+                url
+            )
+        })?;
+
+        let name = &after[..end];
+        let value = lookup(name).ok_or_else(|| {
+            format!(
+                "environment variable {} referenced in registry URL '{}' is not set",
+                name, url
+            )
+        })?;
+        expanded.push_str(&value);
+        rest = &after[end + 1..];
+    }
+    expanded.push_str(rest);
+
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        (name == "NPM_REGION").then(|| "eu-west-1".to_string())
+    }
+
+    #[test]
+    fn test_expand_registry_url() {
+        assert_eq!(
+            expand_registry_url_with("https://npm.${NPM_REGION}.example.com/api", lookup).unwrap(),
+            "https://npm.eu-west-1.example.com/api"
+        );
+        assert_eq!(
+            expand_registry_url_with("https://registry.npmjs.org", lookup).unwrap(),
+            "https://registry.npmjs.org"
+        );
+    }
+
+    #[test]
+    fn test_expand_registry_url_unset_variable() {
+        let err =
+            expand_registry_url_with("https://${MISSING_TOKEN}.example.com", lookup).unwrap_err();
+        assert_eq!(
+            err,
+            "environment variable MISSING_TOKEN referenced in registry URL \
+             'https://${MISSING_TOKEN}.example.com' is not set"
+        );
+
+        assert!(
+            expand_registry_url_with("https://${NPM_REGION.example.com", lookup)
+                .unwrap_err()
+                .contains("unterminated")
+        );
     }
 }
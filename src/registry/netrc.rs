@@ -0,0 +1,185 @@
+//! Minimal `.netrc` reader for registry credentials
+//!
+//! Handles:
+//! - `machine <host> login <user> password <secret>` entries
+//! - A `default` entry used for hosts without their own machine entry
+//! - `account` values and `macdef` macro definitions (ignored)
+//!
+//! The file is read from `$NETRC`, falling back to `~/.netrc`.
+
+use std::path::PathBuf;
+
+/// Environment variable overriding the netrc location
+const NETRC_ENV: &str = "NETRC";
+
+/// Basic-auth credentials from a netrc entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetrcCredentials {
+    /// User name (`login`)
+    pub login: String,
+    /// Password or token (`password`)
+    pub password: String,
+}
+
+/// Parsed netrc file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Netrc {
+    /// Credentials per machine, in file order
+    machines: Vec<(String, NetrcCredentials)>,
+    /// Credentials of the `default` entry
+    default: Option<NetrcCredentials>,
+}
+
+impl Netrc {
+    /// Parse netrc content
+    ///
+    /// Entries without a login are ignored; a missing password is read as empty.
+    pub fn parse(content: &str) -> Self {
+        let mut netrc = Self::default();
+        // (machine, login, password); machine is None for `default`
+        let mut current: Option<(Option<String>, Option<String>, Option<String>)> = None;
+        let mut lines = content.lines();
+
+        while let Some(line) = lines.next() {
+            let mut tokens = line.split_whitespace();
+            while let Some(token) = tokens.next() {
+                match token {
+                    "machine" | "default" => {
+                        netrc.push(current.take());
+                        let machine = if token == "machine" {
+                            tokens.next().map(|m| m.to_ascii_lowercase())
+                        } else {
+                            None
+                        };
+                        current = Some((machine, None, None));
+                    }
+                    "login" => {
+                        if let Some(entry) = current.as_mut() {
+                            entry.1 = tokens.next().map(str::to_string);
+                        }
+                    }
+                    "password" => {
+                        if let Some(entry) = current.as_mut() {
+                            entry.2 = tokens.next().map(str::to_string);
+                        }
+                    }
+                    "account" => {
+                        tokens.next();
+                    }
+                    "macdef" => {
+                        // A macro runs until the next blank line
+                        netrc.push(current.take());
+                        for line in lines.by_ref() {
+                            if line.trim().is_empty() {
+                                break;
+                            }
+                        }
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        netrc.push(current);
+
+        netrc
+    }
+
+    /// Record a finished entry
+    fn push(&mut self, entry: Option<(Option<String>, Option<String>, Option<String>)>) {
+        let Some((machine, Some(login), password)) = entry else {
+            return;
+        };
+        let credentials = NetrcCredentials {
+            login,
+            password: password.unwrap_or_default(),
+        };
+        match machine {
+            Some(machine) => self.machines.push((machine, credentials)),
+            None => self.default = Some(credentials),
+        }
+    }
+
+    /// Location of the netrc file: `$NETRC`, or `.netrc` in the home directory
+    fn path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os(NETRC_ENV).filter(|p| !p.is_empty()) {
+            return Some(PathBuf::from(path));
+        }
+        std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(|home| PathBuf::from(home).join(".netrc"))
+    }
+
+    /// Read the user's netrc file, if there is one
+    pub fn load() -> Option<Self> {
+        let content = std::fs::read_to_string(Self::path()?).ok()?;
+        Some(Self::parse(&content))
+    }
+
+    /// Credentials for a host, falling back to the `default` entry
+    pub fn credentials_for(&self, host: &str) -> Option<&NetrcCredentials> {
+        self.machines
+            .iter()
+            .find(|(machine, _)| machine.eq_ignore_ascii_case(host))
+            .map(|(_, credentials)| credentials)
+            .or(self.default.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+machine pypi.example.com
+  login deploy
+  password s3cret
+
+# Artifactory, one line
+machine artifactory.example.com login ci password token-123 account team
+
+macdef init
+cd /pub
+bin
+
+default login anonymous password guest
+";
+
+    #[test]
+    fn test_parse_netrc() {
+        let netrc = Netrc::parse(SAMPLE);
+        assert_eq!(
+            netrc.credentials_for("pypi.example.com"),
+            Some(&NetrcCredentials {
+                login: "deploy".to_string(),
+                password: "s3cret".to_string(),
+            })
+        );
+        assert_eq!(
+            netrc
+                .credentials_for("Artifactory.Example.com")
+                .map(|c| c.password.as_str()),
+            Some("token-123")
+        );
+    }
+
+    #[test]
+    fn test_credentials_fall_back_to_default() {
+        let netrc = Netrc::parse(SAMPLE);
+        assert_eq!(
+            netrc
+                .credentials_for("registry.npmjs.org")
+                .map(|c| c.login.as_str()),
+            Some("anonymous")
+        );
+
+        let netrc = Netrc::parse("machine pypi.example.com login deploy password s3cret\n");
+        assert_eq!(netrc.credentials_for("registry.npmjs.org"), None);
+    }
+
+    #[test]
+    fn test_parse_skips_entries_without_login() {
+        let netrc = Netrc::parse("machine a.example.com password orphan\n");
+        assert_eq!(netrc, Netrc::default());
+    }
+}
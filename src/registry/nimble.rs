@@ -0,0 +1,231 @@
+//! Nimble adapter for Nim packages
+//!
+//! Nimble has no version registry: the packages index maps names to source
+//! repositories, and releases are the repository's git tags. API endpoints:
+//! - Packages index: https://raw.githubusercontent.com/nim-lang/packages/master/packages.json
+//! - List tags: https://api.github.com/repos/{owner}/{repo}/tags
+//! - Tag commit (release time): https://api.github.com/repos/{owner}/{repo}/commits/{sha}
+//!
+//! Only GitHub-hosted packages can be resolved. Requests to the GitHub API are
+//! authenticated with `GITHUB_TOKEN` when it is set; the token is never sent to
+//! the packages index or a mirror of it.
+
+use crate::domain::Language;
+use crate::error::RegistryError;
+use crate::registry::github::{fetch_tag_versions, GITHUB_API_URL, GITHUB_TOKEN_ENV};
+use crate::registry::{HttpClient, RegistryAdapter};
+use crate::update::VersionInfo;
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+
+/// Base URL of the official Nimble packages index
+const NIMBLE_PACKAGES_URL: &str = "https://raw.githubusercontent.com/nim-lang/packages/master";
+
+/// Nimble adapter
+pub struct NimbleAdapter {
+    /// Client for the packages index
+    client: HttpClient,
+    /// Client for the GitHub API, carrying `GITHUB_TOKEN` when set
+    github_client: HttpClient,
+    /// Base URL of the packages index (`packages.json` lives below it)
+    base_url: String,
+    /// GitHub REST API base URL for tags and commits
+    github_url: String,
+    /// Packages index, fetched once per run
+    index: OnceCell<Vec<NimblePackage>>,
+}
+
+/// Entry in the packages index
+#[derive(Debug, Clone, Deserialize)]
+struct NimblePackage {
+    name: String,
+    /// Source repository URL
+    #[serde(default)]
+    url: Option<String>,
+    /// Name of the package this entry was renamed to
+    #[serde(default)]
+    alias: Option<String>,
+}
+
+impl NimbleAdapter {
+    /// Create a new Nimble adapter, authenticating GitHub API requests with `GITHUB_TOKEN` if set
+    pub fn new(client: HttpClient) -> Self {
+        let github_client = match std::env::var(GITHUB_TOKEN_ENV) {
+            Ok(token) if !token.is_empty() => client.clone().with_bearer_token(token),
+            _ => client.clone(),
+        };
+        Self {
+            github_client,
+            ..Self::with_base_url(client, NIMBLE_PACKAGES_URL)
+        }
+    }
+
+    /// Create a new Nimble adapter for a custom packages index base URL (e.g., a mirror)
+    ///
+    /// No credentials are attached.
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            github_client: client.clone(),
+            client,
+            base_url: base_url.into(),
+            github_url: GITHUB_API_URL.to_string(),
+            index: OnceCell::new(),
+        }
+    }
+
+    /// Use a custom GitHub API base URL for tags and commits
+    pub fn with_github_url(mut self, github_url: impl Into<String>) -> Self {
+        self.github_url = github_url.into();
+        self
+    }
+
+    /// Build the URL of the packages index
+    fn build_index_url(&self) -> String {
+        format!("{}/packages.json", self.base_url)
+    }
+
+    /// Fetch the packages index, once
+    async fn index(&self, package: &str) -> Result<&[NimblePackage], RegistryError> {
+        let index = self
+            .index
+            .get_or_try_init(|| async {
+                self.client
+                    .get_json::<Vec<NimblePackage>>(
+                        &self.build_index_url(),
+                        package,
+                        self.registry_name(),
+                    )
+                    .await
+            })
+            .await?;
+        Ok(index)
+    }
+
+    /// Resolve a package name (or repository URL) to its GitHub `(owner, repo)`
+    async fn resolve_repository(&self, package: &str) -> Result<(String, String), RegistryError> {
+        let url = if package.contains("://") {
+            package.to_string()
+        } else {
+            let index = self.index(package).await?;
+            find_package_url(index, package).ok_or_else(|| RegistryError::PackageNotFound {
+                package: package.to_string(),
+                registry: self.registry_name().to_string(),
+            })?
+        };
+
+        github_owner_repo(&url).ok_or_else(|| RegistryError::InvalidPackageName {
+            name: package.to_string(),
+            registry: self.registry_name().to_string(),
+            reason: format!("repository '{}' is not hosted on GitHub", url),
+        })
+    }
+}
+
+/// Look up a package's repository URL in the index, following renames
+///
+/// Nim identifiers are case-insensitive, so names are compared ignoring case.
+fn find_package_url(index: &[NimblePackage], name: &str) -> Option<String> {
+    let mut name = name.to_string();
+    // Bounded so a rename cycle in the index can't loop forever
+    for _ in 0..=index.len().min(8) {
+        let entry = index.iter().find(|p| p.name.eq_ignore_ascii_case(&name))?;
+        match (&entry.alias, &entry.url) {
+            (Some(alias), _) => name = alias.clone(),
+            (None, Some(url)) => return Some(url.clone()),
+            (None, None) => return None,
+        }
+    }
+    None
+}
+
+/// Extract `(owner, repo)` from a GitHub repository URL
+fn github_owner_repo(url: &str) -> Option<(String, String)> {
+    let path = url
+        .split_once("github.com/")
+        .or_else(|| url.split_once("github.com:"))?
+        .1;
+    let mut parts = path.trim_end_matches('/').split('/');
+    let owner = parts.next().filter(|s| !s.is_empty())?;
+    let repo = parts.next().filter(|s| !s.is_empty())?;
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+    Some((owner.to_string(), repo.to_string()))
+}
+
+#[async_trait]
+impl RegistryAdapter for NimbleAdapter {
+    fn language(&self) -> Language {
+        Language::Nim
+    }
+
+    fn registry_name(&self) -> &'static str {
+        "Nimble"
+    }
+
+    async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        let (owner, repo) = self.resolve_repository(package).await?;
+        fetch_tag_versions(
+            &self.github_client,
+            &self.github_url,
+            &owner,
+            &repo,
+            package,
+            self.registry_name(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> Vec<NimblePackage> {
+        serde_json::from_str(
+            r#"[
+                {"name": "jester", "url": "https://github.com/dom96/jester/", "method": "git"},
+                {"name": "oldname", "alias": "jester"},
+                {"name": "fossil", "url": "https://example.org/fossil.git", "method": "git"}
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_nimble_adapter_language() {
+        let adapter = NimbleAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(adapter.language(), Language::Nim);
+        assert_eq!(adapter.registry_name(), "Nimble");
+        assert_eq!(
+            adapter.build_index_url(),
+            "https://raw.githubusercontent.com/nim-lang/packages/master/packages.json"
+        );
+    }
+
+    #[test]
+    fn test_find_package_url() {
+        let index = index();
+        assert_eq!(
+            find_package_url(&index, "Jester").as_deref(),
+            Some("https://github.com/dom96/jester/")
+        );
+        assert_eq!(
+            find_package_url(&index, "oldname").as_deref(),
+            Some("https://github.com/dom96/jester/")
+        );
+        assert_eq!(find_package_url(&index, "missing"), None);
+    }
+
+    #[test]
+    fn test_github_owner_repo() {
+        assert_eq!(
+            github_owner_repo("https://github.com/dom96/jester/"),
+            Some(("dom96".to_string(), "jester".to_string()))
+        );
+        assert_eq!(
+            github_owner_repo("git@github.com:status-im/nim-stew.git"),
+            Some(("status-im".to_string(), "nim-stew".to_string()))
+        );
+        assert_eq!(github_owner_repo("https://example.org/fossil.git"), None);
+    }
+}
@@ -2,6 +2,11 @@
 //!
 //! Fetches package version information from the npm registry.
 //! API endpoint: https://registry.npmjs.org/{package}
+//!
+//! Candidate versions are capped at a dist-tag (`latest` by default, or the
+//! tag given with `--npm-tag`). Scoped packages can be served by their own
+//! registry (e.g., from `.yarnrc.yml` `npmScopes`). JSR packages are read
+//! from JSR's npm compatibility registry under the `@jsr` scope.
 
 use crate::domain::Language;
 use crate::error::RegistryError;
@@ -15,9 +20,22 @@ use std::collections::HashMap;
 /// npm registry base URL
 const NPM_REGISTRY_URL: &str = "https://registry.npmjs.org";
 
+/// JSR's npm compatibility registry, serving `@jsr/*` packages
+const JSR_NPM_REGISTRY_URL: &str = "https://npm.jsr.io";
+
+/// Scope of JSR packages in the npm compatibility registry
+pub const JSR_SCOPE: &str = "jsr";
+
+/// Dist-tag used when none is configured
+pub const DEFAULT_DIST_TAG: &str = "latest";
+
 /// npm Registry adapter
 pub struct NpmAdapter {
     client: HttpClient,
+    base_url: String,
+    dist_tag: String,
+    /// Registry base URL per scope name, without the `@`
+    scope_registries: HashMap<String, String>,
 }
 
 /// npm package metadata response
@@ -35,12 +53,57 @@ struct NpmPackageResponse {
 impl NpmAdapter {
     /// Create a new npm adapter
     pub fn new(client: HttpClient) -> Self {
-        Self { client }
+        Self::with_base_url(client, NPM_REGISTRY_URL)
+    }
+
+    /// Create a new npm adapter for a custom base URL (e.g., a mirror)
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+            dist_tag: DEFAULT_DIST_TAG.to_string(),
+            scope_registries: HashMap::from([(
+                JSR_SCOPE.to_string(),
+                JSR_NPM_REGISTRY_URL.to_string(),
+            )]),
+        }
+    }
+
+    /// Look up `@scope/*` packages in the registry at `base_url`
+    pub fn with_scope_registry(
+        mut self,
+        scope: impl Into<String>,
+        base_url: impl Into<String>,
+    ) -> Self {
+        self.scope_registries.insert(scope.into(), base_url.into());
+        self
+    }
+
+    /// Registry base URL serving a package
+    fn base_url_for(&self, package: &str) -> &str {
+        package
+            .strip_prefix('@')
+            .and_then(|scoped| scoped.split_once('/'))
+            .and_then(|(scope, _)| self.scope_registries.get(scope))
+            .unwrap_or(&self.base_url)
+    }
+
+    /// Only consider versions up to the one this dist-tag points at (e.g., "next")
+    pub fn with_dist_tag(mut self, tag: impl Into<String>) -> Self {
+        self.dist_tag = tag.into();
+        self
     }
 
     /// Build the URL for a package
+    ///
+    /// Scoped packages keep the `@` but encode the slash (`@types%2Fnode`), which is
+    /// the form the registry and most mirrors expect for the packument endpoint.
     fn build_url(&self, package: &str) -> String {
-        format!("{}/{}", NPM_REGISTRY_URL, package)
+        format!(
+            "{}/{}",
+            self.base_url_for(package),
+            package.replace('/', "%2F")
+        )
     }
 }
 
@@ -61,37 +124,46 @@ impl RegistryAdapter for NpmAdapter {
             .get_json(&url, package, self.registry_name())
             .await?;
 
-        // Get the official "latest" version from dist-tags
-        // This is the version npm considers stable
-        let latest_version = response.dist_tags.get("latest");
-
-        let mut versions = Vec::new();
-
-        for (version, _) in response.versions {
-            // Skip versions newer than dist-tags.latest
-            // This handles cases where npm has published pre-release versions
-            // (e.g., 7.3.0-integration-...) with version numbers higher than
-            // the current stable release (e.g., 7.2.0)
-            if let Some(latest) = latest_version {
-                if compare_versions(&version, latest) == std::cmp::Ordering::Greater {
-                    // This version is newer than the official latest - skip it
-                    continue;
-                }
+        Ok(versions_for_tag(response, &self.dist_tag))
+    }
+}
+
+/// Collect versions up to the version the given dist-tag points at
+///
+/// Falls back to `latest` when the package doesn't publish the requested tag.
+fn versions_for_tag(response: NpmPackageResponse, tag: &str) -> Vec<VersionInfo> {
+    // The tagged version is the newest one npm advertises for that channel
+    let ceiling = response
+        .dist_tags
+        .get(tag)
+        .or_else(|| response.dist_tags.get(DEFAULT_DIST_TAG));
+
+    let mut versions = Vec::new();
+
+    for (version, _) in response.versions {
+        // Skip versions newer than the tagged version
+        // This handles cases where npm has published pre-release versions
+        // (e.g., 7.3.0-integration-...) with version numbers higher than
+        // the current stable release (e.g., 7.2.0)
+        if let Some(ceiling) = ceiling {
+            if compare_versions(&version, ceiling) == std::cmp::Ordering::Greater {
+                // This version is newer than the tagged version - skip it
+                continue;
             }
+        }
 
-            // Get the publish time for this version
-            if let Some(time_str) = response.time.get(&version) {
-                if let Ok(released_at) = time_str.parse::<DateTime<Utc>>() {
-                    versions.push(VersionInfo::new(&version, released_at));
-                }
+        // Get the publish time for this version
+        if let Some(time_str) = response.time.get(&version) {
+            if let Ok(released_at) = time_str.parse::<DateTime<Utc>>() {
+                versions.push(VersionInfo::new(&version, released_at));
             }
         }
+    }
 
-        // Sort by version
-        versions.sort();
+    // Sort by version
+    versions.sort();
 
-        Ok(versions)
-    }
+    versions
 }
 
 #[cfg(test)]
@@ -122,16 +194,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_url_with_base_url() {
+        let client = HttpClient::new().unwrap();
+        let adapter = NpmAdapter::with_base_url(client, "https://registry.npmmirror.com");
+        assert_eq!(
+            adapter.build_url("lodash"),
+            "https://registry.npmmirror.com/lodash"
+        );
+    }
+
     #[test]
     fn test_build_url_scoped_package() {
         let client = HttpClient::new().unwrap();
         let adapter = NpmAdapter::new(client);
         assert_eq!(
             adapter.build_url("@types/node"),
-            "https://registry.npmjs.org/@types/node"
+            "https://registry.npmjs.org/@types%2Fnode"
+        );
+        assert_eq!(
+            adapter.build_url("@angular/core"),
+            "https://registry.npmjs.org/@angular%2Fcore"
         );
     }
 
+    #[test]
+    fn test_build_url_scope_registry() {
+        let client = HttpClient::new().unwrap();
+        let adapter =
+            NpmAdapter::new(client).with_scope_registry("mycompany", "https://npm.mycompany.com");
+        assert_eq!(
+            adapter.build_url("@mycompany/ui"),
+            "https://npm.mycompany.com/@mycompany%2Fui"
+        );
+        assert_eq!(
+            adapter.build_url("@types/node"),
+            "https://registry.npmjs.org/@types%2Fnode"
+        );
+        assert_eq!(
+            adapter.build_url("mycompany"),
+            "https://registry.npmjs.org/mycompany"
+        );
+        assert_eq!(
+            adapter.build_url("@jsr/std__path"),
+            "https://npm.jsr.io/@jsr%2Fstd__path"
+        );
+    }
+
+    /// Recorded (trimmed) npm registry response with several dist-tags
+    const DIST_TAGS_RESPONSE: &str = r#"{
+        "name": "react",
+        "dist-tags": {
+            "latest": "18.2.0",
+            "next": "19.0.0-rc.1",
+            "beta": "18.3.0-beta.2"
+        },
+        "versions": {
+            "18.1.0": {},
+            "18.2.0": {},
+            "18.3.0-beta.2": {},
+            "19.0.0-rc.1": {},
+            "19.0.0-canary.5": {}
+        },
+        "time": {
+            "created": "2013-05-29T20:02:23.000Z",
+            "18.1.0": "2022-04-26T16:00:00.000Z",
+            "18.2.0": "2022-06-14T19:46:38.000Z",
+            "18.3.0-beta.2": "2023-01-10T10:00:00.000Z",
+            "19.0.0-rc.1": "2024-04-25T16:00:00.000Z",
+            "19.0.0-canary.5": "2024-03-01T16:00:00.000Z"
+        }
+    }"#;
+
+    fn version_strings(tag: &str) -> Vec<String> {
+        let response: NpmPackageResponse = serde_json::from_str(DIST_TAGS_RESPONSE).unwrap();
+        versions_for_tag(response, tag)
+            .into_iter()
+            .map(|v| v.version)
+            .collect()
+    }
+
+    #[test]
+    fn test_versions_for_latest_tag() {
+        assert_eq!(version_strings("latest"), vec!["18.1.0", "18.2.0"]);
+    }
+
+    #[test]
+    fn test_versions_for_next_tag() {
+        let versions = version_strings("next");
+        assert_eq!(versions.last().unwrap(), "19.0.0-rc.1");
+        assert!(versions.contains(&"18.3.0-beta.2".to_string()));
+    }
+
+    #[test]
+    fn test_versions_for_missing_tag_falls_back_to_latest() {
+        assert_eq!(version_strings("canary"), vec!["18.1.0", "18.2.0"]);
+    }
+
+    #[test]
+    fn test_with_dist_tag() {
+        let adapter = NpmAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(adapter.dist_tag, DEFAULT_DIST_TAG);
+        assert_eq!(adapter.with_dist_tag("next").dist_tag, "next");
+    }
+
     #[test]
     fn test_prerelease_version_greater_than_latest() {
         // Prisma-style integration versions should be filtered out
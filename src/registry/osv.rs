@@ -0,0 +1,346 @@
+//! OSV vulnerability database client for --only-vulnerable
+//!
+//! Queries known advisories affecting a dependency's current version.
+//! API endpoint: https://api.osv.dev/v1/query
+
+use crate::domain::{Dependency, Language};
+use crate::error::RegistryError;
+use crate::registry::HttpClient;
+use crate::update::compare_versions;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// OSV API base URL
+const OSV_API_URL: &str = "https://api.osv.dev";
+
+/// A known vulnerability affecting a dependency's current version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Advisory {
+    /// Advisory identifier (e.g., "GHSA-xxxx-xxxx-xxxx")
+    pub id: String,
+    /// Short description, if provided
+    pub summary: Option<String>,
+    /// Versions in which the vulnerability is fixed
+    pub fixed_versions: Vec<String>,
+}
+
+impl Advisory {
+    /// Lowest fixed version newer than `current`, if the advisory has been fixed
+    pub fn fix_for(&self, current: &str) -> Option<&str> {
+        self.fixed_versions
+            .iter()
+            .filter(|fixed| compare_versions(fixed, current) == Ordering::Greater)
+            .min_by(|a, b| compare_versions(a, b))
+            .map(String::as_str)
+    }
+}
+
+/// Lowest version that fixes every fixable advisory affecting `current`
+pub fn minimum_fix_version(advisories: &[Advisory], current: &str) -> Option<String> {
+    advisories
+        .iter()
+        .filter_map(|advisory| advisory.fix_for(current))
+        .max_by(|a, b| compare_versions(a, b))
+        .map(str::to_string)
+}
+
+/// Source of advisories for a dependency
+#[async_trait]
+pub trait AdvisorySource: Send + Sync {
+    /// Whether advisories are tracked for the dependency at all
+    ///
+    /// Dependencies outside the source's coverage are skipped rather than
+    /// reported as not vulnerable.
+    fn covers(&self, _dependency: &Dependency) -> bool {
+        true
+    }
+
+    /// Fetch the advisories affecting the dependency's current version
+    async fn query(&self, dependency: &Dependency) -> Result<Vec<Advisory>, RegistryError>;
+}
+
+/// OSV package ecosystem name for a language, if OSV covers it
+fn osv_ecosystem(language: Language) -> Option<&'static str> {
+    match language {
+        Language::Node => Some("npm"),
+        Language::Python => Some("PyPI"),
+        Language::Rust => Some("crates.io"),
+        Language::Go => Some("Go"),
+        Language::Ruby => Some("RubyGems"),
+        Language::Php => Some("Packagist"),
+        Language::Java => Some("Maven"),
+        // Only `npm:` specifiers are tracked (see `OsvClient::covers`)
+        Language::Deno => Some("npm"),
+        Language::R => Some("CRAN"),
+        Language::Nim | Language::Docker | Language::Crystal | Language::PreCommit => None,
+    }
+}
+
+/// Package name OSV knows a dependency by, if it is covered
+///
+/// Deno names carry their specifier scheme; OSV doesn't cover JSR packages.
+fn osv_package(dependency: &Dependency) -> Option<(&str, &'static str)> {
+    let ecosystem = osv_ecosystem(dependency.language)?;
+    let name = match dependency.language {
+        Language::Deno => dependency.name.strip_prefix("npm:")?,
+        _ => &dependency.name,
+    };
+    Some((name, ecosystem))
+}
+
+/// OSV query request body
+#[derive(Debug, Serialize)]
+struct OsvQuery<'a> {
+    version: &'a str,
+    package: OsvPackage<'a>,
+}
+
+/// OSV package identifier
+#[derive(Debug, Serialize)]
+struct OsvPackage<'a> {
+    name: &'a str,
+    ecosystem: &'a str,
+}
+
+/// OSV query response
+#[derive(Debug, Default, Deserialize)]
+pub struct OsvResponse {
+    #[serde(default)]
+    vulns: Vec<OsvVulnerability>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvVulnerability {
+    id: String,
+    #[serde(default)]
+    summary: Option<String>,
+    #[serde(default)]
+    affected: Vec<OsvAffected>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvAffected {
+    #[serde(default)]
+    package: Option<OsvAffectedPackage>,
+    #[serde(default)]
+    ranges: Vec<OsvRange>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvAffectedPackage {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvRange {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    events: Vec<OsvEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvEvent {
+    #[serde(default)]
+    fixed: Option<String>,
+}
+
+impl OsvResponse {
+    /// Convert the response into advisories for the named package
+    ///
+    /// Only version-based (SEMVER/ECOSYSTEM) ranges are used; GIT ranges carry
+    /// commit hashes rather than releasable versions.
+    pub fn into_advisories(self, package: &str) -> Vec<Advisory> {
+        self.vulns
+            .into_iter()
+            .map(|vuln| {
+                let fixed_versions = vuln
+                    .affected
+                    .iter()
+                    .filter(|a| {
+                        a.package
+                            .as_ref()
+                            .is_none_or(|p| p.name.eq_ignore_ascii_case(package))
+                    })
+                    .flat_map(|a| &a.ranges)
+                    .filter(|r| r.kind != "GIT")
+                    .flat_map(|r| &r.events)
+                    .filter_map(|e| e.fixed.clone())
+                    .collect();
+                Advisory {
+                    id: vuln.id,
+                    summary: vuln.summary,
+                    fixed_versions,
+                }
+            })
+            .collect()
+    }
+}
+
+/// OSV API client
+pub struct OsvClient {
+    client: HttpClient,
+    base_url: String,
+}
+
+impl OsvClient {
+    /// Create a new OSV client
+    pub fn new(client: HttpClient) -> Self {
+        Self::with_base_url(client, OSV_API_URL)
+    }
+
+    /// Create a new OSV client for a custom API base URL
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl AdvisorySource for OsvClient {
+    fn covers(&self, dependency: &Dependency) -> bool {
+        osv_package(dependency).is_some()
+    }
+
+    async fn query(&self, dependency: &Dependency) -> Result<Vec<Advisory>, RegistryError> {
+        let url = format!("{}/v1/query", self.base_url);
+        // Prefer the lock file's resolved version, which is what is actually installed
+        let version = dependency
+            .resolved_version
+            .as_deref()
+            .unwrap_or(dependency.version());
+        let Some((name, ecosystem)) = osv_package(dependency) else {
+            return Ok(Vec::new());
+        };
+        let query = OsvQuery {
+            version,
+            package: OsvPackage { name, ecosystem },
+        };
+
+        let response: OsvResponse = self.client.post_json(&url, &query, name, "OSV").await?;
+        Ok(response.into_advisories(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Recorded (trimmed) OSV response for lodash 4.17.15
+    const LODASH_RESPONSE: &str = r#"{
+        "vulns": [
+            {
+                "id": "GHSA-p6mc-m468-83gw",
+                "summary": "Prototype Pollution in lodash",
+                "affected": [
+                    {
+                        "package": {"name": "lodash", "ecosystem": "npm"},
+                        "ranges": [
+                            {"type": "SEMVER", "events": [{"introduced": "3.7.0"}, {"fixed": "4.17.19"}]}
+                        ]
+                    }
+                ]
+            },
+            {
+                "id": "GHSA-35jh-r3h4-6jhm",
+                "summary": "Command Injection in lodash",
+                "affected": [
+                    {
+                        "package": {"name": "lodash", "ecosystem": "npm"},
+                        "ranges": [
+                            {"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "4.17.21"}]},
+                            {"type": "GIT", "events": [{"introduced": "0"}, {"fixed": "c4847ebe"}]}
+                        ]
+                    },
+                    {
+                        "package": {"name": "lodash-es", "ecosystem": "npm"},
+                        "ranges": [
+                            {"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "4.17.22"}]}
+                        ]
+                    }
+                ]
+            }
+        ]
+    }"#;
+
+    fn lodash_advisories() -> Vec<Advisory> {
+        let response: OsvResponse = serde_json::from_str(LODASH_RESPONSE).unwrap();
+        response.into_advisories("lodash")
+    }
+
+    #[test]
+    fn test_into_advisories() {
+        let advisories = lodash_advisories();
+        assert_eq!(advisories.len(), 2);
+        assert_eq!(advisories[0].id, "GHSA-p6mc-m468-83gw");
+        assert_eq!(
+            advisories[0].summary.as_deref(),
+            Some("Prototype Pollution in lodash")
+        );
+        // Other packages' ranges and GIT commits are ignored
+        assert_eq!(advisories[1].fixed_versions, vec!["4.17.21"]);
+    }
+
+    #[test]
+    fn test_empty_response() {
+        let response: OsvResponse = serde_json::from_str("{}").unwrap();
+        assert!(response.into_advisories("lodash").is_empty());
+    }
+
+    #[test]
+    fn test_minimum_fix_version() {
+        let advisories = lodash_advisories();
+        assert_eq!(
+            minimum_fix_version(&advisories, "4.17.15"),
+            Some("4.17.21".to_string())
+        );
+
+        let unfixed = Advisory {
+            id: "GHSA-none".to_string(),
+            summary: None,
+            fixed_versions: Vec::new(),
+        };
+        assert_eq!(minimum_fix_version(&[unfixed], "4.17.15"), None);
+    }
+
+    #[test]
+    fn test_fix_for_ignores_older_fixes() {
+        let advisory = Advisory {
+            id: "GHSA-multi".to_string(),
+            summary: None,
+            fixed_versions: vec!["3.10.1".to_string(), "4.17.19".to_string()],
+        };
+        assert_eq!(advisory.fix_for("4.0.0"), Some("4.17.19"));
+        assert_eq!(advisory.fix_for("3.9.0"), Some("3.10.1"));
+        assert_eq!(advisory.fix_for("4.17.19"), None);
+    }
+
+    #[test]
+    fn test_osv_ecosystem() {
+        assert_eq!(osv_ecosystem(Language::Node), Some("npm"));
+        assert_eq!(osv_ecosystem(Language::Rust), Some("crates.io"));
+        assert_eq!(osv_ecosystem(Language::Java), Some("Maven"));
+        assert_eq!(osv_ecosystem(Language::R), Some("CRAN"));
+        assert_eq!(osv_ecosystem(Language::Docker), None);
+    }
+
+    #[test]
+    fn test_osv_client_covers() {
+        let client = OsvClient::new(HttpClient::new().unwrap());
+        let spec = crate::domain::VersionSpec::new(
+            crate::domain::VersionSpecKind::Exact,
+            "1.0.0",
+            "1.0.0",
+        );
+        let dep = |name: &str, language| Dependency::production(name, spec.clone(), language);
+
+        assert!(client.covers(&dep("lodash", Language::Node)));
+        assert!(client.covers(&dep("npm:chalk", Language::Deno)));
+        assert!(!client.covers(&dep("jsr:@std/path", Language::Deno)));
+        assert!(!client.covers(&dep("kemal", Language::Crystal)));
+        assert!(!client.covers(&dep("nginx", Language::Docker)));
+    }
+}
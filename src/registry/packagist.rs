@@ -18,6 +18,7 @@ const PACKAGIST_API_URL: &str = "https://repo.packagist.org/p2";
 /// Packagist Registry adapter
 pub struct PackagistAdapter {
     client: HttpClient,
+    base_url: String,
 }
 
 /// Packagist API response format (p2 metadata API)
@@ -42,13 +43,24 @@ struct PackagistVersionInfo {
 impl PackagistAdapter {
     /// Create a new Packagist adapter
     pub fn new(client: HttpClient) -> Self {
-        Self { client }
+        Self::with_base_url(client, PACKAGIST_API_URL)
+    }
+
+    /// Create a new Packagist adapter for a custom base URL (e.g., a mirror)
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+        }
     }
 
     /// Build the URL for a package
     /// Package names are in the format vendor/package
+    ///
+    /// The vendor/package slash is a path separator in the p2 API and stays as is;
+    /// names are lowercased because Packagist only serves the lowercase form.
     fn build_url(&self, package: &str) -> String {
-        format!("{}/{}.json", PACKAGIST_API_URL, package)
+        format!("{}/{}.json", self.base_url, package.to_lowercase())
     }
 
     /// Normalize version string by removing 'v' prefix if present
@@ -146,6 +158,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_url_lowercases_name() {
+        let client = HttpClient::new().unwrap();
+        let adapter = PackagistAdapter::new(client);
+        assert_eq!(
+            adapter.build_url("Monolog/Monolog"),
+            "https://repo.packagist.org/p2/monolog/monolog.json"
+        );
+    }
+
     #[test]
     fn test_normalize_version_with_v_prefix() {
         assert_eq!(PackagistAdapter::normalize_version("v1.0.0"), "1.0.0");
@@ -0,0 +1,285 @@
+//! pre-commit adapter for hook repository tags
+//!
+//! Hook repositories have no registry: releases are the repository's git tags,
+//! looked up by the `repo:` URL. API endpoints:
+//! - GitHub tags: https://api.github.com/repos/{owner}/{repo}/tags
+//! - GitHub tag commit (release time): https://api.github.com/repos/{owner}/{repo}/commits/{sha}
+//! - GitLab tags: https://gitlab.com/api/v4/projects/{namespace%2Fproject}/repository/tags
+//!
+//! GitHub requests are authenticated with `GITHUB_TOKEN` when it is set; the
+//! token is only sent to api.github.com, never to a mirror.
+//! Repositories on other hosts are not supported.
+
+use crate::domain::Language;
+use crate::error::RegistryError;
+use crate::registry::github::{fetch_tag_versions, tag_version, GITHUB_API_URL, GITHUB_TOKEN_ENV};
+use crate::registry::{HttpClient, RegistryAdapter};
+use crate::update::VersionInfo;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// GitLab REST API base URL
+const GITLAB_API_URL: &str = "https://gitlab.com/api/v4";
+
+/// pre-commit adapter
+pub struct PreCommitAdapter {
+    /// Client for GitLab (never carries the GitHub token)
+    client: HttpClient,
+    /// Client for GitHub, authenticated when `GITHUB_TOKEN` is set
+    github_client: HttpClient,
+    /// GitHub REST API base URL
+    github_url: String,
+    /// GitLab REST API base URL
+    gitlab_url: String,
+}
+
+/// Tag from the GitLab repository tags API
+#[derive(Debug, Deserialize)]
+struct GitLabTag {
+    name: String,
+    commit: GitLabCommit,
+}
+
+/// Commit a GitLab tag points to
+#[derive(Debug, Deserialize)]
+struct GitLabCommit {
+    committed_date: String,
+}
+
+/// Hosting service of a hook repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Host {
+    GitHub,
+    GitLab,
+}
+
+/// Host and repository path (`owner/repo`, or `group/subgroup/project` on GitLab) of a repo URL
+fn repository_path(url: &str) -> Option<(Host, &str)> {
+    let (host, path) = if let Some((_, path)) = url.split_once("github.com/") {
+        (Host::GitHub, path)
+    } else {
+        (Host::GitLab, url.split_once("gitlab.com/")?.1)
+    };
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    path.contains('/').then_some((host, path))
+}
+
+impl PreCommitAdapter {
+    /// Create a new pre-commit adapter, authenticating GitHub requests with `GITHUB_TOKEN`
+    pub fn new(client: HttpClient) -> Self {
+        let github_client = match std::env::var(GITHUB_TOKEN_ENV) {
+            Ok(token) if !token.is_empty() => client.clone().with_bearer_token(token),
+            _ => client.clone(),
+        };
+        Self {
+            github_client,
+            ..Self::with_base_url(client, GITHUB_API_URL)
+        }
+    }
+
+    /// Create a new pre-commit adapter for a custom GitHub API base URL
+    ///
+    /// No credentials are attached, so the token never reaches a mirror host.
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            github_client: client.clone(),
+            client,
+            github_url: base_url.into(),
+            gitlab_url: GITLAB_API_URL.to_string(),
+        }
+    }
+
+    /// Use a custom GitLab API base URL
+    pub fn with_gitlab_url(mut self, gitlab_url: impl Into<String>) -> Self {
+        self.gitlab_url = gitlab_url.into();
+        self
+    }
+
+    /// Build the GitLab tags URL for a project path
+    fn build_gitlab_url(&self, path: &str) -> String {
+        format!(
+            "{}/projects/{}/repository/tags?per_page=100",
+            self.gitlab_url,
+            path.replace('/', "%2F")
+        )
+    }
+
+    /// Versions of a GitLab project's release tags, dated by their commits
+    async fn fetch_gitlab_versions(
+        &self,
+        path: &str,
+        package: &str,
+    ) -> Result<Vec<VersionInfo>, RegistryError> {
+        let tags: Vec<GitLabTag> = self
+            .client
+            .get_json(&self.build_gitlab_url(path), package, self.registry_name())
+            .await?;
+
+        let mut versions: Vec<VersionInfo> = tags
+            .iter()
+            .filter_map(|tag| {
+                let version = tag_version(&tag.name)?;
+                let released_at = tag.commit.committed_date.parse::<DateTime<Utc>>().ok()?;
+                Some(VersionInfo::new(version, released_at))
+            })
+            .collect();
+
+        // Sort by version
+        versions.sort();
+
+        Ok(versions)
+    }
+}
+
+#[async_trait]
+impl RegistryAdapter for PreCommitAdapter {
+    fn language(&self) -> Language {
+        Language::PreCommit
+    }
+
+    fn registry_name(&self) -> &'static str {
+        "GitHub/GitLab"
+    }
+
+    async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        match repository_path(package) {
+            Some((Host::GitHub, path)) => {
+                let (owner, repo) = path.split_once('/').unwrap_or((path, ""));
+                fetch_tag_versions(
+                    &self.github_client,
+                    &self.github_url,
+                    owner,
+                    repo,
+                    package,
+                    self.registry_name(),
+                )
+                .await
+            }
+            Some((Host::GitLab, path)) => self.fetch_gitlab_versions(path, package).await,
+            None => Err(RegistryError::InvalidPackageName {
+                name: package.to_string(),
+                registry: self.registry_name().to_string(),
+                reason: "only GitHub and GitLab repositories are supported".to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    #[test]
+    fn test_pre_commit_adapter_registry_name() {
+        let adapter = PreCommitAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(adapter.language(), Language::PreCommit);
+        assert_eq!(adapter.registry_name(), "GitHub/GitLab");
+    }
+
+    #[test]
+    fn test_repository_path() {
+        assert_eq!(
+            repository_path("https://github.com/psf/black"),
+            Some((Host::GitHub, "psf/black"))
+        );
+        assert_eq!(
+            repository_path("https://github.com/pre-commit/pre-commit-hooks.git/"),
+            Some((Host::GitHub, "pre-commit/pre-commit-hooks"))
+        );
+        assert_eq!(
+            repository_path("https://gitlab.com/group/sub/project"),
+            Some((Host::GitLab, "group/sub/project"))
+        );
+        assert_eq!(repository_path("https://bitbucket.org/team/hooks"), None);
+        assert_eq!(repository_path("local"), None);
+    }
+
+    #[test]
+    fn test_build_gitlab_url() {
+        let adapter = PreCommitAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(
+            adapter.build_gitlab_url("pycqa/flake8"),
+            "https://gitlab.com/api/v4/projects/pycqa%2Fflake8/repository/tags?per_page=100"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_gitlab_versions() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf);
+                let body = r#"[
+                    {"name": "7.0.0", "commit": {"committed_date": "2024-01-04T10:00:00.000+00:00"}},
+                    {"name": "v6.1.0", "commit": {"committed_date": "2023-07-29T10:00:00.000+00:00"}},
+                    {"name": "nightly", "commit": {"committed_date": "2024-02-01T10:00:00.000+00:00"}}
+                ]"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let adapter = PreCommitAdapter::new(HttpClient::new().unwrap()).with_gitlab_url(base_url);
+        let versions = adapter
+            .fetch_versions("https://gitlab.com/pycqa/flake8")
+            .await
+            .unwrap();
+        let tags: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(tags, vec!["6.1.0", "7.0.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_mirror_sends_no_authorization() {
+        std::env::set_var(GITHUB_TOKEN_ENV, "test-token");
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).unwrap_or(0);
+                let _ = tx.send(String::from_utf8_lossy(&buf[..n]).to_lowercase());
+                let body = "[]";
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let adapter = PreCommitAdapter::with_base_url(HttpClient::new().unwrap(), base_url);
+        let versions = adapter
+            .fetch_versions("https://github.com/psf/black")
+            .await
+            .unwrap();
+        assert!(versions.is_empty());
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("get /repos/psf/black/tags"));
+        assert!(!request.contains("authorization:"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_unsupported_host() {
+        let adapter = PreCommitAdapter::new(HttpClient::new().unwrap());
+        let result = adapter
+            .fetch_versions("https://bitbucket.org/team/hooks")
+            .await;
+        assert!(matches!(
+            result,
+            Err(RegistryError::InvalidPackageName { .. })
+        ));
+    }
+}
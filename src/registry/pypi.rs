@@ -1,6 +1,7 @@
 //! PyPI JSON API adapter
 //!
-//! Fetches package version information from PyPI.
+//! Fetches package version information from PyPI, including each release's
+//! `requires_python` so versions that dropped the project's Python can be skipped.
 //! API endpoint: https://pypi.org/pypi/{package}/json
 
 use crate::domain::Language;
@@ -18,6 +19,7 @@ const PYPI_API_URL: &str = "https://pypi.org/pypi";
 /// PyPI adapter
 pub struct PyPIAdapter {
     client: HttpClient,
+    base_url: String,
 }
 
 /// PyPI package metadata response
@@ -32,17 +34,28 @@ struct PyPIResponse {
 struct ReleaseInfo {
     /// Upload time for the release file
     upload_time_iso_8601: Option<String>,
+    /// Python versions supported by the release file (e.g., ">=3.8")
+    #[serde(default)]
+    requires_python: Option<String>,
 }
 
 impl PyPIAdapter {
     /// Create a new PyPI adapter
     pub fn new(client: HttpClient) -> Self {
-        Self { client }
+        Self::with_base_url(client, PYPI_API_URL)
+    }
+
+    /// Create a new PyPI adapter for a custom base URL (e.g., a mirror)
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+        }
     }
 
     /// Build the URL for a package
     fn build_url(&self, package: &str) -> String {
-        format!("{}/{}/json", PYPI_API_URL, package)
+        format!("{}/{}/json", self.base_url, package)
     }
 }
 
@@ -68,8 +81,12 @@ impl RegistryAdapter for PyPIAdapter {
         for (version, release_files) in response.releases {
             // Get the earliest upload time from release files
             let mut earliest_time: Option<DateTime<Utc>> = None;
+            let mut requires_python: Option<String> = None;
 
             for file_info in release_files {
+                if requires_python.is_none() {
+                    requires_python = file_info.requires_python.filter(|r| !r.trim().is_empty());
+                }
                 if let Some(time_str) = &file_info.upload_time_iso_8601 {
                     if let Ok(time) = time_str.parse::<DateTime<Utc>>() {
                         earliest_time = Some(match earliest_time {
@@ -82,7 +99,11 @@ impl RegistryAdapter for PyPIAdapter {
             }
 
             if let Some(released_at) = earliest_time {
-                versions.push(VersionInfo::new(&version, released_at));
+                let info = VersionInfo::new(&version, released_at);
+                versions.push(match requires_python {
+                    Some(requires) => info.with_requires_python(requires),
+                    None => info,
+                });
             }
         }
 
@@ -96,6 +117,8 @@ impl RegistryAdapter for PyPIAdapter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::{Dependency, UpdateResult, VersionSpec, VersionSpecKind};
+    use crate::update::{UpdateFilter, UpdateJudge};
 
     #[test]
     fn test_pypi_adapter_language() {
@@ -121,6 +144,50 @@ mod tests {
         );
     }
 
+    /// Serve a fixed PyPI JSON response for every request
+    fn serve_pypi_json(body: &'static str) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        base_url
+    }
+
+    #[tokio::test]
+    async fn test_fetch_versions_excludes_unsupported_python() {
+        let base_url = serve_pypi_json(
+            r#"{"releases": {
+                "2.0.0": [{"upload_time_iso_8601": "2023-01-01T00:00:00Z", "requires_python": ">=3.7"}],
+                "2.1.0": [{"upload_time_iso_8601": "2023-06-01T00:00:00Z", "requires_python": ">=3.8"}],
+                "3.0.0": [{"upload_time_iso_8601": "2024-01-01T00:00:00Z", "requires_python": ">=3.10"}]
+            }}"#,
+        );
+        let adapter = PyPIAdapter::with_base_url(HttpClient::new().unwrap(), base_url);
+        let versions = adapter.fetch_versions("example").await.unwrap();
+        assert_eq!(versions[2].requires_python.as_deref(), Some(">=3.10"));
+
+        let spec =
+            VersionSpec::new(VersionSpecKind::GreaterOrEqual, ">=2.0.0", "2.0.0").with_prefix(">=");
+        let dep = Dependency::new("example", spec, false, Language::Python);
+        let judge = UpdateJudge::new(UpdateFilter::new().with_requires_python(">=3.8"));
+        assert!(matches!(
+            judge.judge(&dep, &versions),
+            UpdateResult::Update { ref new_version, .. } if new_version == "2.1.0"
+        ));
+    }
+
     #[test]
     fn test_build_url_with_dashes() {
         let client = HttpClient::new().unwrap();
@@ -17,6 +17,7 @@ const RUBYGEMS_API_URL: &str = "https://rubygems.org/api/v1/versions";
 /// RubyGems Registry adapter
 pub struct RubyGemsAdapter {
     client: HttpClient,
+    base_url: String,
 }
 
 /// RubyGems version info from API response
@@ -37,12 +38,20 @@ struct RubyGemsVersionInfo {
 impl RubyGemsAdapter {
     /// Create a new RubyGems adapter
     pub fn new(client: HttpClient) -> Self {
-        Self { client }
+        Self::with_base_url(client, RUBYGEMS_API_URL)
+    }
+
+    /// Create a new RubyGems adapter for a custom base URL (e.g., a mirror)
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+        }
     }
 
     /// Build the URL for a gem
     fn build_url(&self, gem: &str) -> String {
-        format!("{}/{}.json", RUBYGEMS_API_URL, gem)
+        format!("{}/{}.json", self.base_url, gem)
     }
 }
 
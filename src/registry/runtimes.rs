@@ -0,0 +1,216 @@
+//! Runtime release index client for --runtimes
+//!
+//! Fetches the newest stable release of each runtime from its official index:
+//! - Node.js: https://nodejs.org/dist/index.json
+//! - Go: https://go.dev/dl/?mode=json
+
+use crate::error::RegistryError;
+use crate::manifest::{Runtime, ToolVersion};
+use crate::registry::HttpClient;
+use crate::update::{compare_versions, is_prerelease_version};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Node.js distribution index URL
+const NODE_DIST_INDEX_URL: &str = "https://nodejs.org/dist/index.json";
+
+/// Go download index URL
+const GO_DL_INDEX_URL: &str = "https://go.dev/dl/?mode=json";
+
+/// A Node.js release from the dist index
+#[derive(Debug, Clone, Deserialize)]
+pub struct NodeRelease {
+    /// Release version (e.g., "v20.10.0")
+    pub version: String,
+}
+
+/// A Go release from the download index
+#[derive(Debug, Clone, Deserialize)]
+pub struct GoRelease {
+    /// Release version (e.g., "go1.21.5")
+    pub version: String,
+    /// Whether this is a stable release
+    #[serde(default)]
+    pub stable: bool,
+}
+
+/// Result of checking one pinned runtime against its release index
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuntimeCheck {
+    /// Runtime being checked
+    pub runtime: Runtime,
+    /// Version pinned in .tool-versions
+    pub current: String,
+    /// Newest stable release, if it could be determined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<String>,
+    /// Error message if the release index could not be fetched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl RuntimeCheck {
+    /// Returns true if a newer release than the pinned version exists
+    pub fn is_outdated(&self) -> bool {
+        self.latest
+            .as_deref()
+            .is_some_and(|latest| compare_versions(latest, &self.current) == Ordering::Greater)
+    }
+}
+
+/// Client for the runtime release indexes
+pub struct RuntimeReleaseClient {
+    client: HttpClient,
+    node_index_url: String,
+    go_index_url: String,
+}
+
+impl RuntimeReleaseClient {
+    /// Create a client for the official release indexes
+    pub fn new(client: HttpClient) -> Self {
+        Self::with_index_urls(client, NODE_DIST_INDEX_URL, GO_DL_INDEX_URL)
+    }
+
+    /// Create a client for custom index URLs (for testing or mirrors)
+    pub fn with_index_urls(
+        client: HttpClient,
+        node_index_url: impl Into<String>,
+        go_index_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            client,
+            node_index_url: node_index_url.into(),
+            go_index_url: go_index_url.into(),
+        }
+    }
+
+    /// Fetch the newest stable release of a runtime
+    pub async fn latest_release(&self, runtime: Runtime) -> Result<Option<String>, RegistryError> {
+        match runtime {
+            Runtime::Node => {
+                let releases: Vec<NodeRelease> = self
+                    .client
+                    .get_json(&self.node_index_url, "node", "nodejs.org")
+                    .await?;
+                Ok(latest_node_release(&releases))
+            }
+            Runtime::Go => {
+                let releases: Vec<GoRelease> = self
+                    .client
+                    .get_json(&self.go_index_url, "go", "go.dev")
+                    .await?;
+                Ok(latest_go_release(&releases))
+            }
+        }
+    }
+
+    /// Check every supported runtime pinned in `.tool-versions` entries
+    ///
+    /// Entries for tools depup can't check are ignored.
+    pub async fn check(&self, entries: &[ToolVersion]) -> Vec<RuntimeCheck> {
+        let mut checks = Vec::new();
+        for entry in entries {
+            let Some(runtime) = entry.runtime() else {
+                continue;
+            };
+            let (latest, error) = match self.latest_release(runtime).await {
+                Ok(latest) => (latest, None),
+                Err(e) => (None, Some(e.to_string())),
+            };
+            checks.push(RuntimeCheck {
+                runtime,
+                current: entry.version.clone(),
+                latest,
+                error,
+            });
+        }
+        checks
+    }
+}
+
+/// Newest stable Node.js version, without the leading `v`
+pub fn latest_node_release(releases: &[NodeRelease]) -> Option<String> {
+    releases
+        .iter()
+        .map(|r| r.version.strip_prefix('v').unwrap_or(&r.version))
+        .filter(|v| !is_prerelease_version(v))
+        .max_by(|a, b| compare_versions(a, b))
+        .map(str::to_string)
+}
+
+/// Newest stable Go version, without the leading `go`
+pub fn latest_go_release(releases: &[GoRelease]) -> Option<String> {
+    releases
+        .iter()
+        .filter(|r| r.stable)
+        .map(|r| r.version.strip_prefix("go").unwrap_or(&r.version))
+        .max_by(|a, b| compare_versions(a, b))
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latest_node_release() {
+        let releases: Vec<NodeRelease> = serde_json::from_str(
+            r#"[
+                {"version": "v21.5.0", "date": "2023-12-19", "lts": false},
+                {"version": "v20.10.0", "date": "2023-11-22", "lts": "Iron"},
+                {"version": "v18.19.0", "date": "2023-11-29", "lts": "Hydrogen"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(latest_node_release(&releases), Some("21.5.0".to_string()));
+        assert_eq!(latest_node_release(&[]), None);
+    }
+
+    #[test]
+    fn test_runtime_check_is_outdated() {
+        let check = |current: &str, latest: Option<&str>| RuntimeCheck {
+            runtime: Runtime::Node,
+            current: current.to_string(),
+            latest: latest.map(str::to_string),
+            error: None,
+        };
+        assert!(check("20.10.0", Some("21.5.0")).is_outdated());
+        assert!(!check("21.5.0", Some("21.5.0")).is_outdated());
+        assert!(!check("20.10.0", None).is_outdated());
+    }
+
+    #[tokio::test]
+    async fn test_check_maps_entries_to_runtimes() {
+        // Unreachable indexes: every supported entry still yields a check carrying the error
+        let client = RuntimeReleaseClient::with_index_urls(
+            HttpClient::new().unwrap().with_max_retries(0),
+            "http://127.0.0.1:9/node.json",
+            "http://127.0.0.1:9/go.json",
+        );
+        let entries =
+            crate::manifest::parse_tool_versions("nodejs 20.10.0\npython 3.12.1\ngolang 1.21.0\n");
+
+        let checks = client.check(&entries).await;
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].runtime, Runtime::Node);
+        assert_eq!(checks[0].current, "20.10.0");
+        assert_eq!(checks[1].runtime, Runtime::Go);
+        assert_eq!(checks[1].current, "1.21.0");
+        assert!(checks
+            .iter()
+            .all(|c| c.latest.is_none() && c.error.is_some()));
+    }
+
+    #[test]
+    fn test_latest_go_release_skips_unstable() {
+        let releases: Vec<GoRelease> = serde_json::from_str(
+            r#"[
+                {"version": "go1.22rc1", "stable": false},
+                {"version": "go1.21.5", "stable": true},
+                {"version": "go1.20.12", "stable": true}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(latest_go_release(&releases), Some("1.21.5".to_string()));
+    }
+}
@@ -0,0 +1,179 @@
+//! Shards adapter for Crystal packages
+//!
+//! Crystal has no central registry: a shard is resolved from its source
+//! repository, and releases are the repository's git tags. API endpoints:
+//! - List tags: https://api.github.com/repos/{owner}/{repo}/tags
+//! - Tag commit (release time): https://api.github.com/repos/{owner}/{repo}/commits/{sha}
+//!
+//! Packages are looked up by their `owner/repo` (the shard.yml `github:` value).
+//! Requests are authenticated with `GITHUB_TOKEN` when it is set.
+
+use crate::domain::Language;
+use crate::error::RegistryError;
+use crate::registry::github::{fetch_tag_versions, GITHUB_API_URL, GITHUB_TOKEN_ENV};
+use crate::registry::{HttpClient, RegistryAdapter};
+use crate::update::VersionInfo;
+use async_trait::async_trait;
+
+/// Shards adapter
+pub struct ShardsAdapter {
+    client: HttpClient,
+    base_url: String,
+}
+
+impl ShardsAdapter {
+    /// Create a new shards adapter, authenticating with `GITHUB_TOKEN` if set
+    pub fn new(client: HttpClient) -> Self {
+        let client = match std::env::var(GITHUB_TOKEN_ENV) {
+            Ok(token) if !token.is_empty() => client.with_bearer_token(token),
+            _ => client,
+        };
+        Self::with_base_url(client, GITHUB_API_URL)
+    }
+
+    /// Create a new shards adapter for a custom GitHub API base URL
+    pub fn with_base_url(client: HttpClient, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+        }
+    }
+
+    /// Split an `owner/repo` package into its parts
+    fn repository<'a>(&self, package: &'a str) -> Result<(&'a str, &'a str), RegistryError> {
+        package
+            .split_once('/')
+            .filter(|(owner, repo)| !owner.is_empty() && !repo.is_empty() && !repo.contains('/'))
+            .ok_or_else(|| RegistryError::InvalidPackageName {
+                name: package.to_string(),
+                registry: self.registry_name().to_string(),
+                reason: "expected a GitHub repository as 'owner/repo'".to_string(),
+            })
+    }
+}
+
+#[async_trait]
+impl RegistryAdapter for ShardsAdapter {
+    fn language(&self) -> Language {
+        Language::Crystal
+    }
+
+    fn registry_name(&self) -> &'static str {
+        "GitHub"
+    }
+
+    async fn fetch_versions(&self, package: &str) -> Result<Vec<VersionInfo>, RegistryError> {
+        let (owner, repo) = self.repository(package)?;
+        fetch_tag_versions(
+            &self.client,
+            &self.base_url,
+            owner,
+            repo,
+            package,
+            self.registry_name(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    #[test]
+    fn test_shards_adapter_registry_name() {
+        let adapter = ShardsAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(adapter.language(), Language::Crystal);
+        assert_eq!(adapter.registry_name(), "GitHub");
+    }
+
+    #[test]
+    fn test_repository() {
+        let adapter = ShardsAdapter::new(HttpClient::new().unwrap());
+        assert_eq!(
+            adapter.repository("kemalcr/kemal").unwrap(),
+            ("kemalcr", "kemal")
+        );
+        assert!(adapter.repository("kemal").is_err());
+        assert!(adapter.repository("kemalcr/").is_err());
+        assert!(adapter.repository("github.com/kemalcr/kemal").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_versions_from_tags() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let body = if request.starts_with("GET /repos/kemalcr/kemal/tags") {
+                    r#"[
+                        {"name": "v1.5.0", "commit": {"sha": "bbb"}},
+                        {"name": "v1.4.0", "commit": {"sha": "aaa"}},
+                        {"name": "nightly", "commit": {"sha": "ccc"}}
+                    ]"#
+                } else if request.starts_with("GET /repos/kemalcr/kemal/commits/bbb") {
+                    r#"{"commit": {"committer": {"date": "2024-02-01T10:00:00Z"}}}"#
+                } else {
+                    r#"{"commit": {"committer": {"date": "2023-06-01T10:00:00Z"}}}"#
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let adapter = ShardsAdapter::with_base_url(HttpClient::new().unwrap(), base_url);
+        let versions = adapter.fetch_versions("kemalcr/kemal").await.unwrap();
+        let tags: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(tags, vec!["1.4.0", "1.5.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_versions_follows_tag_pages() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let next = format!("{}/page2", base_url);
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let (link, body) = if request.starts_with("GET /repos/kemalcr/kemal/tags") {
+                    (
+                        format!("Link: <{}>; rel=\"next\"\r\n", next),
+                        r#"[{"name": "v1.4.0", "commit": {"sha": "aaa"}}]"#,
+                    )
+                } else if request.starts_with("GET /page2") {
+                    (
+                        String::new(),
+                        r#"[{"name": "v1.5.0", "commit": {"sha": "bbb"}}]"#,
+                    )
+                } else {
+                    (
+                        String::new(),
+                        r#"{"commit": {"committer": {"date": "2024-02-01T10:00:00Z"}}}"#,
+                    )
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    link,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let adapter = ShardsAdapter::with_base_url(HttpClient::new().unwrap(), base_url);
+        let versions = adapter.fetch_versions("kemalcr/kemal").await.unwrap();
+        let tags: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(tags, vec!["1.4.0", "1.5.0"]);
+    }
+}
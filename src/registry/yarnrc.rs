@@ -0,0 +1,128 @@
+//! Minimal `.yarnrc.yml` reader for Yarn Berry registry settings
+//!
+//! Handles:
+//! - `npmRegistryServer`: the registry used for every package
+//! - `npmScopes.<scope>.npmRegistryServer`: the registry for `@scope/*` packages
+//! - Quoted and unquoted values, comments and blank lines
+//!
+//! Other settings (auth tokens, `npmPublishRegistry`, ...) are ignored.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Yarn Berry configuration file name
+pub const YARNRC_FILENAME: &str = ".yarnrc.yml";
+
+/// Registry settings from a `.yarnrc.yml`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct YarnRc {
+    /// Default registry (`npmRegistryServer`)
+    pub registry: Option<String>,
+    /// Registry per scope name, without the `@` (`npmScopes`)
+    pub scopes: HashMap<String, String>,
+}
+
+/// Value without surrounding whitespace and quotes
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches('"').trim_matches('\'')
+}
+
+/// Split a `key: value` line into its unquoted key and value
+fn key_value(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(':')?;
+    Some((unquote(key), unquote(value)))
+}
+
+/// Registry URL without a trailing slash
+fn normalize_url(url: &str) -> String {
+    url.trim_end_matches('/').to_string()
+}
+
+impl YarnRc {
+    /// Parse `.yarnrc.yml` content
+    pub fn parse(content: &str) -> Self {
+        let mut yarnrc = Self::default();
+        let mut in_scopes = false;
+        let mut scope: Option<String> = None;
+
+        for line in content.lines() {
+            let code = line.split(" #").next().unwrap_or("").trim_end();
+            if code.trim().is_empty() || code.trim_start().starts_with('#') {
+                continue;
+            }
+            let indent = code.len() - code.trim_start().len();
+            let Some((key, value)) = key_value(code.trim_start()) else {
+                continue;
+            };
+
+            if indent == 0 {
+                in_scopes = key == "npmScopes";
+                scope = None;
+                if key == "npmRegistryServer" && !value.is_empty() {
+                    yarnrc.registry = Some(normalize_url(value));
+                }
+            } else if in_scopes && value.is_empty() {
+                // A scope block (`mycompany:`); its settings are indented further
+                scope = Some(key.trim_start_matches('@').to_string());
+            } else if let (Some(scope), "npmRegistryServer") = (&scope, key) {
+                yarnrc.scopes.insert(scope.clone(), normalize_url(value));
+            }
+        }
+
+        yarnrc
+    }
+
+    /// Read the `.yarnrc.yml` in a directory, if present
+    pub fn load(dir: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(dir.join(YARNRC_FILENAME)).ok()?;
+        Some(Self::parse(&content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"nodeLinker: node-modules
+npmRegistryServer: "https://registry.example.com/"
+
+# Private packages
+npmScopes:
+  mycompany:
+    npmAlwaysAuth: true
+    npmAuthToken: "${NPM_TOKEN}"
+    npmRegistryServer: "https://npm.mycompany.com"
+  "@partner":
+    npmRegistryServer: https://npm.partner.dev/  # mirror
+
+yarnPath: .yarn/releases/yarn-4.1.0.cjs
+"#;
+
+    #[test]
+    fn test_parse_yarnrc() {
+        let yarnrc = YarnRc::parse(SAMPLE);
+        assert_eq!(
+            yarnrc.registry.as_deref(),
+            Some("https://registry.example.com")
+        );
+        assert_eq!(yarnrc.scopes.len(), 2);
+        assert_eq!(yarnrc.scopes["mycompany"], "https://npm.mycompany.com");
+        assert_eq!(yarnrc.scopes["partner"], "https://npm.partner.dev");
+    }
+
+    #[test]
+    fn test_parse_yarnrc_without_registries() {
+        let yarnrc = YarnRc::parse("nodeLinker: pnp\nenableTelemetry: false\n");
+        assert_eq!(yarnrc, YarnRc::default());
+    }
+
+    #[test]
+    fn test_load_yarnrc() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(YarnRc::load(dir.path()), None);
+
+        std::fs::write(dir.path().join(YARNRC_FILENAME), SAMPLE).unwrap();
+        let yarnrc = YarnRc::load(dir.path()).unwrap();
+        assert!(yarnrc.scopes.contains_key("mycompany"));
+    }
+}
@@ -4,8 +4,90 @@
 //! all filter options for update judgment.
 
 use crate::domain::Language;
+use crate::update::{compare_versions, glob_match};
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::time::Duration;
 
+/// How newer prereleases are matched when the current version is a prerelease
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrereleaseChannel {
+    /// Any newer prerelease may be offered
+    #[default]
+    Any,
+    /// Only prereleases on the current channel (e.g., canary) or stable releases
+    Strict,
+}
+
+impl PrereleaseChannel {
+    /// Parse a `--prerelease-channel` value (`strict` or `any`)
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "any" => Ok(PrereleaseChannel::Any),
+            "strict" => Ok(PrereleaseChannel::Strict),
+            _ => Err(format!(
+                "invalid prerelease channel mode '{}': expected 'strict' or 'any'",
+                s
+            )),
+        }
+    }
+}
+
+/// Composer stability level of a version, ordered from least to most stable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stability {
+    /// Development builds (e.g., 2.0.x-dev)
+    Dev,
+    /// Alpha releases
+    Alpha,
+    /// Beta releases
+    Beta,
+    /// Release candidates
+    Rc,
+    /// Stable releases
+    Stable,
+}
+
+impl Stability {
+    /// Parse a composer.json `minimum-stability` value
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "dev" => Some(Stability::Dev),
+            "alpha" => Some(Stability::Alpha),
+            "beta" => Some(Stability::Beta),
+            "rc" => Some(Stability::Rc),
+            "stable" => Some(Stability::Stable),
+            _ => None,
+        }
+    }
+
+    /// Stability of a version string (e.g., "2.0.0-beta.1" -> Beta)
+    pub fn of_version(version: &str) -> Self {
+        // Branch versions such as 2.0.x-dev
+        if version.to_lowercase().contains("dev") {
+            return Stability::Dev;
+        }
+        match crate::update::prerelease_channel(version).as_deref() {
+            Some("alpha" | "a") => Stability::Alpha,
+            Some("beta" | "b") => Stability::Beta,
+            Some("rc" | "c") => Stability::Rc,
+            Some("patch" | "pl" | "p") | None => Stability::Stable,
+            // Unknown suffixes (canary, nightly, ...) are treated as development builds
+            Some(_) => Stability::Dev,
+        }
+    }
+}
+
+/// Stability settings declared by a manifest (composer.json)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StabilityPolicy {
+    /// Least stable version that may be offered (`minimum-stability`)
+    pub minimum: Stability,
+    /// Offer a stable release whenever one is newer than the current version (`prefer-stable`)
+    pub prefer_stable: bool,
+}
+
 /// Filter configuration for update judgment
 #[derive(Debug, Clone, Default)]
 pub struct UpdateFilter {
@@ -15,10 +97,36 @@ pub struct UpdateFilter {
     pub exclude: Vec<String>,
     /// If non-empty, only update these packages
     pub only: Vec<String>,
+    /// If non-empty, only update packages in these namespaces (e.g., `@mycompany/*`)
+    pub scopes: Vec<String>,
     /// Include pinned versions in updates
     pub include_pinned: bool,
+    /// Rewrite unconstrained (`Any`) and range constraints instead of skipping them
+    pub force_complex: bool,
+    /// Skip indirect (transitive) dependencies
+    pub only_direct: bool,
+    /// Offer the latest tagged release for Go pseudo-versions
+    pub upgrade_pseudo: bool,
     /// Minimum age for versions to be considered
     pub min_age: Option<Duration>,
+    /// Minimum age for dev dependencies, overriding `min_age` when set
+    pub min_age_dev: Option<Duration>,
+    /// Only consider versions released on or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Versions that must never be suggested, per package (e.g., go.mod `exclude`)
+    pub excluded_versions: HashMap<String, Vec<String>>,
+    /// Prerelease channel matching for dependencies already on a prerelease
+    pub prerelease_channel: PrereleaseChannel,
+    /// Offer prereleases to dependencies on a stable release too
+    pub include_prerelease: bool,
+    /// Move a prerelease to the newest stable release at or above its base version
+    pub prefer_stable: bool,
+    /// Stability floor declared by the manifest, replacing the prerelease rules when set
+    pub stability: Option<StabilityPolicy>,
+    /// Python versions the project supports (pyproject.toml `requires-python`)
+    pub requires_python: Option<String>,
+    /// Python interpreter the project runs on (`.python-version`)
+    pub python_version: Option<String>,
 }
 
 impl UpdateFilter {
@@ -45,18 +153,144 @@ impl UpdateFilter {
         self
     }
 
+    /// Set the namespaces packages must belong to (scope list)
+    pub fn with_scopes(mut self, scopes: Vec<String>) -> Self {
+        self.scopes = scopes;
+        self
+    }
+
     /// Set whether to include pinned versions
     pub fn with_include_pinned(mut self, include: bool) -> Self {
         self.include_pinned = include;
         self
     }
 
+    /// Set whether unconstrained (`Any`) and range constraints may be rewritten
+    pub fn with_force_complex(mut self, force: bool) -> Self {
+        self.force_complex = force;
+        self
+    }
+
+    /// Set whether indirect (transitive) dependencies are skipped
+    pub fn with_only_direct(mut self, only_direct: bool) -> Self {
+        self.only_direct = only_direct;
+        self
+    }
+
+    /// Set whether Go pseudo-versions may be upgraded to tagged releases
+    pub fn with_upgrade_pseudo(mut self, upgrade: bool) -> Self {
+        self.upgrade_pseudo = upgrade;
+        self
+    }
+
     /// Set minimum age for versions
     pub fn with_min_age(mut self, age: Duration) -> Self {
         self.min_age = Some(age);
         self
     }
 
+    /// Set minimum age for versions of dev dependencies
+    pub fn with_min_age_dev(mut self, age: Duration) -> Self {
+        self.min_age_dev = Some(age);
+        self
+    }
+
+    /// Minimum age that applies to a dependency, depending on whether it's a dev dependency
+    pub fn min_age_for(&self, is_dev: bool) -> Option<Duration> {
+        if is_dev {
+            self.min_age_dev.or(self.min_age)
+        } else {
+            self.min_age
+        }
+    }
+
+    /// Set the earliest release time for versions to be considered
+    pub fn with_since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Set how prerelease updates are matched to the current channel
+    pub fn with_prerelease_channel(mut self, channel: PrereleaseChannel) -> Self {
+        self.prerelease_channel = channel;
+        self
+    }
+
+    /// Set whether prereleases are offered to dependencies on a stable release
+    pub fn with_include_prerelease(mut self, include: bool) -> Self {
+        self.include_prerelease = include;
+        self
+    }
+
+    /// Offer stable releases over newer prereleases when already on a prerelease
+    pub fn with_prefer_stable(mut self, prefer_stable: bool) -> Self {
+        self.prefer_stable = prefer_stable;
+        self
+    }
+
+    /// Apply a manifest's stability settings (e.g., composer.json `minimum-stability`)
+    pub fn with_stability(mut self, policy: StabilityPolicy) -> Self {
+        self.stability = Some(policy);
+        self
+    }
+
+    /// Only offer versions supporting the project's Python (pyproject.toml `requires-python`)
+    pub fn with_requires_python(mut self, requires_python: impl Into<String>) -> Self {
+        self.requires_python = Some(requires_python.into());
+        self
+    }
+
+    /// Only offer versions supporting the selected interpreter (`.python-version`)
+    pub fn with_python_version(mut self, python_version: impl Into<String>) -> Self {
+        self.python_version = Some(python_version.into());
+        self
+    }
+
+    /// Check if a version declaring `requires` (its `Requires-Python`) supports the project
+    ///
+    /// The project's lowest supported Python and the selected interpreter must
+    /// both satisfy the version's requirement. Anything that can't be interpreted
+    /// is allowed.
+    pub fn supports_project_python(&self, requires: Option<&str>) -> bool {
+        let Some(requires) = requires else {
+            return true;
+        };
+        let floor = self.requires_python.as_deref().and_then(python_floor);
+        let supports = |python: &str| {
+            requires
+                .split(',')
+                .map(str::trim)
+                .filter(|clause| !clause.is_empty())
+                .all(|clause| python_clause_allows(clause, python))
+        };
+        floor.as_deref().is_none_or(supports) && self.python_version.as_deref().is_none_or(supports)
+    }
+
+    /// Never suggest `version` for `package`
+    pub fn with_excluded_version(
+        mut self,
+        package: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        self.excluded_versions
+            .entry(package.into())
+            .or_default()
+            .push(version.into());
+        self
+    }
+
+    /// Check if a specific version of a package is excluded
+    ///
+    /// A leading `v` is ignored so `v1.2.3` and `1.2.3` are treated alike.
+    pub fn is_version_excluded(&self, package: &str, version: &str) -> bool {
+        let normalize = |v: &str| v.strip_prefix('v').unwrap_or(v).to_string();
+        self.excluded_versions.get(package).is_some_and(|versions| {
+            versions
+                .iter()
+                .any(|excluded| normalize(excluded) == normalize(version))
+        })
+    }
+
     /// Check if a language should be processed
     pub fn should_process_language(&self, language: Language) -> bool {
         if self.languages.is_empty() {
@@ -65,6 +299,26 @@ impl UpdateFilter {
         self.languages.contains(&language)
     }
 
+    /// Check if a package belongs to one of the --scope namespaces
+    ///
+    /// Patterns with `*` or `?` are globs (`@mycompany/*`); a plain pattern matches
+    /// the name itself and everything below it (`github.com/myorg` matches
+    /// `github.com/myorg/tool`). Without scopes every package is in scope.
+    pub fn is_in_scope(&self, name: &str) -> bool {
+        self.scopes.is_empty()
+            || self.scopes.iter().any(|scope| {
+                if scope.contains(['*', '?']) {
+                    glob_match(scope, name)
+                } else {
+                    let scope = scope.trim_end_matches('/');
+                    name == scope
+                        || name
+                            .strip_prefix(scope)
+                            .is_some_and(|rest| rest.starts_with('/'))
+                }
+            })
+    }
+
     /// Check if a package should be processed based on filters
     pub fn should_process_package(&self, name: &str) -> bool {
         // If --only is specified, only process those packages
@@ -79,6 +333,61 @@ impl UpdateFilter {
     }
 }
 
+/// Split a PEP 440 clause such as `>=3.8` into its operator and version
+fn split_specifier(clause: &str) -> Option<(&str, &str)> {
+    let index = clause.find(|c: char| c.is_ascii_digit())?;
+    Some((clause[..index].trim(), clause[index..].trim()))
+}
+
+/// Lowest Python version a `requires-python` specifier allows (e.g., `>=3.8` -> 3.8)
+fn python_floor(specifier: &str) -> Option<String> {
+    specifier.split(',').find_map(|clause| {
+        let (op, version) = split_specifier(clause.trim())?;
+        matches!(op, ">=" | ">" | "~=" | "==" | "===")
+            .then(|| version.trim_end_matches(".*").to_string())
+    })
+}
+
+/// Check if `version` starts with the release segments of `prefix` (`3.8` matches `3.8.*`)
+fn has_release_prefix(version: &str, prefix: &str) -> bool {
+    let version: Vec<&str> = version.split('.').collect();
+    let prefix: Vec<&str> = prefix.split('.').collect();
+    prefix
+        .iter()
+        .enumerate()
+        .all(|(i, part)| version.get(i).copied().unwrap_or("0") == *part)
+}
+
+/// Check a single PEP 440 clause (e.g., `>=3.10`, `!=3.0.*`) against a Python version
+fn python_clause_allows(clause: &str, python: &str) -> bool {
+    let Some((op, version)) = split_specifier(clause) else {
+        return true;
+    };
+    if let Some(prefix) = version.strip_suffix(".*") {
+        return match op {
+            "==" => has_release_prefix(python, prefix),
+            "!=" => !has_release_prefix(python, prefix),
+            _ => true,
+        };
+    }
+
+    let ordering = compare_versions(python, version);
+    match op {
+        ">=" => ordering != Ordering::Less,
+        ">" => ordering == Ordering::Greater,
+        "<=" => ordering != Ordering::Greater,
+        "<" => ordering == Ordering::Less,
+        "==" | "===" => ordering == Ordering::Equal,
+        "!=" => ordering != Ordering::Equal,
+        "~=" => {
+            // ~=3.8 means >=3.8 and ==3.*
+            let prefix = version.rsplit_once('.').map_or(version, |(head, _)| head);
+            ordering != Ordering::Less && has_release_prefix(python, prefix)
+        }
+        _ => true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,7 +399,27 @@ mod tests {
         assert!(filter.exclude.is_empty());
         assert!(filter.only.is_empty());
         assert!(!filter.include_pinned);
+        assert!(!filter.upgrade_pseudo);
         assert!(filter.min_age.is_none());
+        assert!(filter.min_age_dev.is_none());
+    }
+
+    #[test]
+    fn test_min_age_for() {
+        let week = Duration::from_secs(7 * 24 * 60 * 60);
+        let day = Duration::from_secs(24 * 60 * 60);
+
+        let filter = UpdateFilter::new().with_min_age(week);
+        assert_eq!(filter.min_age_for(false), Some(week));
+        assert_eq!(filter.min_age_for(true), Some(week));
+
+        let filter = filter.with_min_age_dev(day);
+        assert_eq!(filter.min_age_for(false), Some(week));
+        assert_eq!(filter.min_age_for(true), Some(day));
+
+        let filter = UpdateFilter::new().with_min_age_dev(day);
+        assert_eq!(filter.min_age_for(false), None);
+        assert_eq!(filter.min_age_for(true), Some(day));
     }
 
     #[test]
@@ -113,6 +442,12 @@ mod tests {
         assert_eq!(filter.only, vec!["foo"]);
     }
 
+    #[test]
+    fn test_with_upgrade_pseudo() {
+        let filter = UpdateFilter::new().with_upgrade_pseudo(true);
+        assert!(filter.upgrade_pseudo);
+    }
+
     #[test]
     fn test_with_include_pinned() {
         let filter = UpdateFilter::new().with_include_pinned(true);
@@ -157,6 +492,32 @@ mod tests {
         assert!(filter.should_process_package("bar"));
     }
 
+    #[test]
+    fn test_is_in_scope_npm() {
+        let filter = UpdateFilter::new().with_scopes(vec!["@mycompany/*".to_string()]);
+        assert!(filter.is_in_scope("@mycompany/ui"));
+        assert!(!filter.is_in_scope("@other/ui"));
+        assert!(!filter.is_in_scope("mycompany-ui"));
+
+        let filter = UpdateFilter::new().with_scopes(vec!["@mycompany".to_string()]);
+        assert!(filter.is_in_scope("@mycompany/ui"));
+        assert!(!filter.is_in_scope("@mycompany-labs/ui"));
+        assert!(UpdateFilter::new().is_in_scope("react"));
+    }
+
+    #[test]
+    fn test_is_in_scope_go_module() {
+        let filter = UpdateFilter::new().with_scopes(vec![
+            "github.com/myorg/".to_string(),
+            "example.com/*/internal".to_string(),
+        ]);
+        assert!(filter.is_in_scope("github.com/myorg/tool"));
+        assert!(filter.is_in_scope("github.com/myorg/tool/v2"));
+        assert!(!filter.is_in_scope("github.com/myorgx/tool"));
+        assert!(!filter.is_in_scope("github.com/other/tool"));
+        assert!(filter.is_in_scope("example.com/team/internal"));
+    }
+
     #[test]
     fn test_should_process_package_with_only() {
         let filter = UpdateFilter::new().with_only(vec!["foo".to_string()]);
@@ -174,6 +535,88 @@ mod tests {
         assert!(filter.should_process_package("foo"));
     }
 
+    #[test]
+    fn test_with_since() {
+        let since = chrono::Utc::now();
+        let filter = UpdateFilter::new().with_since(since);
+        assert_eq!(filter.since, Some(since));
+    }
+
+    #[test]
+    fn test_is_version_excluded() {
+        let filter = UpdateFilter::new().with_excluded_version("github.com/foo/bar", "v1.2.3");
+        assert!(filter.is_version_excluded("github.com/foo/bar", "v1.2.3"));
+        assert!(filter.is_version_excluded("github.com/foo/bar", "1.2.3"));
+        assert!(!filter.is_version_excluded("github.com/foo/bar", "v1.2.4"));
+        assert!(!filter.is_version_excluded("github.com/other/pkg", "v1.2.3"));
+    }
+
+    #[test]
+    fn test_prerelease_channel_parse() {
+        assert_eq!(
+            PrereleaseChannel::parse("strict"),
+            Ok(PrereleaseChannel::Strict)
+        );
+        assert_eq!(PrereleaseChannel::parse("any"), Ok(PrereleaseChannel::Any));
+        assert!(PrereleaseChannel::parse("canary").is_err());
+        assert_eq!(
+            UpdateFilter::new().prerelease_channel,
+            PrereleaseChannel::Any
+        );
+    }
+
+    #[test]
+    fn test_stability_parse_and_order() {
+        assert_eq!(Stability::parse("beta"), Some(Stability::Beta));
+        assert_eq!(Stability::parse("RC"), Some(Stability::Rc));
+        assert_eq!(Stability::parse("nightly"), None);
+        assert!(Stability::Dev < Stability::Alpha);
+        assert!(Stability::Rc < Stability::Stable);
+
+        assert_eq!(Stability::of_version("2.0.0"), Stability::Stable);
+        assert_eq!(Stability::of_version("2.0.0-beta.1"), Stability::Beta);
+        assert_eq!(Stability::of_version("v2.0.0-RC2"), Stability::Rc);
+        assert_eq!(Stability::of_version("2.0.0-alpha1"), Stability::Alpha);
+        assert_eq!(Stability::of_version("2.0.x-dev"), Stability::Dev);
+        assert_eq!(Stability::of_version("5.3.0.RELEASE"), Stability::Stable);
+        assert_eq!(Stability::of_version("2.0.0+build.5"), Stability::Stable);
+    }
+
+    #[test]
+    fn test_supports_project_python() {
+        let filter = UpdateFilter::new().with_requires_python(">=3.8");
+        assert!(filter.supports_project_python(Some(">=3.7")));
+        assert!(filter.supports_project_python(Some(">=3.8, <4")));
+        assert!(filter.supports_project_python(Some(">=2.7, !=3.0.*, !=3.1.*")));
+        assert!(filter.supports_project_python(None));
+        assert!(!filter.supports_project_python(Some(">=3.10")));
+        assert!(!filter.supports_project_python(Some("~=3.9")));
+        assert!(!filter.supports_project_python(Some("!=3.8.*")));
+
+        // No project requirement (or no lower bound): nothing is filtered
+        assert!(UpdateFilter::new().supports_project_python(Some(">=3.12")));
+        assert!(UpdateFilter::new()
+            .with_requires_python("<4")
+            .supports_project_python(Some(">=3.12")));
+    }
+
+    #[test]
+    fn test_supports_selected_python() {
+        let filter = UpdateFilter::new().with_python_version("3.9");
+        assert!(filter.supports_project_python(Some(">=3.8")));
+        assert!(!filter.supports_project_python(Some(">=3.11")));
+
+        // Both the declared floor and the interpreter must be supported
+        let filter = UpdateFilter::new()
+            .with_requires_python(">=3.8")
+            .with_python_version("3.12");
+        assert!(filter.supports_project_python(Some(">=3.8")));
+        assert!(!filter.supports_project_python(Some(">=3.10")));
+        assert!(!UpdateFilter::new()
+            .with_python_version("3.12")
+            .supports_project_python(Some("<3.12")));
+    }
+
     #[test]
     fn test_chained_builders() {
         let filter = UpdateFilter::new()
@@ -0,0 +1,225 @@
+//! Update groups for packages that should move together
+//!
+//! This module provides:
+//! - `--group NAME=GLOB,GLOB` parsing with `*`/`?` wildcards
+//! - A post-judge pass that labels updates of a group whose members can all update
+//! - Optional all-or-nothing mode that skips the whole group if a member is blocked
+
+use crate::domain::{ManifestUpdateResult, SkipReason, UpdateResult};
+
+/// A named set of package patterns (e.g., `babel=@babel/*`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateGroup {
+    /// Group name shown in the output
+    pub name: String,
+    /// Package name patterns (`*` matches any sequence, `?` a single character)
+    pub patterns: Vec<String>,
+}
+
+impl UpdateGroup {
+    /// Parse a `NAME=GLOB,GLOB` group definition
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let (name, patterns) = s
+            .split_once('=')
+            .ok_or_else(|| format!("invalid group '{}': expected NAME=GLOB[,GLOB...]", s))?;
+
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(format!("invalid group '{}': empty name", s));
+        }
+
+        let patterns: Vec<String> = patterns
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(str::to_string)
+            .collect();
+        if patterns.is_empty() {
+            return Err(format!("invalid group '{}': no package patterns", s));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            patterns,
+        })
+    }
+
+    /// Returns true if a package belongs to this group
+    pub fn matches(&self, package: &str) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| glob_match(pattern, package))
+    }
+}
+
+/// Match `text` against a pattern with `*` and `?` wildcards
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    glob_match_bytes(pattern.as_bytes(), text.as_bytes())
+}
+
+fn glob_match_bytes(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern.split_first(), text.split_first()) {
+        (None, None) => true,
+        (Some((b'*', rest)), _) => {
+            glob_match_bytes(rest, text)
+                || (!text.is_empty() && glob_match_bytes(pattern, &text[1..]))
+        }
+        (Some((b'?', rest)), Some((_, text_rest))) => glob_match_bytes(rest, text_rest),
+        (Some((p, rest)), Some((t, text_rest))) if p == t => glob_match_bytes(rest, text_rest),
+        _ => false,
+    }
+}
+
+/// Returns true if a skip means the member can't move with its group
+///
+/// Members that are already up to date or were filtered out by the user don't block.
+fn blocks_group(reason: &SkipReason) -> bool {
+    !matches!(
+        reason,
+        SkipReason::AlreadyLatest
+            | SkipReason::Excluded
+            | SkipReason::NotInOnlyList
+            | SkipReason::LanguageFiltered
+    )
+}
+
+/// Label or skip updates belonging to groups, across all manifests
+///
+/// A package belongs to the first group whose patterns match it. When no member
+/// of a group is blocked, its updates are labelled with the group name. When a
+/// member is blocked and `all_or_nothing` is set, the group's updates are turned
+/// into `GroupBlocked` skips.
+pub fn apply_groups(
+    manifests: &mut [ManifestUpdateResult],
+    groups: &[UpdateGroup],
+    all_or_nothing: bool,
+) {
+    let group_of = |result: &UpdateResult| {
+        groups
+            .iter()
+            .position(|group| group.matches(result.package_name()))
+    };
+
+    // Find groups with at least one blocked member
+    let mut blocked = vec![false; groups.len()];
+    for result in manifests.iter().flat_map(|m| &m.results) {
+        if let (Some(index), UpdateResult::Skip { reason, .. }) = (group_of(result), result) {
+            if blocks_group(reason) {
+                blocked[index] = true;
+            }
+        }
+    }
+
+    for result in manifests.iter_mut().flat_map(|m| m.results.iter_mut()) {
+        let Some(index) = group_of(result) else {
+            continue;
+        };
+        let UpdateResult::Update { dependency, .. } = result else {
+            continue;
+        };
+
+        let group = &groups[index];
+        if !blocked[index] {
+            result.set_group(&group.name);
+        } else if all_or_nothing {
+            *result = UpdateResult::skip(
+                dependency.clone(),
+                SkipReason::GroupBlocked(group.name.clone()),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Dependency, Language, VersionSpec, VersionSpecKind};
+
+    fn dependency(name: &str) -> Dependency {
+        let spec = VersionSpec::new(VersionSpecKind::Caret, "^7.0.0", "7.0.0").with_prefix("^");
+        Dependency::production(name, spec, Language::Node)
+    }
+
+    fn babel_group() -> UpdateGroup {
+        UpdateGroup::parse("babel=@babel/*,babel-*").unwrap()
+    }
+
+    #[test]
+    fn test_parse_group() {
+        let group = babel_group();
+        assert_eq!(group.name, "babel");
+        assert_eq!(group.patterns, vec!["@babel/*", "babel-*"]);
+
+        assert!(UpdateGroup::parse("babel").is_err());
+        assert!(UpdateGroup::parse("=@babel/*").is_err());
+        assert!(UpdateGroup::parse("babel= , ").is_err());
+    }
+
+    #[test]
+    fn test_group_matches() {
+        let group = babel_group();
+        assert!(group.matches("@babel/core"));
+        assert!(group.matches("babel-loader"));
+        assert!(!group.matches("@types/babel__core"));
+
+        let serde = UpdateGroup::parse("serde=serde,serde_derive,serde_?son").unwrap();
+        assert!(serde.matches("serde"));
+        assert!(serde.matches("serde_json"));
+        assert!(!serde.matches("serde_yaml"));
+    }
+
+    #[test]
+    fn test_apply_groups_all_members_update() {
+        let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+        manifest.add_result(UpdateResult::update(dependency("@babel/core"), "7.24.0"));
+        manifest.add_result(UpdateResult::update(
+            dependency("@babel/preset-env"),
+            "7.24.0",
+        ));
+        manifest.add_result(UpdateResult::skip_already_latest(dependency(
+            "babel-loader",
+        )));
+        manifest.add_result(UpdateResult::update(dependency("react"), "18.2.0"));
+        let mut manifests = vec![manifest];
+
+        apply_groups(&mut manifests, &[babel_group()], true);
+
+        let results = &manifests[0].results;
+        assert_eq!(results[0].group(), Some("babel"));
+        assert_eq!(results[1].group(), Some("babel"));
+        assert!(results[2].is_skip());
+        assert_eq!(results[3].group(), None);
+        assert_eq!(manifests[0].update_count(), 3);
+    }
+
+    #[test]
+    fn test_apply_groups_blocked_member() {
+        let build = || {
+            let mut manifest = ManifestUpdateResult::new("package.json", Language::Node);
+            manifest.add_result(UpdateResult::update(dependency("@babel/core"), "7.24.0"));
+            manifest.add_result(UpdateResult::skip(
+                dependency("@babel/preset-env"),
+                SkipReason::NoSuitableVersion,
+            ));
+            manifest.add_result(UpdateResult::update(dependency("react"), "18.2.0"));
+            vec![manifest]
+        };
+
+        // Without all-or-nothing the update stays but isn't reported as a group
+        let mut manifests = build();
+        apply_groups(&mut manifests, &[babel_group()], false);
+        assert!(manifests[0].results[0].is_update());
+        assert_eq!(manifests[0].results[0].group(), None);
+
+        // With all-or-nothing the whole group is held back
+        let mut manifests = build();
+        apply_groups(&mut manifests, &[babel_group()], true);
+        match &manifests[0].results[0] {
+            UpdateResult::Skip { reason, .. } => {
+                assert_eq!(reason, &SkipReason::GroupBlocked("babel".to_string()))
+            }
+            other => panic!("Expected skip, got {:?}", other),
+        }
+        assert!(manifests[0].results[2].is_update());
+    }
+}
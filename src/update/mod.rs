@@ -4,21 +4,31 @@
 //! - Update filter configuration from CLI args
 //! - Version info from registry with release date
 //! - Update judgment engine that decides whether to update or skip
+//! - Update groups applied after judgment (--group)
+//! - Lock drift judgment against the manifest range (--drift)
 
 mod filter;
+mod group;
 mod version_info;
 
-pub use filter::UpdateFilter;
-pub use version_info::{compare_versions, is_prerelease_version, VersionInfo};
+pub use filter::{PrereleaseChannel, Stability, StabilityPolicy, UpdateFilter};
+pub use group::{apply_groups, glob_match, UpdateGroup};
+pub use version_info::{compare_versions, is_prerelease_version, prerelease_channel, VersionInfo};
 
-use crate::domain::{Dependency, SkipReason, UpdateResult, VersionSpecKind};
+use crate::domain::{Dependency, Language, SkipReason, UpdateResult, VersionSpecKind};
+use crate::parser::{get_parser, is_pseudo_version};
 use chrono::{DateTime, Utc};
 use regex::Regex;
+use std::cmp::Ordering;
 use std::sync::LazyLock;
 
 /// Regex to extract upper bound from Range constraint (e.g., ">=3.5.0,<4.0.0" -> "4.0.0")
 static UPPER_BOUND_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<(\d+(?:\.\d+)*)").unwrap());
 
+/// Regex to extract upper bound from a Maven-style range (e.g., "[1.0,2.0)" -> "2.0", ")")
+static MAVEN_UPPER_BOUND_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[\[(][^,]*,\s*([^,\s\])]+)\s*([\])])$").unwrap());
+
 /// Extract upper bound version from a Range constraint string
 /// e.g., ">=3.5.0,<4.0.0" -> Some("4.0.0")
 fn extract_upper_bound(raw: &str) -> Option<String> {
@@ -28,6 +38,24 @@ fn extract_upper_bound(raw: &str) -> Option<String> {
         .map(|m| m.as_str().to_string())
 }
 
+/// Extract the upper bound of a Range constraint and whether it is inclusive
+/// e.g., "[1.0,2.0]" -> Some(("2.0", true)), ">=1.0,<2.0" -> Some(("2.0", false))
+pub(crate) fn extract_range_upper_bound(raw: &str) -> Option<(String, bool)> {
+    if let Some(caps) = MAVEN_UPPER_BOUND_RE.captures(raw) {
+        return Some((caps[1].to_string(), &caps[2] == "]"));
+    }
+    extract_upper_bound(raw).map(|bound| (bound, false))
+}
+
+/// Release a prerelease version leads up to (e.g., "1.0.0-rc.1" -> "1.0.0", "2.1b3" -> "2.1")
+fn release_base(version: &str) -> &str {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    let end = version
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(version.len());
+    version[..end].trim_end_matches('.')
+}
+
 /// Update judgment engine that decides whether to update a dependency
 pub struct UpdateJudge {
     /// Filter configuration
@@ -67,6 +95,38 @@ impl UpdateJudge {
             }
         }
 
+        // Check namespace filter (--scope)
+        if !self.filter.is_in_scope(&dependency.name) {
+            return Some(SkipReason::NotInOnlyList);
+        }
+
+        // Check parser-determined skips (e.g., path or VCS sources)
+        if let Some(reason) = &dependency.skip_reason {
+            return Some(reason.clone());
+        }
+
+        // Transitive requirements are managed by whatever requires them
+        if dependency.is_indirect && self.filter.only_direct {
+            return Some(SkipReason::Indirect);
+        }
+
+        // Unconstrained and range constraints can't be rewritten confidently
+        if matches!(
+            dependency.version_spec.kind,
+            VersionSpecKind::Any | VersionSpecKind::Range
+        ) && !self.filter.force_complex
+        {
+            return Some(SkipReason::ComplexConstraint);
+        }
+
+        // Go pseudo-versions track a specific commit, so keep them unless --upgrade-pseudo
+        if dependency.language == Language::Go
+            && !self.filter.upgrade_pseudo
+            && is_pseudo_version(&dependency.version_spec.raw)
+        {
+            return Some(SkipReason::PseudoVersion);
+        }
+
         // Check pinned version (unless --include-pinned or language always uses pinned versions)
         // Languages like Go and Java don't have range specifiers, so all versions are pinned.
         // For these languages, we should always include them even without --include-pinned.
@@ -86,54 +146,241 @@ impl UpdateJudge {
         dependency: &Dependency,
         available_versions: &[VersionInfo],
     ) -> UpdateResult {
+        let eligible_versions = match self.eligible_versions(dependency, available_versions) {
+            Ok(versions) => versions,
+            Err(reason) => return UpdateResult::skip(dependency.clone(), reason),
+        };
+
+        // --prefer-stable: leave a prerelease for the newest stable release that is at
+        // least its base version (1.0.0-rc.1 -> 1.0.0), even if newer prereleases exist
+        if self.filter.prefer_stable && is_prerelease_version(dependency.version()) {
+            let base = release_base(dependency.version());
+            let stable = eligible_versions
+                .iter()
+                .filter(|v| {
+                    !v.is_prerelease()
+                        && version_info::compare_versions(&v.version, base)
+                            != std::cmp::Ordering::Less
+                })
+                .max();
+            if let Some(stable) = stable {
+                return UpdateResult::update_with_date(
+                    dependency.clone(),
+                    &stable.version,
+                    stable.released_at,
+                );
+            }
+        }
+
+        // Find the latest eligible version (uses VersionInfo's Ord which does proper semver comparison)
+        let latest = eligible_versions.iter().max().unwrap();
+
+        // Check if already at latest or current version is newer (prevents downgrades)
+        // compare_versions returns Less if current < latest, so we only update in that case
+        if version_info::compare_versions(dependency.version(), &latest.version)
+            != std::cmp::Ordering::Less
+        {
+            return UpdateResult::skip_already_latest(dependency.clone());
+        }
+
+        // Return update result with release date
+        UpdateResult::update_with_date(dependency.clone(), &latest.version, latest.released_at)
+    }
+
+    /// Judge a security update: pick the lowest eligible version at or above `fixed_version`
+    ///
+    /// Used by --only-vulnerable so that a fix doesn't pull in unrelated upgrades.
+    pub fn judge_fix(
+        &self,
+        dependency: &Dependency,
+        available_versions: &[VersionInfo],
+        fixed_version: &str,
+    ) -> UpdateResult {
+        let eligible_versions = match self.eligible_versions(dependency, available_versions) {
+            Ok(versions) => versions,
+            Err(reason) => return UpdateResult::skip(dependency.clone(), reason),
+        };
+
+        let fix = eligible_versions
+            .into_iter()
+            .filter(|v| {
+                version_info::compare_versions(&v.version, fixed_version)
+                    != std::cmp::Ordering::Less
+                    && version_info::compare_versions(&v.version, dependency.version())
+                        == std::cmp::Ordering::Greater
+            })
+            .min();
+
+        match fix {
+            Some(fix) => {
+                UpdateResult::update_with_date(dependency.clone(), &fix.version, fix.released_at)
+            }
+            None => UpdateResult::skip(dependency.clone(), SkipReason::NoSuitableVersion),
+        }
+    }
+
+    /// Judge lock drift: the manifest range already allows a newer version than
+    /// the lock file resolves, so a plain install would pick it up (--drift)
+    ///
+    /// The newest in-range version, by the language parser's range rules, is
+    /// reported as the update. Dependencies without a locked version, or locked
+    /// at the newest in-range version, are skipped.
+    pub fn judge_drift(
+        &self,
+        dependency: &Dependency,
+        available_versions: &[VersionInfo],
+    ) -> UpdateResult {
+        let Some(locked) = dependency.resolved_version.as_deref() else {
+            return UpdateResult::skip(dependency.clone(), SkipReason::NoDrift);
+        };
+        let eligible_versions = match self.eligible_versions(dependency, available_versions) {
+            Ok(versions) => versions,
+            Err(reason) => return UpdateResult::skip(dependency.clone(), reason),
+        };
+
+        let parser = get_parser(dependency.language);
+        let newest_in_range = eligible_versions
+            .into_iter()
+            .filter(|v| parser.matches(&dependency.version_spec, &v.version))
+            .max();
+
+        match newest_in_range {
+            Some(newest)
+                if version_info::compare_versions(locked, &newest.version) == Ordering::Less =>
+            {
+                UpdateResult::update_with_date(
+                    dependency.clone(),
+                    &newest.version,
+                    newest.released_at,
+                )
+            }
+            _ => UpdateResult::skip(dependency.clone(), SkipReason::NoDrift),
+        }
+    }
+
+    /// Keep versions at or above the stability floor, preferring stable ones if asked
+    fn apply_stability<'a>(
+        &self,
+        dependency: &Dependency,
+        versions: impl Iterator<Item = &'a VersionInfo>,
+        policy: StabilityPolicy,
+    ) -> Vec<&'a VersionInfo> {
+        let allowed: Vec<&VersionInfo> = versions
+            .filter(|v| Stability::of_version(&v.version) >= policy.minimum)
+            .collect();
+
+        let is_newer_stable = |v: &&VersionInfo| {
+            Stability::of_version(&v.version) == Stability::Stable
+                && version_info::compare_versions(&v.version, dependency.version())
+                    == std::cmp::Ordering::Greater
+        };
+        if policy.prefer_stable && allowed.iter().any(is_newer_stable) {
+            allowed
+                .into_iter()
+                .filter(|v| Stability::of_version(&v.version) == Stability::Stable)
+                .collect()
+        } else {
+            allowed
+        }
+    }
+
+    /// Apply skip checks and version filters, returning the candidate versions
+    fn eligible_versions<'a>(
+        &self,
+        dependency: &Dependency,
+        available_versions: &'a [VersionInfo],
+    ) -> Result<Vec<&'a VersionInfo>, SkipReason> {
         // First check if we should skip this dependency
         if let Some(reason) = self.should_skip(dependency) {
-            return UpdateResult::skip(dependency.clone(), reason);
+            return Err(reason);
         }
 
         // If no versions available, skip
         if available_versions.is_empty() {
-            return UpdateResult::skip(
-                dependency.clone(),
-                SkipReason::FetchFailed("no versions available".to_string()),
-            );
+            return Err(SkipReason::FetchFailed("no versions available".to_string()));
+        }
+
+        // Only consider versions released on or after --since, if specified
+        let recent_versions: Vec<&VersionInfo> = match self.filter.since {
+            Some(since) => available_versions
+                .iter()
+                .filter(|v| v.released_at >= since)
+                .collect(),
+            None => available_versions.iter().collect(),
+        };
+
+        if let Some(since) = self.filter.since {
+            if recent_versions.is_empty() {
+                return Err(SkipReason::NoReleaseSince(
+                    since.format("%Y-%m-%d").to_string(),
+                ));
+            }
         }
 
+        // Drop versions the manifest explicitly excludes (e.g., go.mod `exclude`),
+        // and versions that dropped the project's Python (pyproject.toml `requires-python`)
+        let allowed_versions = recent_versions.into_iter().filter(|v| {
+            !self
+                .filter
+                .is_version_excluded(&dependency.name, &v.version)
+                && self
+                    .filter
+                    .supports_project_python(v.requires_python.as_deref())
+        });
+
         // Filter out pre-release versions (alpha, beta, canary, dev, etc.) by default
         // Only consider stable releases unless the current version is already a prerelease
         let current_is_prerelease = is_prerelease_version(dependency.version());
-        let stable_versions: Vec<&VersionInfo> = if current_is_prerelease {
-            // If current version is prerelease, allow prerelease updates
-            available_versions.iter().collect()
+        let stable_versions: Vec<&VersionInfo> = if let Some(policy) = self.filter.stability {
+            // The manifest's stability floor (composer.json) decides instead
+            self.apply_stability(dependency, allowed_versions, policy)
+        } else if current_is_prerelease {
+            match self.filter.prerelease_channel {
+                // If current version is prerelease, allow prerelease updates
+                PrereleaseChannel::Any => allowed_versions.collect(),
+                // Otherwise stay on the current channel (e.g., canary), or move to stable
+                PrereleaseChannel::Strict => {
+                    let channel = prerelease_channel(dependency.version());
+                    allowed_versions
+                        .filter(|v| !v.is_prerelease() || prerelease_channel(&v.version) == channel)
+                        .collect()
+                }
+            }
+        } else if self.filter.include_prerelease {
+            // Prereleases were asked for explicitly (--include-prerelease)
+            allowed_versions.collect()
         } else {
             // Otherwise, only consider stable versions
-            available_versions
-                .iter()
-                .filter(|v| !v.is_prerelease())
-                .collect()
+            allowed_versions.filter(|v| !v.is_prerelease()).collect()
         };
 
-        // Filter versions by age if specified
-        let age_filtered: Vec<&VersionInfo> = if let Some(min_age) = self.filter.min_age {
-            let min_release_time = self.now - chrono::Duration::from_std(min_age).unwrap();
-            stable_versions
-                .into_iter()
-                .filter(|v| v.released_at <= min_release_time)
-                .collect()
-        } else {
-            stable_versions
-        };
+        // Filter versions by age if specified (dev dependencies may use a separate age)
+        let age_filtered: Vec<&VersionInfo> =
+            if let Some(min_age) = self.filter.min_age_for(dependency.is_dev) {
+                let min_release_time = self.now - chrono::Duration::from_std(min_age).unwrap();
+                stable_versions
+                    .into_iter()
+                    .filter(|v| v.released_at <= min_release_time)
+                    .collect()
+            } else {
+                stable_versions
+            };
 
         // Filter versions by Range constraint upper bound if applicable
         // e.g., for ">=3.5.0,<4.0.0", exclude versions >= 4.0.0
         let eligible_versions: Vec<&VersionInfo> =
             if dependency.version_spec.kind == VersionSpecKind::Range {
-                if let Some(upper_bound) = extract_upper_bound(&dependency.version_spec.raw) {
+                if let Some((upper_bound, inclusive)) =
+                    extract_range_upper_bound(&dependency.version_spec.raw)
+                {
                     age_filtered
                         .into_iter()
                         .filter(|v| {
-                            version_info::compare_versions(&v.version, &upper_bound)
-                                == std::cmp::Ordering::Less
+                            match version_info::compare_versions(&v.version, &upper_bound) {
+                                std::cmp::Ordering::Less => true,
+                                std::cmp::Ordering::Equal => inclusive,
+                                std::cmp::Ordering::Greater => false,
+                            }
                         })
                         .collect()
                 } else {
@@ -144,29 +391,18 @@ impl UpdateJudge {
             };
 
         if eligible_versions.is_empty() {
-            return UpdateResult::skip(dependency.clone(), SkipReason::NoSuitableVersion);
-        }
-
-        // Find the latest eligible version (uses VersionInfo's Ord which does proper semver comparison)
-        let latest = eligible_versions.iter().max().unwrap();
-
-        // Check if already at latest or current version is newer (prevents downgrades)
-        // compare_versions returns Less if current < latest, so we only update in that case
-        if version_info::compare_versions(dependency.version(), &latest.version)
-            != std::cmp::Ordering::Less
-        {
-            return UpdateResult::skip_already_latest(dependency.clone());
+            return Err(SkipReason::NoSuitableVersion);
         }
 
-        // Return update result with release date
-        UpdateResult::update_with_date(dependency.clone(), &latest.version, latest.released_at)
+        Ok(eligible_versions)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{Language, VersionSpec, VersionSpecKind};
+    use crate::domain::{VersionSpec, VersionSpecKind};
+    use crate::parser::{PythonVersionParser, VersionParser};
     use chrono::TimeZone;
     use std::time::Duration;
 
@@ -234,6 +470,101 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_judge_fix_picks_lowest_fixed_version() {
+        let judge = UpdateJudge::new(UpdateFilter::new());
+
+        let dep = make_dependency("lodash", "4.17.15", Language::Node, false);
+        let versions = vec![
+            make_version_info("4.17.15", 400),
+            make_version_info("4.17.19", 300),
+            make_version_info("4.17.21", 200),
+            make_version_info("5.0.0", 10),
+        ];
+
+        let result = judge.judge_fix(&dep, &versions, "4.17.19");
+        assert!(result.is_update());
+        if let UpdateResult::Update { new_version, .. } = result {
+            assert_eq!(new_version, "4.17.19");
+        }
+    }
+
+    #[test]
+    fn test_judge_drift_reports_newest_in_range() {
+        let judge = UpdateJudge::new(UpdateFilter::new());
+
+        let dep = make_dependency("lodash", "4.17.0", Language::Node, false)
+            .with_resolved_version("4.17.5");
+        let versions = vec![
+            make_version_info("4.17.0", 400),
+            make_version_info("4.17.5", 300),
+            make_version_info("4.17.21", 200),
+            make_version_info("5.0.0", 10),
+        ];
+
+        match judge.judge_drift(&dep, &versions) {
+            UpdateResult::Update { new_version, .. } => assert_eq!(new_version, "4.17.21"),
+            other => panic!("Expected drift update, got {:?}", other),
+        }
+
+        // Locked at the newest in-range version: nothing to pick up
+        let dep = dep.with_resolved_version("4.17.21");
+        match judge.judge_drift(&dep, &versions) {
+            UpdateResult::Skip { reason, .. } => assert_eq!(reason, SkipReason::NoDrift),
+            other => panic!("Expected skip, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_judge_drift_without_lock() {
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        let dep = make_dependency("lodash", "4.17.0", Language::Node, false);
+        let versions = vec![make_version_info("4.17.21", 200)];
+
+        match judge.judge_drift(&dep, &versions) {
+            UpdateResult::Skip { reason, .. } => assert_eq!(reason, SkipReason::NoDrift),
+            other => panic!("Expected skip, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_judge_drift_follows_language_range_rules() {
+        let judge = UpdateJudge::new(UpdateFilter::new());
+
+        // PEP 440 `~=1.2` allows any 1.x, unlike npm's `~1.2`
+        let spec = PythonVersionParser.parse("~=1.2").unwrap();
+        let dep =
+            Dependency::new("requests", spec, false, Language::Python).with_resolved_version("1.2");
+        let versions = vec![
+            make_version_info("1.2", 300),
+            make_version_info("1.9", 200),
+            make_version_info("2.0", 10),
+        ];
+
+        match judge.judge_drift(&dep, &versions) {
+            UpdateResult::Update { new_version, .. } => assert_eq!(new_version, "1.9"),
+            other => panic!("Expected drift update, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_judge_fix_without_fixed_release() {
+        let judge = UpdateJudge::new(UpdateFilter::new());
+
+        let dep = make_dependency("lodash", "4.17.15", Language::Node, false);
+        let versions = vec![
+            make_version_info("4.17.15", 400),
+            make_version_info("4.17.16", 300),
+        ];
+
+        let result = judge.judge_fix(&dep, &versions, "4.17.19");
+        if let UpdateResult::Skip { reason, .. } = result {
+            assert_eq!(reason, SkipReason::NoSuitableVersion);
+        } else {
+            panic!("Expected skip");
+        }
+    }
+
     #[test]
     fn test_judge_skip_pinned() {
         let filter = UpdateFilter::new();
@@ -291,6 +622,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_judge_scope() {
+        let filter = UpdateFilter::new().with_scopes(vec!["@mycompany/*".to_string()]);
+        let judge = UpdateJudge::new(filter);
+        let versions = vec![make_version_info("2.0.0", 10)];
+
+        let dep = make_dependency("react", "1.0.0", Language::Node, false);
+        assert!(matches!(
+            judge.judge(&dep, &versions),
+            UpdateResult::Skip {
+                reason: SkipReason::NotInOnlyList,
+                ..
+            }
+        ));
+
+        let dep = make_dependency("@mycompany/ui", "1.0.0", Language::Node, false);
+        assert!(judge.judge(&dep, &versions).is_update());
+    }
+
     #[test]
     fn test_judge_only_list_match() {
         let filter = UpdateFilter::new().with_only(vec!["lodash".to_string()]);
@@ -374,6 +724,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_judge_dev_age_filter() {
+        let now = fixed_time();
+        let filter = UpdateFilter::new()
+            .with_min_age(Duration::from_secs(14 * 24 * 60 * 60)) // 14 days
+            .with_min_age_dev(Duration::from_secs(2 * 24 * 60 * 60)); // 2 days
+        let judge = UpdateJudge::with_time(filter, now);
+
+        // Released 5 days ago: old enough for dev dependencies only
+        let versions = vec![make_version_info_at(
+            "2.0.0",
+            now - chrono::Duration::days(5),
+        )];
+
+        let mut dev = make_dependency("eslint", "1.0.0", Language::Node, false);
+        dev.is_dev = true;
+        let result = judge.judge(&dev, &versions);
+        assert!(result.is_update());
+        if let UpdateResult::Update { new_version, .. } = result {
+            assert_eq!(new_version, "2.0.0");
+        }
+
+        let prod = make_dependency("lodash", "1.0.0", Language::Node, false);
+        let result = judge.judge(&prod, &versions);
+        if let UpdateResult::Skip { reason, .. } = result {
+            assert_eq!(reason, SkipReason::NoSuitableVersion);
+        } else {
+            panic!("Expected Skip variant");
+        }
+    }
+
     #[test]
     fn test_judge_no_versions() {
         let filter = UpdateFilter::new();
@@ -407,6 +788,16 @@ mod tests {
         assert_eq!(judge.should_skip(&dep), Some(SkipReason::Pinned));
     }
 
+    #[test]
+    fn test_should_skip_returns_parser_skip_reason() {
+        let filter = UpdateFilter::new();
+        let judge = UpdateJudge::new(filter);
+
+        let dep = make_dependency("vendor/local", "1.0.0", Language::Php, false)
+            .with_skip_reason(SkipReason::NonRegistrySource);
+        assert_eq!(judge.should_skip(&dep), Some(SkipReason::NonRegistrySource));
+    }
+
     #[test]
     fn test_should_skip_go_always_pinned_language() {
         // Go only supports exact versions, so pinned deps should NOT be skipped
@@ -640,6 +1031,150 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_judge_prefer_stable() {
+        let spec = VersionSpec::new(VersionSpecKind::Caret, "^1.0.0-rc.1", "1.0.0-rc.1");
+        let dep = Dependency::new("lib", spec, false, Language::Node);
+        let versions = vec![
+            make_version_info("0.9.0", 40),
+            make_version_info("1.0.0-rc.1", 30),
+            make_version_info("1.0.0", 20),
+            make_version_info("1.1.0-beta.1", 10),
+        ];
+
+        // By default the rc sorts above 1.0.0 and only the beta is newer
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        assert!(matches!(
+            judge.judge(&dep, &versions),
+            UpdateResult::Update { ref new_version, .. } if new_version == "1.1.0-beta.1"
+        ));
+
+        // --prefer-stable offers the stable release of the rc's base version
+        let judge = UpdateJudge::new(UpdateFilter::new().with_prefer_stable(true));
+        assert!(matches!(
+            judge.judge(&dep, &versions),
+            UpdateResult::Update { ref new_version, .. } if new_version == "1.0.0"
+        ));
+
+        // Older stable releases are never offered; newer prereleases still are
+        let without_stable: Vec<VersionInfo> = versions
+            .iter()
+            .filter(|v| v.version != "1.0.0")
+            .cloned()
+            .collect();
+        assert!(matches!(
+            judge.judge(&dep, &without_stable),
+            UpdateResult::Update { ref new_version, .. } if new_version == "1.1.0-beta.1"
+        ));
+    }
+
+    #[test]
+    fn test_release_base() {
+        assert_eq!(release_base("1.0.0-rc.1"), "1.0.0");
+        assert_eq!(release_base("v2.0.0-beta"), "2.0.0");
+        assert_eq!(release_base("2.1b3"), "2.1");
+        assert_eq!(release_base("1.2.3"), "1.2.3");
+    }
+
+    #[test]
+    fn test_judge_strict_prerelease_channel() {
+        let spec = VersionSpec::new(
+            VersionSpecKind::Caret,
+            "^19.3.0-canary-123",
+            "19.3.0-canary-123",
+        );
+        let dep = Dependency::new("react", spec, false, Language::Node);
+        let versions = vec![
+            make_version_info("19.3.0-canary-123", 30),
+            make_version_info("19.3.0-canary-456", 20),
+            make_version_info("19.4.0-beta.1", 10),
+        ];
+
+        // Any channel: the beta sorts highest and is offered
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        assert!(matches!(
+            judge.judge(&dep, &versions),
+            UpdateResult::Update { ref new_version, .. } if new_version == "19.4.0-beta.1"
+        ));
+
+        // Strict: a canary user is not offered the beta
+        let filter = UpdateFilter::new().with_prerelease_channel(PrereleaseChannel::Strict);
+        let judge = UpdateJudge::new(filter);
+        assert!(matches!(
+            judge.judge(&dep, &versions),
+            UpdateResult::Update { ref new_version, .. } if new_version == "19.3.0-canary-456"
+        ));
+
+        // Strict still allows moving to a stable release
+        let mut with_stable = versions.clone();
+        with_stable.push(make_version_info("19.4.1", 5));
+        assert!(matches!(
+            judge.judge(&dep, &with_stable),
+            UpdateResult::Update { ref new_version, .. } if new_version == "19.4.1"
+        ));
+    }
+
+    #[test]
+    fn test_judge_include_prerelease() {
+        let dep = make_dependency("org.example:widget", "2.0.0", Language::Java, false);
+        let versions = vec![
+            make_version_info("2.0.0", 30),
+            make_version_info("2.1.0-rc.1", 10),
+        ];
+
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        assert!(matches!(
+            judge.judge(&dep, &versions),
+            UpdateResult::Skip {
+                reason: SkipReason::AlreadyLatest,
+                ..
+            }
+        ));
+
+        let judge = UpdateJudge::new(UpdateFilter::new().with_include_prerelease(true));
+        assert!(matches!(
+            judge.judge(&dep, &versions),
+            UpdateResult::Update { ref new_version, .. } if new_version == "2.1.0-rc.1"
+        ));
+    }
+
+    #[test]
+    fn test_judge_composer_minimum_stability() {
+        let dep = make_dependency("symfony/console", "6.4.0", Language::Php, false);
+        let versions = vec![
+            make_version_info("6.4.0", 60),
+            make_version_info("7.0.0-beta1", 20),
+            make_version_info("7.0.0-alpha1", 30),
+        ];
+        let policy = |minimum, prefer_stable| StabilityPolicy {
+            minimum,
+            prefer_stable,
+        };
+
+        // Stable-only project: never offered the beta
+        let judge =
+            UpdateJudge::new(UpdateFilter::new().with_stability(policy(Stability::Stable, false)));
+        assert!(!judge.judge(&dep, &versions).is_update());
+
+        // Beta-allowing project: offered the beta, but not the alpha
+        let judge =
+            UpdateJudge::new(UpdateFilter::new().with_stability(policy(Stability::Beta, false)));
+        assert!(matches!(
+            judge.judge(&dep, &versions),
+            UpdateResult::Update { ref new_version, .. } if new_version == "7.0.0-beta1"
+        ));
+
+        // prefer-stable picks a newer stable release over a newer beta
+        let mut with_stable = versions.clone();
+        with_stable.push(make_version_info("6.4.1", 40));
+        let judge =
+            UpdateJudge::new(UpdateFilter::new().with_stability(policy(Stability::Beta, true)));
+        assert!(matches!(
+            judge.judge(&dep, &with_stable),
+            UpdateResult::Update { ref new_version, .. } if new_version == "6.4.1"
+        ));
+    }
+
     #[test]
     fn test_judge_no_suitable_stable_version() {
         // If all newer versions are prerelease, and current is stable, no suitable version
@@ -682,6 +1217,85 @@ mod tests {
         assert_eq!(super::extract_upper_bound(">1.0"), None);
     }
 
+    #[test]
+    fn test_extract_range_upper_bound() {
+        assert_eq!(
+            super::extract_range_upper_bound("[1.0,2.0)"),
+            Some(("2.0".to_string(), false))
+        );
+        assert_eq!(
+            super::extract_range_upper_bound("[5.2.0, 5.3.8]"),
+            Some(("5.3.8".to_string(), true))
+        );
+        assert_eq!(
+            super::extract_range_upper_bound("(,1.0]"),
+            Some(("1.0".to_string(), true))
+        );
+        assert_eq!(super::extract_range_upper_bound("[1.0,)"), None);
+        assert_eq!(
+            super::extract_range_upper_bound(">=1.0,<2.0"),
+            Some(("2.0".to_string(), false))
+        );
+    }
+
+    #[test]
+    fn test_judge_complex_constraints_skipped_by_default() {
+        use crate::manifest::{GemfileParser, ManifestParser};
+
+        let deps = GemfileParser
+            .parse("gem 'rails'\ngem 'pg', '>= 0.18', '< 2.0'\n")
+            .unwrap();
+        assert_eq!(deps[0].version_spec.kind, VersionSpecKind::Any);
+        assert_eq!(deps[1].version_spec.kind, VersionSpecKind::Range);
+        let versions = vec![
+            make_version_info("0.18.0", 300),
+            make_version_info("1.5.0", 50),
+            make_version_info("2.0.0", 10),
+        ];
+
+        let judge = UpdateJudge::new(UpdateFilter::new());
+        for dep in &deps {
+            assert_eq!(
+                judge.judge(dep, &versions),
+                UpdateResult::skip(dep.clone(), SkipReason::ComplexConstraint)
+            );
+        }
+
+        // --force-complex keeps the previous behavior
+        let judge = UpdateJudge::new(UpdateFilter::new().with_force_complex(true));
+        assert!(deps.iter().all(|dep| judge.judge(dep, &versions)
+            != UpdateResult::skip(dep.clone(), SkipReason::ComplexConstraint)));
+    }
+
+    #[test]
+    fn test_judge_maven_range_respects_upper_bound() {
+        let filter = UpdateFilter::new().with_force_complex(true);
+        let judge = UpdateJudge::new(filter);
+
+        let dep = make_range_dependency("org.example:lib", "[1.0,2.0)", "1.0", Language::Java);
+        let versions = vec![
+            make_version_info("1.0", 100),
+            make_version_info("1.9", 50),
+            make_version_info("2.0", 10),
+        ];
+
+        let result = judge.judge(&dep, &versions);
+        if let UpdateResult::Update { new_version, .. } = result {
+            assert_eq!(new_version, "1.9");
+        } else {
+            panic!("Expected Update variant");
+        }
+
+        // Inclusive upper bound allows the bound itself
+        let dep = make_range_dependency("org.example:lib", "[1.0,2.0]", "1.0", Language::Java);
+        let result = judge.judge(&dep, &versions);
+        if let UpdateResult::Update { new_version, .. } = result {
+            assert_eq!(new_version, "2.0");
+        } else {
+            panic!("Expected Update variant");
+        }
+    }
+
     fn make_range_dependency(
         name: &str,
         raw: &str,
@@ -695,7 +1309,7 @@ mod tests {
     #[test]
     fn test_judge_range_respects_upper_bound() {
         // Regression test: paramiko>=3.5.0,<4.0.0 should NOT update to 4.0.0
-        let filter = UpdateFilter::new();
+        let filter = UpdateFilter::new().with_force_complex(true);
         let judge = UpdateJudge::new(filter);
 
         let dep = make_range_dependency("paramiko", ">=3.5.0,<4.0.0", "3.5.0", Language::Python);
@@ -717,7 +1331,7 @@ mod tests {
     #[test]
     fn test_judge_range_already_at_max_within_bound() {
         // If already at latest version within bound, should skip
-        let filter = UpdateFilter::new();
+        let filter = UpdateFilter::new().with_force_complex(true);
         let judge = UpdateJudge::new(filter);
 
         let dep = make_range_dependency("paramiko", ">=3.5.0,<4.0.0", "3.9.0", Language::Python);
@@ -739,7 +1353,7 @@ mod tests {
     #[test]
     fn test_judge_range_no_suitable_version_all_above_bound() {
         // If all newer versions are above the upper bound, no suitable version
-        let filter = UpdateFilter::new();
+        let filter = UpdateFilter::new().with_force_complex(true);
         let judge = UpdateJudge::new(filter);
 
         let dep = make_range_dependency("package", ">=1.0,<2.0", "1.0", Language::Python);
@@ -762,7 +1376,7 @@ mod tests {
         // Range without upper bound (just >=) should update to latest
         // Note: This is technically not a proper Range in our parser,
         // but testing defensive behavior
-        let filter = UpdateFilter::new();
+        let filter = UpdateFilter::new().with_force_complex(true);
         let judge = UpdateJudge::new(filter);
 
         // Create a Range spec without upper bound (edge case)
@@ -825,4 +1439,110 @@ mod tests {
             assert_eq!(new_version, "1.49.0");
         }
     }
+
+    #[test]
+    fn test_judge_skips_excluded_version() {
+        // go.mod `exclude github.com/foo/bar v1.3.0` must never be suggested
+        let filter = UpdateFilter::new().with_excluded_version("github.com/foo/bar", "v1.3.0");
+        let judge = UpdateJudge::new(filter);
+
+        let dep = make_dependency("github.com/foo/bar", "v1.1.0", Language::Go, true);
+        let versions = vec![
+            make_version_info("v1.1.0", 100),
+            make_version_info("v1.2.0", 50),
+            make_version_info("v1.3.0", 20),
+        ];
+
+        let result = judge.judge(&dep, &versions);
+        if let UpdateResult::Update { new_version, .. } = result {
+            assert_eq!(new_version, "v1.2.0");
+        } else {
+            panic!("Expected Update variant");
+        }
+    }
+
+    #[test]
+    fn test_judge_since_picks_latest_recent_release() {
+        let now = fixed_time();
+        let since = now - chrono::Duration::days(30);
+        let filter = UpdateFilter::new().with_since(since);
+        let judge = UpdateJudge::with_time(filter, now);
+
+        let dep = make_dependency("lodash", "1.0.0", Language::Node, false);
+        let versions = vec![
+            make_version_info_at("1.5.0", now - chrono::Duration::days(60)),
+            make_version_info_at("1.6.0", now - chrono::Duration::days(10)),
+        ];
+
+        let result = judge.judge(&dep, &versions);
+        if let UpdateResult::Update { new_version, .. } = result {
+            assert_eq!(new_version, "1.6.0");
+        } else {
+            panic!("Expected Update variant");
+        }
+    }
+
+    #[test]
+    fn test_judge_since_skips_when_all_releases_predate_cutoff() {
+        let now = fixed_time();
+        let since = now - chrono::Duration::days(30);
+        let filter = UpdateFilter::new().with_since(since);
+        let judge = UpdateJudge::with_time(filter, now);
+
+        let dep = make_dependency("lodash", "1.0.0", Language::Node, false);
+        let versions = vec![
+            make_version_info_at("1.0.0", now - chrono::Duration::days(200)),
+            make_version_info_at("1.5.0", now - chrono::Duration::days(60)),
+        ];
+
+        let result = judge.judge(&dep, &versions);
+        if let UpdateResult::Skip { reason, .. } = result {
+            assert_eq!(
+                reason,
+                SkipReason::NoReleaseSince(since.format("%Y-%m-%d").to_string())
+            );
+        } else {
+            panic!("Expected Skip variant");
+        }
+    }
+
+    #[test]
+    fn test_judge_skips_go_pseudo_version_by_default() {
+        let judge = UpdateJudge::new(UpdateFilter::new());
+
+        let dep = make_dependency(
+            "golang.org/x/exp",
+            "v0.0.0-20231010123456-abcdef123456",
+            Language::Go,
+            true,
+        );
+        let versions = vec![make_version_info("v0.1.0", 10)];
+
+        let result = judge.judge(&dep, &versions);
+        if let UpdateResult::Skip { reason, .. } = result {
+            assert_eq!(reason, SkipReason::PseudoVersion);
+        } else {
+            panic!("Expected Skip variant");
+        }
+    }
+
+    #[test]
+    fn test_judge_upgrade_pseudo_offers_latest_tag() {
+        let judge = UpdateJudge::new(UpdateFilter::new().with_upgrade_pseudo(true));
+
+        let dep = make_dependency(
+            "golang.org/x/exp",
+            "v0.0.0-20231010123456-abcdef123456",
+            Language::Go,
+            true,
+        );
+        let versions = vec![make_version_info("v0.1.0", 10)];
+
+        let result = judge.judge(&dep, &versions);
+        if let UpdateResult::Update { new_version, .. } = result {
+            assert_eq!(new_version, "v0.1.0");
+        } else {
+            panic!("Expected Update variant");
+        }
+    }
 }
@@ -1,7 +1,7 @@
 //! Version information from registry
 //!
 //! This module provides the VersionInfo struct that represents
-//! a package version with its release date.
+//! a package version with its release date (and, for PyPI, its `Requires-Python`).
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -13,6 +13,9 @@ pub struct VersionInfo {
     pub version: String,
     /// When this version was released
     pub released_at: DateTime<Utc>,
+    /// Python versions this release supports (PyPI `requires_python`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires_python: Option<String>,
 }
 
 impl VersionInfo {
@@ -21,6 +24,7 @@ impl VersionInfo {
         Self {
             version: version.into(),
             released_at,
+            requires_python: None,
         }
     }
 
@@ -29,9 +33,16 @@ impl VersionInfo {
         Self {
             version: version.into(),
             released_at: Utc::now(),
+            requires_python: None,
         }
     }
 
+    /// Set the Python versions this release supports (builder pattern)
+    pub fn with_requires_python(mut self, requires_python: impl Into<String>) -> Self {
+        self.requires_python = Some(requires_python.into());
+        self
+    }
+
     /// Check if this version is a pre-release (alpha, beta, rc, canary, dev, etc.)
     pub fn is_prerelease(&self) -> bool {
         is_prerelease_version(&self.version)
@@ -54,9 +65,23 @@ const PRERELEASE_IDENTIFIERS: &[&str] = &[
     "experimental",
 ];
 
+/// Qualifiers that mark a stable release rather than a prerelease (Maven `5.3.0.RELEASE`,
+/// `6.4.0.Final`, `1.0.GA`)
+const STABLE_QUALIFIERS: &[&str] = &["release", "final", "ga"];
+
+/// Version without semver build metadata (e.g., "1.0.0+build.5" -> "1.0.0")
+///
+/// Build metadata never affects precedence, so it is ignored when comparing and
+/// classifying versions. Version strings themselves are written back unchanged.
+fn strip_build_metadata(version: &str) -> &str {
+    version
+        .split_once('+')
+        .map_or(version, |(release, _)| release)
+}
+
 /// Check if a version string represents a pre-release version
 pub fn is_prerelease_version(version: &str) -> bool {
-    let lower = version.to_lowercase();
+    let lower = strip_build_metadata(version).to_lowercase();
 
     // Check for word-based identifiers (alpha, beta, canary, etc.)
     if PRERELEASE_IDENTIFIERS.iter().any(|id| lower.contains(id)) {
@@ -80,6 +105,23 @@ pub fn is_prerelease_version(version: &str) -> bool {
     false
 }
 
+/// Extract the prerelease channel of a version (e.g., "19.3.0-canary-123" -> "canary")
+///
+/// The channel is the first alphabetic run after the release numbers, lowercased,
+/// so PEP 440 versions yield "a", "b" or "rc". Returns `None` if there is none,
+/// including for build metadata and stable qualifiers such as `.RELEASE` or `.Final`.
+pub fn prerelease_channel(version: &str) -> Option<String> {
+    let version = strip_build_metadata(version);
+    let version = version.strip_prefix('v').unwrap_or(version);
+    let start = version.find(|c: char| c.is_ascii_alphabetic())?;
+    let channel: String = version[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect::<String>()
+        .to_lowercase();
+    (!STABLE_QUALIFIERS.contains(&channel.as_str())).then_some(channel)
+}
+
 impl Ord for VersionInfo {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         // Compare by version using semver-like comparison
@@ -94,10 +136,12 @@ impl PartialOrd for VersionInfo {
 }
 
 /// Compare two version strings using semver-like rules
-/// Missing parts are treated as 0 (e.g., "1.0" == "1.0.0")
+/// Missing parts are treated as 0 (e.g., "1.0" == "1.0.0"), and build metadata
+/// is ignored (e.g., "1.0.0+a" == "1.0.0+b")
 pub fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
     let parse_parts = |s: &str| -> Vec<u64> {
-        // Remove leading 'v' if present
+        // Remove build metadata and a leading 'v' if present
+        let s = strip_build_metadata(s);
         let s = s.strip_prefix('v').unwrap_or(s);
         // Split by . and - and take only the numeric parts
         s.split(['.', '-']).filter_map(|p| p.parse().ok()).collect()
@@ -250,6 +294,23 @@ mod tests {
         assert!(compare_versions("10.0.0", "9.0.0") == std::cmp::Ordering::Greater);
     }
 
+    #[test]
+    fn test_compare_versions_ignores_build_metadata() {
+        assert_eq!(
+            compare_versions("1.0.0+a", "1.0.0+b"),
+            std::cmp::Ordering::Equal
+        );
+        assert_eq!(
+            compare_versions("1.0.0+build.5", "1.0.0"),
+            std::cmp::Ordering::Equal
+        );
+        assert_eq!(
+            compare_versions("1.0.0+build.5", "1.0.1+build.1"),
+            std::cmp::Ordering::Less
+        );
+        assert!(!is_prerelease_version("1.0.0+build.5"));
+    }
+
     #[test]
     fn test_serde_version_info() {
         let date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
@@ -301,6 +362,23 @@ mod tests {
         assert_eq!(max.version, "2.5.0");
     }
 
+    #[test]
+    fn test_prerelease_channel() {
+        assert_eq!(
+            prerelease_channel("19.3.0-canary-52684925-20251110"),
+            Some("canary".to_string())
+        );
+        assert_eq!(
+            prerelease_channel("v8.0.0-Beta.5"),
+            Some("beta".to_string())
+        );
+        assert_eq!(prerelease_channel("3.13.0rc1"), Some("rc".to_string()));
+        assert_eq!(prerelease_channel("1.2.3"), None);
+        assert_eq!(prerelease_channel("1.2.3+build.5"), None);
+        assert_eq!(prerelease_channel("5.3.0.RELEASE"), None);
+        assert_eq!(prerelease_channel("6.4.0.Final"), None);
+    }
+
     #[test]
     fn test_is_prerelease_stable_versions() {
         // Stable versions should NOT be detected as prerelease
@@ -143,6 +143,52 @@ mod dry_run_tests {
         );
     }
 
+    /// Test that --post-update hooks don't run in dry-run mode
+    #[cfg(unix)]
+    #[test]
+    fn test_post_update_hook_skipped_in_dry_run() {
+        let temp_dir = create_test_project();
+        let binary = get_binary_path();
+
+        let _output = Command::new(&binary)
+            .args([
+                "--dry-run",
+                "--post-update",
+                "touch hook-ran",
+                temp_dir.path().to_str().unwrap(),
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            !temp_dir.path().join("hook-ran").exists(),
+            "post-update hook should not run in dry-run mode"
+        );
+    }
+
+    /// Test that --post-update hooks don't run when nothing was written
+    #[cfg(unix)]
+    #[test]
+    fn test_post_update_hook_skipped_without_updates() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let binary = get_binary_path();
+
+        let output = Command::new(&binary)
+            .args([
+                "--post-update",
+                "touch hook-ran",
+                temp_dir.path().to_str().unwrap(),
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(output.status.success());
+        assert!(
+            !temp_dir.path().join("hook-ran").exists(),
+            "post-update hook should not run when no manifest was updated"
+        );
+    }
+
     /// Test that dry-run with specific language filter still leaves files unchanged
     #[test]
     fn test_dry_run_with_language_filter() {
@@ -291,7 +337,10 @@ mod json_output_tests {
                 manifest.get("updates").is_some(),
                 "Manifest should have 'updates' field"
             );
-            // Note: 'skips' field is only included in verbose mode and when non-empty
+            assert!(
+                manifest.get("skips").is_some(),
+                "Manifest should have 'skips' field"
+            );
 
             // Verify language is valid (display names)
             let language = manifest["language"].as_str().unwrap();
@@ -334,6 +383,75 @@ mod json_output_tests {
             "summary.updates should be 0 for empty directory"
         );
     }
+
+    /// Test --write-summary persists the JSON output to a file
+    #[test]
+    fn test_write_summary_json_file() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let summary_path = temp_dir.path().join("summary.json");
+        let binary = get_binary_path();
+
+        let output = Command::new(&binary)
+            .args([
+                "--dry-run",
+                "--json",
+                "--write-summary",
+                summary_path.to_str().unwrap(),
+                temp_dir.path().to_str().unwrap(),
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        // stdout still receives the output
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(serde_json::from_str::<serde_json::Value>(&stdout).is_ok());
+
+        let written = fs::read_to_string(&summary_path).expect("Summary file should exist");
+        let json: serde_json::Value =
+            serde_json::from_str(&written).expect("Summary file should be valid JSON");
+        assert!(json["manifests"].as_array().unwrap().is_empty());
+        assert_eq!(json["summary"]["updates"].as_i64(), Some(0));
+    }
+
+    /// Test --summary-json-to-stderr keeps text on stdout and adds JSON on stderr
+    #[test]
+    fn test_summary_json_to_stderr() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        // A local path dependency needs no registry lookup, but the manifest is reported
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"dependencies": {"local-lib": "file:../local-lib"}}"#,
+        )
+        .unwrap();
+        let binary = get_binary_path();
+
+        let output = Command::new(&binary)
+            .args([
+                "--dry-run",
+                "--summary-json-to-stderr",
+                temp_dir.path().to_str().unwrap(),
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("Summary"), "stdout: {}", stdout);
+        assert!(serde_json::from_str::<serde_json::Value>(&stdout).is_err());
+
+        // The JSON is a single line, so wrappers can pick it out of other stderr output
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let line = stderr
+            .lines()
+            .find(|line| line.starts_with('{'))
+            .expect("stderr should contain a JSON line");
+        let json: serde_json::Value =
+            serde_json::from_str(line).expect("stderr line should be valid JSON");
+        assert_eq!(json["dry_run"], true);
+        assert_eq!(json["summary"]["updates"].as_i64(), Some(0));
+        let manifests = json["manifests"].as_array().unwrap();
+        assert_eq!(manifests.len(), 1);
+        assert_eq!(manifests[0]["language"], "Node.js");
+    }
 }
 
 mod exit_code_tests {
@@ -358,6 +476,193 @@ mod exit_code_tests {
         );
     }
 
+    /// Serve a fixed npm packument for every request (used as a registry mirror)
+    fn serve_npm_packument(body: &'static str) -> String {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        format!("node=http://{}", addr)
+    }
+
+    /// Run --diff-exit-code on a package.json pinning lodash to `spec`
+    fn run_diff_exit_code(spec: &str) -> (Option<i32>, String) {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let package_json = format!(r#"{{"dependencies": {{"lodash": "{}"}}}}"#, spec);
+        fs::write(temp_dir.path().join("package.json"), &package_json).unwrap();
+
+        // The public registry is blocked by --allow-host, so only the local mirror answers
+        let mirror = serve_npm_packument(
+            r#"{"dist-tags":{"latest":"4.17.21"},"versions":{"4.0.0":{},"4.17.21":{}},"time":{"4.0.0":"2016-01-12T00:00:00.000Z","4.17.21":"2021-02-20T15:42:16.891Z"}}"#,
+        );
+        let binary = get_binary_path();
+
+        let output = Command::new(&binary)
+            .args([
+                "--diff-exit-code",
+                "--allow-host",
+                "127.0.0.1",
+                "--registry-mirror",
+                &mirror,
+                temp_dir.path().to_str().unwrap(),
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        // Implies dry-run: the manifest is never rewritten
+        let after = fs::read_to_string(temp_dir.path().join("package.json")).unwrap();
+        assert_eq!(after, package_json);
+
+        (
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        )
+    }
+
+    /// Test --diff-exit-code exits 1 when an update is pending
+    #[test]
+    fn test_diff_exit_code_with_pending_updates() {
+        let (code, stderr) = run_diff_exit_code("^4.0.0");
+        assert_eq!(code, Some(1), "stderr: {}", stderr);
+    }
+
+    /// Test --diff-exit-code exits 0 when everything is up to date
+    #[test]
+    fn test_diff_exit_code_without_pending_updates() {
+        let (code, stderr) = run_diff_exit_code("^4.17.21");
+        assert_eq!(code, Some(0), "stderr: {}", stderr);
+    }
+
+    /// Run --verbose on a package whose registry lookup is blocked by --allow-host
+    fn run_with_blocked_registry(extra_args: &[&str]) -> String {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"dependencies": {"lodash": "^4.0.0"}}"#,
+        )
+        .unwrap();
+        let binary = get_binary_path();
+
+        let mut args = vec!["--dry-run", "--verbose", "--allow-host", "127.0.0.1"];
+        args.extend_from_slice(extra_args);
+        args.push(temp_dir.path().to_str().unwrap());
+        let output = Command::new(&binary)
+            .args(&args)
+            .output()
+            .expect("Failed to execute command");
+
+        String::from_utf8_lossy(&output.stderr).to_string()
+    }
+
+    /// Test the verbose error listing leaves out fetch failures with --quiet-errors
+    #[test]
+    fn test_verbose_errors_respect_quiet_errors() {
+        let stderr = run_with_blocked_registry(&[]);
+        assert!(stderr.contains("Errors encountered:"), "stderr: {}", stderr);
+
+        let stderr = run_with_blocked_registry(&["--quiet-errors"]);
+        assert!(
+            !stderr.contains("Errors encountered:"),
+            "stderr: {}",
+            stderr
+        );
+        assert!(!stderr.contains("lodash"), "stderr: {}", stderr);
+    }
+
+    /// Test a package declared twice is a warning that keeps the normal exit code
+    #[test]
+    fn test_duplicate_dependency_exit_code() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"dependencies": {"lodash": "^4.17.21"}, "devDependencies": {"lodash": "^4.17.21"}}"#,
+        )
+        .unwrap();
+        let mirror = serve_npm_packument(
+            r#"{"dist-tags":{"latest":"4.17.21"},"versions":{"4.17.21":{}},"time":{"4.17.21":"2021-02-20T15:42:16.891Z"}}"#,
+        );
+        let binary = get_binary_path();
+
+        let output = Command::new(&binary)
+            .args([
+                "--dry-run",
+                "--allow-host",
+                "127.0.0.1",
+                "--registry-mirror",
+                &mirror,
+                temp_dir.path().to_str().unwrap(),
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(output.status.code(), Some(0), "stdout: {}", stdout);
+        assert!(
+            stdout.contains("Duplicate dependency lodash"),
+            "stdout: {}",
+            stdout
+        );
+    }
+
+    /// Run depup on a project with a broken Cargo.toml next to a valid package.json
+    fn run_with_broken_cargo_toml(extra_args: &[&str]) -> (Option<i32>, String) {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package\nname = \"broken\"\n",
+        )
+        .unwrap();
+        let package_json = r#"{"dependencies": {"lodash": "^4.0.0"}}"#;
+        fs::write(temp_dir.path().join("package.json"), package_json).unwrap();
+        let binary = get_binary_path();
+
+        let mut args = extra_args.to_vec();
+        args.push(temp_dir.path().to_str().unwrap());
+        let output = Command::new(&binary)
+            .args(&args)
+            .output()
+            .expect("Failed to execute command");
+
+        // The valid manifest is never rewritten when the run stops early
+        let after = fs::read_to_string(temp_dir.path().join("package.json")).unwrap();
+        if extra_args.contains(&"--strict-parse") {
+            assert_eq!(after, package_json);
+        }
+
+        (
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        )
+    }
+
+    /// Test --strict-parse exits 3 when a manifest fails to parse
+    #[test]
+    fn test_strict_parse_exit_code() {
+        let (code, stderr) = run_with_broken_cargo_toml(&["--strict-parse"]);
+        assert_eq!(code, Some(3), "stderr: {}", stderr);
+        assert!(stderr.contains("Cargo.toml"), "stderr: {}", stderr);
+    }
+
+    /// Test a parse failure without --strict-parse is reported as a partial failure
+    #[test]
+    fn test_parse_failure_without_strict_parse() {
+        let (code, stderr) = run_with_broken_cargo_toml(&["--dry-run", "--node", "--rust"]);
+        assert_eq!(code, Some(2), "stderr: {}", stderr);
+    }
+
     /// Test exit code with help flag
     #[test]
     fn test_exit_code_help() {